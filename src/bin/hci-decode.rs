@@ -0,0 +1,113 @@
+//! Prints human-readable descriptions of HCI packets read from the standard input.
+//!
+//! The input is either a btsnoop capture with the H4 datalink, or text containing hex dumps of H4
+//! packets (with their packet type bytes), such as a log of the UART traffic. Hex bytes may be
+//! separated by spaces or commas, and may carry a `0x` prefix. Words that are not hex, such as
+//! timestamps or `TX:` markers, are ignored.
+//!
+//! ```text
+//! $ echo "01 03 0c 00 04 0e 04 01 03 0c 00" | hci-decode
+//! < HCI Command: RESET (0x03|0x0003) plen 0
+//! > HCI Event: Command Complete (0x0e) plen 4
+//!     ...
+//! ```
+
+extern crate stm32wb_hci as hci;
+
+use hci::btsnoop::{Direction, Reader};
+use hci::decode::{describe, packets};
+use std::io::{self, Read};
+use std::process::ExitCode;
+use std::time::Duration;
+
+fn main() -> ExitCode {
+    let mut input = Vec::new();
+    if let Err(e) = io::stdin().read_to_end(&mut input) {
+        eprintln!("hci-decode: cannot read input: {}", e);
+        return ExitCode::FAILURE;
+    }
+
+    if input.starts_with(b"btsnoop\0") {
+        decode_capture(&input)
+    } else {
+        decode_hex(&String::from_utf8_lossy(&input))
+    }
+}
+
+fn decode_capture(input: &[u8]) -> ExitCode {
+    let reader = match Reader::new(input) {
+        Ok(reader) => reader,
+        Err(e) => {
+            eprintln!("hci-decode: bad capture: {:?}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut start = None;
+    for record in reader {
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                eprintln!("hci-decode: bad capture: {:?}", e);
+                return ExitCode::FAILURE;
+            }
+        };
+
+        let start = *start.get_or_insert(record.timestamp);
+        let elapsed = record
+            .timestamp
+            .duration_since(start)
+            .unwrap_or(Duration::ZERO);
+        let direction = match record.direction {
+            Direction::Sent => '<',
+            Direction::Received => '>',
+        };
+        println!(
+            "{} [{}.{:06}] {}",
+            direction,
+            elapsed.as_secs(),
+            elapsed.subsec_micros(),
+            describe(&record.data)
+        );
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn decode_hex(input: &str) -> ExitCode {
+    let bytes = parse_hex(input);
+    for packet in packets(&bytes) {
+        // Commands go to the controller and events come from it; ACL data may go either way.
+        let direction = match packet[0] {
+            0x01 => '<',
+            0x04 => '>',
+            _ => '*',
+        };
+        println!("{} {}", direction, describe(packet));
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Returns the bytes of every hex word of `input`.
+fn parse_hex(input: &str) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for word in input.split(|c: char| c.is_whitespace() || c == ',') {
+        let digits = word
+            .strip_prefix("0x")
+            .or_else(|| word.strip_prefix("0X"))
+            .unwrap_or(word);
+        if digits.is_empty()
+            || digits.len() % 2 != 0
+            || !digits.bytes().all(|b| b.is_ascii_hexdigit())
+        {
+            continue;
+        }
+
+        for i in (0..digits.len()).step_by(2) {
+            bytes.push(u8::from_str_radix(&digits[i..i + 2], 16).unwrap());
+        }
+    }
+
+    bytes
+}
@@ -0,0 +1,355 @@
+//! Blocking versions of the host-side HCI traits.
+
+use super::{Blocking, Controller};
+use crate::event::{NumberOfCompletedPackets, PowerControlPhy};
+use crate::host::executor::{CommandResponse, EventQueue};
+use crate::host::uart;
+use crate::host::{
+    AdvertisingParameters, AesParameters, AuthenticatedPayloadTimeout, CodedPhyOptions,
+    ConnectionParameters, ConnectionUpdateParameters, DhKeyPrivateKey, EncryptionKey,
+    EncryptionParameters, Error, EventFlags, EventFlagsPage2, ExtendedAdvertisingData,
+    ExtendedAdvertisingParameters, ExtendedConnectionParameters, ExtendedScanEnable,
+    ExtendedScanParameters, FlowControl, HostBufferSize, LeEventFlags, MaxTxOctets, MaxTxTime,
+    P256PublicKey, PathLossReportingParameters, PeriodicAdvertisingData,
+    PeriodicAdvertisingParameters, PeriodicAdvertisingSyncParameters,
+    PeriodicAdvertisingSyncTransferParameters, PhyMask, PrivacyMode, ResolvingListEntry,
+    ScanParameters, TestPacketPayload, TxPowerLevel,
+};
+use crate::types::extended_advertisement::AdvSet;
+use crate::{AdvertisingHandle, BdAddr, BdAddrType, ChannelClassification, ConnectionHandle};
+use crate::{Opcode, Status, SyncHandle};
+use core::time::Duration;
+
+blocking_trait! {
+    /// Blocking version of [`HostHci`](crate::host::HostHci).
+    ///
+    /// Each function writes the same command as its async counterpart and returns once the command
+    /// has been written.
+    pub trait HostHci: crate::host::HostHci {
+        fn disconnect(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            reason: Status,
+        ) -> Result<(), Error<Self::Error>>;
+        fn read_remote_version_information(
+            &mut self,
+            conn_handle: ConnectionHandle,
+        ) -> Result<(), Self::Error>;
+        fn set_event_mask(&mut self, mask: EventFlags) -> Result<(), Self::Error>;
+        fn reset(&mut self) -> Result<(), Self::Error>;
+        fn read_tx_power_level(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            power_level_type: TxPowerLevel,
+        ) -> Result<(), Self::Error>;
+        fn set_controller_to_host_flow_control(
+            &mut self,
+            flow_control: FlowControl,
+        ) -> Result<(), Self::Error>;
+        fn host_buffer_size(&mut self, params: HostBufferSize) -> Result<(), Self::Error>;
+        fn number_of_completed_packets(
+            &mut self,
+            params: NumberOfCompletedPackets,
+        ) -> Result<(), Self::Error>;
+        fn set_event_mask_page_2(&mut self, mask: EventFlagsPage2) -> Result<(), Self::Error>;
+        fn read_authenticated_payload_timeout(
+            &mut self,
+            conn_handle: ConnectionHandle,
+        ) -> Result<(), Self::Error>;
+        fn write_authenticated_payload_timeout(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            timeout: AuthenticatedPayloadTimeout,
+        ) -> Result<(), Self::Error>;
+        fn read_local_version_information(&mut self) -> Result<(), Self::Error>;
+        fn read_local_supported_commands(&mut self) -> Result<(), Self::Error>;
+        fn read_local_supported_features(&mut self) -> Result<(), Self::Error>;
+        fn read_bd_addr(&mut self) -> Result<(), Self::Error>;
+        fn read_rssi(&mut self, conn_handle: ConnectionHandle) -> Result<(), Self::Error>;
+        fn le_set_event_mask(&mut self, event_mask: LeEventFlags) -> Result<(), Self::Error>;
+        fn le_read_buffer_size(&mut self) -> Result<(), Self::Error>;
+        fn le_read_local_supported_features(&mut self) -> Result<(), Self::Error>;
+        fn le_set_random_address(&mut self, bd_addr: BdAddr) -> Result<(), Error<Self::Error>>;
+        fn le_set_advertising_parameters(
+            &mut self,
+            params: &AdvertisingParameters,
+        ) -> Result<(), Error<Self::Error>>;
+        fn le_read_advertising_channel_tx_power(&mut self) -> Result<(), Self::Error>;
+        fn le_set_advertising_data(&mut self, data: &[u8]) -> Result<(), Error<Self::Error>>;
+        fn le_set_scan_response_data(&mut self, data: &[u8]) -> Result<(), Error<Self::Error>>;
+        fn le_set_advertising_enable(&mut self, enable: bool) -> Result<(), Self::Error>;
+        fn le_set_scan_parameters(&mut self, params: &ScanParameters) -> Result<(), Self::Error>;
+        fn le_set_scan_enable(
+            &mut self,
+            enable: bool,
+            filter_duplicates: bool,
+        ) -> Result<(), Self::Error>;
+        fn le_create_connection(
+            &mut self,
+            params: &ConnectionParameters,
+        ) -> Result<(), Self::Error>;
+        fn le_create_connection_cancel(&mut self) -> Result<(), Self::Error>;
+        fn le_read_white_list_size(&mut self) -> Result<(), Self::Error>;
+        fn le_clear_white_list(&mut self) -> Result<(), Self::Error>;
+        fn le_add_device_to_white_list(&mut self, addr: BdAddrType) -> Result<(), Self::Error>;
+        fn le_add_anon_advertising_devices_to_white_list(&mut self) -> Result<(), Self::Error>;
+        fn le_remove_device_from_white_list(&mut self, addr: BdAddrType) -> Result<(), Self::Error>;
+        fn le_remove_anon_advertising_devices_from_white_list(&mut self) -> Result<(), Self::Error>;
+        fn le_connection_update(
+            &mut self,
+            params: &ConnectionUpdateParameters,
+        ) -> Result<(), Self::Error>;
+        fn le_set_host_channel_classification(
+            &mut self,
+            channels: ChannelClassification,
+        ) -> Result<(), Error<Self::Error>>;
+        fn le_read_channel_map(&mut self, conn_handle: ConnectionHandle) -> Result<(), Self::Error>;
+        fn le_read_remote_used_features(
+            &mut self,
+            conn_handle: ConnectionHandle,
+        ) -> Result<(), Self::Error>;
+        fn le_encrypt(&mut self, params: &AesParameters) -> Result<(), Self::Error>;
+        fn le_rand(&mut self) -> Result<(), Self::Error>;
+        fn le_start_encryption(&mut self, params: &EncryptionParameters) -> Result<(), Self::Error>;
+        fn le_long_term_key_request_reply(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            key: &EncryptionKey,
+        ) -> Result<(), Self::Error>;
+        fn le_long_term_key_request_negative_reply(
+            &mut self,
+            conn_handle: ConnectionHandle,
+        ) -> Result<(), Self::Error>;
+        fn le_read_supported_states(&mut self) -> Result<(), Self::Error>;
+        fn le_receiver_test(&mut self, channel: u8) -> Result<(), Error<Self::Error>>;
+        fn le_transmitter_test(
+            &mut self,
+            channel: u8,
+            payload_length: usize,
+            payload: TestPacketPayload,
+        ) -> Result<(), Error<Self::Error>>;
+        fn le_test_end(&mut self) -> Result<(), Self::Error>;
+        fn le_remote_connection_parameter_request_reply(
+            &mut self,
+            params: &ConnectionUpdateParameters,
+        ) -> Result<(), Self::Error>;
+        fn le_remote_connection_parameter_request_negative_reply(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            reason: Status,
+        ) -> Result<(), Self::Error>;
+        fn le_set_data_length(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            max_tx_octets: MaxTxOctets,
+            max_tx_time: MaxTxTime,
+        ) -> Result<(), Self::Error>;
+        fn le_read_suggested_default_data_length(&mut self) -> Result<(), Self::Error>;
+        fn le_write_suggested_default_data_length(
+            &mut self,
+            max_tx_octets: MaxTxOctets,
+            max_tx_time: MaxTxTime,
+        ) -> Result<(), Self::Error>;
+        fn le_read_maximum_data_length(&mut self) -> Result<(), Self::Error>;
+        fn le_read_phy(&mut self, conn_handle: ConnectionHandle) -> Result<(), Self::Error>;
+        fn le_set_default_phy(
+            &mut self,
+            tx_phys: Option<PhyMask>,
+            rx_phys: Option<PhyMask>,
+        ) -> Result<(), Error<Self::Error>>;
+        fn le_set_phy(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            tx_phys: Option<PhyMask>,
+            rx_phys: Option<PhyMask>,
+            coded_phy_options: CodedPhyOptions,
+        ) -> Result<(), Error<Self::Error>>;
+        fn le_set_extended_advertising_parameters(
+            &mut self,
+            params: &ExtendedAdvertisingParameters,
+        ) -> Result<(), Error<Self::Error>>;
+        fn le_set_extended_advertising_data(
+            &mut self,
+            data: &ExtendedAdvertisingData<'_>,
+        ) -> Result<(), Error<Self::Error>>;
+        fn le_set_extended_scan_response_data(
+            &mut self,
+            data: &ExtendedAdvertisingData<'_>,
+        ) -> Result<(), Error<Self::Error>>;
+        fn le_set_extended_advertising_enable(
+            &mut self,
+            enable: bool,
+            sets: &[AdvSet],
+        ) -> Result<(), Error<Self::Error>>;
+        fn le_read_maximum_advertising_data_length(&mut self) -> Result<(), Self::Error>;
+        fn le_read_number_of_supported_advertising_sets(&mut self) -> Result<(), Self::Error>;
+        fn le_remove_advertising_set(
+            &mut self,
+            adv_handle: AdvertisingHandle,
+        ) -> Result<(), Self::Error>;
+        fn le_clear_advertising_sets(&mut self) -> Result<(), Self::Error>;
+        fn le_set_periodic_advertising_parameters(
+            &mut self,
+            params: &PeriodicAdvertisingParameters,
+        ) -> Result<(), Error<Self::Error>>;
+        fn le_set_periodic_advertising_data(
+            &mut self,
+            data: &PeriodicAdvertisingData<'_>,
+        ) -> Result<(), Error<Self::Error>>;
+        fn le_set_periodic_advertising_enable(
+            &mut self,
+            enable: bool,
+            adv_handle: AdvertisingHandle,
+        ) -> Result<(), Self::Error>;
+        fn le_set_extended_scan_parameters(
+            &mut self,
+            params: &ExtendedScanParameters,
+        ) -> Result<(), Error<Self::Error>>;
+        fn le_set_extended_scan_enable(
+            &mut self,
+            params: &ExtendedScanEnable,
+        ) -> Result<(), Error<Self::Error>>;
+        fn le_extended_create_connection(
+            &mut self,
+            params: &ExtendedConnectionParameters,
+        ) -> Result<(), Error<Self::Error>>;
+        fn le_periodic_advertising_create_sync(
+            &mut self,
+            params: &PeriodicAdvertisingSyncParameters,
+        ) -> Result<(), Error<Self::Error>>;
+        fn le_periodic_advertising_create_sync_cancel(&mut self) -> Result<(), Self::Error>;
+        fn le_periodic_advertising_terminate_sync(
+            &mut self,
+            sync_handle: SyncHandle,
+        ) -> Result<(), Self::Error>;
+        fn le_periodic_advertising_sync_transfer(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            service_data: u16,
+            sync_handle: SyncHandle,
+        ) -> Result<(), Self::Error>;
+        fn le_periodic_advertising_set_info_transfer(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            service_data: u16,
+            adv_handle: AdvertisingHandle,
+        ) -> Result<(), Self::Error>;
+        fn le_set_periodic_advertising_sync_transfer_parameters(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            params: &PeriodicAdvertisingSyncTransferParameters,
+        ) -> Result<(), Error<Self::Error>>;
+        fn le_set_default_periodic_advertising_sync_transfer_parameters(
+            &mut self,
+            params: &PeriodicAdvertisingSyncTransferParameters,
+        ) -> Result<(), Error<Self::Error>>;
+        fn le_add_device_to_resolving_list(
+            &mut self,
+            entry: &ResolvingListEntry,
+        ) -> Result<(), Self::Error>;
+        fn le_remove_device_from_resolving_list(
+            &mut self,
+            peer_identity_address: BdAddrType,
+        ) -> Result<(), Self::Error>;
+        fn le_clear_resolving_list(&mut self) -> Result<(), Self::Error>;
+        fn le_read_resolving_list_size(&mut self) -> Result<(), Self::Error>;
+        fn le_read_peer_resolvable_address(
+            &mut self,
+            peer_identity_address: BdAddrType,
+        ) -> Result<(), Self::Error>;
+        fn le_read_local_resolvable_address(
+            &mut self,
+            peer_identity_address: BdAddrType,
+        ) -> Result<(), Self::Error>;
+        fn le_set_address_resolution_enable(&mut self, enable: bool) -> Result<(), Self::Error>;
+        fn le_set_resolvable_private_address_timeout(
+            &mut self,
+            timeout: Duration,
+        ) -> Result<(), Error<Self::Error>>;
+        fn le_set_privacy_mode(
+            &mut self,
+            peer_identity_address: BdAddrType,
+            privacy_mode: PrivacyMode,
+        ) -> Result<(), Self::Error>;
+        fn le_enhanced_read_transmit_power_level(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            phy: PowerControlPhy,
+        ) -> Result<(), Self::Error>;
+        fn le_read_remote_transmit_power_level(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            phy: PowerControlPhy,
+        ) -> Result<(), Self::Error>;
+        fn le_set_path_loss_reporting_parameters(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            params: &PathLossReportingParameters,
+        ) -> Result<(), Error<Self::Error>>;
+        fn le_set_path_loss_reporting_enable(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            enable: bool,
+        ) -> Result<(), Self::Error>;
+        fn le_set_transmit_power_reporting_enable(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            local_enable: bool,
+            remote_enable: bool,
+        ) -> Result<(), Self::Error>;
+        fn le_read_local_p256_public_key(&mut self) -> Result<(), Self::Error>;
+        fn le_generate_dhkey(
+            &mut self,
+            remote_public_key: &P256PublicKey,
+        ) -> Result<(), Self::Error>;
+        fn le_generate_dhkey_v2(
+            &mut self,
+            remote_public_key: &P256PublicKey,
+            private_key: DhKeyPrivateKey,
+        ) -> Result<(), Self::Error>;
+    }
+}
+
+blocking_trait! {
+    /// Blocking version of [`UartHci`](crate::host::uart::UartHci).
+    pub trait UartHci: uart::UartHci {
+        fn read(&mut self) -> Result<uart::Packet, uart::Error<Self::Error>>;
+    }
+}
+
+blocking_trait! {
+    /// Blocking version of [`AclHci`](crate::host::uart::AclHci).
+    pub trait AclHci: uart::AclHci {
+        fn write_acl_data(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            packet_boundary: uart::PacketBoundary,
+            data: &[u8],
+        ) -> Result<(), uart::Error<Self::Error>>;
+    }
+}
+
+blocking_trait! {
+    /// Blocking version of [`CommandExecutor`](crate::host::executor::CommandExecutor).
+    pub trait CommandExecutor: crate::host::executor::CommandExecutor {
+        fn execute(
+            &mut self,
+            opcode: Opcode,
+            params: &[u8],
+            queue: &mut impl EventQueue,
+        ) -> Result<CommandResponse, uart::Error<Self::Error>>;
+        fn wait_for_response(
+            &mut self,
+            opcode: Opcode,
+            queue: &mut impl EventQueue,
+        ) -> Result<CommandResponse, uart::Error<Self::Error>>;
+    }
+}
+
+impl<C: Controller> HostHci for Blocking<C> {}
+
+impl<C: Controller> UartHci for Blocking<C> {}
+
+impl<C: super::AclController> AclHci for Blocking<C> {}
+
+impl<C: Controller> CommandExecutor for Blocking<C> {}
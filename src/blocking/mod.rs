@@ -0,0 +1,204 @@
+//! Blocking (non-async) interface to the controller.
+//!
+//! Every command and event of this crate is implemented once, in terms of the async
+//! [`Controller`](crate::Controller) trait. Applications without an async executor implement the
+//! blocking [`Controller`] trait of this module instead and wrap their controller in [`Blocking`].
+//! The traits of this module, such as [`HostHci`] and [`GapCommands`], then provide every command
+//! as a blocking function that runs the async one to completion with [`block_on`], so both write
+//! the same bytes:
+//!
+//! ```
+//! # extern crate stm32wb_hci as hci;
+//! use hci::blocking::{Blocking, HostHci};
+//!
+//! struct Uart;
+//!
+//! impl hci::blocking::Controller for Uart {
+//!     type Error = ();
+//!
+//!     fn controller_write(&mut self, _opcode: hci::Opcode, _payload: &[u8]) -> Result<(), ()> {
+//!         // Write the command to the UART
+//!         Ok(())
+//!     }
+//!
+//!     fn controller_read_into(&self, _buf: &mut [u8]) -> Result<(), ()> {
+//!         // Read the packet from the UART
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let mut hci = Blocking(Uart);
+//! hci.reset().unwrap();
+//! ```
+//!
+//! Import these traits instead of their async counterparts; with both in scope, the method calls
+//! are ambiguous.
+//!
+//! Because the wrapped controller never suspends, [`block_on`] does not need a real executor: the
+//! future completes the first time it is polled. Other futures, including those that may suspend,
+//! can be run with [`block_on_with_idle`], which calls an idle hook between polls.
+
+use core::future::Future;
+use core::pin::pin;
+use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+/// Defines a blocking trait whose functions run the function of the same name of an async trait to
+/// completion with [`block_on`].
+macro_rules! blocking_trait {
+    (
+        $(#[$attr:meta])*
+        pub trait $name:ident: $async_trait:path {
+            $(
+                fn $function:ident(&mut self $(, $arg:ident: $ty:ty)* $(,)?) -> $ret:ty;
+            )*
+        }
+    ) => {
+        $(#[$attr])*
+        pub trait $name: $async_trait {
+            $(
+                #[doc = concat!(
+                    "Blocking version of [`",
+                    stringify!($function),
+                    "`](",
+                    stringify!($async_trait),
+                    "::",
+                    stringify!($function),
+                    ")."
+                )]
+                fn $function(&mut self $(, $arg: $ty)*) -> $ret {
+                    super::block_on(<Self as $async_trait>::$function(self $(, $arg)*))
+                }
+            )*
+        }
+    };
+}
+
+mod host;
+mod vendor;
+
+pub use self::host::{AclHci, CommandExecutor, HostHci, UartHci};
+pub use self::vendor::{GapCommands, GattCommands, HalCommands, L2capCommands};
+
+/// Blocking interface to the Bluetooth controller from the host's perspective.
+///
+/// This is the blocking equivalent of [`crate::Controller`]. Wrap implementors in [`Blocking`] to
+/// use them with the [`HostHci`], [`UartHci`] and vendor command traits of this module.
+pub trait Controller {
+    /// Enumeration of communication errors of the controller, such as bus or transport errors.
+    type Error;
+
+    /// Writes the bytes to the controller, in a single transaction if possible. See
+    /// [`crate::Controller::controller_write`].
+    ///
+    /// # Errors
+    ///
+    /// Returns any error that prevented the command from being written to the controller.
+    fn controller_write(
+        &mut self,
+        opcode: crate::Opcode,
+        payload: &[u8],
+    ) -> Result<(), Self::Error>;
+
+    /// Reads data from the controller into the provided `buf`, blocking until enough data is
+    /// available. See [`crate::Controller::controller_read_into`].
+    ///
+    /// # Errors
+    ///
+    /// Returns any error that prevented the bytes from being read from the controller.
+    fn controller_read_into(&self, buf: &mut [u8]) -> Result<(), Self::Error>;
+}
+
+/// Blocking interface to write ACL data packets to the Bluetooth controller.
+///
+/// This is the blocking equivalent of [`crate::AclController`].
+pub trait AclController: Controller {
+    /// Writes an ACL data packet to the controller. See
+    /// [`crate::AclController::controller_write_acl_data`].
+    ///
+    /// # Errors
+    ///
+    /// Returns any error that prevented the packet from being written to the controller.
+    fn controller_write_acl_data(
+        &mut self,
+        header: &crate::host::uart::AclDataHeader,
+        data: &[u8],
+    ) -> Result<(), Self::Error>;
+}
+
+/// Adapts a blocking [`Controller`] to the async [`crate::Controller`] trait.
+///
+/// The futures returned by the commands of a wrapped controller complete the first time they are
+/// polled, so they can be run with [`block_on`].
+pub struct Blocking<C>(pub C);
+
+impl<C> crate::Controller for Blocking<C>
+where
+    C: Controller,
+{
+    type Error = C::Error;
+
+    async fn controller_write(
+        &mut self,
+        opcode: crate::Opcode,
+        payload: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.0.controller_write(opcode, payload)
+    }
+
+    async fn controller_read_into(&self, buf: &mut [u8]) -> Result<(), Self::Error> {
+        self.0.controller_read_into(buf)
+    }
+}
+
+impl<C> crate::AclController for Blocking<C>
+where
+    C: AclController,
+{
+    async fn controller_write_acl_data(
+        &mut self,
+        header: &crate::host::uart::AclDataHeader,
+        data: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.0.controller_write_acl_data(header, data)
+    }
+}
+
+/// Runs the future to completion on the current thread.
+///
+/// This is only meant for the futures of a [`Blocking`] controller, which complete the first time
+/// they are polled. A future that returns [`Poll::Pending`] is polled again in a busy loop, since
+/// nothing tells `block_on` when it can make progress; use [`block_on_with_idle`] to run such
+/// futures.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    block_on_with_idle(future, core::hint::spin_loop)
+}
+
+/// Runs the future to completion on the current thread, calling `idle` every time the future
+/// returns [`Poll::Pending`].
+///
+/// The waker given to the future does nothing, so the future is polled again as soon as `idle`
+/// returns. `idle` should therefore wait until the future may make progress, for example until the
+/// next interrupt with `cortex_m::asm::wfi` when the controller is driven by interrupts.
+pub fn block_on_with_idle<F: Future, I: FnMut()>(future: F, mut idle: I) -> F::Output {
+    let waker = noop_waker();
+    let mut context = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+        idle();
+    }
+}
+
+fn noop_waker() -> Waker {
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(
+        |_| RawWaker::new(core::ptr::null(), &VTABLE),
+        |_| (),
+        |_| (),
+        |_| (),
+    );
+
+    // SAFETY: The vtable functions do not use the data pointer, so a null pointer is valid.
+    unsafe { Waker::from_raw(RawWaker::new(core::ptr::null(), &VTABLE)) }
+}
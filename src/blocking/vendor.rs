@@ -0,0 +1,441 @@
+//! Blocking versions of the vendor-specific command traits.
+
+use super::{Blocking, Controller};
+use crate::host::PeerAddrType;
+use crate::vendor::command::{gap, gatt, hal, l2cap};
+use crate::vendor::event::AttributeHandle;
+use crate::{AdvertisingHandle, BdAddr, BdAddrType, ConnectionHandle, Status};
+use core::ops::Range;
+
+blocking_trait! {
+    /// Blocking version of [`GapCommands`](crate::vendor::command::gap::GapCommands).
+    pub trait GapCommands: gap::GapCommands {
+        fn gap_set_nondiscoverable(&mut self) -> Result<(), Self::Error>;
+        fn set_limited_discoverable(
+            &mut self,
+            params: &gap::DiscoverableParameters<'_, '_>,
+        ) -> Result<(), gap::Error<Self::Error>>;
+        fn set_discoverable(
+            &mut self,
+            params: &gap::DiscoverableParameters<'_, '_>,
+        ) -> Result<(), gap::Error<Self::Error>>;
+        fn set_direct_connectable(
+            &mut self,
+            params: &gap::DirectConnectableParameters,
+        ) -> Result<(), gap::Error<Self::Error>>;
+        fn set_io_capability(&mut self, capability: gap::IoCapability) -> Result<(), Self::Error>;
+        fn set_authentication_requirement(
+            &mut self,
+            requirements: &gap::AuthenticationRequirements,
+        ) -> Result<(), gap::Error<Self::Error>>;
+        fn set_authorization_requirement(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            authorization_required: bool,
+        ) -> Result<(), Self::Error>;
+        fn pass_key_response(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            pin: u32,
+        ) -> Result<(), gap::Error<Self::Error>>;
+        fn authorization_response(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            authorization: gap::Authorization,
+        ) -> Result<(), Self::Error>;
+        fn init(
+            &mut self,
+            role: gap::Role,
+            privacy_enabled: bool,
+            dev_name_characteristic_len: u8,
+        ) -> Result<(), Self::Error>;
+        fn init_gap(
+            &mut self,
+            role: gap::Role,
+            privacy_enabled: bool,
+            dev_name_characteristic_len: u8,
+        ) -> Result<(), Self::Error>;
+        fn set_nonconnectable(
+            &mut self,
+            advertising_type: gap::AdvertisingType,
+            address_type: gap::AddressType,
+        ) -> Result<(), gap::Error<Self::Error>>;
+        fn set_undirected_connectable(
+            &mut self,
+            params: &gap::UndirectedConnectableParameters,
+        ) -> Result<(), gap::Error<Self::Error>>;
+        fn peripheral_security_request(
+            &mut self,
+            conn_handle: &ConnectionHandle,
+        ) -> Result<(), Self::Error>;
+        fn update_advertising_data(&mut self, data: &[u8]) -> Result<(), gap::Error<Self::Error>>;
+        fn delete_ad_type(&mut self, ad_type: gap::AdvertisingDataType) -> Result<(), Self::Error>;
+        fn get_security_level(&mut self, conn_handle: &ConnectionHandle) -> Result<(), Self::Error>;
+        fn set_event_mask(&mut self, flags: gap::EventFlags) -> Result<(), Self::Error>;
+        fn set_gap_event_mask(&mut self, flags: gap::EventFlags) -> Result<(), Self::Error>;
+        fn configure_white_list(&mut self) -> Result<(), Self::Error>;
+        fn terminate(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            reason: Status,
+        ) -> Result<(), gap::Error<Self::Error>>;
+        fn clear_security_database(&mut self) -> Result<(), Self::Error>;
+        fn allow_rebond(&mut self, conn_handle: ConnectionHandle) -> Result<(), Self::Error>;
+        fn start_limited_discovery_procedure(
+            &mut self,
+            params: &gap::DiscoveryProcedureParameters,
+        ) -> Result<(), Self::Error>;
+        fn start_general_discovery_procedure(
+            &mut self,
+            params: &gap::DiscoveryProcedureParameters,
+        ) -> Result<(), Self::Error>;
+        fn start_auto_connection_establishment_procedure(
+            &mut self,
+            params: &gap::AutoConnectionEstablishmentParameters<'_>,
+        ) -> Result<(), gap::Error<Self::Error>>;
+        fn start_general_connection_establishment_procedure(
+            &mut self,
+            params: &gap::GeneralConnectionEstablishmentParameters,
+        ) -> Result<(), Self::Error>;
+        fn start_selective_connection_establishment_procedure(
+            &mut self,
+            params: &gap::SelectiveConnectionEstablishmentParameters<'_>,
+        ) -> Result<(), gap::Error<Self::Error>>;
+        fn create_connection(
+            &mut self,
+            params: &gap::ConnectionParameters,
+        ) -> Result<(), Self::Error>;
+        fn terminate_gap_procedure(
+            &mut self,
+            procedure: gap::Procedure,
+        ) -> Result<(), gap::Error<Self::Error>>;
+        fn start_connection_update(
+            &mut self,
+            params: &gap::ConnectionUpdateParameters,
+        ) -> Result<(), Self::Error>;
+        fn send_pairing_request(&mut self, params: &gap::PairingRequest) -> Result<(), Self::Error>;
+        fn resolve_private_address(&mut self, addr: BdAddr) -> Result<(), Self::Error>;
+        fn set_broadcast_mode(
+            &mut self,
+            params: &gap::BroadcastModeParameters,
+        ) -> Result<(), gap::Error<Self::Error>>;
+        fn start_observation_procedure(
+            &mut self,
+            params: &gap::ObservationProcedureParameters,
+        ) -> Result<(), Self::Error>;
+        fn get_bonded_devices(&mut self) -> Result<(), Self::Error>;
+        fn is_device_bonded(&mut self, addr: PeerAddrType) -> Result<(), Self::Error>;
+        fn numeric_comparison_value_confirm_yes_no(
+            &mut self,
+            params: &gap::NumericComparisonValueConfirmYesNoParameters,
+        ) -> Result<(), Self::Error>;
+        fn passkey_input(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            input_type: gap::InputType,
+        ) -> Result<(), Self::Error>;
+        fn get_oob_data(&mut self, oob_data_type: gap::OobDataType) -> Result<(), Self::Error>;
+        fn set_oob_data(&mut self, params: &gap::SetOobDataParameters) -> Result<(), Self::Error>;
+        fn add_devices_to_resolving_list(
+            &mut self,
+            whitelist_identities: &[PeerAddrType],
+            clear_resolving_list: bool,
+        ) -> Result<(), Self::Error>;
+        fn remove_bonded_device(&mut self, address: BdAddrType) -> Result<(), Self::Error>;
+        fn add_devices_to_list(
+            &mut self,
+            list_entries: &[BdAddrType],
+            mode: gap::AddDeviceToListMode,
+        ) -> Result<(), Self::Error>;
+        fn additional_beacon_start(
+            &mut self,
+            params: &gap::AdditonalBeaconStartParameters,
+        ) -> Result<(), gap::Error<Self::Error>>;
+        fn additional_beacon_stop(&mut self) -> Result<(), Self::Error>;
+        fn additonal_beacon_set_data(&mut self, advertising_data: &[u8]) -> Result<(), Self::Error>;
+        fn adv_set_config(&mut self, params: &gap::AdvSetConfig) -> Result<(), Self::Error>;
+        fn adv_set_enable(&mut self, params: &gap::AdvSetEnable<'_>) -> Result<(), Self::Error>;
+        fn adv_set_advertising_data(
+            &mut self,
+            params: &gap::AdvSetAdvertisingData,
+        ) -> Result<(), Self::Error>;
+        fn adv_set_scan_response_data(
+            &mut self,
+            params: &gap::AdvSetAdvertisingData,
+        ) -> Result<(), Self::Error>;
+        fn adv_remove_set(&mut self, handle: AdvertisingHandle) -> Result<(), Self::Error>;
+        fn adv_clear_sets(&mut self) -> Result<(), Self::Error>;
+        fn adv_set_random_address(
+            &mut self,
+            handle: AdvertisingHandle,
+            addr: BdAddr,
+        ) -> Result<(), Self::Error>;
+    }
+}
+
+blocking_trait! {
+    /// Blocking version of [`GattCommands`](crate::vendor::command::gatt::GattCommands).
+    pub trait GattCommands: gatt::GattCommands {
+        fn init(&mut self) -> Result<(), Self::Error>;
+        fn init_gatt(&mut self) -> Result<(), Self::Error>;
+        fn add_service(&mut self, params: &gatt::AddServiceParameters) -> Result<(), Self::Error>;
+        fn include_service(
+            &mut self,
+            params: &gatt::IncludeServiceParameters,
+        ) -> Result<(), Self::Error>;
+        fn add_characteristic(
+            &mut self,
+            params: &gatt::AddCharacteristicParameters,
+        ) -> Result<(), Self::Error>;
+        fn add_characteristic_descriptor(
+            &mut self,
+            params: &gatt::AddDescriptorParameters<'_>,
+        ) -> Result<(), gatt::Error<Self::Error>>;
+        fn update_characteristic_value(
+            &mut self,
+            params: &gatt::UpdateCharacteristicValueParameters<'_>,
+        ) -> Result<(), gatt::Error<Self::Error>>;
+        fn delete_characteristic(
+            &mut self,
+            service: AttributeHandle,
+            characteristic: AttributeHandle,
+        ) -> Result<(), Self::Error>;
+        fn delete_service(&mut self, service: AttributeHandle) -> Result<(), Self::Error>;
+        fn delete_included_service(
+            &mut self,
+            params: &gatt::DeleteIncludedServiceParameters,
+        ) -> Result<(), Self::Error>;
+        fn set_event_mask(&mut self, mask: gatt::Event) -> Result<(), Self::Error>;
+        fn set_gatt_event_mask(&mut self, mask: gatt::Event) -> Result<(), Self::Error>;
+        fn exchange_configuration(
+            &mut self,
+            conn_handle: ConnectionHandle,
+        ) -> Result<(), Self::Error>;
+        fn find_information_request(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            attribute_range: Range<AttributeHandle>,
+        ) -> Result<(), Self::Error>;
+        fn find_by_type_value_request(
+            &mut self,
+            params: &gatt::FindByTypeValueParameters,
+        ) -> Result<(), gatt::Error<Self::Error>>;
+        fn read_by_type_request(
+            &mut self,
+            params: &gatt::ReadByTypeParameters,
+        ) -> Result<(), Self::Error>;
+        fn read_by_group_type_request(
+            &mut self,
+            params: &gatt::ReadByTypeParameters,
+        ) -> Result<(), Self::Error>;
+        fn prepare_write_request(
+            &mut self,
+            params: &gatt::WriteRequest<'_>,
+        ) -> Result<(), gatt::Error<Self::Error>>;
+        fn execute_write_request(
+            &mut self,
+            conn_handle: ConnectionHandle,
+        ) -> Result<(), Self::Error>;
+        fn cancel_write_request(
+            &mut self,
+            conn_handle: ConnectionHandle,
+        ) -> Result<(), Self::Error>;
+        fn discover_all_primary_services(
+            &mut self,
+            conn_handle: ConnectionHandle,
+        ) -> Result<(), Self::Error>;
+        fn discover_primary_services_by_uuid(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            uuid: gatt::Uuid,
+        ) -> Result<(), Self::Error>;
+        fn find_included_services(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            service_handle_range: Range<AttributeHandle>,
+        ) -> Result<(), Self::Error>;
+        fn discover_all_characteristics_of_service(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            attribute_handle_range: Range<AttributeHandle>,
+        ) -> Result<(), Self::Error>;
+        fn discover_characteristics_by_uuid(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            attribute_handle_range: Range<AttributeHandle>,
+            uuid: gatt::Uuid,
+        ) -> Result<(), Self::Error>;
+        fn discover_all_characteristic_descriptors(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            characteristic_handle_range: Range<AttributeHandle>,
+        ) -> Result<(), Self::Error>;
+        fn read_characteristic_value(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            characteristic_handle: AttributeHandle,
+        ) -> Result<(), Self::Error>;
+        fn read_characteristic_using_uuid(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            characteristic_handle_range: Range<AttributeHandle>,
+            uuid: gatt::Uuid,
+        ) -> Result<(), Self::Error>;
+        fn read_long_characteristic_value(
+            &mut self,
+            params: &gatt::LongCharacteristicReadParameters,
+        ) -> Result<(), Self::Error>;
+        fn read_multiple_characteristic_values(
+            &mut self,
+            params: &gatt::MultipleCharacteristicReadParameters<'_>,
+        ) -> Result<(), gatt::Error<Self::Error>>;
+        fn write_characteristic_value(
+            &mut self,
+            params: &gatt::CharacteristicValue<'_>,
+        ) -> Result<(), gatt::Error<Self::Error>>;
+        fn write_long_characteristic_value(
+            &mut self,
+            params: &gatt::LongCharacteristicValue<'_>,
+        ) -> Result<(), gatt::Error<Self::Error>>;
+        fn write_characteristic_value_reliably(
+            &mut self,
+            params: &gatt::LongCharacteristicValue<'_>,
+        ) -> Result<(), gatt::Error<Self::Error>>;
+        fn write_long_characteristic_descriptor(
+            &mut self,
+            params: &gatt::LongCharacteristicValue<'_>,
+        ) -> Result<(), gatt::Error<Self::Error>>;
+        fn read_long_characteristic_descriptor(
+            &mut self,
+            params: &gatt::LongCharacteristicReadParameters,
+        ) -> Result<(), Self::Error>;
+        fn write_characteristic_descriptor(
+            &mut self,
+            params: &gatt::CharacteristicValue<'_>,
+        ) -> Result<(), gatt::Error<Self::Error>>;
+        fn read_characteristic_descriptor(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            characteristic_handle: AttributeHandle,
+        ) -> Result<(), Self::Error>;
+        fn write_without_response(
+            &mut self,
+            params: &gatt::CharacteristicValue<'_>,
+        ) -> Result<(), gatt::Error<Self::Error>>;
+        fn signed_write_without_response(
+            &mut self,
+            params: &gatt::CharacteristicValue<'_>,
+        ) -> Result<(), gatt::Error<Self::Error>>;
+        fn confirm_indication(&mut self, conn_handle: ConnectionHandle) -> Result<(), Self::Error>;
+        fn write_response(
+            &mut self,
+            params: &gatt::WriteResponseParameters<'_>,
+        ) -> Result<(), gatt::Error<Self::Error>>;
+        fn allow_read(&mut self, conn_handle: ConnectionHandle) -> Result<(), Self::Error>;
+        fn set_security_permission(
+            &mut self,
+            params: &gatt::SecurityPermissionParameters,
+        ) -> Result<(), Self::Error>;
+        fn set_descriptor_value(
+            &mut self,
+            params: &gatt::DescriptorValueParameters<'_>,
+        ) -> Result<(), gatt::Error<Self::Error>>;
+        fn read_handle_value_offset(
+            &mut self,
+            handle: AttributeHandle,
+            offset: usize,
+        ) -> Result<(), Self::Error>;
+        fn update_characteristic_value_ext(
+            &mut self,
+            params: &gatt::UpdateCharacteristicValueExt<'_>,
+        ) -> Result<(), gatt::Error<Self::Error>>;
+        fn deny_read(&mut self, handle: ConnectionHandle, err: u8) -> Result<(), Self::Error>;
+        fn set_access_permission(
+            &mut self,
+            service: AttributeHandle,
+            attribute: AttributeHandle,
+            permissions: gatt::AccessPermission,
+        ) -> Result<(), Self::Error>;
+        fn store_database(&mut self) -> Result<(), Self::Error>;
+        fn send_multiple_notification(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            handles: &[AttributeHandle],
+        ) -> Result<(), Self::Error>;
+        fn read_multiple_variable_characteristic_value(
+            &mut self,
+            conn_handle: ConnectionHandle,
+            handles: &[AttributeHandle],
+        ) -> Result<(), Self::Error>;
+    }
+}
+
+blocking_trait! {
+    /// Blocking version of [`HalCommands`](crate::vendor::command::hal::HalCommands).
+    pub trait HalCommands: hal::HalCommands {
+        fn get_firmware_revision(&mut self) -> Result<(), Self::Error>;
+        fn write_config_data(&mut self, config: &hal::ConfigData) -> Result<(), Self::Error>;
+        fn read_config_data(&mut self, param: hal::ConfigParameter) -> Result<(), Self::Error>;
+        fn set_tx_power_level(&mut self, level: hal::PowerLevel) -> Result<(), Self::Error>;
+        fn get_tx_test_packet_count(&mut self) -> Result<(), Self::Error>;
+        fn start_tone(
+            &mut self,
+            channel: u8,
+            freq_offset: u8,
+        ) -> Result<(), hal::Error<Self::Error>>;
+        fn stop_tone(&mut self) -> Result<(), Self::Error>;
+        fn get_link_status(&mut self) -> Result<(), Self::Error>;
+        fn set_radio_activity_mask(
+            &mut self,
+            mask: hal::RadioActivityFlags,
+        ) -> Result<(), Self::Error>;
+        fn get_anchor_period(&mut self) -> Result<(), Self::Error>;
+        fn set_event_mask(&mut self, mask: hal::HalEventFlags) -> Result<(), Self::Error>;
+        fn get_pm_debug_info(&mut self) -> Result<(), Self::Error>;
+        fn set_peripheral_latency(&mut self, enabled: bool) -> Result<(), Self::Error>;
+        fn read_rssi(&mut self) -> Result<(), Self::Error>;
+        fn read_radio_reg(&mut self, address: u8) -> Result<(), Self::Error>;
+        fn read_raw_rssi(&mut self) -> Result<(), Self::Error>;
+        fn rx_start(&mut self, rf_channel: u8) -> Result<(), Self::Error>;
+        fn rx_stop(&mut self) -> Result<(), Self::Error>;
+        fn stack_reset(&mut self) -> Result<(), Self::Error>;
+    }
+}
+
+blocking_trait! {
+    /// Blocking version of [`L2capCommands`](crate::vendor::command::l2cap::L2capCommands).
+    pub trait L2capCommands: l2cap::L2capCommands {
+        fn connection_parameter_update_request(
+            &mut self,
+            params: &l2cap::ConnectionParameterUpdateRequest,
+        ) -> Result<(), Self::Error>;
+        fn connection_parameter_update_response(
+            &mut self,
+            params: &l2cap::ConnectionParameterUpdateResponse,
+        ) -> Result<(), Self::Error>;
+        fn coc_connect(&mut self, params: &l2cap::L2CapCocConnect) -> Result<(), Self::Error>;
+        fn coc_connect_confirm(
+            &mut self,
+            params: &l2cap::L2CapCocConnectConfirm,
+        ) -> Result<(), Self::Error>;
+        fn coc_reconfig(&mut self, params: &l2cap::L2CapCocReconfig) -> Result<(), Self::Error>;
+        fn coc_reconfig_confirm(
+            &mut self,
+            params: &l2cap::L2CapCocReconfigConfirm,
+        ) -> Result<(), Self::Error>;
+        fn coc_disconnect(&mut self, channel_index: u8) -> Result<(), Self::Error>;
+        fn coc_flow_control(
+            &mut self,
+            params: &l2cap::L2CapCocFlowControl,
+        ) -> Result<(), Self::Error>;
+        fn coc_tx_data(&mut self, params: &l2cap::L2CapCocTxData) -> Result<(), Self::Error>;
+    }
+}
+
+impl<C: Controller> GapCommands for Blocking<C> {}
+
+impl<C: Controller> GattCommands for Blocking<C> {}
+
+impl<C: Controller> HalCommands for Blocking<C> {}
+
+impl<C: Controller> L2capCommands for Blocking<C> {}
@@ -0,0 +1,499 @@
+//! Capture files in the btsnoop format.
+//!
+//! A btsnoop file records the packets exchanged between the host and the controller, with the
+//! time and direction of each packet. This module writes them with the H4 (UART) datalink, which
+//! stores every packet with its packet type byte, so the files open in Wireshark and other
+//! Bluetooth analyzers.
+//!
+//! [`Recorder`] wraps a [`Controller`] and records every command written through it and every
+//! packet read back by [`UartHci::read`](crate::host::uart::UartHci::read):
+//!
+//! ```
+//! # extern crate stm32wb_hci as hci;
+//! # use hci::Controller as HciController;
+//! # struct Controller;
+//! # impl HciController for Controller {
+//! #     type Error = core::convert::Infallible;
+//! #     async fn controller_write(&mut self, opcode: hci::Opcode, _payload: &[u8]) -> Result<(), Self::Error> {
+//! #         Ok(())
+//! #     }
+//! #     async fn controller_read_into(&self, buf: &mut [u8]) -> Result<(), Self::Error> {
+//! #         buf[..7].copy_from_slice(&[0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00]);
+//! #         Ok(())
+//! #     }
+//! # }
+//! use hci::blocking::block_on;
+//! use hci::btsnoop::{Direction, Reader, Recorder};
+//! use hci::host::{uart::UartHci, HostHci};
+//!
+//! let mut recorder = Recorder::new(Controller, Vec::new()).unwrap();
+//! block_on(recorder.reset()).unwrap();
+//! block_on(recorder.read()).unwrap();
+//!
+//! let (_, capture) = recorder.into_inner();
+//! let records: Vec<_> = Reader::new(capture.as_slice())
+//!     .unwrap()
+//!     .collect::<Result<_, _>>()
+//!     .unwrap();
+//! assert_eq!(records[0].direction, Direction::Sent);
+//! assert_eq!(records[1].direction, Direction::Received);
+//! ```
+
+use crate::host::uart::{
+    parse_packet, AclDataHeader, Packet, COMMAND_PACKET_HEADER_LENGTH, PACKET_TYPE_HCI_COMMAND,
+    PACKET_TYPE_HCI_EVENT,
+};
+use crate::{AclController, Controller, Opcode};
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+use core::cell::RefCell;
+use core::convert::Infallible;
+use std::io::{self, Read, Write};
+use std::time::{Duration, SystemTime};
+use std::vec::Vec;
+
+const MAGIC: &[u8; 8] = b"btsnoop\0";
+const VERSION: u32 = 1;
+
+/// Datalink type of the H4 (UART) transport, which includes the packet type byte in each packet.
+pub const DATALINK_H4: u32 = 1002;
+
+const FILE_HEADER_LENGTH: usize = 16;
+const RECORD_HEADER_LENGTH: usize = 24;
+
+/// Length of the largest H4 packet: the packet type byte, and an ACL data header followed by the
+/// largest data length it can encode.
+const MAX_RECORD_LENGTH: usize = 1 + 4 + 0xFFFF;
+
+const FLAG_RECEIVED: u32 = 0x01;
+const FLAG_COMMAND_OR_EVENT: u32 = 0x02;
+
+/// Microseconds between the btsnoop epoch (midnight, January 1st, 0 AD) and the Unix epoch.
+const UNIX_EPOCH_OFFSET_US: i64 = 0x00dc_ddb3_0f2f_8000;
+
+/// Direction of a recorded packet.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Direction {
+    /// The host sent the packet to the controller.
+    Sent,
+    /// The host received the packet from the controller.
+    Received,
+}
+
+/// One packet of a capture file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    /// Whether the packet was sent to or received from the controller.
+    pub direction: Direction,
+
+    /// Time at which the packet was recorded.
+    pub timestamp: SystemTime,
+
+    /// The packet, starting with its H4 packet type byte.
+    pub data: Vec<u8>,
+}
+
+/// Contents of a [`Record`], as returned by [`Record::decode`].
+#[derive(Clone, Debug)]
+pub enum Payload<'a> {
+    /// A command sent to the controller.
+    Command {
+        /// Opcode of the command.
+        opcode: Opcode,
+        /// Parameters of the command.
+        params: &'a [u8],
+    },
+    /// An event or ACL data packet, in the same form [`UartHci::read`] returns it. ACL data sent
+    /// to the controller are also decoded this way.
+    ///
+    /// [`UartHci::read`]: crate::host::uart::UartHci::read
+    Packet(Packet),
+}
+
+/// Errors that may occur when reading a capture file or decoding its records.
+#[derive(Debug)]
+pub enum Error {
+    /// The underlying reader failed.
+    Io(io::Error),
+    /// The file does not start with the btsnoop identification pattern.
+    BadMagic,
+    /// The file uses a version of the format other than 1. Contains the version.
+    UnsupportedVersion(u32),
+    /// The file uses a datalink other than [`DATALINK_H4`]. Contains the datalink type.
+    UnsupportedDatalink(u32),
+    /// The file or packet ends before the length given in its header.
+    Truncated,
+    /// The record header gives a packet length greater than the original length of the packet or
+    /// than any H4 packet. Contains the length.
+    BadRecordLength(u32),
+    /// The packet type byte is not a command, ACL data or event packet. Contains the value of the
+    /// byte.
+    BadPacketType(u8),
+    /// The packet is correctly framed, but cannot be deserialized. Contains the underlying error.
+    Packet(crate::host::uart::Error<Infallible>),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+/// Errors returned by the [`Recorder`] from the [`Controller`] functions.
+#[derive(Debug)]
+pub enum RecorderError<E> {
+    /// The wrapped controller failed. Contains the underlying error.
+    Comm(E),
+    /// The packet was exchanged with the controller, but could not be written to the capture
+    /// file. Contains the underlying error.
+    Capture(io::Error),
+}
+
+impl Record {
+    /// Decodes the packet of the record.
+    ///
+    /// # Errors
+    ///
+    /// - [`Truncated`](Error::Truncated) if the packet is shorter than its header says.
+    /// - [`BadPacketType`](Error::BadPacketType) if the packet is not a command, ACL data or
+    ///   event packet.
+    /// - [`Packet`](Error::Packet) if the event or ACL data cannot be deserialized.
+    pub fn decode(&self) -> Result<Payload<'_>, Error> {
+        let packet_len = packet_length(&self.data)?;
+        if self.data.len() < packet_len {
+            return Err(Error::Truncated);
+        }
+
+        match self.data[0] {
+            PACKET_TYPE_HCI_COMMAND => Ok(Payload::Command {
+                opcode: Opcode(LittleEndian::read_u16(&self.data[1..])),
+                params: &self.data[COMMAND_PACKET_HEADER_LENGTH..packet_len],
+            }),
+            _ => parse_packet(&self.data[..packet_len])
+                .map(Payload::Packet)
+                .map_err(Error::Packet),
+        }
+    }
+}
+
+/// Returns the length of the H4 packet at the beginning of `packet`, as given by its header.
+fn packet_length(packet: &[u8]) -> Result<usize, Error> {
+    match crate::host::uart::packet_length::<Infallible>(packet) {
+        Ok(Some(len)) => Ok(len),
+        Ok(None) => Err(Error::Truncated),
+        Err(crate::host::uart::Error::BadPacketType(other)) => Err(Error::BadPacketType(other)),
+        Err(e) => Err(Error::Packet(e)),
+    }
+}
+
+/// Returns the H4 packet of a command.
+pub(crate) fn command_packet(opcode: Opcode, params: &[u8]) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(COMMAND_PACKET_HEADER_LENGTH + params.len());
+    packet.push(PACKET_TYPE_HCI_COMMAND);
+    packet.extend_from_slice(&opcode.0.to_le_bytes());
+    packet.push(params.len() as u8);
+    packet.extend_from_slice(params);
+    packet
+}
+
+/// Returns the H4 packet of ACL data.
+pub(crate) fn acl_data_packet(header: &AclDataHeader, data: &[u8]) -> Vec<u8> {
+    let mut packet = std::vec![0; AclDataHeader::HEADER_LENGTH + data.len()];
+    header.copy_into_slice(&mut packet);
+    packet[AclDataHeader::HEADER_LENGTH..].copy_from_slice(data);
+    packet
+}
+
+fn to_btsnoop_timestamp(timestamp: SystemTime) -> i64 {
+    let micros = match timestamp.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(since) => since.as_micros() as i64,
+        Err(e) => -(e.duration().as_micros() as i64),
+    };
+
+    UNIX_EPOCH_OFFSET_US + micros
+}
+
+fn from_btsnoop_timestamp(timestamp: i64) -> SystemTime {
+    let micros = timestamp - UNIX_EPOCH_OFFSET_US;
+    if micros >= 0 {
+        SystemTime::UNIX_EPOCH + Duration::from_micros(micros as u64)
+    } else {
+        SystemTime::UNIX_EPOCH - Duration::from_micros(micros.unsigned_abs())
+    }
+}
+
+/// Writes packets to a btsnoop file with the H4 datalink.
+#[derive(Debug)]
+pub struct Writer<W> {
+    inner: W,
+}
+
+impl<W: Write> Writer<W> {
+    /// Creates a writer, and writes the file header to `inner`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of `inner`.
+    pub fn new(mut inner: W) -> io::Result<Writer<W>> {
+        let mut header = [0; FILE_HEADER_LENGTH];
+        header[..8].copy_from_slice(MAGIC);
+        BigEndian::write_u32(&mut header[8..], VERSION);
+        BigEndian::write_u32(&mut header[12..], DATALINK_H4);
+        inner.write_all(&header)?;
+
+        Ok(Writer { inner })
+    }
+
+    /// Writes one packet. `packet` starts with its H4 packet type byte.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of the underlying writer.
+    pub fn write_packet(
+        &mut self,
+        direction: Direction,
+        timestamp: SystemTime,
+        packet: &[u8],
+    ) -> io::Result<()> {
+        let mut flags = match direction {
+            Direction::Sent => 0,
+            Direction::Received => FLAG_RECEIVED,
+        };
+        if matches!(
+            packet.first(),
+            Some(&PACKET_TYPE_HCI_COMMAND) | Some(&PACKET_TYPE_HCI_EVENT)
+        ) {
+            flags |= FLAG_COMMAND_OR_EVENT;
+        }
+
+        let mut header = [0; RECORD_HEADER_LENGTH];
+        BigEndian::write_u32(&mut header[0..], packet.len() as u32);
+        BigEndian::write_u32(&mut header[4..], packet.len() as u32);
+        BigEndian::write_u32(&mut header[8..], flags);
+        BigEndian::write_u32(&mut header[12..], 0);
+        BigEndian::write_i64(&mut header[16..], to_btsnoop_timestamp(timestamp));
+        self.inner.write_all(&header)?;
+        self.inner.write_all(packet)
+    }
+
+    /// Writes a [`Record`].
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of the underlying writer.
+    pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        self.write_packet(record.direction, record.timestamp, &record.data)
+    }
+
+    /// Flushes the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+/// Reads the records of a btsnoop file with the H4 datalink.
+///
+/// The reader is an iterator over the records of the file. It ends at the end of the file, or
+/// after the first error.
+#[derive(Debug)]
+pub struct Reader<R> {
+    inner: R,
+    done: bool,
+}
+
+impl<R: Read> Reader<R> {
+    /// Creates a reader, and reads and checks the file header from `inner`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Io`](Error::Io) if `inner` fails.
+    /// - [`Truncated`](Error::Truncated) if the file is shorter than its header.
+    /// - [`BadMagic`](Error::BadMagic) if the file is not a btsnoop file.
+    /// - [`UnsupportedVersion`](Error::UnsupportedVersion) or
+    ///   [`UnsupportedDatalink`](Error::UnsupportedDatalink) if the file is not a version 1, H4
+    ///   btsnoop file.
+    pub fn new(mut inner: R) -> Result<Reader<R>, Error> {
+        let mut header = [0; FILE_HEADER_LENGTH];
+        if read_full(&mut inner, &mut header)? < FILE_HEADER_LENGTH {
+            return Err(Error::Truncated);
+        }
+        if header[..8] != MAGIC[..] {
+            return Err(Error::BadMagic);
+        }
+
+        let version = BigEndian::read_u32(&header[8..]);
+        if version != VERSION {
+            return Err(Error::UnsupportedVersion(version));
+        }
+
+        let datalink = BigEndian::read_u32(&header[12..]);
+        if datalink != DATALINK_H4 {
+            return Err(Error::UnsupportedDatalink(datalink));
+        }
+
+        Ok(Reader { inner, done: false })
+    }
+
+    fn read_record(&mut self) -> Result<Option<Record>, Error> {
+        let mut header = [0; RECORD_HEADER_LENGTH];
+        match read_full(&mut self.inner, &mut header)? {
+            0 => return Ok(None),
+            RECORD_HEADER_LENGTH => (),
+            _ => return Err(Error::Truncated),
+        }
+
+        let original_len = BigEndian::read_u32(&header[0..]);
+        let included_len = BigEndian::read_u32(&header[4..]);
+        let flags = BigEndian::read_u32(&header[8..]);
+        let timestamp = BigEndian::read_i64(&header[16..]);
+        if included_len > original_len || included_len as usize > MAX_RECORD_LENGTH {
+            return Err(Error::BadRecordLength(included_len));
+        }
+
+        let included_len = included_len as usize;
+        let mut data = std::vec![0; included_len];
+        if read_full(&mut self.inner, &mut data)? < included_len {
+            return Err(Error::Truncated);
+        }
+
+        Ok(Some(Record {
+            direction: if flags & FLAG_RECEIVED == 0 {
+                Direction::Sent
+            } else {
+                Direction::Received
+            },
+            timestamp: from_btsnoop_timestamp(timestamp),
+            data,
+        }))
+    }
+}
+
+impl<R: Read> Iterator for Reader<R> {
+    type Item = Result<Record, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let record = self.read_record().transpose();
+        self.done = !matches!(record, Some(Ok(_)));
+        record
+    }
+}
+
+/// Reads into `buf` until it is full or `reader` reaches the end of the file. Returns the number
+/// of bytes read.
+fn read_full<R: Read>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut len = 0;
+    while len < buf.len() {
+        match reader.read(&mut buf[len..]) {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => (),
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(len)
+}
+
+/// Controller that records the packets exchanged with the wrapped controller in a btsnoop file.
+///
+/// Commands and ACL data are recorded after they were written to the controller. Packets are
+/// recorded after they were read from the controller; like [`UartHci::read`], the recorder
+/// expects each read to return one whole packet.
+///
+/// [`UartHci::read`]: crate::host::uart::UartHci::read
+#[derive(Debug)]
+pub struct Recorder<C, W> {
+    controller: C,
+    writer: RefCell<Writer<W>>,
+}
+
+impl<C, W: Write> Recorder<C, W> {
+    /// Wraps `controller`, and writes the file header to `output`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of `output`.
+    pub fn new(controller: C, output: W) -> io::Result<Recorder<C, W>> {
+        Ok(Recorder {
+            controller,
+            writer: RefCell::new(Writer::new(output)?),
+        })
+    }
+
+    /// Returns a reference to the wrapped controller.
+    pub fn controller(&self) -> &C {
+        &self.controller
+    }
+
+    /// Returns a mutable reference to the wrapped controller.
+    pub fn controller_mut(&mut self) -> &mut C {
+        &mut self.controller
+    }
+
+    /// Returns the wrapped controller and the capture output.
+    pub fn into_inner(self) -> (C, W) {
+        (self.controller, self.writer.into_inner().into_inner())
+    }
+
+    fn record<E>(&self, direction: Direction, packet: &[u8]) -> Result<(), RecorderError<E>> {
+        self.writer
+            .borrow_mut()
+            .write_packet(direction, SystemTime::now(), packet)
+            .map_err(RecorderError::Capture)
+    }
+}
+
+impl<C: Controller, W: Write> Controller for Recorder<C, W> {
+    type Error = RecorderError<C::Error>;
+
+    async fn controller_write(
+        &mut self,
+        opcode: Opcode,
+        payload: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.controller
+            .controller_write(opcode, payload)
+            .await
+            .map_err(RecorderError::Comm)?;
+
+        self.record(Direction::Sent, &command_packet(opcode, payload))
+    }
+
+    async fn controller_read_into(&self, buf: &mut [u8]) -> Result<(), Self::Error> {
+        self.controller
+            .controller_read_into(buf)
+            .await
+            .map_err(RecorderError::Comm)?;
+
+        // Packets that cannot be framed are recorded whole, so the capture shows what was read.
+        let len = packet_length(buf).map_or(buf.len(), |len| len.min(buf.len()));
+        self.record(Direction::Received, &buf[..len])
+    }
+}
+
+impl<C: AclController, W: Write> AclController for Recorder<C, W> {
+    async fn controller_write_acl_data(
+        &mut self,
+        header: &AclDataHeader,
+        data: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.controller
+            .controller_write_acl_data(header, data)
+            .await
+            .map_err(RecorderError::Comm)?;
+
+        self.record(Direction::Sent, &acl_data_packet(header, data))
+    }
+}
@@ -0,0 +1,83 @@
+//! Transport-independent encoding of commands.
+//!
+//! Every command of [`HostHci`](crate::host::HostHci) and of the vendor command traits is also
+//! available as a value: [`HciCommand`](crate::host::HciCommand),
+//! [`GapCommand`](crate::vendor::command::gap::GapCommand),
+//! [`GattCommand`](crate::vendor::command::gatt::GattCommand),
+//! [`HalCommand`](crate::vendor::command::hal::HalCommand) and
+//! [`L2capCommand`](crate::vendor::command::l2cap::L2capCommand). These implement [`Command`],
+//! which serializes the parameters of the command into any buffer. Commands can then be built into
+//! DMA buffers, queued, logged, or sent over a transport that does not implement
+//! [`Controller`](crate::Controller).
+//!
+//! The command functions of the traits are implemented in terms of these values, so both produce
+//! the same bytes.
+//!
+//! ```
+//! # extern crate stm32wb_hci as hci;
+//! use hci::command::{Command, MAX_PARAMETERS_LENGTH};
+//! use hci::host::HciCommand;
+//! use hci::ConnectionHandle;
+//! use hci::Status;
+//!
+//! let command = HciCommand::Disconnect {
+//!     conn_handle: ConnectionHandle(0x0201),
+//!     reason: Status::RemoteTerminationByUser,
+//! };
+//! command.validate::<()>().unwrap();
+//!
+//! let mut params = [0; MAX_PARAMETERS_LENGTH];
+//! let len = command.encode_into(&mut params);
+//! assert_eq!(command.opcode(), hci::opcode::DISCONNECT);
+//! assert_eq!(params[..len], [0x01, 0x02, 0x13]);
+//! ```
+
+use crate::{Controller, Opcode};
+
+/// Maximum length of the parameters of a command. The parameter length of a command packet is a
+/// single byte.
+pub const MAX_PARAMETERS_LENGTH: usize = 255;
+
+/// A command that can be sent to the controller.
+pub trait Command {
+    /// Returns the opcode of the command.
+    fn opcode(&self) -> Opcode;
+
+    /// Serializes the parameters of the command into `buf`, in Bluetooth byte order
+    /// (little-endian), and returns the number of bytes written. The command header is not
+    /// included.
+    ///
+    /// The parameters are encoded as they are, without validation. Commands that fail validation
+    /// may be encoded incorrectly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is too short for the parameters. [`MAX_PARAMETERS_LENGTH`] bytes are always
+    /// enough.
+    fn encode_into(&self, buf: &mut [u8]) -> usize;
+}
+
+/// Encodes the command and writes it to the controller.
+///
+/// # Errors
+///
+/// Only underlying communication errors are reported.
+pub async fn write_command<C, T>(controller: &mut C, command: &T) -> Result<(), C::Error>
+where
+    C: Controller + ?Sized,
+    T: Command + ?Sized,
+{
+    let mut params = [0; MAX_PARAMETERS_LENGTH];
+    let len = command.encode_into(&mut params);
+    controller
+        .controller_write(command.opcode(), &params[..len])
+        .await
+}
+
+/// Returns the first `len` bytes of `buf`, cleared to zero. Used for parameters that do not write
+/// every byte of their encoding, since `buf` may contain stale data.
+pub(crate) fn zeroed(buf: &mut [u8], len: usize) -> &mut [u8] {
+    let buf = &mut buf[..len];
+    buf.fill(0);
+    buf
+}
@@ -0,0 +1,1214 @@
+//! Human-readable descriptions of HCI packets.
+//!
+//! [`describe`] renders a command, ACL data or event packet, starting with its H4 packet type
+//! byte, as an annotated multi-line description in the style of `btmon`: the packet type and
+//! length, the name and OGF/OCF of the command and its decoded parameters, the name of the event,
+//! the meaning of the status, and the decoded event parameters, including the return parameters
+//! of the command an event completes. Packets that cannot be deserialized are described as far as
+//! possible. The parameters of commands whose layout is not known are shown as a hex dump, and
+//! events whose layout is not known are shown deserialized.
+//!
+//! ```
+//! # extern crate stm32wb_hci as hci;
+//! use hci::decode::describe;
+//!
+//! let description = describe(&[0x01, 0x03, 0x0C, 0x00]).to_string();
+//! assert_eq!(description, "HCI Command: RESET (0x03|0x0003) plen 0");
+//! ```
+//!
+//! Logs of the UART traffic contain many packets back to back; [`packets`] splits them.
+
+use crate::host::uart::{
+    packet_length, AclDataHeader, Error, COMMAND_PACKET_HEADER_LENGTH, EVENT_PACKET_HEADER_LENGTH,
+    PACKET_TYPE_ACL_DATA, PACKET_TYPE_HCI_COMMAND, PACKET_TYPE_HCI_EVENT,
+};
+use crate::vendor::event::VendorStatus;
+use crate::{opcode, vendor, Event, Opcode, Status};
+use byteorder::{ByteOrder, LittleEndian};
+use core::convert::Infallible;
+use core::fmt::{self, Display, Formatter, Write};
+
+const INDENT: &str = "    ";
+const COMMAND_COMPLETE_EVENT: u8 = 0x0E;
+const COMMAND_STATUS_EVENT: u8 = 0x0F;
+const LE_META_EVENT: u8 = 0x3E;
+
+/// Returns a description of `packet`, which starts with its H4 packet type byte. The description
+/// is rendered by its [`Display`] implementation, and does not end with a line break.
+pub fn describe(packet: &[u8]) -> Description<'_> {
+    Description { packet }
+}
+
+/// Description of a packet, as returned by [`describe`].
+#[derive(Copy, Clone, Debug)]
+pub struct Description<'a> {
+    packet: &'a [u8],
+}
+
+/// Returns an iterator over the packets of `stream`, which contains H4 packets back to back.
+///
+/// If the stream contains an unknown packet type byte, or ends in the middle of a packet, the
+/// rest of the stream is returned as the last packet. Its [description](describe) explains the
+/// problem.
+pub fn packets(stream: &[u8]) -> Packets<'_> {
+    Packets { stream }
+}
+
+/// Iterator over the packets of a stream, as returned by [`packets`].
+#[derive(Clone, Debug)]
+pub struct Packets<'a> {
+    stream: &'a [u8],
+}
+
+impl<'a> Iterator for Packets<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if self.stream.is_empty() {
+            return None;
+        }
+
+        let len = match packet_length::<Infallible>(self.stream) {
+            Ok(Some(len)) if len <= self.stream.len() => len,
+            _ => self.stream.len(),
+        };
+        let (packet, rest) = self.stream.split_at(len);
+        self.stream = rest;
+        Some(packet)
+    }
+}
+
+/// Returns the name of the standard or vendor-specific command with the given opcode, e.g.
+/// `"RESET"` or `"GAP_INIT"`.
+pub fn opcode_name(opcode: Opcode) -> Option<&'static str> {
+    crate::opcode::name(opcode).or_else(|| crate::vendor::opcode::name(opcode))
+}
+
+impl Display for Description<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let len = match packet_length::<Infallible>(self.packet) {
+            Ok(Some(len)) if len <= self.packet.len() => len,
+            Ok(_) => {
+                write!(f, "Truncated packet: {} bytes", self.packet.len())?;
+                return write_hex(f, self.packet);
+            }
+            Err(Error::BadPacketType(packet_type)) => {
+                write!(f, "Unknown packet type 0x{:02x}", packet_type)?;
+                return write_hex(f, self.packet);
+            }
+            Err(_) => unreachable!(),
+        };
+
+        let packet = &self.packet[..len];
+        match packet[0] {
+            PACKET_TYPE_HCI_COMMAND => describe_command(f, packet)?,
+            PACKET_TYPE_ACL_DATA => describe_acl_data(f, packet)?,
+            PACKET_TYPE_HCI_EVENT => describe_event(f, packet)?,
+            _ => unreachable!(),
+        }
+
+        if len < self.packet.len() {
+            write!(f, "\n{}Trailing bytes:", INDENT)?;
+            write_hex(f, &self.packet[len..])?;
+        }
+
+        Ok(())
+    }
+}
+
+fn describe_command(f: &mut Formatter, packet: &[u8]) -> fmt::Result {
+    let opcode = Opcode(LittleEndian::read_u16(&packet[1..]));
+    let params = &packet[COMMAND_PACKET_HEADER_LENGTH..];
+
+    write!(f, "HCI Command: ")?;
+    write_opcode(f, opcode)?;
+    write!(f, " plen {}", params.len())?;
+    match parameters(opcode) {
+        Some(fields) => write_fields(f, fields, params),
+        None => write_hex(f, params),
+    }
+}
+
+fn describe_acl_data(f: &mut Formatter, packet: &[u8]) -> fmt::Result {
+    let data = &packet[AclDataHeader::HEADER_LENGTH..];
+    match AclDataHeader::from_bytes::<Infallible>(&packet[1..]) {
+        Ok(header) => write!(
+            f,
+            "ACL Data: handle 0x{:04x} {:?} {:?} dlen {}",
+            header.conn_handle.0,
+            header.packet_boundary,
+            header.broadcast,
+            data.len()
+        )?,
+        Err(e) => {
+            write!(f, "ACL Data: dlen {}", data.len())?;
+            write!(f, "\n{}Error: {:?}", INDENT, e)?;
+        }
+    }
+    write_hex(f, data)
+}
+
+fn describe_event(f: &mut Formatter, packet: &[u8]) -> fmt::Result {
+    let event_code = packet[1];
+    let params = &packet[EVENT_PACKET_HEADER_LENGTH..];
+    write!(
+        f,
+        "HCI Event: {} (0x{:02x}) plen {}",
+        event_name(event_code).unwrap_or("Unknown"),
+        event_code,
+        params.len()
+    )?;
+
+    // The layout of the parameters that follow the ones described here, if it is known.
+    let (fields, rest) = match event_code {
+        COMMAND_COMPLETE_EVENT if params.len() >= 3 => {
+            let opcode = Opcode(LittleEndian::read_u16(&params[1..]));
+            write_command_credit(f, opcode, params[0])?;
+            match params.get(3) {
+                Some(&status) if opcode.0 != 0 => {
+                    write!(f, "\n{}Status: ", INDENT)?;
+                    write_status(f, opcode, status)?;
+
+                    // Many commands return their status alone, and a failed command may omit
+                    // its other return parameters.
+                    let rest = &params[4..];
+                    if rest.is_empty() {
+                        (Some(NO_FIELDS), rest)
+                    } else {
+                        (return_parameters(opcode), rest)
+                    }
+                }
+                _ => (Some(NO_FIELDS), &params[3..]),
+            }
+        }
+        COMMAND_STATUS_EVENT if params.len() >= 4 => {
+            let opcode = Opcode(LittleEndian::read_u16(&params[2..]));
+            write_command_credit(f, opcode, params[1])?;
+            write!(f, "\n{}Status: ", INDENT)?;
+            write_status(f, opcode, params[0])?;
+            (Some(NO_FIELDS), &params[4..])
+        }
+        LE_META_EVENT if !params.is_empty() => {
+            let subevent = params[0];
+            write!(
+                f,
+                "\n{}Subevent: {} (0x{:02x})",
+                INDENT,
+                le_subevent_name(subevent).unwrap_or("Unknown"),
+                subevent
+            )?;
+            (le_event_parameters(subevent), &params[1..])
+        }
+        _ => (event_parameters(event_code), params),
+    };
+
+    let event = Event::new(crate::event::Packet(&packet[1..]));
+    if let Err(e) = &event {
+        write!(f, "\n{}Error: {:?}", INDENT, e)?;
+    }
+    match (fields, event) {
+        (Some(fields), _) => write_fields(f, fields, rest),
+        (None, Ok(Event::CommandComplete(event))) => {
+            writeln!(f)?;
+            write!(Indented::new(f), "{:#?}", event.return_params)
+        }
+        (None, Ok(event)) => {
+            writeln!(f)?;
+            write!(Indented::new(f), "{:#?}", event)
+        }
+        (None, Err(_)) => write_hex(f, rest),
+    }
+}
+
+/// Writes the command a Command Complete or Command Status event refers to, and the number of
+/// commands the Controller is ready to accept.
+fn write_command_credit(f: &mut Formatter, opcode: Opcode, credit: u8) -> fmt::Result {
+    write!(f, "\n{}Command: ", INDENT)?;
+    if opcode.0 == 0 {
+        write!(f, "None")?;
+    } else {
+        write_opcode(f, opcode)?;
+    }
+    write!(f, " ncmd {}", credit)
+}
+
+fn write_opcode(f: &mut Formatter, opcode: Opcode) -> fmt::Result {
+    write!(
+        f,
+        "{} (0x{:02x}|0x{:04x})",
+        opcode_name(opcode).unwrap_or("Unknown"),
+        opcode.ogf(),
+        opcode.ocf()
+    )
+}
+
+/// Writes the meaning of the status code returned by the command. Some vendor-specific status
+/// codes are also standard status codes; the vendor-specific meaning is used for vendor-specific
+/// commands.
+fn write_status(f: &mut Formatter, opcode: Opcode, status: u8) -> fmt::Result {
+    if opcode.ogf() == crate::vendor::opcode::VENDOR_OGF {
+        if let Ok(vendor) = VendorStatus::try_from(status) {
+            return write!(f, "{:?} (0x{:02x})", vendor, status);
+        }
+    }
+
+    write_standard_status(f, status)
+}
+
+fn write_standard_status(f: &mut Formatter, status: u8) -> fmt::Result {
+    match Status::try_from(status) {
+        Ok(Status::Vendor(vendor)) => write!(f, "{:?} (0x{:02x})", vendor, status),
+        Ok(known) => write!(f, "{:?} (0x{:02x})", known, status),
+        Err(_) => write!(f, "Unknown (0x{:02x})", status),
+    }
+}
+
+/// Writes `bytes` as hex, 16 bytes per line, each line indented.
+fn write_hex(f: &mut Formatter, bytes: &[u8]) -> fmt::Result {
+    write_hex_indented(f, bytes, 1)
+}
+
+/// Writes `bytes` as hex, 16 bytes per line, each line indented `depth` times.
+fn write_hex_indented(f: &mut Formatter, bytes: &[u8], depth: usize) -> fmt::Result {
+    for line in bytes.chunks(16) {
+        f.write_char('\n')?;
+        for _ in 0..depth {
+            f.write_str(INDENT)?;
+        }
+        for (i, byte) in line.iter().enumerate() {
+            if i > 0 {
+                f.write_char(' ')?;
+            }
+            write!(f, "{:02x}", byte)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Encoding of a command or event parameter.
+#[derive(Copy, Clone)]
+enum Kind {
+    /// Unsigned little-endian integer of the given number of bytes, shown in decimal.
+    Uint(usize),
+    /// Signed 8-bit integer.
+    Int8,
+    /// Bit mask or identifier of the given number of bytes, shown in hex.
+    Mask(usize),
+    /// Connection, sync or attribute handle.
+    Handle,
+    /// Controller error code, given as a status or a reason.
+    ErrorCode,
+    /// Boolean.
+    Bool,
+    /// Bluetooth device address.
+    BdAddr,
+    /// Fixed number of bytes, shown in hex.
+    Bytes(usize),
+    /// Length byte followed by that many bytes.
+    Data,
+    /// Length byte followed by a fixed-size field, of which only the first `length` bytes are
+    /// used.
+    PaddedData(usize),
+    /// UUID type byte (1 for 16-bit, 2 for 128-bit UUIDs) followed by the UUID.
+    Uuid,
+    /// All remaining bytes, shown in hex.
+    Rest,
+}
+
+use Kind::*;
+
+/// Name and encoding of a command or event parameter.
+type Field = (&'static str, Kind);
+
+const NO_FIELDS: &[Field] = &[];
+const STATUS: Field = ("Status", ErrorCode);
+const HANDLE: Field = ("Handle", Handle);
+const ADDRESS_TYPE: Field = ("Address type", Uint(1));
+const ADDRESS: Field = ("Address", BdAddr);
+const ADVERTISING_HANDLE: Field = ("Advertising handle", Uint(1));
+const SYNC_HANDLE: Field = ("Sync handle", Handle);
+const SERVICE: Field = ("Service", Handle);
+const CHARACTERISTIC: Field = ("Characteristic", Handle);
+const ATTRIBUTE: Field = ("Attribute", Handle);
+const START_HANDLE: Field = ("Start handle", Handle);
+const END_HANDLE: Field = ("End handle", Handle);
+const OFFSET: Field = ("Offset", Uint(2));
+const VALUE: Field = ("Value", Data);
+const FEATURES: Field = ("Features", Mask(8));
+const TX_POWER: Field = ("TX power", Int8);
+
+const CONNECTION_PARAMETERS: &[Field] = &[
+    HANDLE,
+    ("Minimum interval", Uint(2)),
+    ("Maximum interval", Uint(2)),
+    ("Latency", Uint(2)),
+    ("Supervision timeout", Uint(2)),
+    ("Minimum CE length", Uint(2)),
+    ("Maximum CE length", Uint(2)),
+];
+const PEER_ADDRESS: &[Field] = &[ADDRESS_TYPE, ADDRESS];
+const HANDLE_RANGE: &[Field] = &[HANDLE, START_HANDLE, END_HANDLE];
+const HANDLE_RANGE_UUID: &[Field] = &[HANDLE, START_HANDLE, END_HANDLE, ("UUID", Uuid)];
+const HANDLE_LIST: &[Field] = &[HANDLE, ("Number of handles", Uint(1)), ("Handles", Rest)];
+const CHARACTERISTIC_VALUE: &[Field] = &[HANDLE, CHARACTERISTIC, VALUE];
+const LONG_CHARACTERISTIC_VALUE: &[Field] = &[HANDLE, CHARACTERISTIC, OFFSET, VALUE];
+const EXTENDED_ADVERTISING_DATA: &[Field] = &[
+    ADVERTISING_HANDLE,
+    ("Operation", Uint(1)),
+    ("Fragment preference", Uint(1)),
+    ("Data", Data),
+];
+
+/// Returns the layout of the parameters of the command with the given opcode, or `None` if it is
+/// not known.
+fn parameters(opcode: Opcode) -> Option<&'static [Field]> {
+    let fields: &[Field] = match opcode {
+        opcode::DISCONNECT => &[HANDLE, ("Reason", ErrorCode)],
+        opcode::READ_REMOTE_VERSION_INFO
+        | opcode::READ_RSSI
+        | opcode::LE_READ_CHANNEL_MAP
+        | opcode::LE_READ_REMOTE_USED_FEATURES
+        | opcode::LE_LTK_REQUEST_NEGATIVE_REPLY
+        | opcode::LE_READ_PHY
+        | opcode::LE_PERIODIC_ADVERTISING_TERMINATE_SYNC
+        | opcode::READ_AUTHENTICATED_PAYLOAD_TIMEOUT => &[HANDLE],
+        opcode::SET_EVENT_MASK | opcode::SET_EVENT_MASK_PAGE_2 | opcode::LE_SET_EVENT_MASK => {
+            &[("Mask", Mask(8))]
+        }
+        opcode::RESET
+        | opcode::READ_LOCAL_VERSION_INFO
+        | opcode::READ_LOCAL_SUPPORTED_COMMANDS
+        | opcode::READ_LOCAL_SUPPORTED_FEATURES
+        | opcode::READ_BD_ADDR
+        | opcode::LE_READ_BUFFER_SIZE
+        | opcode::LE_READ_LOCAL_SUPPORTED_FEATURES
+        | opcode::LE_READ_ADVERTISING_CHANNEL_TX_POWER
+        | opcode::LE_CREATE_CONNECTION_CANCEL
+        | opcode::LE_READ_WHITE_LIST_SIZE
+        | opcode::LE_CLEAR_WHITE_LIST
+        | opcode::LE_RAND
+        | opcode::LE_READ_STATES
+        | opcode::LE_TEST_END
+        | opcode::LE_READ_MAXIMUM_ADVERTISING_DATA_LENGTH
+        | opcode::LE_READ_NUMBER_OF_SUPPORTED_ADVERTISING_SETS
+        | opcode::LE_CLEAR_ADVERTISING_SETS
+        | opcode::LE_CLEAR_RESOLVING_LIST
+        | opcode::LE_READ_RESOLVING_LIST_SIZE
+        | opcode::LE_READ_LOCAL_P256_PUBLIC_KEY
+        | opcode::LE_READ_SUGGESTED_DEFAULT_DATA_LENGTH
+        | opcode::LE_READ_MAXIMUM_DATA_LENGTH
+        | opcode::LE_PERIODIC_ADVERTISING_CREATE_SYNC_CANCEL => &[],
+        opcode::READ_TX_POWER_LEVEL => &[HANDLE, ("Type", Uint(1))],
+        opcode::SET_CONTROLLER_TO_HOST_FLOW_CONTROL => &[("Flow control", Uint(1))],
+        opcode::HOST_BUFFER_SIZE => &[
+            ("ACL data packet length", Uint(2)),
+            ("SCO data packet length", Uint(1)),
+            ("Total ACL data packets", Uint(2)),
+            ("Total SCO data packets", Uint(2)),
+        ],
+        opcode::NUMBER_OF_COMPLETED_PACKETS => {
+            &[("Number of handles", Uint(1)), ("Handles and counts", Rest)]
+        }
+        opcode::LE_SET_RANDOM_ADDRESS => &[ADDRESS],
+        opcode::LE_SET_ADVERTISING_PARAMETERS => &[
+            ("Minimum interval", Uint(2)),
+            ("Maximum interval", Uint(2)),
+            ("Type", Uint(1)),
+            ("Own address type", Uint(1)),
+            ("Peer address type", Uint(1)),
+            ("Peer address", BdAddr),
+            ("Channel map", Mask(1)),
+            ("Filter policy", Uint(1)),
+        ],
+        opcode::LE_SET_ADVERTISING_DATA | opcode::LE_SET_SCAN_RESPONSE_DATA => {
+            &[("Data", PaddedData(31))]
+        }
+        opcode::LE_SET_ADVERTISE_ENABLE | opcode::LE_SET_ADDRESS_RESOLUTION_ENABLE => {
+            &[("Enable", Bool)]
+        }
+        opcode::LE_SET_SCAN_PARAMETERS => &[
+            ("Type", Uint(1)),
+            ("Interval", Uint(2)),
+            ("Window", Uint(2)),
+            ("Own address type", Uint(1)),
+            ("Filter policy", Uint(1)),
+        ],
+        opcode::LE_SET_SCAN_ENABLE => &[("Enable", Bool), ("Filter duplicates", Bool)],
+        opcode::LE_CREATE_CONNECTION => &[
+            ("Scan interval", Uint(2)),
+            ("Scan window", Uint(2)),
+            ("Filter policy", Uint(1)),
+            ("Peer address type", Uint(1)),
+            ("Peer address", BdAddr),
+            ("Own address type", Uint(1)),
+            ("Minimum interval", Uint(2)),
+            ("Maximum interval", Uint(2)),
+            ("Latency", Uint(2)),
+            ("Supervision timeout", Uint(2)),
+            ("Minimum CE length", Uint(2)),
+            ("Maximum CE length", Uint(2)),
+        ],
+        opcode::LE_ADD_DEVICE_TO_WHITE_LIST | opcode::LE_REMOVE_DEVICE_FROM_WHITE_LIST => {
+            PEER_ADDRESS
+        }
+        opcode::LE_CONNECTION_UPDATE => CONNECTION_PARAMETERS,
+        opcode::LE_SET_HOST_CHANNEL_CLASSIFICATION => &[("Channel map", Mask(5))],
+        opcode::LE_ENCRYPT => &[("Key", Bytes(16)), ("Plaintext", Bytes(16))],
+        opcode::LE_START_ENCRYPTION => &[
+            HANDLE,
+            ("Random number", Bytes(8)),
+            ("Encrypted diversifier", Mask(2)),
+            ("Long term key", Bytes(16)),
+        ],
+        opcode::LE_LTK_REQUEST_REPLY => &[HANDLE, ("Long term key", Bytes(16))],
+        opcode::LE_RECEIVER_TEST => &[("Channel", Uint(1))],
+        opcode::LE_TRANSMITTER_TEST => &[
+            ("Channel", Uint(1)),
+            ("Payload length", Uint(1)),
+            ("Payload", Uint(1)),
+        ],
+        opcode::LE_SET_DATA_LENGTH => &[HANDLE, ("TX octets", Uint(2)), ("TX time", Uint(2))],
+        opcode::LE_WRITE_SUGGESTED_DEFAULT_DATA_LENGTH => {
+            &[("TX octets", Uint(2)), ("TX time", Uint(2))]
+        }
+        opcode::LE_SET_DEFAULT_PHY => &[
+            ("All PHYs", Mask(1)),
+            ("TX PHYs", Mask(1)),
+            ("RX PHYs", Mask(1)),
+        ],
+        opcode::LE_SET_EXTENDED_ADVERTISING_PARAMETERS => &[
+            ADVERTISING_HANDLE,
+            ("Properties", Mask(2)),
+            ("Minimum interval", Uint(3)),
+            ("Maximum interval", Uint(3)),
+            ("Channel map", Mask(1)),
+            ("Own address type", Uint(1)),
+            ("Peer address type", Uint(1)),
+            ("Peer address", BdAddr),
+            ("Filter policy", Uint(1)),
+            ("TX power", Int8),
+            ("Primary PHY", Uint(1)),
+            ("Secondary maximum skip", Uint(1)),
+            ("Secondary PHY", Uint(1)),
+            ("SID", Uint(1)),
+            ("Scan request notification", Bool),
+        ],
+        opcode::LE_SET_EXTENDED_ADVERTISING_DATA | opcode::LE_SET_EXTENDED_SCAN_RESPONSE_DATA => {
+            EXTENDED_ADVERTISING_DATA
+        }
+        opcode::LE_SET_EXTENDED_ADVERTISING_ENABLE => &[
+            ("Enable", Bool),
+            ("Number of sets", Uint(1)),
+            ("Sets", Rest),
+        ],
+        opcode::LE_REMOVE_ADVERTISING_SET => &[ADVERTISING_HANDLE],
+        opcode::LE_SET_EXTENDED_SCAN_PARAMETERS => &[
+            ("Own address type", Uint(1)),
+            ("Filter policy", Uint(1)),
+            ("PHYs", Mask(1)),
+            ("PHY parameters", Rest),
+        ],
+        opcode::LE_SET_EXTENDED_SCAN_ENABLE => &[
+            ("Enable", Bool),
+            ("Filter duplicates", Uint(1)),
+            ("Duration", Uint(2)),
+            ("Period", Uint(2)),
+        ],
+        opcode::LE_EXTENDED_CREATE_CONNECTION => &[
+            ("Filter policy", Uint(1)),
+            ("Own address type", Uint(1)),
+            ("Peer address type", Uint(1)),
+            ("Peer address", BdAddr),
+            ("PHYs", Mask(1)),
+            ("PHY parameters", Rest),
+        ],
+        opcode::LE_ADD_DEVICE_TO_RESOLVING_LIST => &[
+            ADDRESS_TYPE,
+            ADDRESS,
+            ("Peer IRK", Bytes(16)),
+            ("Local IRK", Bytes(16)),
+        ],
+        opcode::LE_REMOVE_DEVICE_FROM_RESOLVING_LIST
+        | opcode::LE_READ_PEER_RESOLVABLE_ADDRESS
+        | opcode::LE_READ_LOCAL_RESOLVABLE_ADDRESS => PEER_ADDRESS,
+        opcode::LE_SET_RESOLVABLE_PRIVATE_ADDRESS_TIMEOUT => &[("Timeout", Uint(2))],
+        opcode::LE_SET_PRIVACY_MODE => &[ADDRESS_TYPE, ADDRESS, ("Mode", Uint(1))],
+        opcode::LE_GENERATE_DHKEY => &[("Remote public key", Bytes(64))],
+        opcode::LE_GENERATE_DHKEY_V2 => &[("Remote public key", Bytes(64)), ("Key type", Uint(1))],
+        opcode::LE_SET_PHY => &[
+            HANDLE,
+            ("All PHYs", Mask(1)),
+            ("TX PHYs", Mask(1)),
+            ("RX PHYs", Mask(1)),
+            ("PHY options", Uint(2)),
+        ],
+        opcode::LE_SET_PERIODIC_ADVERTISING_PARAMETERS => &[
+            ADVERTISING_HANDLE,
+            ("Minimum interval", Uint(2)),
+            ("Maximum interval", Uint(2)),
+            ("Properties", Mask(2)),
+        ],
+        opcode::LE_SET_PERIODIC_ADVERTISING_DATA => {
+            &[ADVERTISING_HANDLE, ("Operation", Uint(1)), ("Data", Data)]
+        }
+        opcode::LE_SET_PERIODIC_ADVERTISING_ENABLE => &[("Enable", Bool), ADVERTISING_HANDLE],
+        opcode::LE_PERIODIC_ADVERTISING_CREATE_SYNC => &[
+            ("Options", Mask(1)),
+            ("SID", Uint(1)),
+            ADDRESS_TYPE,
+            ADDRESS,
+            ("Skip", Uint(2)),
+            ("Sync timeout", Uint(2)),
+            ("CTE type", Mask(1)),
+        ],
+        opcode::LE_PERIODIC_ADVERTISING_SYNC_TRANSFER => {
+            &[HANDLE, ("Service data", Mask(2)), SYNC_HANDLE]
+        }
+        opcode::LE_PERIODIC_ADVERTISING_SET_INFO_TRANSFER => {
+            &[HANDLE, ("Service data", Mask(2)), ADVERTISING_HANDLE]
+        }
+        opcode::LE_SET_PERIODIC_ADVERTISING_SYNC_TRANSFER_PARAMETERS => &[
+            HANDLE,
+            ("Mode", Uint(1)),
+            ("Skip", Uint(2)),
+            ("Sync timeout", Uint(2)),
+            ("CTE type", Mask(1)),
+        ],
+        opcode::LE_SET_DEFAULT_PERIODIC_ADVERTISING_SYNC_TRANSFER_PARAMETERS => &[
+            ("Mode", Uint(1)),
+            ("Skip", Uint(2)),
+            ("Sync timeout", Uint(2)),
+            ("CTE type", Mask(1)),
+        ],
+        opcode::LE_REMOTE_CONNECTION_PARAMETER_REQUEST_REPLY => CONNECTION_PARAMETERS,
+        opcode::LE_REMOTE_CONNECTION_PARAMETER_REQUEST_NEGATIVE_REPLY => {
+            &[HANDLE, ("Reason", ErrorCode)]
+        }
+        opcode::WRITE_AUTHENTICATED_PAYLOAD_TIMEOUT => &[HANDLE, ("Timeout", Uint(2))],
+        opcode::LE_ENHANCED_READ_TRANSMIT_POWER_LEVEL
+        | opcode::LE_READ_REMOTE_TRANSMIT_POWER_LEVEL => &[HANDLE, ("PHY", Uint(1))],
+        opcode::LE_SET_PATH_LOSS_REPORTING_PARAMETERS => &[
+            HANDLE,
+            ("High threshold", Uint(1)),
+            ("High hysteresis", Uint(1)),
+            ("Low threshold", Uint(1)),
+            ("Low hysteresis", Uint(1)),
+            ("Minimum time spent", Uint(2)),
+        ],
+        opcode::LE_SET_PATH_LOSS_REPORTING_ENABLE => &[HANDLE, ("Enable", Bool)],
+        opcode::LE_SET_TRANSMIT_POWER_REPORTING_ENABLE => {
+            &[HANDLE, ("Local enable", Bool), ("Remote enable", Bool)]
+        }
+        _ => return vendor_parameters(opcode),
+    };
+
+    Some(fields)
+}
+
+/// Returns the layout of the parameters of the vendor-specific command with the given opcode, or
+/// `None` if it is not known.
+fn vendor_parameters(opcode: Opcode) -> Option<&'static [Field]> {
+    use vendor::opcode::*;
+
+    let fields: &[Field] = match opcode {
+        HAL_GET_FIRMWARE_REVISION
+        | HAL_DEVICE_STANDBY
+        | HAL_TX_TEST_PACKET_COUNT
+        | HAL_STOP_TONE
+        | HAL_GET_LINK_STATUS
+        | HAL_GET_ANCHOR_PERIOD
+        | HAL_GET_PM_DEBUG_INFO
+        | HAL_READ_RSSI
+        | HAL_READ_RAW_RSSI
+        | HAL_RX_STOP
+        | HAL_STACK_RESET
+        | GAP_SET_NONDISCOVERABLE
+        | GAP_CONFIGURE_WHITE_LIST
+        | GAP_CLEAR_SECURITY_DATABASE
+        | GAP_GET_BONDED_DEVICES
+        | GAP_ADDITIONAL_BEACON_STOP
+        | GAP_ADV_CLEAR_SETS
+        | GATT_INIT
+        | GATT_STORE_DB => &[],
+        HAL_WRITE_CONFIG_DATA => &[("Offset", Uint(1)), VALUE],
+        HAL_READ_CONFIG_DATA => &[("Offset", Uint(1))],
+        HAL_SET_TX_POWER_LEVEL => &[("High power", Uint(1)), ("PA level", Uint(1))],
+        HAL_START_TONE => &[("Channel", Uint(1)), ("Frequency offset", Uint(1))],
+        HAL_SET_RADIO_ACTIVITY_MASK => &[("Mask", Mask(2))],
+        HAL_SET_EVENT_MASK => &[("Mask", Mask(4))],
+        HAL_SET_PERIPHERAL_LATENCY => &[("Enable", Bool)],
+        HAL_READ_RADIO_REG => &[("Address", Mask(1))],
+        HAL_RX_START => &[("Channel", Uint(1))],
+        GAP_SET_IO_CAPABILITY => &[("IO capability", Uint(1))],
+        GAP_SET_AUTHORIZATION_REQUIREMENT => &[HANDLE, ("Authorization required", Bool)],
+        GAP_PASS_KEY_RESPONSE => &[HANDLE, ("Passkey", Uint(4))],
+        GAP_AUTHORIZATION_RESPONSE => &[HANDLE, ("Authorization", Uint(1))],
+        GAP_INIT => &[
+            ("Role", Mask(1)),
+            ("Privacy", Bool),
+            ("Device name length", Uint(1)),
+        ],
+        GAP_SET_NONCONNECTABLE => &[("Advertising type", Uint(1)), ADDRESS_TYPE],
+        GAP_PERIPHERAL_SECURITY_REQUEST | GAP_GET_SECURITY_LEVEL | GAP_ALLOW_REBOND => &[HANDLE],
+        GAP_UPDATE_ADVERTISING_DATA => &[("Data", Data)],
+        GAP_DELETE_AD_TYPE => &[("AD type", Mask(1))],
+        GAP_SET_EVENT_MASK => &[("Mask", Mask(2))],
+        GAP_TERMINATE => &[HANDLE, ("Reason", ErrorCode)],
+        GAP_TERMINATE_PROCEDURE => &[("Procedure", Mask(1))],
+        GAP_RESOLVE_PRIVATE_ADDRESS => &[ADDRESS],
+        GAP_IS_DEVICE_BONDED | GAP_REMOVE_BONDED_DEVICE => PEER_ADDRESS,
+        GAP_PASSKEY_INPUT => &[HANDLE, ("Input type", Uint(1))],
+        GAP_GET_OOB_DATA => &[("OOB data type", Uint(1))],
+        GAP_ADDITIONAL_BEACON_SET_DATA => &[("Data", Rest)],
+        GAP_ADV_REMOVE_SET => &[ADVERTISING_HANDLE],
+        GAP_ADV_SET_RANDOM_ADDRESS => &[ADVERTISING_HANDLE, ADDRESS],
+        GATT_ADD_SERVICE => &[
+            ("UUID", Uuid),
+            ("Service type", Uint(1)),
+            ("Maximum attribute records", Uint(1)),
+        ],
+        GATT_INCLUDE_SERVICE => &[SERVICE, START_HANDLE, END_HANDLE, ("UUID", Uuid)],
+        GATT_ADD_CHARACTERISTIC => &[
+            SERVICE,
+            ("UUID", Uuid),
+            ("Value length", Uint(2)),
+            ("Properties", Mask(1)),
+            ("Security permissions", Mask(1)),
+            ("Event mask", Mask(1)),
+            ("Encryption key size", Uint(1)),
+            ("Variable length", Bool),
+        ],
+        GATT_ADD_CHARACTERISTIC_DESCRIPTOR => &[
+            SERVICE,
+            CHARACTERISTIC,
+            ("UUID", Uuid),
+            ("Maximum value length", Uint(1)),
+            VALUE,
+            ("Security permissions", Mask(1)),
+            ("Access permissions", Mask(1)),
+            ("Event mask", Mask(1)),
+            ("Encryption key size", Uint(1)),
+            ("Variable length", Bool),
+        ],
+        GATT_UPDATE_CHARACTERISTIC_VALUE => &[SERVICE, CHARACTERISTIC, ("Offset", Uint(1)), VALUE],
+        GATT_DELETE_CHARACTERISTIC => &[SERVICE, CHARACTERISTIC],
+        GATT_DELETE_SERVICE => &[SERVICE],
+        GATT_DELETE_INCLUDED_SERVICE => &[SERVICE, ("Included service", Handle)],
+        GATT_SET_EVENT_MASK => &[("Mask", Mask(4))],
+        GATT_EXCHANGE_CONFIGURATION
+        | GATT_DISCOVER_ALL_PRIMARY_SERVICES
+        | GATT_CONFIRM_INDICATION
+        | GATT_ALLOW_READ => &[HANDLE],
+        GATT_FIND_INFORMATION_REQUEST
+        | GATT_FIND_INCLUDED_SERVICES
+        | GATT_DISCOVER_ALL_CHARACTERISTICS_OF_SERVICE
+        | GATT_DISCOVER_ALL_CHARACTERISTIC_DESCRIPTORS => HANDLE_RANGE,
+        GATT_FIND_BY_TYPE_VALUE_REQUEST => {
+            &[HANDLE, START_HANDLE, END_HANDLE, ("UUID", Mask(2)), VALUE]
+        }
+        GATT_READ_BY_TYPE_REQUEST
+        | GATT_READ_BY_GROUP_TYPE_REQUEST
+        | GATT_DISCOVER_CHARACTERISTICS_BY_UUID
+        | GATT_READ_CHARACTERISTIC_BY_UUID => HANDLE_RANGE_UUID,
+        GATT_PREPARE_WRITE_REQUEST => &[HANDLE, ATTRIBUTE, OFFSET, VALUE],
+        GATT_EXECUTE_WRITE_REQUEST => &[HANDLE, ("Execute", Bool)],
+        GATT_DISCOVER_PRIMARY_SERVICES_BY_UUID => &[HANDLE, ("UUID", Uuid)],
+        GATT_READ_CHARACTERISTIC_VALUE | GATT_READ_CHARACTERISTIC_DESCRIPTOR => {
+            &[HANDLE, CHARACTERISTIC]
+        }
+        GATT_READ_LONG_CHARACTERISTIC_VALUE | GATT_READ_LONG_CHARACTERISTIC_DESCRIPTOR => {
+            &[HANDLE, ATTRIBUTE, OFFSET]
+        }
+        GATT_READ_MULTIPLE_CHARACTERISTIC_VALUES
+        | GATT_SEND_MULT_NOTIFICATION
+        | GATT_READ_MULTIPLE_VAR_CHAR_VALUE => HANDLE_LIST,
+        GATT_WRITE_CHARACTERISTIC_VALUE
+        | GATT_WRITE_CHARACTERISTIC_DESCRIPTOR
+        | GATT_WRITE_WITHOUT_RESPONSE
+        | GATT_SIGNED_WRITE_WITHOUT_RESPONSE => CHARACTERISTIC_VALUE,
+        GATT_WRITE_LONG_CHARACTERISTIC_VALUE
+        | GATT_WRITE_CHARACTERISTIC_VALUE_RELIABLY
+        | GATT_WRITE_LONG_CHARACTERISTIC_DESCRIPTOR => LONG_CHARACTERISTIC_VALUE,
+        GATT_WRITE_RESPONSE => &[
+            HANDLE,
+            ATTRIBUTE,
+            ("Write status", Uint(1)),
+            ("Error code", Mask(1)),
+            VALUE,
+        ],
+        GATT_SET_SECURITY_PERMISSION | GATT_SET_ACCESS_PERMISSION => {
+            &[SERVICE, ATTRIBUTE, ("Permissions", Mask(1))]
+        }
+        GATT_SET_DESCRIPTOR_VALUE => &[
+            SERVICE,
+            CHARACTERISTIC,
+            ("Descriptor", Handle),
+            OFFSET,
+            VALUE,
+        ],
+        GATT_READ_HANDLE_VALUE_OFFSET => &[ATTRIBUTE, ("Offset", Uint(1))],
+        GATT_UPDATE_LONG_CHARACTERISTIC_VALUE => &[
+            HANDLE,
+            SERVICE,
+            CHARACTERISTIC,
+            ("Update type", Mask(1)),
+            ("Total length", Uint(2)),
+            OFFSET,
+            VALUE,
+        ],
+        GATT_DENY_READ => &[ATTRIBUTE, ("Error code", Mask(1))],
+        L2CAP_CONN_PARAM_UPDATE_REQ => &[
+            HANDLE,
+            ("Minimum interval", Uint(2)),
+            ("Maximum interval", Uint(2)),
+            ("Latency", Uint(2)),
+            ("Supervision timeout", Uint(2)),
+        ],
+        L2CAP_COC_DISCONNECT => &[("Channel index", Uint(1))],
+        L2CAP_COC_TX_DATA => &[
+            ("Channel index", Uint(1)),
+            ("Length", Uint(2)),
+            ("Data", Rest),
+        ],
+        _ => return None,
+    };
+
+    Some(fields)
+}
+
+/// Returns the layout of the return parameters that follow the status in the Command Complete
+/// event of the command with the given opcode, or `None` if it is not known.
+fn return_parameters(opcode: Opcode) -> Option<&'static [Field]> {
+    let fields: &[Field] = match opcode {
+        opcode::READ_LOCAL_VERSION_INFO => &[
+            ("HCI version", Uint(1)),
+            ("HCI revision", Uint(2)),
+            ("LMP version", Uint(1)),
+            ("Manufacturer", Mask(2)),
+            ("LMP subversion", Uint(2)),
+        ],
+        opcode::READ_LOCAL_SUPPORTED_COMMANDS => &[("Commands", Bytes(64))],
+        opcode::READ_LOCAL_SUPPORTED_FEATURES | opcode::LE_READ_LOCAL_SUPPORTED_FEATURES => {
+            &[FEATURES]
+        }
+        opcode::READ_BD_ADDR => &[ADDRESS],
+        opcode::READ_RSSI => &[HANDLE, ("RSSI", Int8)],
+        opcode::READ_TX_POWER_LEVEL => &[HANDLE, TX_POWER],
+        opcode::LE_READ_BUFFER_SIZE => &[
+            ("ACL data packet length", Uint(2)),
+            ("Total ACL data packets", Uint(1)),
+        ],
+        opcode::LE_READ_ADVERTISING_CHANNEL_TX_POWER => &[TX_POWER],
+        opcode::LE_READ_WHITE_LIST_SIZE => &[("Size", Uint(1))],
+        opcode::LE_READ_CHANNEL_MAP => &[HANDLE, ("Channel map", Mask(5))],
+        opcode::LE_ENCRYPT => &[("Encrypted data", Bytes(16))],
+        opcode::LE_RAND => &[("Random number", Bytes(8))],
+        opcode::LE_LTK_REQUEST_REPLY | opcode::LE_LTK_REQUEST_NEGATIVE_REPLY => &[HANDLE],
+        opcode::LE_READ_STATES => &[("States", Mask(8))],
+        opcode::LE_TEST_END => &[("Number of packets", Uint(2))],
+        opcode::LE_SET_EXTENDED_ADVERTISING_PARAMETERS => &[("Selected TX power", Int8)],
+        opcode::LE_READ_MAXIMUM_ADVERTISING_DATA_LENGTH => &[("Maximum length", Uint(2))],
+        opcode::LE_READ_NUMBER_OF_SUPPORTED_ADVERTISING_SETS => &[("Number of sets", Uint(1))],
+        opcode::LE_READ_RESOLVING_LIST_SIZE => &[("Size", Uint(1))],
+        opcode::LE_READ_PEER_RESOLVABLE_ADDRESS | opcode::LE_READ_LOCAL_RESOLVABLE_ADDRESS => {
+            &[ADDRESS]
+        }
+        opcode::LE_READ_PHY => &[HANDLE, ("TX PHY", Uint(1)), ("RX PHY", Uint(1))],
+        opcode::LE_READ_SUGGESTED_DEFAULT_DATA_LENGTH => {
+            &[("TX octets", Uint(2)), ("TX time", Uint(2))]
+        }
+        opcode::LE_READ_MAXIMUM_DATA_LENGTH => &[
+            ("Maximum TX octets", Uint(2)),
+            ("Maximum TX time", Uint(2)),
+            ("Maximum RX octets", Uint(2)),
+            ("Maximum RX time", Uint(2)),
+        ],
+        opcode::LE_PERIODIC_ADVERTISING_SYNC_TRANSFER
+        | opcode::LE_PERIODIC_ADVERTISING_SET_INFO_TRANSFER
+        | opcode::LE_SET_PERIODIC_ADVERTISING_SYNC_TRANSFER_PARAMETERS => &[HANDLE],
+        opcode::LE_REMOTE_CONNECTION_PARAMETER_REQUEST_REPLY
+        | opcode::LE_REMOTE_CONNECTION_PARAMETER_REQUEST_NEGATIVE_REPLY => &[HANDLE],
+        opcode::READ_AUTHENTICATED_PAYLOAD_TIMEOUT => &[HANDLE, ("Timeout", Uint(2))],
+        opcode::WRITE_AUTHENTICATED_PAYLOAD_TIMEOUT => &[HANDLE],
+        opcode::LE_ENHANCED_READ_TRANSMIT_POWER_LEVEL => &[
+            HANDLE,
+            ("PHY", Uint(1)),
+            ("Current TX power", Int8),
+            ("Maximum TX power", Int8),
+        ],
+        opcode::LE_SET_PATH_LOSS_REPORTING_PARAMETERS
+        | opcode::LE_SET_PATH_LOSS_REPORTING_ENABLE
+        | opcode::LE_SET_TRANSMIT_POWER_REPORTING_ENABLE => &[HANDLE],
+        _ => return vendor_return_parameters(opcode),
+    };
+
+    Some(fields)
+}
+
+/// Returns the layout of the return parameters that follow the status in the Command Complete
+/// event of the vendor-specific command with the given opcode, or `None` if it is not known.
+fn vendor_return_parameters(opcode: Opcode) -> Option<&'static [Field]> {
+    use vendor::opcode::*;
+
+    let fields: &[Field] = match opcode {
+        HAL_GET_FIRMWARE_REVISION => &[("Revision", Mask(2))],
+        HAL_READ_CONFIG_DATA => &[("Value", Rest)],
+        HAL_TX_TEST_PACKET_COUNT => &[("Number of packets", Uint(4))],
+        HAL_GET_LINK_STATUS => &[("States", Bytes(8)), ("Handles", Bytes(16))],
+        HAL_GET_ANCHOR_PERIOD => &[("Anchor interval", Uint(4)), ("Maximum slot", Uint(4))],
+        HAL_READ_RSSI | HAL_READ_RAW_RSSI => &[("RSSI", Uint(1))],
+        HAL_READ_RADIO_REG => &[("Value", Mask(1))],
+        GAP_INIT => &[
+            SERVICE,
+            ("Device name characteristic", Handle),
+            ("Appearance characteristic", Handle),
+        ],
+        GAP_GET_SECURITY_LEVEL => &[
+            ("MITM protection required", Bool),
+            ("Bonding required", Bool),
+            ("OOB data present", Bool),
+            ("Passkey required", Uint(1)),
+        ],
+        GAP_RESOLVE_PRIVATE_ADDRESS => &[ADDRESS],
+        GAP_GET_BONDED_DEVICES => &[("Number of devices", Uint(1)), ("Devices", Rest)],
+        GATT_ADD_SERVICE | GATT_INCLUDE_SERVICE => &[SERVICE],
+        GATT_ADD_CHARACTERISTIC => &[CHARACTERISTIC],
+        GATT_ADD_CHARACTERISTIC_DESCRIPTOR => &[("Descriptor", Handle)],
+        _ => return None,
+    };
+
+    Some(fields)
+}
+
+/// Returns the layout of the parameters of the event with the given event code, or `None` if it is
+/// not known. Command Complete, Command Status and LE Meta events are described separately.
+fn event_parameters(event_code: u8) -> Option<&'static [Field]> {
+    let fields: &[Field] = match event_code {
+        0x03 => &[
+            STATUS,
+            HANDLE,
+            ADDRESS,
+            ("Link type", Uint(1)),
+            ("Encryption", Bool),
+        ],
+        0x05 => &[STATUS, HANDLE, ("Reason", ErrorCode)],
+        0x08 => &[STATUS, HANDLE, ("Encryption", Uint(1))],
+        0x0C => &[
+            STATUS,
+            HANDLE,
+            ("Version", Uint(1)),
+            ("Manufacturer", Mask(2)),
+            ("Subversion", Uint(2)),
+        ],
+        0x10 => &[("Hardware code", Mask(1))],
+        0x13 => &[("Number of handles", Uint(1)), ("Handles and counts", Rest)],
+        0x1A => &[("Link type", Uint(1))],
+        0x30 => &[STATUS, HANDLE],
+        0x57 => &[HANDLE],
+        _ => return None,
+    };
+
+    Some(fields)
+}
+
+/// Returns the layout of the parameters that follow the subevent code of the LE Meta event with
+/// the given subevent code, or `None` if it is not known.
+fn le_event_parameters(subevent_code: u8) -> Option<&'static [Field]> {
+    let fields: &[Field] = match subevent_code {
+        0x01 => &[
+            STATUS,
+            HANDLE,
+            ("Role", Uint(1)),
+            ("Peer address type", Uint(1)),
+            ("Peer address", BdAddr),
+            ("Interval", Uint(2)),
+            ("Latency", Uint(2)),
+            ("Supervision timeout", Uint(2)),
+            ("Central clock accuracy", Uint(1)),
+        ],
+        0x02 => &[("Number of reports", Uint(1)), ("Reports", Rest)],
+        0x03 => &[
+            STATUS,
+            HANDLE,
+            ("Interval", Uint(2)),
+            ("Latency", Uint(2)),
+            ("Supervision timeout", Uint(2)),
+        ],
+        0x04 => &[STATUS, HANDLE, FEATURES],
+        0x05 => &[
+            HANDLE,
+            ("Random number", Bytes(8)),
+            ("Encrypted diversifier", Mask(2)),
+        ],
+        0x06 => &[
+            HANDLE,
+            ("Minimum interval", Uint(2)),
+            ("Maximum interval", Uint(2)),
+            ("Latency", Uint(2)),
+            ("Supervision timeout", Uint(2)),
+        ],
+        0x07 => &[
+            HANDLE,
+            ("Maximum TX octets", Uint(2)),
+            ("Maximum TX time", Uint(2)),
+            ("Maximum RX octets", Uint(2)),
+            ("Maximum RX time", Uint(2)),
+        ],
+        0x08 => &[STATUS, ("Public key", Bytes(64))],
+        0x09 => &[STATUS, ("DHKey", Bytes(32))],
+        0x0A => &[
+            STATUS,
+            HANDLE,
+            ("Role", Uint(1)),
+            ("Peer address type", Uint(1)),
+            ("Peer address", BdAddr),
+            ("Local resolvable private address", BdAddr),
+            ("Peer resolvable private address", BdAddr),
+            ("Interval", Uint(2)),
+            ("Latency", Uint(2)),
+            ("Supervision timeout", Uint(2)),
+            ("Central clock accuracy", Uint(1)),
+        ],
+        0x0C => &[STATUS, HANDLE, ("TX PHY", Uint(1)), ("RX PHY", Uint(1))],
+        0x0D => &[("Number of reports", Uint(1)), ("Reports", Rest)],
+        0x0E => &[
+            STATUS,
+            SYNC_HANDLE,
+            ("SID", Uint(1)),
+            ADDRESS_TYPE,
+            ADDRESS,
+            ("PHY", Uint(1)),
+            ("Interval", Uint(2)),
+            ("Clock accuracy", Uint(1)),
+        ],
+        0x0F => &[
+            SYNC_HANDLE,
+            TX_POWER,
+            ("RSSI", Int8),
+            ("CTE type", Uint(1)),
+            ("Data status", Uint(1)),
+            ("Data", Data),
+        ],
+        0x10 => &[SYNC_HANDLE],
+        0x11 => NO_FIELDS,
+        0x14 => &[HANDLE, ("Algorithm", Uint(1))],
+        0x18 => &[
+            STATUS,
+            HANDLE,
+            ("Service data", Mask(2)),
+            SYNC_HANDLE,
+            ("SID", Uint(1)),
+            ADDRESS_TYPE,
+            ADDRESS,
+            ("PHY", Uint(1)),
+            ("Interval", Uint(2)),
+            ("Clock accuracy", Uint(1)),
+        ],
+        0x20 => &[
+            HANDLE,
+            ("Current path loss", Uint(1)),
+            ("Zone entered", Uint(1)),
+        ],
+        0x21 => &[
+            STATUS,
+            HANDLE,
+            ("Reason", Uint(1)),
+            ("PHY", Uint(1)),
+            ("TX power level", Int8),
+            ("Flags", Mask(1)),
+            ("Delta", Int8),
+        ],
+        _ => return None,
+    };
+
+    Some(fields)
+}
+
+/// Writes the parameters of a command or event, one per line. Parameters that do not match the
+/// layout are written as a hex dump.
+fn write_fields(f: &mut Formatter, fields: &[Field], params: &[u8]) -> fmt::Result {
+    let mut rest = params;
+    for &(name, kind) in fields {
+        let len = match field_length(kind, rest) {
+            Some(len) if len <= rest.len() => len,
+            _ => {
+                write!(f, "\n{}Error: {} is truncated", INDENT, name)?;
+                return write_hex(f, rest);
+            }
+        };
+        let (value, next) = rest.split_at(len);
+        write!(f, "\n{}{}: ", INDENT, name)?;
+        write_field(f, kind, value)?;
+        rest = next;
+    }
+
+    if !rest.is_empty() {
+        write!(f, "\n{}Trailing bytes:", INDENT)?;
+        write_hex(f, rest)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the number of bytes of the field at the start of `bytes`, or `None` if the header of the
+/// field is missing or invalid.
+fn field_length(kind: Kind, bytes: &[u8]) -> Option<usize> {
+    match kind {
+        Uint(len) | Mask(len) | Bytes(len) => Some(len),
+        Int8 | Bool | ErrorCode => Some(1),
+        Handle => Some(2),
+        BdAddr => Some(6),
+        Data => bytes.first().map(|&len| 1 + len as usize),
+        PaddedData(len) => Some(1 + len),
+        Uuid => match bytes.first() {
+            Some(0x01) => Some(3),
+            Some(0x02) => Some(17),
+            _ => None,
+        },
+        Rest => Some(bytes.len()),
+    }
+}
+
+/// Writes the value of a field. `bytes` contains exactly the bytes of the field.
+fn write_field(f: &mut Formatter, kind: Kind, bytes: &[u8]) -> fmt::Result {
+    match kind {
+        Uint(_) => write!(f, "{}", LittleEndian::read_uint(bytes, bytes.len())),
+        Int8 => write!(f, "{}", bytes[0] as i8),
+        Mask(len) => write!(
+            f,
+            "0x{:0width$x}",
+            LittleEndian::read_uint(bytes, len),
+            width = 2 * len
+        ),
+        Handle => write!(f, "0x{:04x}", LittleEndian::read_u16(bytes)),
+        ErrorCode => write_standard_status(f, bytes[0]),
+        Bool => match bytes[0] {
+            0 => write!(f, "false"),
+            1 => write!(f, "true"),
+            other => write!(f, "Invalid (0x{:02x})", other),
+        },
+        BdAddr => {
+            for (i, byte) in bytes.iter().rev().enumerate() {
+                if i > 0 {
+                    f.write_char(':')?;
+                }
+                write!(f, "{:02X}", byte)?;
+            }
+            Ok(())
+        }
+        Bytes(_) | Rest => write_bytes(f, bytes),
+        Data => write_bytes(f, &bytes[1..]),
+        PaddedData(max_len) => {
+            let len = bytes[0] as usize;
+            if len > max_len {
+                write!(f, "Invalid length {}", len)?;
+                return write_hex_indented(f, &bytes[1..], 2);
+            }
+            write_bytes(f, &bytes[1..=len])
+        }
+        Uuid => {
+            let uuid = &bytes[1..];
+            if uuid.len() == 2 {
+                write!(f, "0x{:04x}", LittleEndian::read_u16(uuid))
+            } else {
+                for (i, byte) in uuid.iter().rev().enumerate() {
+                    if matches!(i, 4 | 6 | 8 | 10) {
+                        f.write_char('-')?;
+                    }
+                    write!(f, "{:02x}", byte)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Writes the length of `bytes`, followed by their hex dump on the next lines.
+fn write_bytes(f: &mut Formatter, bytes: &[u8]) -> fmt::Result {
+    write!(f, "{} bytes", bytes.len())?;
+    write_hex_indented(f, bytes, 2)
+}
+
+/// Returns the name of the event with the given event code, e.g. `"Command Complete"`.
+fn event_name(event_code: u8) -> Option<&'static str> {
+    Some(match event_code {
+        0x03 => "Connection Complete",
+        0x05 => "Disconnection Complete",
+        0x08 => "Encryption Change",
+        0x0C => "Read Remote Version Information Complete",
+        COMMAND_COMPLETE_EVENT => "Command Complete",
+        COMMAND_STATUS_EVENT => "Command Status",
+        0x10 => "Hardware Error",
+        0x13 => "Number of Completed Packets",
+        0x1A => "Data Buffer Overflow",
+        0x30 => "Encryption Key Refresh Complete",
+        0x57 => "Authenticated Payload Timeout Expired",
+        LE_META_EVENT => "LE Meta",
+        0xFF => "Vendor",
+        _ => return None,
+    })
+}
+
+/// Returns the name of the LE Meta event with the given subevent code, e.g.
+/// `"LE Connection Complete"`.
+fn le_subevent_name(subevent_code: u8) -> Option<&'static str> {
+    Some(match subevent_code {
+        0x01 => "LE Connection Complete",
+        0x02 => "LE Advertising Report",
+        0x03 => "LE Connection Update Complete",
+        0x04 => "LE Read Remote Features Complete",
+        0x05 => "LE Long Term Key Request",
+        0x06 => "LE Remote Connection Parameter Request",
+        0x07 => "LE Data Length Change",
+        0x08 => "LE Read Local P-256 Public Key Complete",
+        0x09 => "LE Generate DHKey Complete",
+        0x0A => "LE Enhanced Connection Complete",
+        0x0C => "LE PHY Update Complete",
+        0x0D => "LE Extended Advertising Report",
+        0x0E => "LE Periodic Advertising Sync Established",
+        0x0F => "LE Periodic Advertising Report",
+        0x10 => "LE Periodic Advertising Sync Lost",
+        0x11 => "LE Scan Timeout",
+        0x14 => "LE Channel Selection Algorithm",
+        0x18 => "LE Periodic Advertising Sync Transfer Received",
+        0x20 => "LE Path Loss Threshold",
+        0x21 => "LE Transmit Power Reporting",
+        _ => return None,
+    })
+}
+
+/// Writer that indents every line written through it.
+struct Indented<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    line_start: bool,
+}
+
+impl<'a, 'b> Indented<'a, 'b> {
+    fn new(f: &'a mut Formatter<'b>) -> Self {
+        Indented {
+            f,
+            line_start: true,
+        }
+    }
+}
+
+impl Write for Indented<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for (i, line) in s.split('\n').enumerate() {
+            if i > 0 {
+                self.f.write_char('\n')?;
+                self.line_start = true;
+            }
+            if !line.is_empty() {
+                if self.line_start {
+                    self.f.write_str(INDENT)?;
+                    self.line_start = false;
+                }
+                self.f.write_str(line)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -9,6 +9,7 @@
 //! For the return parameters of the commands, see the description of each command in sections 7.1 -
 //! 7.6 of the same part of the spec.
 
+use crate::types::AuthenticatedPayloadTimeout;
 use crate::vendor::opcode::VENDOR_OGF;
 use crate::{ConnectionHandle, Status};
 use byteorder::{ByteOrder, LittleEndian};
@@ -37,6 +38,10 @@ pub struct CommandComplete {
     /// command packets that the Host can send before waiting.
     pub num_hci_command_packets: u8,
 
+    /// Opcode of the command that has completed. [`Spontaneous`](ReturnParameters::Spontaneous)
+    /// events carry opcode 0x0000.
+    pub opcode: crate::opcode::Opcode,
+
     /// The type of command that has completed, and any parameters that it returns.
     pub return_params: ReturnParameters,
 }
@@ -57,7 +62,8 @@ impl CommandComplete {
     pub fn new(bytes: &[u8]) -> Result<CommandComplete, crate::event::Error> {
         require_len_at_least!(bytes, 3);
 
-        let params = match crate::opcode::Opcode(LittleEndian::read_u16(&bytes[1..])) {
+        let opcode = crate::opcode::Opcode(LittleEndian::read_u16(&bytes[1..]));
+        let params = match opcode {
             crate::opcode::Opcode(0x0000) => ReturnParameters::Spontaneous,
             crate::opcode::SET_EVENT_MASK => {
                 ReturnParameters::SetEventMask(to_status(&bytes[3..])?)
@@ -66,6 +72,19 @@ impl CommandComplete {
             crate::opcode::READ_TX_POWER_LEVEL => {
                 ReturnParameters::ReadTxPowerLevel(to_tx_power_level(&bytes[3..])?)
             }
+            crate::opcode::SET_EVENT_MASK_PAGE_2 => {
+                ReturnParameters::SetEventMaskPage2(to_status(&bytes[3..])?)
+            }
+            crate::opcode::READ_AUTHENTICATED_PAYLOAD_TIMEOUT => {
+                ReturnParameters::ReadAuthenticatedPayloadTimeout(
+                    to_read_authenticated_payload_timeout(&bytes[3..])?,
+                )
+            }
+            crate::opcode::WRITE_AUTHENTICATED_PAYLOAD_TIMEOUT => {
+                ReturnParameters::WriteAuthenticatedPayloadTimeout(
+                    to_connection_handle_return_parameters(&bytes[3..])?,
+                )
+            }
             crate::opcode::READ_LOCAL_VERSION_INFO => {
                 ReturnParameters::ReadLocalVersionInformation(to_local_version_info(&bytes[3..])?)
             }
@@ -157,15 +176,156 @@ impl CommandComplete {
                 ReturnParameters::LeTransmitterTest(to_status(&bytes[3..])?)
             }
             crate::opcode::LE_TEST_END => ReturnParameters::LeTestEnd(to_le_test_end(&bytes[3..])?),
+            crate::opcode::LE_REMOTE_CONNECTION_PARAMETER_REQUEST_REPLY => {
+                ReturnParameters::LeRemoteConnectionParameterRequestReply(
+                    to_connection_handle_return_parameters(&bytes[3..])?,
+                )
+            }
+            crate::opcode::LE_REMOTE_CONNECTION_PARAMETER_REQUEST_NEGATIVE_REPLY => {
+                ReturnParameters::LeRemoteConnectionParameterRequestNegativeReply(
+                    to_connection_handle_return_parameters(&bytes[3..])?,
+                )
+            }
             crate::opcode::LE_SET_DATA_LENGTH => {
                 ReturnParameters::LeSetDataLength(to_status(&bytes[3..])?)
             }
+            crate::opcode::LE_READ_SUGGESTED_DEFAULT_DATA_LENGTH => {
+                ReturnParameters::LeReadSuggestedDefaultDataLength(
+                    to_le_suggested_default_data_length(&bytes[3..])?,
+                )
+            }
             crate::opcode::LE_WRITE_SUGGESTED_DEFAULT_DATA_LENGTH => {
                 ReturnParameters::LeWriteSuggestedDefaultDataLength(to_status(&bytes[3..])?)
             }
+            crate::opcode::LE_READ_MAXIMUM_DATA_LENGTH => {
+                ReturnParameters::LeReadMaximumDataLength(to_le_maximum_data_length(&bytes[3..])?)
+            }
+            crate::opcode::LE_READ_PHY => ReturnParameters::LeReadPhy(to_le_read_phy(&bytes[3..])?),
             crate::opcode::LE_SET_DEFAULT_PHY => {
                 ReturnParameters::LeSetDefaultPhy(to_status(&bytes[3..])?)
             }
+            crate::opcode::LE_SET_EXTENDED_ADVERTISING_PARAMETERS => {
+                ReturnParameters::LeSetExtendedAdvertisingParameters(
+                    to_le_extended_advertising_parameters(&bytes[3..])?,
+                )
+            }
+            crate::opcode::LE_SET_EXTENDED_ADVERTISING_DATA => {
+                ReturnParameters::LeSetExtendedAdvertisingData(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_EXTENDED_SCAN_RESPONSE_DATA => {
+                ReturnParameters::LeSetExtendedScanResponseData(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_EXTENDED_ADVERTISING_ENABLE => {
+                ReturnParameters::LeSetExtendedAdvertisingEnable(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_READ_MAXIMUM_ADVERTISING_DATA_LENGTH => {
+                ReturnParameters::LeReadMaximumAdvertisingDataLength(
+                    to_le_maximum_advertising_data_length(&bytes[3..])?,
+                )
+            }
+            crate::opcode::LE_READ_NUMBER_OF_SUPPORTED_ADVERTISING_SETS => {
+                ReturnParameters::LeReadNumberOfSupportedAdvertisingSets(
+                    to_le_number_of_supported_advertising_sets(&bytes[3..])?,
+                )
+            }
+            crate::opcode::LE_REMOVE_ADVERTISING_SET => {
+                ReturnParameters::LeRemoveAdvertisingSet(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_CLEAR_ADVERTISING_SETS => {
+                ReturnParameters::LeClearAdvertisingSets(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_PERIODIC_ADVERTISING_PARAMETERS => {
+                ReturnParameters::LeSetPeriodicAdvertisingParameters(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_PERIODIC_ADVERTISING_DATA => {
+                ReturnParameters::LeSetPeriodicAdvertisingData(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_PERIODIC_ADVERTISING_ENABLE => {
+                ReturnParameters::LeSetPeriodicAdvertisingEnable(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_EXTENDED_SCAN_PARAMETERS => {
+                ReturnParameters::LeSetExtendedScanParameters(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_EXTENDED_SCAN_ENABLE => {
+                ReturnParameters::LeSetExtendedScanEnable(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_PERIODIC_ADVERTISING_CREATE_SYNC_CANCEL => {
+                ReturnParameters::LePeriodicAdvertisingCreateSyncCancel(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_PERIODIC_ADVERTISING_TERMINATE_SYNC => {
+                ReturnParameters::LePeriodicAdvertisingTerminateSync(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_PERIODIC_ADVERTISING_SYNC_TRANSFER => {
+                ReturnParameters::LePeriodicAdvertisingSyncTransfer(
+                    to_connection_handle_return_parameters(&bytes[3..])?,
+                )
+            }
+            crate::opcode::LE_PERIODIC_ADVERTISING_SET_INFO_TRANSFER => {
+                ReturnParameters::LePeriodicAdvertisingSetInfoTransfer(
+                    to_connection_handle_return_parameters(&bytes[3..])?,
+                )
+            }
+            crate::opcode::LE_SET_PERIODIC_ADVERTISING_SYNC_TRANSFER_PARAMETERS => {
+                ReturnParameters::LeSetPeriodicAdvertisingSyncTransferParameters(
+                    to_connection_handle_return_parameters(&bytes[3..])?,
+                )
+            }
+            crate::opcode::LE_SET_DEFAULT_PERIODIC_ADVERTISING_SYNC_TRANSFER_PARAMETERS => {
+                ReturnParameters::LeSetDefaultPeriodicAdvertisingSyncTransferParameters(to_status(
+                    &bytes[3..],
+                )?)
+            }
+            crate::opcode::LE_ADD_DEVICE_TO_RESOLVING_LIST => {
+                ReturnParameters::LeAddDeviceToResolvingList(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_REMOVE_DEVICE_FROM_RESOLVING_LIST => {
+                ReturnParameters::LeRemoveDeviceFromResolvingList(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_CLEAR_RESOLVING_LIST => {
+                ReturnParameters::LeClearResolvingList(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_READ_RESOLVING_LIST_SIZE => {
+                ReturnParameters::LeReadResolvingListSize(to_le_resolving_list_size(&bytes[3..])?)
+            }
+            crate::opcode::LE_READ_PEER_RESOLVABLE_ADDRESS => {
+                ReturnParameters::LeReadPeerResolvableAddress(to_le_resolvable_address(
+                    &bytes[3..],
+                )?)
+            }
+            crate::opcode::LE_READ_LOCAL_RESOLVABLE_ADDRESS => {
+                ReturnParameters::LeReadLocalResolvableAddress(to_le_resolvable_address(
+                    &bytes[3..],
+                )?)
+            }
+            crate::opcode::LE_SET_ADDRESS_RESOLUTION_ENABLE => {
+                ReturnParameters::LeSetAddressResolutionEnable(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_RESOLVABLE_PRIVATE_ADDRESS_TIMEOUT => {
+                ReturnParameters::LeSetResolvablePrivateAddressTimeout(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_PRIVACY_MODE => {
+                ReturnParameters::LeSetPrivacyMode(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_ENHANCED_READ_TRANSMIT_POWER_LEVEL => {
+                ReturnParameters::LeEnhancedReadTransmitPowerLevel(
+                    to_le_enhanced_transmit_power_level(&bytes[3..])?,
+                )
+            }
+            crate::opcode::LE_SET_PATH_LOSS_REPORTING_PARAMETERS => {
+                ReturnParameters::LeSetPathLossReportingParameters(
+                    to_connection_handle_return_parameters(&bytes[3..])?,
+                )
+            }
+            crate::opcode::LE_SET_PATH_LOSS_REPORTING_ENABLE => {
+                ReturnParameters::LeSetPathLossReportingEnable(
+                    to_connection_handle_return_parameters(&bytes[3..])?,
+                )
+            }
+            crate::opcode::LE_SET_TRANSMIT_POWER_REPORTING_ENABLE => {
+                ReturnParameters::LeSetTransmitPowerReportingEnable(
+                    to_connection_handle_return_parameters(&bytes[3..])?,
+                )
+            }
             other => {
                 if other.ogf() != VENDOR_OGF {
                     return Err(crate::event::Error::UnknownOpcode(other));
@@ -178,9 +338,26 @@ impl CommandComplete {
         };
         Ok(CommandComplete {
             num_hci_command_packets: bytes[0],
+            opcode,
             return_params: params,
         })
     }
+
+    /// Serializes the event parameters into `bytes`, in the format read by
+    /// [`CommandComplete::new`]. Returns the number of bytes written.
+    ///
+    /// The opcode is written as it is, so it must match the type of the return parameters for the
+    /// bytes to deserialize into the same event.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is too short for the return parameters.
+    pub fn copy_into_slice(&self, bytes: &mut [u8]) -> usize {
+        bytes[0] = self.num_hci_command_packets;
+        LittleEndian::write_u16(&mut bytes[1..], self.opcode.0);
+
+        3 + self.return_params.copy_into_slice(&mut bytes[3..])
+    }
 }
 
 /// Commands that may generate the [Command Complete](crate::event::Event::CommandComplete) event.
@@ -202,6 +379,20 @@ pub enum ReturnParameters {
     /// [Read Transmit Power Level](crate::host::HostHci::read_tx_power_level) return parameters.
     ReadTxPowerLevel(TxPowerLevel),
 
+    /// Status returned by the [Set Event Mask Page 2](crate::host::HostHci::set_event_mask_page_2)
+    /// command.
+    SetEventMaskPage2(Status),
+
+    /// Parameters returned by the
+    /// [Read Authenticated Payload Timeout](crate::host::HostHci::read_authenticated_payload_timeout)
+    /// command.
+    ReadAuthenticatedPayloadTimeout(ReadAuthenticatedPayloadTimeout),
+
+    /// Parameters returned by the
+    /// [Write Authenticated Payload Timeout](crate::host::HostHci::write_authenticated_payload_timeout)
+    /// command.
+    WriteAuthenticatedPayloadTimeout(ConnectionHandleReturnParameters),
+
     /// Local version info returned by the
     /// [Read Local Version Information](crate::host::HostHci::read_local_version_information) command.
     ReadLocalVersionInformation(LocalVersionInfo),
@@ -316,16 +507,429 @@ pub enum ReturnParameters {
     /// Parameters returned by the [LE Test End](crate::host::HostHci::le_test_end) command.
     LeTestEnd(LeTestEnd),
 
+    /// Parameters returned by the
+    /// [LE Remote Connection Parameter Request Reply](crate::host::HostHci::le_remote_connection_parameter_request_reply)
+    /// command.
+    LeRemoteConnectionParameterRequestReply(ConnectionHandleReturnParameters),
+
+    /// Parameters returned by the
+    /// [LE Remote Connection Parameter Request Negative Reply](crate::host::HostHci::le_remote_connection_parameter_request_negative_reply)
+    /// command.
+    LeRemoteConnectionParameterRequestNegativeReply(ConnectionHandleReturnParameters),
+
+    /// Status returned by the [LE Set Data Length](crate::host::HostHci::le_set_data_length)
+    /// command.
     LeSetDataLength(Status),
 
+    /// Parameters returned by the
+    /// [LE Read Suggested Default Data Length](crate::host::HostHci::le_read_suggested_default_data_length)
+    /// command.
+    LeReadSuggestedDefaultDataLength(LeSuggestedDefaultDataLength),
+
+    /// Status returned by the
+    /// [LE Write Suggested Default Data Length](crate::host::HostHci::le_write_suggested_default_data_length)
+    /// command.
     LeWriteSuggestedDefaultDataLength(Status),
 
+    /// Parameters returned by the
+    /// [LE Read Maximum Data Length](crate::host::HostHci::le_read_maximum_data_length) command.
+    LeReadMaximumDataLength(LeMaximumDataLength),
+
     LeSetDefaultPhy(Status),
 
+    /// Parameters returned by the [LE Read PHY](crate::host::HostHci::le_read_phy) command.
+    LeReadPhy(LeReadPhy),
+
+    /// Parameters returned by the
+    /// [LE Set Extended Advertising Parameters](crate::host::HostHci::le_set_extended_advertising_parameters)
+    /// command.
+    LeSetExtendedAdvertisingParameters(LeExtendedAdvertisingParameters),
+
+    /// Status returned by the
+    /// [LE Set Extended Advertising Data](crate::host::HostHci::le_set_extended_advertising_data)
+    /// command.
+    LeSetExtendedAdvertisingData(Status),
+
+    /// Status returned by the
+    /// [LE Set Extended Scan Response Data](crate::host::HostHci::le_set_extended_scan_response_data)
+    /// command.
+    LeSetExtendedScanResponseData(Status),
+
+    /// Status returned by the
+    /// [LE Set Extended Advertising Enable](crate::host::HostHci::le_set_extended_advertising_enable)
+    /// command.
+    LeSetExtendedAdvertisingEnable(Status),
+
+    /// Parameters returned by the
+    /// [LE Read Maximum Advertising Data Length](crate::host::HostHci::le_read_maximum_advertising_data_length)
+    /// command.
+    LeReadMaximumAdvertisingDataLength(LeMaximumAdvertisingDataLength),
+
+    /// Parameters returned by the
+    /// [LE Read Number of Supported Advertising Sets](crate::host::HostHci::le_read_number_of_supported_advertising_sets)
+    /// command.
+    LeReadNumberOfSupportedAdvertisingSets(LeNumberOfSupportedAdvertisingSets),
+
+    /// Status returned by the
+    /// [LE Remove Advertising Set](crate::host::HostHci::le_remove_advertising_set) command.
+    LeRemoveAdvertisingSet(Status),
+
+    /// Status returned by the
+    /// [LE Clear Advertising Sets](crate::host::HostHci::le_clear_advertising_sets) command.
+    LeClearAdvertisingSets(Status),
+
+    /// Status returned by the
+    /// [LE Set Periodic Advertising Parameters](crate::host::HostHci::le_set_periodic_advertising_parameters)
+    /// command.
+    LeSetPeriodicAdvertisingParameters(Status),
+
+    /// Status returned by the
+    /// [LE Set Periodic Advertising Data](crate::host::HostHci::le_set_periodic_advertising_data)
+    /// command.
+    LeSetPeriodicAdvertisingData(Status),
+
+    /// Status returned by the
+    /// [LE Set Periodic Advertising Enable](crate::host::HostHci::le_set_periodic_advertising_enable)
+    /// command.
+    LeSetPeriodicAdvertisingEnable(Status),
+
+    /// Status returned by the
+    /// [LE Set Extended Scan Parameters](crate::host::HostHci::le_set_extended_scan_parameters)
+    /// command.
+    LeSetExtendedScanParameters(Status),
+
+    /// Status returned by the
+    /// [LE Set Extended Scan Enable](crate::host::HostHci::le_set_extended_scan_enable) command.
+    LeSetExtendedScanEnable(Status),
+
+    /// Status returned by the
+    /// [LE Periodic Advertising Create Sync Cancel](crate::host::HostHci::le_periodic_advertising_create_sync_cancel)
+    /// command.
+    LePeriodicAdvertisingCreateSyncCancel(Status),
+
+    /// Status returned by the
+    /// [LE Periodic Advertising Terminate Sync](crate::host::HostHci::le_periodic_advertising_terminate_sync)
+    /// command.
+    LePeriodicAdvertisingTerminateSync(Status),
+
+    /// Parameters returned by the
+    /// [LE Periodic Advertising Sync Transfer](crate::host::HostHci::le_periodic_advertising_sync_transfer)
+    /// command.
+    LePeriodicAdvertisingSyncTransfer(ConnectionHandleReturnParameters),
+
+    /// Parameters returned by the
+    /// [LE Periodic Advertising Set Info Transfer](crate::host::HostHci::le_periodic_advertising_set_info_transfer)
+    /// command.
+    LePeriodicAdvertisingSetInfoTransfer(ConnectionHandleReturnParameters),
+
+    /// Parameters returned by the
+    /// [LE Set Periodic Advertising Sync Transfer Parameters](crate::host::HostHci::le_set_periodic_advertising_sync_transfer_parameters)
+    /// command.
+    LeSetPeriodicAdvertisingSyncTransferParameters(ConnectionHandleReturnParameters),
+
+    /// Status returned by the
+    /// [LE Set Default Periodic Advertising Sync Transfer Parameters](crate::host::HostHci::le_set_default_periodic_advertising_sync_transfer_parameters)
+    /// command.
+    LeSetDefaultPeriodicAdvertisingSyncTransferParameters(Status),
+
+    /// Status returned by the
+    /// [LE Add Device To Resolving List](crate::host::HostHci::le_add_device_to_resolving_list)
+    /// command.
+    LeAddDeviceToResolvingList(Status),
+
+    /// Status returned by the
+    /// [LE Remove Device From Resolving List](crate::host::HostHci::le_remove_device_from_resolving_list)
+    /// command.
+    LeRemoveDeviceFromResolvingList(Status),
+
+    /// Status returned by the [LE Clear Resolving List](crate::host::HostHci::le_clear_resolving_list)
+    /// command.
+    LeClearResolvingList(Status),
+
+    /// Parameters returned by the
+    /// [LE Read Resolving List Size](crate::host::HostHci::le_read_resolving_list_size) command.
+    LeReadResolvingListSize(LeResolvingListSize),
+
+    /// Parameters returned by the
+    /// [LE Read Peer Resolvable Address](crate::host::HostHci::le_read_peer_resolvable_address)
+    /// command.
+    LeReadPeerResolvableAddress(LeResolvableAddress),
+
+    /// Parameters returned by the
+    /// [LE Read Local Resolvable Address](crate::host::HostHci::le_read_local_resolvable_address)
+    /// command.
+    LeReadLocalResolvableAddress(LeResolvableAddress),
+
+    /// Status returned by the
+    /// [LE Set Address Resolution Enable](crate::host::HostHci::le_set_address_resolution_enable)
+    /// command.
+    LeSetAddressResolutionEnable(Status),
+
+    /// Status returned by the
+    /// [LE Set Resolvable Private Address Timeout](crate::host::HostHci::le_set_resolvable_private_address_timeout)
+    /// command.
+    LeSetResolvablePrivateAddressTimeout(Status),
+
+    /// Status returned by the [LE Set Privacy Mode](crate::host::HostHci::le_set_privacy_mode)
+    /// command.
+    LeSetPrivacyMode(Status),
+
+    /// Parameters returned by the
+    /// [LE Enhanced Read Transmit Power Level](crate::host::HostHci::le_enhanced_read_transmit_power_level)
+    /// command.
+    LeEnhancedReadTransmitPowerLevel(LeEnhancedTransmitPowerLevel),
+
+    /// Parameters returned by the
+    /// [LE Set Path Loss Reporting Parameters](crate::host::HostHci::le_set_path_loss_reporting_parameters)
+    /// command.
+    LeSetPathLossReportingParameters(ConnectionHandleReturnParameters),
+
+    /// Parameters returned by the
+    /// [LE Set Path Loss Reporting Enable](crate::host::HostHci::le_set_path_loss_reporting_enable)
+    /// command.
+    LeSetPathLossReportingEnable(ConnectionHandleReturnParameters),
+
+    /// Parameters returned by the
+    /// [LE Set Transmit Power Reporting Enable](crate::host::HostHci::le_set_transmit_power_reporting_enable)
+    /// command.
+    LeSetTransmitPowerReportingEnable(ConnectionHandleReturnParameters),
+
     /// Parameters returned by vendor-specific commands.
     Vendor(crate::vendor::event::response::VendorReturnParameters),
 }
 
+impl ReturnParameters {
+    /// Serializes the return parameters into `bytes`, in the format read from a
+    /// [Command Complete](crate::event::Event::CommandComplete) event. Returns the number of bytes
+    /// written, which is 0 for [`Spontaneous`](ReturnParameters::Spontaneous) events.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is too short for the return parameters.
+    pub fn copy_into_slice(&self, bytes: &mut [u8]) -> usize {
+        match self {
+            ReturnParameters::Spontaneous => 0,
+            ReturnParameters::SetEventMask(status)
+            | ReturnParameters::Reset(status)
+            | ReturnParameters::SetEventMaskPage2(status)
+            | ReturnParameters::LeSetEventMask(status)
+            | ReturnParameters::LeSetRandomAddress(status)
+            | ReturnParameters::LeSetAdvertisingParameters(status)
+            | ReturnParameters::LeSetAdvertisingData(status)
+            | ReturnParameters::LeSetScanResponseData(status)
+            | ReturnParameters::LeSetAdvertisingEnable(status)
+            | ReturnParameters::LeSetScanParameters(status)
+            | ReturnParameters::LeSetScanEnable(status)
+            | ReturnParameters::LeCreateConnectionCancel(status)
+            | ReturnParameters::LeClearWhiteList(status)
+            | ReturnParameters::LeAddDeviceToWhiteList(status)
+            | ReturnParameters::LeRemoveDeviceFromWhiteList(status)
+            | ReturnParameters::LeSetHostChannelClassification(status)
+            | ReturnParameters::LeReceiverTest(status)
+            | ReturnParameters::LeTransmitterTest(status)
+            | ReturnParameters::LeSetDataLength(status)
+            | ReturnParameters::LeWriteSuggestedDefaultDataLength(status)
+            | ReturnParameters::LeSetDefaultPhy(status)
+            | ReturnParameters::LeSetExtendedAdvertisingData(status)
+            | ReturnParameters::LeSetExtendedScanResponseData(status)
+            | ReturnParameters::LeSetExtendedAdvertisingEnable(status)
+            | ReturnParameters::LeRemoveAdvertisingSet(status)
+            | ReturnParameters::LeClearAdvertisingSets(status)
+            | ReturnParameters::LeSetPeriodicAdvertisingParameters(status)
+            | ReturnParameters::LeSetPeriodicAdvertisingData(status)
+            | ReturnParameters::LeSetPeriodicAdvertisingEnable(status)
+            | ReturnParameters::LeSetExtendedScanParameters(status)
+            | ReturnParameters::LeSetExtendedScanEnable(status)
+            | ReturnParameters::LePeriodicAdvertisingCreateSyncCancel(status)
+            | ReturnParameters::LePeriodicAdvertisingTerminateSync(status)
+            | ReturnParameters::LeSetDefaultPeriodicAdvertisingSyncTransferParameters(status)
+            | ReturnParameters::LeAddDeviceToResolvingList(status)
+            | ReturnParameters::LeRemoveDeviceFromResolvingList(status)
+            | ReturnParameters::LeClearResolvingList(status)
+            | ReturnParameters::LeSetAddressResolutionEnable(status)
+            | ReturnParameters::LeSetResolvablePrivateAddressTimeout(status)
+            | ReturnParameters::LeSetPrivacyMode(status) => {
+                bytes[0] = (*status).into();
+                1
+            }
+            ReturnParameters::ReadTxPowerLevel(params) => {
+                bytes[0] = params.status.into();
+                LittleEndian::write_u16(&mut bytes[1..], params.conn_handle.0);
+                bytes[3] = params.tx_power_level_dbm as u8;
+                4
+            }
+            ReturnParameters::ReadLocalVersionInformation(params) => {
+                bytes[0] = params.status.into();
+                bytes[1] = params.hci_version;
+                LittleEndian::write_u16(&mut bytes[2..], params.hci_revision);
+                bytes[4] = params.lmp_version;
+                LittleEndian::write_u16(&mut bytes[5..], params.manufacturer_name);
+                LittleEndian::write_u16(&mut bytes[7..], params.lmp_subversion);
+                9
+            }
+            ReturnParameters::ReadLocalSupportedCommands(params) => {
+                bytes[0] = params.status.into();
+                params
+                    .supported_commands
+                    .copy_into_slice(&mut bytes[1..=COMMAND_FLAGS_SIZE]);
+                1 + COMMAND_FLAGS_SIZE
+            }
+            ReturnParameters::ReadLocalSupportedFeatures(params) => {
+                bytes[0] = params.status.into();
+                LittleEndian::write_u64(&mut bytes[1..], params.supported_features.bits());
+                9
+            }
+            ReturnParameters::ReadBdAddr(params) => {
+                bytes[0] = params.status.into();
+                bytes[1..7].copy_from_slice(&params.bd_addr.0);
+                7
+            }
+            ReturnParameters::ReadRssi(params) => {
+                bytes[0] = params.status.into();
+                LittleEndian::write_u16(&mut bytes[1..], params.conn_handle.0);
+                bytes[3] = params.rssi as u8;
+                4
+            }
+            ReturnParameters::LeReadBufferSize(params) => {
+                bytes[0] = params.status.into();
+                LittleEndian::write_u16(&mut bytes[1..], params.data_packet_length);
+                bytes[3] = params.data_packet_count;
+                4
+            }
+            ReturnParameters::LeReadLocalSupportedFeatures(params) => {
+                bytes[0] = params.status.into();
+                LittleEndian::write_u64(&mut bytes[1..], params.supported_features.bits());
+                9
+            }
+            ReturnParameters::LeReadAdvertisingChannelTxPower(params) => {
+                bytes[0] = params.status.into();
+                bytes[1] = params.power as u8;
+                2
+            }
+            ReturnParameters::LeReadWhiteListSize(status, size) => {
+                bytes[0] = (*status).into();
+                bytes[1] = *size as u8;
+                2
+            }
+            ReturnParameters::LeReadChannelMap(params) => {
+                bytes[0] = params.status.into();
+                LittleEndian::write_u16(&mut bytes[1..], params.conn_handle.0);
+                params.channel_map.copy_into_slice(&mut bytes[3..8]);
+                8
+            }
+            ReturnParameters::LeEncrypt(params) => {
+                bytes[0] = params.status.into();
+                bytes[1..17].copy_from_slice(&params.encrypted_data.0);
+                17
+            }
+            ReturnParameters::LeRand(params) => {
+                bytes[0] = params.status.into();
+                LittleEndian::write_u64(&mut bytes[1..], params.random_number);
+                9
+            }
+            ReturnParameters::LeLongTermKeyRequestReply(params)
+            | ReturnParameters::LeLongTermKeyRequestNegativeReply(params) => {
+                bytes[0] = params.status.into();
+                LittleEndian::write_u16(&mut bytes[1..], params.conn_handle.0);
+                3
+            }
+            ReturnParameters::LeEnhancedReadTransmitPowerLevel(params) => {
+                bytes[0] = params.status.into();
+                LittleEndian::write_u16(&mut bytes[1..], params.conn_handle.0);
+                match params.levels {
+                    Some(levels) => {
+                        bytes[3] = levels.phy as u8;
+                        bytes[4] = levels.current_tx_power_level as u8;
+                        bytes[5] = levels.max_tx_power_level as u8;
+                    }
+                    None => bytes[3..6].fill(0),
+                }
+                6
+            }
+            ReturnParameters::ReadAuthenticatedPayloadTimeout(params) => {
+                bytes[0] = params.status.into();
+                LittleEndian::write_u16(&mut bytes[1..], params.conn_handle.0);
+                match params.timeout {
+                    Some(timeout) => timeout.copy_into_slice(&mut bytes[3..5]),
+                    None => LittleEndian::write_u16(&mut bytes[3..], 0),
+                }
+                5
+            }
+            ReturnParameters::WriteAuthenticatedPayloadTimeout(params)
+            | ReturnParameters::LeRemoteConnectionParameterRequestReply(params)
+            | ReturnParameters::LeRemoteConnectionParameterRequestNegativeReply(params)
+            | ReturnParameters::LePeriodicAdvertisingSyncTransfer(params)
+            | ReturnParameters::LePeriodicAdvertisingSetInfoTransfer(params)
+            | ReturnParameters::LeSetPeriodicAdvertisingSyncTransferParameters(params)
+            | ReturnParameters::LeSetPathLossReportingParameters(params)
+            | ReturnParameters::LeSetPathLossReportingEnable(params)
+            | ReturnParameters::LeSetTransmitPowerReportingEnable(params) => {
+                bytes[0] = params.status.into();
+                LittleEndian::write_u16(&mut bytes[1..], params.conn_handle.0);
+                3
+            }
+            ReturnParameters::LeReadSupportedStates(params) => {
+                bytes[0] = params.status.into();
+                LittleEndian::write_u64(&mut bytes[1..], params.supported_states.bits());
+                9
+            }
+            ReturnParameters::LeTestEnd(params) => {
+                bytes[0] = params.status.into();
+                LittleEndian::write_u16(&mut bytes[1..], params.number_of_packets as u16);
+                3
+            }
+            ReturnParameters::LeReadSuggestedDefaultDataLength(params) => {
+                bytes[0] = params.status.into();
+                LittleEndian::write_u16(&mut bytes[1..], params.max_tx_octets);
+                LittleEndian::write_u16(&mut bytes[3..], params.max_tx_time);
+                5
+            }
+            ReturnParameters::LeReadMaximumDataLength(params) => {
+                bytes[0] = params.status.into();
+                LittleEndian::write_u16(&mut bytes[1..], params.max_tx_octets);
+                LittleEndian::write_u16(&mut bytes[3..], params.max_tx_time);
+                LittleEndian::write_u16(&mut bytes[5..], params.max_rx_octets);
+                LittleEndian::write_u16(&mut bytes[7..], params.max_rx_time);
+                9
+            }
+            ReturnParameters::LeSetExtendedAdvertisingParameters(params) => {
+                bytes[0] = params.status.into();
+                bytes[1] = params.selected_tx_power as u8;
+                2
+            }
+            ReturnParameters::LeReadMaximumAdvertisingDataLength(params) => {
+                bytes[0] = params.status.into();
+                LittleEndian::write_u16(&mut bytes[1..], params.max_advertising_data_length as u16);
+                3
+            }
+            ReturnParameters::LeReadNumberOfSupportedAdvertisingSets(params) => {
+                bytes[0] = params.status.into();
+                bytes[1] = params.num_supported_advertising_sets as u8;
+                2
+            }
+            ReturnParameters::LeReadResolvingListSize(params) => {
+                bytes[0] = params.status.into();
+                bytes[1] = params.resolving_list_size as u8;
+                2
+            }
+            ReturnParameters::LeReadPeerResolvableAddress(params)
+            | ReturnParameters::LeReadLocalResolvableAddress(params) => {
+                bytes[0] = params.status.into();
+                bytes[1..7].copy_from_slice(&params.resolvable_address.0);
+                7
+            }
+            ReturnParameters::LeReadPhy(params) => {
+                bytes[0] = params.status.into();
+                LittleEndian::write_u16(&mut bytes[1..], params.conn_handle.0);
+                bytes[3] = params.tx_phy as u8;
+                bytes[4] = params.rx_phy as u8;
+                5
+            }
+            ReturnParameters::Vendor(params) => params.copy_into_slice(bytes),
+        }
+    }
+}
+
 fn to_status(bytes: &[u8]) -> Result<Status, crate::event::Error> {
     bytes[0].try_into().map_err(super::rewrap_bad_status)
 }
@@ -356,6 +960,43 @@ fn to_tx_power_level(bytes: &[u8]) -> Result<TxPowerLevel, crate::event::Error>
     })
 }
 
+/// Parameters returned by the
+/// [Read Authenticated Payload Timeout](crate::host::HostHci::read_authenticated_payload_timeout)
+/// command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ReadAuthenticatedPayloadTimeout {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Connection whose timeout was read.
+    pub conn_handle: ConnectionHandle,
+
+    /// Authenticated payload timeout of the connection. Only present if the status is
+    /// [`Success`](Status::Success).
+    pub timeout: Option<AuthenticatedPayloadTimeout>,
+}
+
+fn to_read_authenticated_payload_timeout(
+    bytes: &[u8],
+) -> Result<ReadAuthenticatedPayloadTimeout, crate::event::Error> {
+    require_len!(bytes, 5);
+
+    let status = to_status(bytes)?;
+    Ok(ReadAuthenticatedPayloadTimeout {
+        status,
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&bytes[1..])),
+        timeout: if status == Status::Success {
+            Some(
+                AuthenticatedPayloadTimeout::from_bytes(&bytes[3..])
+                    .map_err(crate::event::Error::BadAuthenticatedPayloadTimeout)?,
+            )
+        } else {
+            None
+        },
+    })
+}
+
 /// Values returned by
 /// [Read Local Version Information](crate::host::HostHci::read_local_version_information) command.
 #[derive(Copy, Clone, Debug)]
@@ -1848,3 +2489,296 @@ fn to_le_test_end(bytes: &[u8]) -> Result<LeTestEnd, crate::event::Error> {
         number_of_packets: LittleEndian::read_u16(&bytes[1..]) as usize,
     })
 }
+
+/// Parameters returned by the
+/// [LE Set Extended Advertising Parameters](crate::host::HostHci::le_set_extended_advertising_parameters)
+/// command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeExtendedAdvertisingParameters {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// The transmit power selected by the Controller for the advertising set.
+    ///   - Range: -127 ≤ N ≤ 20
+    ///   - Units: dBm
+    pub selected_tx_power: i8,
+}
+
+fn to_le_extended_advertising_parameters(
+    bytes: &[u8],
+) -> Result<LeExtendedAdvertisingParameters, crate::event::Error> {
+    require_len!(bytes, 2);
+
+    Ok(LeExtendedAdvertisingParameters {
+        status: to_status(bytes)?,
+        selected_tx_power: bytes[1] as i8,
+    })
+}
+
+/// Parameters returned by the
+/// [LE Read Maximum Advertising Data Length](crate::host::HostHci::le_read_maximum_advertising_data_length)
+/// command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeMaximumAdvertisingDataLength {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Maximum length of the advertising or scan response data of an advertising set, in bytes.
+    /// Range: 31 - 1650.
+    pub max_advertising_data_length: usize,
+}
+
+fn to_le_maximum_advertising_data_length(
+    bytes: &[u8],
+) -> Result<LeMaximumAdvertisingDataLength, crate::event::Error> {
+    require_len!(bytes, 3);
+
+    Ok(LeMaximumAdvertisingDataLength {
+        status: to_status(bytes)?,
+        max_advertising_data_length: LittleEndian::read_u16(&bytes[1..]) as usize,
+    })
+}
+
+/// Parameters returned by the
+/// [LE Read Number of Supported Advertising Sets](crate::host::HostHci::le_read_number_of_supported_advertising_sets)
+/// command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeNumberOfSupportedAdvertisingSets {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Maximum number of advertising sets supported by the Controller at the same time. Range: 1 -
+    /// 240.
+    pub num_supported_advertising_sets: usize,
+}
+
+fn to_le_number_of_supported_advertising_sets(
+    bytes: &[u8],
+) -> Result<LeNumberOfSupportedAdvertisingSets, crate::event::Error> {
+    require_len!(bytes, 2);
+
+    Ok(LeNumberOfSupportedAdvertisingSets {
+        status: to_status(bytes)?,
+        num_supported_advertising_sets: bytes[1] as usize,
+    })
+}
+
+/// Parameters returned by the
+/// [LE Read Resolving List Size](crate::host::HostHci::le_read_resolving_list_size) command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeResolvingListSize {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Number of address translation entries in the resolving list.
+    pub resolving_list_size: usize,
+}
+
+fn to_le_resolving_list_size(bytes: &[u8]) -> Result<LeResolvingListSize, crate::event::Error> {
+    require_len!(bytes, 2);
+
+    Ok(LeResolvingListSize {
+        status: to_status(bytes)?,
+        resolving_list_size: bytes[1] as usize,
+    })
+}
+
+/// Parameters returned by the
+/// [LE Read Peer Resolvable Address](crate::host::HostHci::le_read_peer_resolvable_address) and
+/// [LE Read Local Resolvable Address](crate::host::HostHci::le_read_local_resolvable_address)
+/// commands.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeResolvableAddress {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Resolvable Private Address being used by the peer or local device.
+    pub resolvable_address: crate::BdAddr,
+}
+
+fn to_le_resolvable_address(bytes: &[u8]) -> Result<LeResolvableAddress, crate::event::Error> {
+    require_len!(bytes, 7);
+
+    let mut resolvable_address = crate::BdAddr([0; 6]);
+    resolvable_address.0.copy_from_slice(&bytes[1..7]);
+    Ok(LeResolvableAddress {
+        status: to_status(bytes)?,
+        resolvable_address,
+    })
+}
+
+/// Parameters returned by the [LE Read PHY](crate::host::HostHci::le_read_phy) command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeReadPhy {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Connection handle for which the PHYs are returned.
+    pub conn_handle: ConnectionHandle,
+
+    /// PHY used for transmission on the connection.
+    pub tx_phy: crate::event::Phy,
+
+    /// PHY used for reception on the connection.
+    pub rx_phy: crate::event::Phy,
+}
+
+fn to_le_read_phy(bytes: &[u8]) -> Result<LeReadPhy, crate::event::Error> {
+    require_len!(bytes, 5);
+
+    Ok(LeReadPhy {
+        status: to_status(bytes)?,
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&bytes[1..])),
+        tx_phy: bytes[3].try_into()?,
+        rx_phy: bytes[4].try_into()?,
+    })
+}
+
+/// Parameters returned by the
+/// [LE Read Suggested Default Data Length](crate::host::HostHci::le_read_suggested_default_data_length)
+/// command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeSuggestedDefaultDataLength {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Suggested maximum number of payload octets that the controller sends in a single LL Data
+    /// PDU on new connections.
+    pub max_tx_octets: u16,
+
+    /// Suggested maximum time, in microseconds, that the controller takes to send a single LL Data
+    /// PDU on new connections.
+    pub max_tx_time: u16,
+}
+
+fn to_le_suggested_default_data_length(
+    bytes: &[u8],
+) -> Result<LeSuggestedDefaultDataLength, crate::event::Error> {
+    require_len!(bytes, 5);
+
+    Ok(LeSuggestedDefaultDataLength {
+        status: to_status(bytes)?,
+        max_tx_octets: LittleEndian::read_u16(&bytes[1..]),
+        max_tx_time: LittleEndian::read_u16(&bytes[3..]),
+    })
+}
+
+/// Parameters returned by the
+/// [LE Read Maximum Data Length](crate::host::HostHci::le_read_maximum_data_length) command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeMaximumDataLength {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Maximum number of payload octets that the controller supports sending in a single LL Data
+    /// PDU.
+    pub max_tx_octets: u16,
+
+    /// Maximum time, in microseconds, that the controller supports taking to send a single LL Data
+    /// PDU.
+    pub max_tx_time: u16,
+
+    /// Maximum number of payload octets that the controller supports receiving in a single LL Data
+    /// PDU.
+    pub max_rx_octets: u16,
+
+    /// Maximum time, in microseconds, that the controller supports taking to receive a single LL
+    /// Data PDU.
+    pub max_rx_time: u16,
+}
+
+fn to_le_maximum_data_length(bytes: &[u8]) -> Result<LeMaximumDataLength, crate::event::Error> {
+    require_len!(bytes, 9);
+
+    Ok(LeMaximumDataLength {
+        status: to_status(bytes)?,
+        max_tx_octets: LittleEndian::read_u16(&bytes[1..]),
+        max_tx_time: LittleEndian::read_u16(&bytes[3..]),
+        max_rx_octets: LittleEndian::read_u16(&bytes[5..]),
+        max_rx_time: LittleEndian::read_u16(&bytes[7..]),
+    })
+}
+
+/// Parameters returned by commands that only return a status and the connection handle that the
+/// command applies to.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ConnectionHandleReturnParameters {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Connection handle that the command applies to.
+    pub conn_handle: ConnectionHandle,
+}
+
+fn to_connection_handle_return_parameters(
+    bytes: &[u8],
+) -> Result<ConnectionHandleReturnParameters, crate::event::Error> {
+    require_len!(bytes, 3);
+
+    Ok(ConnectionHandleReturnParameters {
+        status: to_status(bytes)?,
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&bytes[1..])),
+    })
+}
+
+/// Parameters returned by the
+/// [LE Enhanced Read Transmit Power Level](crate::host::HostHci::le_enhanced_read_transmit_power_level)
+/// command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeEnhancedTransmitPowerLevel {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Connection whose power levels were read.
+    pub conn_handle: ConnectionHandle,
+
+    /// Power levels that were read. Only present if the status is [`Success`](Status::Success).
+    pub levels: Option<TransmitPowerLevels>,
+}
+
+/// Transmit power levels returned by the
+/// [LE Enhanced Read Transmit Power Level](crate::host::HostHci::le_enhanced_read_transmit_power_level)
+/// command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TransmitPowerLevels {
+    /// PHY whose power levels were read.
+    pub phy: crate::event::PowerControlPhy,
+
+    /// Current transmit power level, in dBm.
+    pub current_tx_power_level: i8,
+
+    /// Maximum transmit power level, in dBm.
+    pub max_tx_power_level: i8,
+}
+
+fn to_le_enhanced_transmit_power_level(
+    bytes: &[u8],
+) -> Result<LeEnhancedTransmitPowerLevel, crate::event::Error> {
+    require_len!(bytes, 6);
+
+    let status = to_status(bytes)?;
+    Ok(LeEnhancedTransmitPowerLevel {
+        status,
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&bytes[1..])),
+        levels: if status == Status::Success {
+            Some(TransmitPowerLevels {
+                phy: crate::event::PowerControlPhy::try_from(bytes[3])?,
+                current_tx_power_level: bytes[4] as i8,
+                max_tx_power_level: bytes[5] as i8,
+            })
+        } else {
+            None
+        },
+    })
+}
@@ -30,14 +30,18 @@ macro_rules! require_len_at_least {
 
 pub mod command;
 
-use crate::types::{ConnectionIntervalError, FixedConnectionInterval};
+use crate::types::{
+    AuthenticatedPayloadTimeoutError, ConnectionInterval, ConnectionIntervalError,
+    FixedConnectionInterval,
+};
 use crate::vendor::event::VendorEvent;
 use crate::vendor::VendorError;
-use crate::{BadStatusError, ConnectionHandle, Status};
+use crate::{BadStatusError, ConnectionHandle, Status, SyncHandle};
 use byteorder::{ByteOrder, LittleEndian};
 use core::convert::{TryFrom, TryInto};
 use core::fmt::{Debug, Formatter, Result as FmtResult};
 use core::mem;
+use core::time::Duration;
 
 /// Potential events that can be generated by the controller.
 ///
@@ -80,6 +84,12 @@ pub enum Event {
     /// Vol 2, Part E, Section 7.7.39
     EncryptionKeyRefreshComplete(EncryptionKeyRefreshComplete),
 
+    /// Indicates that no packet containing a valid MIC has been received on the connection within
+    /// the [authenticated payload timeout](crate::host::HostHci::write_authenticated_payload_timeout).
+    ///
+    /// Vol 2, Part E, Section 7.7.75
+    AuthenticatedPayloadTimeoutExpired(ConnectionHandle),
+
     /// Vol 2, Part E, Section 7.7.65.1
     LeConnectionComplete(LeConnectionComplete),
 
@@ -95,18 +105,21 @@ pub enum Event {
     /// Vol 2, Part E, Section 7.7.65.5
     LeLongTermKeyRequest(LeLongTermKeyRequest),
 
+    /// Vol 2, Part E, Section 7.7.65.6
+    LeRemoteConnectionParameterRequest(LeRemoteConnectionParameterRequest),
+
     /// Vol 2, Part E, Section 7.7.65.7
     LeDataLengthChangeEvent(LeDataLengthChangeEvent),
 
     /// This event is generated when local P-256 key generation is complete.
     ///
     /// Vol 4, Part E, 7.7.65.8
-    LeReadLocalP256PublicKeyComplete([u8; 64]),
+    LeReadLocalP256PublicKeyComplete(LeReadLocalP256PublicKeyComplete),
 
     /// This event indicates that LE Diffie Hellman key generation has been completed by the Controller.
     ///
     /// Vol 4, Part E, Section 7.7.65.9
-    LeGenerateDHKeyComplete([u8; 32]),
+    LeGenerateDHKeyComplete(LeGenerateDHKeyComplete),
 
     /// Vol 4, Part E, Section 7.7.65.10
     LeEnhancedConnectionComplete(LeEnhancedConnectionComplete),
@@ -114,14 +127,46 @@ pub enum Event {
     /// Vol 2, Part E, Section 7.7.65.12
     LePhyUpdateComplete(LePhyUpdateComplete),
 
+    /// Vol 2, Part E, Section 7.7.65.13
+    LeExtendedAdvertisingReport(LeExtendedAdvertisingReport),
+
+    /// Vol 2, Part E, Section 7.7.65.14
+    LePeriodicAdvertisingSyncEstablished(LePeriodicAdvertisingSyncEstablished),
+
+    /// Vol 2, Part E, Section 7.7.65.15
+    LePeriodicAdvertisingReport(LePeriodicAdvertisingReport),
+
+    /// Indicates that the Controller has not received a periodic advertising PDU within the sync
+    /// timeout, and is no longer synchronized with the periodic advertising identified by the
+    /// handle.
+    ///
+    /// Vol 2, Part E, Section 7.7.65.16
+    LePeriodicAdvertisingSyncLost(SyncHandle),
+
+    /// Indicates that scanning has ended because the duration given to the
+    /// [LE Set Extended Scan Enable](crate::host::HostHci::le_set_extended_scan_enable) command
+    /// has expired.
+    ///
+    /// Vol 2, Part E, Section 7.7.65.17
+    LeScanTimeout,
+
+    /// Vol 2, Part E, Section 7.7.65.20
+    LeChannelSelectionAlgorithm(LeChannelSelectionAlgorithm),
+
+    /// Vol 2, Part E, Section 7.7.65.24
+    LePeriodicAdvertisingSyncTransferReceived(LePeriodicAdvertisingSyncTransferReceived),
+
+    /// Vol 2, Part E, Section 7.7.65.32
+    LePathLossThreshold(LePathLossThreshold),
+
+    /// Vol 2, Part E, Section 7.7.65.33
+    LeTransmitPowerReporting(LeTransmitPowerReporting),
+
     // TODO: le_enhanced_connection_complete
     // TODO: le_directed_advertising_report
     // TODO: le_phy_update_complete
-    // TODO: le_extended_advertising_report
-    // TODO: le_scan_timeout
     // TODO: le_advertising_set_terminated
     // TODO: le_scan_reauest_received
-    // TODO: le_channel_selection_algorithm
     /// Vendor-specific events (opcode 0xFF)
     Vendor(VendorEvent),
 }
@@ -186,32 +231,62 @@ pub enum Error {
     /// Includes the unrecognized byte.
     BadLeAddressType(u8),
 
-    /// For the [LE Connection Complete](Event::LeConnectionComplete) event: The returned connection
-    /// interval was invalid. Includes the error returned when attempting to create the
-    /// [FixedConnectionInterval].
+    /// For the [LE Connection Complete](Event::LeConnectionComplete) and
+    /// [LE Remote Connection Parameter Request](Event::LeRemoteConnectionParameterRequest) events:
+    /// The returned connection interval was invalid. Includes the error returned when attempting to
+    /// create the [FixedConnectionInterval] or [ConnectionInterval].
     BadConnectionInterval(ConnectionIntervalError),
 
+    /// For the
+    /// [Read Authenticated Payload Timeout](crate::host::HostHci::read_authenticated_payload_timeout)
+    /// command return parameters: The returned timeout was invalid. Includes the error returned
+    /// when attempting to create the [AuthenticatedPayloadTimeout](crate::types::AuthenticatedPayloadTimeout).
+    BadAuthenticatedPayloadTimeout(AuthenticatedPayloadTimeoutError),
+
     /// For the [LE Connection Complete](Event::LeConnectionComplete) event: The central clock
     /// accuracy value was not recognized.  Includes the unrecognized byte.
     BadLeCentralClockAccuracy(u8),
 
-    /// For the [LE Advertising Report](Event::LeAdvertisingReport) event: The packet ended with a
-    /// partial report.
+    /// For the [LE Advertising Report](Event::LeAdvertisingReport) and
+    /// [LE Extended Advertising Report](Event::LeExtendedAdvertisingReport) events: The packet
+    /// ended with a partial report.
     LeAdvertisementReportIncomplete,
 
     /// For the [LE Advertising Report](Event::LeAdvertisingReport) event: The packet includes an
     /// invalid advertisement type.  Includes the unrecognized byte.
     BadLeAdvertisementType(u8),
 
+    /// For the [LE Extended Advertising Report](Event::LeExtendedAdvertisingReport) event: The
+    /// data status of a report was not recognized. Includes the unrecognized value.
+    BadDataStatus(u8),
+
     /// For the [LE Read Remote Used Features Complete](Event::LeReadRemoteUsedFeaturesComplete)
     /// event: The response included an invalid bit set for the remote features.  Includes the 8
     /// bytes of flags.
     BadRemoteUsedFeatureFlag(u64),
 
-    /// For the [LE PHY Update Complete](Event::LePhyUpdateComplete) event: The PHY type was not
+    /// For the [LE PHY Update Complete](Event::LePhyUpdateComplete),
+    /// [LE Extended Advertising Report](Event::LeExtendedAdvertisingReport), or
+    /// [LE Transmit Power Reporting](Event::LeTransmitPowerReporting) events: The PHY type was not
     /// recognized. Includes the unrecognized byte.
     BadPhy(u8),
 
+    /// For the [LE Channel Selection Algorithm](Event::LeChannelSelectionAlgorithm) event: The
+    /// channel selection algorithm was not recognized. Includes the unrecognized byte.
+    BadChannelSelectionAlgorithm(u8),
+
+    /// For the [LE Periodic Advertising Report](Event::LePeriodicAdvertisingReport) event: The
+    /// Constant Tone Extension type was not recognized. Includes the unrecognized byte.
+    BadCteType(u8),
+
+    /// For the [LE Path Loss Threshold](Event::LePathLossThreshold) event: The zone entered was not
+    /// recognized. Includes the unrecognized byte.
+    BadPathLossZone(u8),
+
+    /// For the [LE Transmit Power Reporting](Event::LeTransmitPowerReporting) event: The reason
+    /// for the report was not recognized. Includes the unrecognized byte.
+    BadTransmitPowerReportingReason(u8),
+
     /// For the [Hardware Error](Event::HardwareError) event: The error code was not recongnized.
     /// Includes the unrecongnized code.
     BadHardwareError(u8),
@@ -249,6 +324,12 @@ const PACKET_HEADER_LENGTH: usize = 2;
 const EVENT_TYPE_BYTE: usize = 0;
 const PARAM_LEN_BYTE: usize = 1;
 
+/// Maximum length of an event packet: the event code, the parameter length and up to 255 bytes of
+/// parameters.
+pub const MAX_EVENT_LENGTH: usize = PACKET_HEADER_LENGTH + 255;
+
+const LE_META_EVENT: u8 = 0x3E;
+
 impl Event {
     /// Deserializes an event from the given packet. The packet should contain all of the data
     /// needed to deserialize the event.
@@ -291,11 +372,144 @@ impl Event {
             0x30 => Ok(Event::EncryptionKeyRefreshComplete(
                 to_encryption_key_refresh_complete(payload)?,
             )),
-            0x3E => to_le_meta_event(payload),
+            0x57 => Ok(Event::AuthenticatedPayloadTimeoutExpired(
+                to_authenticated_payload_timeout_expired(payload)?,
+            )),
+            LE_META_EVENT => to_le_meta_event(payload),
             0xFF => Ok(Event::Vendor(VendorEvent::new(payload)?)),
             _ => Err(Error::UnknownEvent(event_type)),
         }
     }
+
+    /// Serializes the event into `buffer`, in the format read by [`Event::new`]: the event code,
+    /// the parameter length and the parameters. Returns the number of bytes written.
+    ///
+    /// Deserializing the written bytes returns the same event. Deserializing an event and
+    /// serializing it again reproduces the original bytes, except for values that the event does
+    /// not keep, such as reserved bits, which are written as zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` is too short for the event. [`MAX_EVENT_LENGTH`] bytes are always
+    /// enough.
+    pub fn copy_into_slice(&self, buffer: &mut [u8]) -> usize {
+        let payload = &mut buffer[PACKET_HEADER_LENGTH..];
+        let (event_type, param_len) = match self {
+            Event::ConnectionComplete(event) => (0x03, write_connection_complete(event, payload)),
+            Event::DisconnectionComplete(event) => {
+                (0x05, write_disconnection_complete(event, payload))
+            }
+            Event::EncryptionChange(event) => (0x08, write_encryption_change(event, payload)),
+            Event::ReadRemoteVersionInformationComplete(event) => {
+                (0x0C, write_remote_version_info(event, payload))
+            }
+            Event::CommandComplete(event) => (0x0E, event.copy_into_slice(payload)),
+            Event::CommandStatus(event) => (0x0F, write_command_status(event, payload)),
+            Event::HardwareError(error) => {
+                payload[0] = *error as u8;
+                (0x10, 1)
+            }
+            Event::NumberOfCompletedPackets(event) => {
+                (0x13, write_number_of_completed_packets(event, payload))
+            }
+            Event::DataBufferOverflow(event) => {
+                payload[0] = event.link_type as u8;
+                (0x1A, 1)
+            }
+            Event::EncryptionKeyRefreshComplete(event) => {
+                (0x30, write_encryption_key_refresh_complete(event, payload))
+            }
+            Event::AuthenticatedPayloadTimeoutExpired(conn_handle) => {
+                LittleEndian::write_u16(payload, conn_handle.0);
+                (0x57, 2)
+            }
+            Event::LeConnectionComplete(event) => {
+                (LE_META_EVENT, write_le_connection_complete(event, payload))
+            }
+            Event::LeAdvertisingReport(event) => {
+                (LE_META_EVENT, write_le_advertising_report(event, payload))
+            }
+            Event::LeConnectionUpdateComplete(event) => (
+                LE_META_EVENT,
+                write_le_connection_update_complete(event, payload),
+            ),
+            Event::LeReadRemoteUsedFeaturesComplete(event) => (
+                LE_META_EVENT,
+                write_le_read_remote_used_features_complete(event, payload),
+            ),
+            Event::LeLongTermKeyRequest(event) => {
+                (LE_META_EVENT, write_le_ltk_request(event, payload))
+            }
+            Event::LeRemoteConnectionParameterRequest(event) => (
+                LE_META_EVENT,
+                write_le_remote_connection_parameter_request(event, payload),
+            ),
+            Event::LeDataLengthChangeEvent(event) => (
+                LE_META_EVENT,
+                write_le_data_length_change_event(event, payload),
+            ),
+            Event::LeReadLocalP256PublicKeyComplete(event) => {
+                payload[0] = 0x08;
+                payload[1] = event.status.into();
+                payload[2..66].copy_from_slice(&event.public_key.0);
+                (LE_META_EVENT, 66)
+            }
+            Event::LeGenerateDHKeyComplete(event) => {
+                payload[0] = 0x09;
+                payload[1] = event.status.into();
+                payload[2..34].copy_from_slice(&event.dh_key.0);
+                (LE_META_EVENT, 34)
+            }
+            Event::LeEnhancedConnectionComplete(event) => (
+                LE_META_EVENT,
+                write_le_enhanced_connection_complete(event, payload),
+            ),
+            Event::LePhyUpdateComplete(event) => {
+                (LE_META_EVENT, write_le_phy_update_complete(event, payload))
+            }
+            Event::LeExtendedAdvertisingReport(event) => (
+                LE_META_EVENT,
+                write_le_extended_advertising_report(event, payload),
+            ),
+            Event::LePeriodicAdvertisingSyncEstablished(event) => (
+                LE_META_EVENT,
+                write_le_periodic_advertising_sync_established(event, payload),
+            ),
+            Event::LePeriodicAdvertisingReport(event) => (
+                LE_META_EVENT,
+                write_le_periodic_advertising_report(event, payload),
+            ),
+            Event::LePeriodicAdvertisingSyncLost(sync_handle) => {
+                payload[0] = 0x10;
+                LittleEndian::write_u16(&mut payload[1..], sync_handle.0);
+                (LE_META_EVENT, 3)
+            }
+            Event::LeScanTimeout => {
+                payload[0] = 0x11;
+                (LE_META_EVENT, 1)
+            }
+            Event::LeChannelSelectionAlgorithm(event) => (
+                LE_META_EVENT,
+                write_le_channel_selection_algorithm(event, payload),
+            ),
+            Event::LePeriodicAdvertisingSyncTransferReceived(event) => (
+                LE_META_EVENT,
+                write_le_periodic_advertising_sync_transfer_received(event, payload),
+            ),
+            Event::LePathLossThreshold(event) => {
+                (LE_META_EVENT, write_le_path_loss_threshold(event, payload))
+            }
+            Event::LeTransmitPowerReporting(event) => (
+                LE_META_EVENT,
+                write_le_transmit_power_reporting(event, payload),
+            ),
+            Event::Vendor(event) => (0xFF, event.copy_into_slice(payload)),
+        };
+        buffer[EVENT_TYPE_BYTE] = event_type;
+        buffer[PARAM_LEN_BYTE] = param_len as u8;
+
+        PACKET_HEADER_LENGTH + param_len
+    }
 }
 
 fn to_le_meta_event(payload: &[u8]) -> Result<Event, Error> {
@@ -314,6 +528,9 @@ fn to_le_meta_event(payload: &[u8]) -> Result<Event, Error> {
             to_le_read_remote_used_features_complete(payload)?,
         )),
         0x05 => Ok(Event::LeLongTermKeyRequest(to_le_ltk_request(payload)?)),
+        0x06 => Ok(Event::LeRemoteConnectionParameterRequest(
+            to_le_remote_connection_parameter_request(payload)?,
+        )),
         0x07 => Ok(Event::LeDataLengthChangeEvent(
             to_le_data_length_change_event(payload)?,
         )),
@@ -329,6 +546,31 @@ fn to_le_meta_event(payload: &[u8]) -> Result<Event, Error> {
         0x0C => Ok(Event::LePhyUpdateComplete(to_le_phy_update_complete(
             payload,
         )?)),
+        0x0D => Ok(Event::LeExtendedAdvertisingReport(
+            to_le_extended_advertising_report(payload)?,
+        )),
+        0x0E => Ok(Event::LePeriodicAdvertisingSyncEstablished(
+            to_le_periodic_advertising_sync_established(payload)?,
+        )),
+        0x0F => Ok(Event::LePeriodicAdvertisingReport(
+            to_le_periodic_advertising_report(payload)?,
+        )),
+        0x10 => Ok(Event::LePeriodicAdvertisingSyncLost(
+            to_le_periodic_advertising_sync_lost(payload)?,
+        )),
+        0x11 => Ok(Event::LeScanTimeout),
+        0x14 => Ok(Event::LeChannelSelectionAlgorithm(
+            to_le_channel_selection_algorithm(payload)?,
+        )),
+        0x18 => Ok(Event::LePeriodicAdvertisingSyncTransferReceived(
+            to_le_periodic_advertising_sync_transfer_received(payload)?,
+        )),
+        0x20 => Ok(Event::LePathLossThreshold(to_le_path_loss_threshold(
+            payload,
+        )?)),
+        0x21 => Ok(Event::LeTransmitPowerReporting(
+            to_le_transmit_power_reporting(payload)?,
+        )),
 
         _ => Err(Error::UnknownEvent(payload[0])),
     }
@@ -391,6 +633,16 @@ fn to_connection_complete(payload: &[u8]) -> Result<ConnectionComplete, Error> {
     })
 }
 
+fn write_connection_complete(event: &ConnectionComplete, payload: &mut [u8]) -> usize {
+    payload[0] = event.status.into();
+    LittleEndian::write_u16(&mut payload[1..], event.conn_handle.0);
+    payload[3..9].copy_from_slice(&event.bd_addr.0);
+    payload[9] = event.link_type as u8;
+    payload[10] = event.encryption_enabled as u8;
+
+    11
+}
+
 fn try_into_encryption_enabled(value: u8) -> Result<bool, Error> {
     match value {
         0 => Ok(false),
@@ -435,6 +687,14 @@ fn to_disconnection_complete(payload: &[u8]) -> Result<DisconnectionComplete, Er
     })
 }
 
+fn write_disconnection_complete(event: &DisconnectionComplete, payload: &mut [u8]) -> usize {
+    payload[0] = event.status.into();
+    LittleEndian::write_u16(&mut payload[1..], event.conn_handle.0);
+    payload[3] = event.reason.into();
+
+    4
+}
+
 /// The [Encryption Change](Event::EncryptionChange) event is used to indicate that the change of
 /// the encryption mode has been completed.
 ///
@@ -504,6 +764,14 @@ fn to_encryption_change(payload: &[u8]) -> Result<EncryptionChange, Error> {
     })
 }
 
+fn write_encryption_change(event: &EncryptionChange, payload: &mut [u8]) -> usize {
+    payload[0] = event.status.into();
+    LittleEndian::write_u16(&mut payload[1..], event.conn_handle.0);
+    payload[3] = event.encryption as u8;
+
+    4
+}
+
 /// Indicates the completion of the process obtaining the version information of the remote
 /// Controller specified by [`conn_handle`](RemoteVersionInformation::conn_handle).
 ///
@@ -559,6 +827,16 @@ fn to_remote_version_info(payload: &[u8]) -> Result<RemoteVersionInformation, Er
     })
 }
 
+fn write_remote_version_info(event: &RemoteVersionInformation, payload: &mut [u8]) -> usize {
+    payload[0] = event.status.into();
+    LittleEndian::write_u16(&mut payload[1..], event.conn_handle.0);
+    payload[3] = event.version;
+    LittleEndian::write_u16(&mut payload[4..], event.mfgr_name);
+    LittleEndian::write_u16(&mut payload[6..], event.subversion);
+
+    8
+}
+
 /// The [Command Status](Event::CommandStatus) event is used to indicate that the command described
 /// by the [`opcode`](CommandStatus::opcode) parameter has been received, and that the Controller is
 /// currently performing the task for this command.
@@ -589,6 +867,14 @@ fn to_command_status(buffer: &[u8]) -> Result<CommandStatus, Error> {
     })
 }
 
+fn write_command_status(event: &CommandStatus, buffer: &mut [u8]) -> usize {
+    buffer[0] = event.status.into();
+    buffer[1] = event.num_hci_command_packets;
+    LittleEndian::write_u16(&mut buffer[2..], event.opcode.0);
+
+    4
+}
+
 /// The [Hardware Error](Event::HardwareError) event is used to notify the Host that a hardware
 /// failure has occurred in the Controller.
 ///
@@ -734,6 +1020,17 @@ fn to_number_of_completed_packets(payload: &[u8]) -> Result<NumberOfCompletedPac
     })
 }
 
+fn write_number_of_completed_packets(
+    event: &NumberOfCompletedPackets,
+    payload: &mut [u8],
+) -> usize {
+    let data_len = event.num_handles * NUM_COMPLETED_PACKETS_PAIR_LEN;
+    payload[0] = event.num_handles as u8;
+    payload[1..=data_len].copy_from_slice(&event.data_buf[..data_len]);
+
+    1 + data_len
+}
+
 /// Indicates that the Controller's data buffers have been overflowed.  This can occur if the Host
 /// has sent more packets than allowed.
 ///
@@ -788,6 +1085,22 @@ fn to_encryption_key_refresh_complete(
     })
 }
 
+fn write_encryption_key_refresh_complete(
+    event: &EncryptionKeyRefreshComplete,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = event.status.into();
+    LittleEndian::write_u16(&mut payload[1..], event.conn_handle.0);
+
+    3
+}
+
+fn to_authenticated_payload_timeout_expired(payload: &[u8]) -> Result<ConnectionHandle, Error> {
+    require_len!(payload, 2);
+
+    Ok(ConnectionHandle(LittleEndian::read_u16(payload)))
+}
+
 /// Indicates to both of the Hosts forming the connection that a new connection has been
 /// created. Upon the creation of the connection a connection handle shall be assigned by the
 /// Controller, and passed to the Host in this event. If the connection establishment fails this
@@ -904,6 +1217,18 @@ fn to_le_connection_complete(payload: &[u8]) -> Result<LeConnectionComplete, Err
     })
 }
 
+fn write_le_connection_complete(event: &LeConnectionComplete, payload: &mut [u8]) -> usize {
+    payload[0] = 0x01;
+    payload[1] = event.status.into();
+    LittleEndian::write_u16(&mut payload[2..], event.conn_handle.0);
+    payload[4] = event.role as u8;
+    event.peer_bd_addr.copy_into_slice(&mut payload[5..12]);
+    event.conn_interval.copy_into_slice(&mut payload[12..18]);
+    payload[18] = event.central_clock_accuracy as u8;
+
+    19
+}
+
 /// The [LE Advertising Report](Event::LeAdvertisingReport) event indicates that a Bluetooth device
 /// or multiple Bluetooth devices have responded to an active scan or received some information
 /// during a passive scan. The Controller may queue these advertising reports and send information
@@ -1109,6 +1434,636 @@ fn to_le_advertising_report(payload: &[u8]) -> Result<LeAdvertisingReport, Error
     Ok(LeAdvertisingReport { data_len, data_buf })
 }
 
+fn write_le_advertising_report(event: &LeAdvertisingReport, payload: &mut [u8]) -> usize {
+    payload[0] = 0x02;
+    payload[1] = event.iter().count() as u8;
+    payload[2..2 + event.data_len].copy_from_slice(&event.data_buf[..event.data_len]);
+
+    2 + event.data_len
+}
+
+/// The [LE Extended Advertising Report](Event::LeExtendedAdvertisingReport) event indicates that
+/// one or more Bluetooth devices have responded to an active scan or have broadcast
+/// advertisements that were received during a passive scan. The Controller may coalesce multiple
+/// advertising reports from the same or different advertisers into a single event.
+///
+/// This event shall only be generated if scanning was enabled using the
+/// [LE Set Extended Scan Enable](crate::host::HostHci::le_set_extended_scan_enable) command. It
+/// reports advertising events using either legacy or extended advertising PDUs.
+///
+/// Advertising data that does not fit in a single event is split over several reports; every
+/// report but the last has the [`Incomplete`](DataStatus::Incomplete) data status.
+///
+/// Defined in Vol 2, Part E, Section 7.7.65.13 of the spec.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeExtendedAdvertisingReport {
+    data_len: usize,
+    data_buf: [u8; MAX_ADVERTISING_REPORT_LEN],
+}
+
+impl LeExtendedAdvertisingReport {
+    /// Returns an iterator over the advertisements from the event.
+    pub fn iter(&self) -> LeExtendedAdvertisingReportIterator {
+        LeExtendedAdvertisingReportIterator {
+            inner_iter: self.inner_iter(),
+        }
+    }
+
+    fn inner_iter(&self) -> LeExtendedAdvertisingReportInnerIterator<'_> {
+        LeExtendedAdvertisingReportInnerIterator {
+            event_data: &self.data_buf[..self.data_len],
+            next_index: 0,
+        }
+    }
+}
+
+impl Debug for LeExtendedAdvertisingReport {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{{")?;
+        for report in self.iter() {
+            write!(f, "{:?}", report)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+/// Iterator over the individual LE advertisement responses in the
+/// [LE Extended Advertising Report](Event::LeExtendedAdvertisingReport) event.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeExtendedAdvertisingReportIterator<'a> {
+    inner_iter: LeExtendedAdvertisingReportInnerIterator<'a>,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+struct LeExtendedAdvertisingReportInnerIterator<'a> {
+    event_data: &'a [u8],
+    next_index: usize,
+}
+
+impl<'a> Iterator for LeExtendedAdvertisingReportIterator<'a> {
+    type Item = LeExtendedAdvertisement<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner_iter.next().unwrap()
+    }
+}
+
+/// Length of the fixed part of each report in the
+/// [LE Extended Advertising Report](Event::LeExtendedAdvertisingReport) event, up to and including
+/// the data length.
+const EXTENDED_ADVERTISEMENT_HEADER_LEN: usize = 24;
+
+impl<'a> LeExtendedAdvertisingReportInnerIterator<'a> {
+    fn next(&mut self) -> Result<Option<LeExtendedAdvertisement<'a>>, Error> {
+        if self.next_index >= self.event_data.len() {
+            return Ok(None);
+        }
+
+        let report = &self.event_data[self.next_index..];
+        if report.len() < EXTENDED_ADVERTISEMENT_HEADER_LEN {
+            return Err(Error::LeAdvertisementReportIncomplete);
+        }
+        let data_end = EXTENDED_ADVERTISEMENT_HEADER_LEN + report[23] as usize;
+        if report.len() < data_end {
+            return Err(Error::LeAdvertisementReportIncomplete);
+        }
+        self.next_index += data_end;
+
+        let event_type = LittleEndian::read_u16(report);
+        let event_type_flags = ExtendedAdvertisingEventType::from_bits_truncate(event_type);
+        Ok(Some(LeExtendedAdvertisement {
+            event_type: event_type_flags,
+            data_status: DataStatus::try_from(((event_type >> 5) & 0x03) as u8)?,
+            address: to_report_address(report[2], &report[3..9])?,
+            primary_phy: Phy::try_from(report[9])?,
+            secondary_phy: match report[10] {
+                0 => None,
+                phy => Some(Phy::try_from(phy)?),
+            },
+            sid: match report[11] {
+                0xFF => None,
+                sid => Some(sid),
+            },
+            tx_power: match report[12] as i8 {
+                127 => None,
+                value => Some(value),
+            },
+            rssi: match report[13] as i8 {
+                127 => None,
+                value => Some(value),
+            },
+            periodic_advertising_interval: match LittleEndian::read_u16(&report[14..]) {
+                0 => None,
+                interval => Some(Duration::from_micros(1_250 * interval as u64)),
+            },
+            direct_address: if event_type_flags.contains(ExtendedAdvertisingEventType::DIRECTED) {
+                Some(to_report_address(report[16], &report[17..23])?)
+            } else {
+                None
+            },
+            data: &report[EXTENDED_ADVERTISEMENT_HEADER_LEN..data_end],
+        }))
+    }
+}
+
+/// A single advertising report returned by the
+/// [LE Extended Advertising Report](Event::LeExtendedAdvertisingReport) event.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeExtendedAdvertisement<'a> {
+    /// Properties of the advertising event.
+    pub event_type: ExtendedAdvertisingEventType,
+
+    /// Whether the data of the advertising event is complete, or continues in later reports.
+    pub data_status: DataStatus,
+
+    /// Address of the advertising device, or [`Anonymous`](ReportAddress::Anonymous) for
+    /// advertisements that do not include the address.
+    pub address: ReportAddress,
+
+    /// PHY on which the primary advertising channel PDU was received. This is either
+    /// [`Le1M`](Phy::Le1M) or [`LeCoded`](Phy::LeCoded).
+    pub primary_phy: Phy,
+
+    /// PHY on which the secondary advertising channel PDUs were received, if any.
+    pub secondary_phy: Option<Phy>,
+
+    /// Advertising Set ID of the advertiser, if it was included in the advertisement.
+    ///
+    /// - Range: 0x00 to 0x0F
+    pub sid: Option<u8>,
+
+    /// Transmit power of the advertiser, if it was included in the advertisement.
+    ///
+    /// - Range is -127 dBm to 126 dBm.
+    pub tx_power: Option<i8>,
+
+    /// Received signal strength.
+    ///
+    /// - Range is -127 dBm to 20 dBm.
+    /// - If the controller sends the value 127, `None` is returned here, since that value indicates
+    ///   "RSSI is not available".
+    pub rssi: Option<i8>,
+
+    /// Interval of the periodic advertising of the advertiser, if it does periodic advertising.
+    ///
+    /// - Range: 7.5 ms to 81.91875 s
+    /// - Resolution: 1.25 ms
+    pub periodic_advertising_interval: Option<Duration>,
+
+    /// Address the advertisement was directed to, for
+    /// [directed](ExtendedAdvertisingEventType::DIRECTED) advertisements.
+    pub direct_address: Option<ReportAddress>,
+
+    /// Advertising or scan response data formatted as defined in Vol 3, Part C, Section 11 of the
+    /// spec. This may be a fragment of the complete data; see
+    /// [`data_status`](LeExtendedAdvertisement::data_status).
+    pub data: &'a [u8],
+}
+
+#[cfg(not(feature = "defmt"))]
+bitflags::bitflags! {
+    /// Properties of an advertising event reported in the
+    /// [LE Extended Advertising Report](Event::LeExtendedAdvertisingReport) event.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct ExtendedAdvertisingEventType: u16 {
+        /// Connectable advertising
+        const CONNECTABLE = 0x0001;
+        /// Scannable advertising
+        const SCANNABLE = 0x0002;
+        /// Directed advertising
+        const DIRECTED = 0x0004;
+        /// Scan response
+        const SCAN_RESPONSE = 0x0008;
+        /// Legacy advertising PDUs used
+        const LEGACY = 0x0010;
+    }
+}
+
+#[cfg(feature = "defmt")]
+defmt::bitflags! {
+    /// Properties of an advertising event reported in the
+    /// [LE Extended Advertising Report](Event::LeExtendedAdvertisingReport) event.
+    pub struct ExtendedAdvertisingEventType: u16 {
+        /// Connectable advertising
+        const CONNECTABLE = 0x0001;
+        /// Scannable advertising
+        const SCANNABLE = 0x0002;
+        /// Directed advertising
+        const DIRECTED = 0x0004;
+        /// Scan response
+        const SCAN_RESPONSE = 0x0008;
+        /// Legacy advertising PDUs used
+        const LEGACY = 0x0010;
+    }
+}
+
+/// Completeness of the data in an [`LeExtendedAdvertisement`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DataStatus {
+    /// The data is complete.
+    Complete = 0x00,
+    /// The data is incomplete; more data continues in the following reports.
+    Incomplete = 0x01,
+    /// The data is incomplete, and no more data will be reported.
+    Truncated = 0x02,
+}
+
+impl TryFrom<u8> for DataStatus {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<DataStatus, Self::Error> {
+        match value {
+            0 => Ok(DataStatus::Complete),
+            1 => Ok(DataStatus::Incomplete),
+            2 => Ok(DataStatus::Truncated),
+            _ => Err(Error::BadDataStatus(value)),
+        }
+    }
+}
+
+/// Addresses reported in the [`LeExtendedAdvertisement`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ReportAddress {
+    /// Public Device Address
+    Public(crate::BdAddr),
+    /// Random Device Address
+    Random(crate::BdAddr),
+    /// Public Identity Address, resolved by the Controller from a Resolvable Private Address.
+    PublicIdentity(crate::BdAddr),
+    /// Random (static) Identity Address, resolved by the Controller from a Resolvable Private
+    /// Address.
+    RandomIdentity(crate::BdAddr),
+    /// Resolvable Private Address that the Controller could not resolve. Only used for the
+    /// [direct address](LeExtendedAdvertisement::direct_address).
+    UnresolvedRandom(crate::BdAddr),
+    /// No address was provided by the advertiser.
+    Anonymous,
+}
+
+impl ReportAddress {
+    fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), 7);
+
+        let (addr_type, bd_addr) = match *self {
+            ReportAddress::Public(bd_addr) => (0x00, bd_addr),
+            ReportAddress::Random(bd_addr) => (0x01, bd_addr),
+            ReportAddress::PublicIdentity(bd_addr) => (0x02, bd_addr),
+            ReportAddress::RandomIdentity(bd_addr) => (0x03, bd_addr),
+            ReportAddress::UnresolvedRandom(bd_addr) => (0xFE, bd_addr),
+            ReportAddress::Anonymous => (0xFF, crate::BdAddr([0; 6])),
+        };
+        bytes[0] = addr_type;
+        bytes[1..].copy_from_slice(&bd_addr.0);
+    }
+}
+
+fn to_report_address(addr_type: u8, addr: &[u8]) -> Result<ReportAddress, Error> {
+    let mut bd_addr = crate::BdAddr([0; 6]);
+    bd_addr.0.copy_from_slice(addr);
+    match addr_type {
+        0x00 => Ok(ReportAddress::Public(bd_addr)),
+        0x01 => Ok(ReportAddress::Random(bd_addr)),
+        0x02 => Ok(ReportAddress::PublicIdentity(bd_addr)),
+        0x03 => Ok(ReportAddress::RandomIdentity(bd_addr)),
+        0xFE => Ok(ReportAddress::UnresolvedRandom(bd_addr)),
+        0xFF => Ok(ReportAddress::Anonymous),
+        other => Err(Error::BadLeAddressType(other)),
+    }
+}
+
+fn to_le_extended_advertising_report(payload: &[u8]) -> Result<LeExtendedAdvertisingReport, Error> {
+    require_len_at_least!(payload, 2);
+    let mut check_iter = LeExtendedAdvertisingReportInnerIterator {
+        event_data: &payload[2..],
+        next_index: 0,
+    };
+    while (check_iter.next()?).is_some() {}
+
+    let data_len = payload.len() - 2;
+    let mut data_buf = [0; MAX_ADVERTISING_REPORT_LEN];
+    data_buf[..data_len].copy_from_slice(&payload[2..]);
+    Ok(LeExtendedAdvertisingReport { data_len, data_buf })
+}
+
+fn write_le_extended_advertising_report(
+    event: &LeExtendedAdvertisingReport,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x0D;
+    payload[1] = event.iter().count() as u8;
+    payload[2..2 + event.data_len].copy_from_slice(&event.data_buf[..event.data_len]);
+
+    2 + event.data_len
+}
+
+/// Indicates that the Controller has received the first periodic advertising PDU from an
+/// advertiser after the
+/// [LE Periodic Advertising Create Sync](crate::host::HostHci::le_periodic_advertising_create_sync)
+/// command, and is synchronized with its periodic advertising. It is also generated with an error
+/// status if the synchronization failed or was cancelled.
+///
+/// Defined in Vol 2, Part E, Section 7.7.65.14 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LePeriodicAdvertisingSyncEstablished {
+    /// Did the synchronization fail, and if so, how?
+    pub status: Status,
+
+    /// The periodic advertising the Controller is synchronized with. Only present if the status is
+    /// [`Success`](Status::Success); otherwise the Controller does not report it.
+    pub sync: Option<PeriodicAdvertisingSync>,
+}
+
+/// Periodic advertising the Controller has synchronized with, as reported by the
+/// [LE Periodic Advertising Sync Established](Event::LePeriodicAdvertisingSyncEstablished) and
+/// [LE Periodic Advertising Sync Transfer Received](Event::LePeriodicAdvertisingSyncTransferReceived)
+/// events.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PeriodicAdvertisingSync {
+    /// Identifies the periodic advertising in later events and commands.
+    pub sync_handle: SyncHandle,
+
+    /// Advertising SID of the periodic advertising.
+    pub adv_sid: u8,
+
+    /// Address of the advertiser.
+    pub advertiser_address: ReportAddress,
+
+    /// PHY used by the periodic advertising.
+    pub advertiser_phy: Phy,
+
+    /// Interval between periodic advertising events.
+    ///
+    /// - Range: 7.5 ms to 81.91875 s
+    /// - Resolution: 1.25 ms
+    pub periodic_advertising_interval: Duration,
+
+    /// Clock accuracy of the advertiser.
+    pub advertiser_clock_accuracy: CentralClockAccuracy,
+}
+
+/// Unit of the periodic advertising interval, in microseconds.
+const PERIODIC_ADVERTISING_INTERVAL_UNIT: u64 = 1_250;
+
+/// Length of the [`PeriodicAdvertisingSync`] parameters in an event.
+const PERIODIC_ADVERTISING_SYNC_LEN: usize = 14;
+
+fn to_periodic_advertising_sync(
+    status: Status,
+    bytes: &[u8],
+) -> Result<Option<PeriodicAdvertisingSync>, Error> {
+    // The remaining parameters are not valid if the synchronization failed or was cancelled, and
+    // are typically zeroed.
+    if status != Status::Success {
+        return Ok(None);
+    }
+
+    Ok(Some(PeriodicAdvertisingSync {
+        sync_handle: SyncHandle(LittleEndian::read_u16(&bytes[0..])),
+        adv_sid: bytes[2],
+        advertiser_address: to_report_address(bytes[3], &bytes[4..10])?,
+        advertiser_phy: Phy::try_from(bytes[10])?,
+        periodic_advertising_interval: Duration::from_micros(
+            PERIODIC_ADVERTISING_INTERVAL_UNIT * LittleEndian::read_u16(&bytes[11..]) as u64,
+        ),
+        advertiser_clock_accuracy: bytes[13].try_into()?,
+    }))
+}
+
+fn write_periodic_advertising_sync(sync: &Option<PeriodicAdvertisingSync>, bytes: &mut [u8]) {
+    match sync {
+        Some(sync) => {
+            LittleEndian::write_u16(&mut bytes[0..], sync.sync_handle.0);
+            bytes[2] = sync.adv_sid;
+            sync.advertiser_address.copy_into_slice(&mut bytes[3..10]);
+            bytes[10] = sync.advertiser_phy as u8;
+            LittleEndian::write_u16(
+                &mut bytes[11..],
+                (sync.periodic_advertising_interval.as_micros() as u64
+                    / PERIODIC_ADVERTISING_INTERVAL_UNIT) as u16,
+            );
+            bytes[13] = sync.advertiser_clock_accuracy as u8;
+        }
+        None => bytes[..PERIODIC_ADVERTISING_SYNC_LEN].fill(0),
+    }
+}
+
+fn to_le_periodic_advertising_sync_established(
+    payload: &[u8],
+) -> Result<LePeriodicAdvertisingSyncEstablished, Error> {
+    require_len!(payload, 16);
+
+    let status = payload[1].try_into().map_err(rewrap_bad_status)?;
+    Ok(LePeriodicAdvertisingSyncEstablished {
+        status,
+        sync: to_periodic_advertising_sync(status, &payload[2..])?,
+    })
+}
+
+fn write_le_periodic_advertising_sync_established(
+    event: &LePeriodicAdvertisingSyncEstablished,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x0E;
+    payload[1] = event.status.into();
+    write_periodic_advertising_sync(&event.sync, &mut payload[2..]);
+
+    16
+}
+
+/// Maximum length of the data in an
+/// [LE Periodic Advertising Report](Event::LePeriodicAdvertisingReport) event.
+const MAX_PERIODIC_ADVERTISING_REPORT_DATA_LEN: usize = 247;
+
+/// Indicates that the Controller has received a periodic advertising PDU from an advertiser it is
+/// synchronized with.
+///
+/// Periodic advertising data that does not fit in a single event is split over several reports;
+/// every report but the last has the [`Incomplete`](DataStatus::Incomplete) data status.
+///
+/// Defined in Vol 2, Part E, Section 7.7.65.15 of the spec.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LePeriodicAdvertisingReport {
+    /// Identifies the periodic advertising the PDU belongs to.
+    pub sync_handle: SyncHandle,
+
+    /// Transmit power of the advertiser, if it was included in the PDU.
+    ///
+    /// - Range is -127 dBm to 20 dBm.
+    pub tx_power: Option<i8>,
+
+    /// Received signal strength.
+    ///
+    /// - Range is -127 dBm to 20 dBm.
+    /// - If the controller sends the value 127, `None` is returned here, since that value indicates
+    ///   "RSSI is not available".
+    pub rssi: Option<i8>,
+
+    /// Type of the Constant Tone Extension of the PDU, if it has one.
+    pub cte_type: Option<CteType>,
+
+    /// Whether the data is complete, or continues in later reports.
+    pub data_status: DataStatus,
+
+    data_len: usize,
+    data_buf: [u8; MAX_PERIODIC_ADVERTISING_REPORT_DATA_LEN],
+}
+
+impl LePeriodicAdvertisingReport {
+    /// Returns the periodic advertising data, formatted as defined in Vol 3, Part C, Section 11 of
+    /// the spec. This may be a fragment of the complete data; see
+    /// [`data_status`](LePeriodicAdvertisingReport::data_status).
+    pub fn data(&self) -> &[u8] {
+        &self.data_buf[..self.data_len]
+    }
+}
+
+impl Debug for LePeriodicAdvertisingReport {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("LePeriodicAdvertisingReport")
+            .field("sync_handle", &self.sync_handle)
+            .field("tx_power", &self.tx_power)
+            .field("rssi", &self.rssi)
+            .field("cte_type", &self.cte_type)
+            .field("data_status", &self.data_status)
+            .field("data", &self.data())
+            .finish()
+    }
+}
+
+/// Types of Constant Tone Extension, defined in Vol 6, Part B, Section 2.5.1 of the spec.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CteType {
+    /// AoA Constant Tone Extension
+    AoA = 0x00,
+    /// AoD Constant Tone Extension with 1 µs slots
+    AoD1Us = 0x01,
+    /// AoD Constant Tone Extension with 2 µs slots
+    AoD2Us = 0x02,
+}
+
+impl TryFrom<u8> for CteType {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(CteType::AoA),
+            1 => Ok(CteType::AoD1Us),
+            2 => Ok(CteType::AoD2Us),
+            other => Err(Error::BadCteType(other)),
+        }
+    }
+}
+
+fn to_le_periodic_advertising_report(payload: &[u8]) -> Result<LePeriodicAdvertisingReport, Error> {
+    require_len_at_least!(payload, 8);
+    let data_len = payload[7] as usize;
+    require_len!(payload, 8 + data_len);
+
+    let mut data_buf = [0; MAX_PERIODIC_ADVERTISING_REPORT_DATA_LEN];
+    data_buf[..data_len].copy_from_slice(&payload[8..]);
+    Ok(LePeriodicAdvertisingReport {
+        sync_handle: SyncHandle(LittleEndian::read_u16(&payload[1..])),
+        tx_power: match payload[3] as i8 {
+            127 => None,
+            value => Some(value),
+        },
+        rssi: match payload[4] as i8 {
+            127 => None,
+            value => Some(value),
+        },
+        cte_type: match payload[5] {
+            0xFF => None,
+            value => Some(CteType::try_from(value)?),
+        },
+        data_status: DataStatus::try_from(payload[6])?,
+        data_len,
+        data_buf,
+    })
+}
+
+fn write_le_periodic_advertising_report(
+    event: &LePeriodicAdvertisingReport,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x0F;
+    LittleEndian::write_u16(&mut payload[1..], event.sync_handle.0);
+    payload[3] = event.tx_power.unwrap_or(127) as u8;
+    payload[4] = event.rssi.unwrap_or(127) as u8;
+    payload[5] = event.cte_type.map_or(0xFF, |cte_type| cte_type as u8);
+    payload[6] = event.data_status as u8;
+    payload[7] = event.data_len as u8;
+    payload[8..8 + event.data_len].copy_from_slice(event.data());
+
+    8 + event.data_len
+}
+
+fn to_le_periodic_advertising_sync_lost(payload: &[u8]) -> Result<SyncHandle, Error> {
+    require_len!(payload, 3);
+
+    Ok(SyncHandle(LittleEndian::read_u16(&payload[1..])))
+}
+
+/// Indicates that the Controller has received periodic advertising synchronization information
+/// from the peer of a connection, and has either synchronized with the periodic advertising or
+/// failed to do so.
+///
+/// Whether this event is generated depends on the mode set with the
+/// [LE Set Periodic Advertising Sync Transfer Parameters](crate::host::HostHci::le_set_periodic_advertising_sync_transfer_parameters)
+/// command.
+///
+/// Defined in Vol 2, Part E, Section 7.7.65.24 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LePeriodicAdvertisingSyncTransferReceived {
+    /// Did the synchronization fail, and if so, how?
+    pub status: Status,
+
+    /// Connection the synchronization information was received on.
+    pub conn_handle: ConnectionHandle,
+
+    /// Value provided by the Host of the peer.
+    pub service_data: u16,
+
+    /// The periodic advertising the Controller is synchronized with. Only present if the status is
+    /// [`Success`](Status::Success); otherwise the Controller does not report it.
+    pub sync: Option<PeriodicAdvertisingSync>,
+}
+
+fn to_le_periodic_advertising_sync_transfer_received(
+    payload: &[u8],
+) -> Result<LePeriodicAdvertisingSyncTransferReceived, Error> {
+    require_len!(payload, 20);
+
+    let status = payload[1].try_into().map_err(rewrap_bad_status)?;
+    Ok(LePeriodicAdvertisingSyncTransferReceived {
+        status,
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&payload[2..])),
+        service_data: LittleEndian::read_u16(&payload[4..]),
+        sync: to_periodic_advertising_sync(status, &payload[6..])?,
+    })
+}
+
+fn write_le_periodic_advertising_sync_transfer_received(
+    event: &LePeriodicAdvertisingSyncTransferReceived,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x18;
+    payload[1] = event.status.into();
+    LittleEndian::write_u16(&mut payload[2..], event.conn_handle.0);
+    LittleEndian::write_u16(&mut payload[4..], event.service_data);
+    write_periodic_advertising_sync(&event.sync, &mut payload[6..]);
+
+    20
+}
+
 /// Indicates that the Controller process to update the connection has completed.
 ///
 /// On a peripheral, if no connection parameters are updated, then this event shall not
@@ -1150,6 +2105,18 @@ fn to_le_connection_update_complete(payload: &[u8]) -> Result<LeConnectionUpdate
     })
 }
 
+fn write_le_connection_update_complete(
+    event: &LeConnectionUpdateComplete,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x03;
+    payload[1] = event.status.into();
+    LittleEndian::write_u16(&mut payload[2..], event.conn_handle.0);
+    event.conn_interval.copy_into_slice(&mut payload[4..10]);
+
+    10
+}
+
 /// Indicates the completion of the process of the Controller obtaining the features used on the
 /// connection and the features supported by the remote Bluetooth device specified by
 /// [`conn_handle`](LeReadRemoteUsedFeaturesComplete::conn_handle).
@@ -1184,6 +2151,18 @@ fn to_le_read_remote_used_features_complete(
     })
 }
 
+fn write_le_read_remote_used_features_complete(
+    event: &LeReadRemoteUsedFeaturesComplete,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x04;
+    payload[1] = event.status.into();
+    LittleEndian::write_u16(&mut payload[2..], event.conn_handle.0);
+    LittleEndian::write_u64(&mut payload[4..], event.features.bits());
+
+    12
+}
+
 /// The [LE Long Term Key Request](Event::LeLongTermKeyRequest) event indicates that the master
 /// device is attempting to encrypt or re-encrypt the link and is requesting the Long Term Key from
 /// the Host. (See Vol 6, Part B, Section 5.1.3).
@@ -1210,6 +2189,56 @@ fn to_le_ltk_request(payload: &[u8]) -> Result<LeLongTermKeyRequest, Error> {
     })
 }
 
+fn write_le_ltk_request(event: &LeLongTermKeyRequest, payload: &mut [u8]) -> usize {
+    payload[0] = 0x05;
+    LittleEndian::write_u16(&mut payload[1..], event.conn_handle.0);
+    LittleEndian::write_u64(&mut payload[3..], event.random_value);
+    LittleEndian::write_u16(&mut payload[11..], event.encrypted_diversifier);
+
+    13
+}
+
+/// The [LE Remote Connection Parameter Request](Event::LeRemoteConnectionParameterRequest) event
+/// indicates that the peer is requesting a change of the connection parameters. The Host replies
+/// with either the
+/// [`le_remote_connection_parameter_request_reply`](crate::host::HostHci::le_remote_connection_parameter_request_reply)
+/// or the
+/// [`le_remote_connection_parameter_request_negative_reply`](crate::host::HostHci::le_remote_connection_parameter_request_negative_reply)
+/// command.
+///
+/// Defined in Vol 2, Part E, Section 7.7.65.6 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeRemoteConnectionParameterRequest {
+    /// Connection handle to be used to identify a connection between two Bluetooth devices.
+    pub conn_handle: ConnectionHandle,
+    /// Range of the connection interval, latency, and supervision timeout requested by the peer.
+    pub conn_interval: ConnectionInterval,
+}
+
+fn to_le_remote_connection_parameter_request(
+    payload: &[u8],
+) -> Result<LeRemoteConnectionParameterRequest, Error> {
+    require_len!(payload, 11);
+
+    Ok(LeRemoteConnectionParameterRequest {
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&payload[1..])),
+        conn_interval: ConnectionInterval::from_bytes(&payload[3..11])
+            .map_err(Error::BadConnectionInterval)?,
+    })
+}
+
+fn write_le_remote_connection_parameter_request(
+    event: &LeRemoteConnectionParameterRequest,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x06;
+    LittleEndian::write_u16(&mut payload[1..], event.conn_handle.0);
+    event.conn_interval.copy_into_slice(&mut payload[3..11]);
+
+    11
+}
+
 /// Indicates that either the maximum Payload length of a LL DATA PDU
 /// has changed or the maximum transmission time of packets which contain
 /// LL Data PDUs.
@@ -1250,18 +2279,29 @@ fn to_le_data_length_change_event(payload: &[u8]) -> Result<LeDataLengthChangeEv
     })
 }
 
+fn write_le_data_length_change_event(event: &LeDataLengthChangeEvent, payload: &mut [u8]) -> usize {
+    payload[0] = 0x07;
+    LittleEndian::write_u16(&mut payload[1..], event.conn_handle.0);
+    LittleEndian::write_u16(&mut payload[3..], event.max_tx_octets);
+    LittleEndian::write_u16(&mut payload[5..], event.max_tx_time);
+    LittleEndian::write_u16(&mut payload[7..], event.max_rx_octets);
+    LittleEndian::write_u16(&mut payload[9..], event.max_rx_time);
+
+    11
+}
+
 /// PHY types supported by Bluetooth LE.
 ///
 /// See Vol 1, Part A, Section 3.2.2 of the spec.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Phy {
     /// The LE 1M PHY supports a datarate of 1 MBit/s.
-    Le1M,
+    Le1M = 0x01,
     /// The LE 2M PHY supports a datarate of 2 MBit/s.
-    Le2M,
+    Le2M = 0x02,
     /// The LE Coded PHY supports a datarate of either 125 kBit/s or 500 kBit/s.
-    LeCoded,
+    LeCoded = 0x03,
 }
 
 impl TryFrom<u8> for Phy {
@@ -1269,9 +2309,40 @@ impl TryFrom<u8> for Phy {
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
-            0 => Ok(Phy::Le1M),
-            1 => Ok(Phy::Le2M),
-            2 => Ok(Phy::LeCoded),
+            1 => Ok(Phy::Le1M),
+            2 => Ok(Phy::Le2M),
+            3 => Ok(Phy::LeCoded),
+            other => Err(Error::BadPhy(other)),
+        }
+    }
+}
+
+/// PHYs distinguished by LE Power Control, which treats the two coding schemes of the LE Coded PHY
+/// separately.
+///
+/// See Vol 6, Part B, Section 5.1.17 of the spec.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PowerControlPhy {
+    /// LE 1M PHY
+    Le1M = 0x01,
+    /// LE 2M PHY
+    Le2M = 0x02,
+    /// LE Coded PHY with S=8 data coding
+    LeCodedS8 = 0x03,
+    /// LE Coded PHY with S=2 data coding
+    LeCodedS2 = 0x04,
+}
+
+impl TryFrom<u8> for PowerControlPhy {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(PowerControlPhy::Le1M),
+            2 => Ok(PowerControlPhy::Le2M),
+            3 => Ok(PowerControlPhy::LeCodedS8),
+            4 => Ok(PowerControlPhy::LeCodedS2),
             other => Err(Error::BadPhy(other)),
         }
     }
@@ -1308,20 +2379,65 @@ fn to_le_phy_update_complete(payload: &[u8]) -> Result<LePhyUpdateComplete, Erro
     })
 }
 
-fn to_le_read_local_p256_public_key(payload: &[u8]) -> Result<[u8; 64], Error> {
-    require_len!(payload, 65);
+fn write_le_phy_update_complete(event: &LePhyUpdateComplete, payload: &mut [u8]) -> usize {
+    payload[0] = 0x0C;
+    payload[1] = event.status.into();
+    LittleEndian::write_u16(&mut payload[2..], event.conn_handle.0);
+    payload[4] = event.tx_phy as u8;
+    payload[5] = event.rx_phy as u8;
+
+    6
+}
 
-    let mut key = [0; 64];
-    key.copy_from_slice(&payload[1..]);
-    Ok(key)
+/// Returns the P-256 public key generated by the Controller in response to the
+/// [LE Read Local P-256 Public Key](crate::host::HostHci::le_read_local_p256_public_key) command.
+///
+/// Defined in Vol 2, Part E, Section 7.7.65.8 of the spec.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeReadLocalP256PublicKeyComplete {
+    /// Did the key generation fail, and if so, how?
+    pub status: Status,
+    /// Public key of the local device. Only valid if the status is
+    /// [`Success`](Status::Success).
+    pub public_key: crate::host::P256PublicKey,
 }
 
-fn to_le_generate_dhkey_complete(payload: &[u8]) -> Result<[u8; 32], Error> {
-    require_len!(payload, 33);
+fn to_le_read_local_p256_public_key(
+    payload: &[u8],
+) -> Result<LeReadLocalP256PublicKeyComplete, Error> {
+    require_len!(payload, 66);
 
-    let mut key = [0; 32];
-    key.copy_from_slice(&payload[1..]);
-    Ok(key)
+    let mut public_key = crate::host::P256PublicKey([0; 64]);
+    public_key.0.copy_from_slice(&payload[2..]);
+    Ok(LeReadLocalP256PublicKeyComplete {
+        status: payload[1].try_into().map_err(rewrap_bad_status)?,
+        public_key,
+    })
+}
+
+/// Returns the Diffie-Hellman key computed by the Controller in response to the
+/// [LE Generate DHKey](crate::host::HostHci::le_generate_dhkey) command.
+///
+/// Defined in Vol 2, Part E, Section 7.7.65.9 of the spec.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeGenerateDHKeyComplete {
+    /// Did the key computation fail, and if so, how?
+    pub status: Status,
+    /// Diffie-Hellman key. Only valid if the status is [`Success`](Status::Success).
+    pub dh_key: crate::host::DhKey,
+}
+
+fn to_le_generate_dhkey_complete(payload: &[u8]) -> Result<LeGenerateDHKeyComplete, Error> {
+    require_len!(payload, 34);
+
+    let mut dh_key = crate::host::DhKey([0; 32]);
+    dh_key.0.copy_from_slice(&payload[2..]);
+    Ok(LeGenerateDHKeyComplete {
+        status: payload[1].try_into().map_err(rewrap_bad_status)?,
+        dh_key,
+    })
 }
 
 /// This event indicates to both of the Hosts forming the connection that a new connection has been created.
@@ -1415,3 +2531,313 @@ fn to_le_enhanced_connection_complete(
         central_clock_accuracy: payload[30].try_into()?,
     })
 }
+
+fn write_le_enhanced_connection_complete(
+    event: &LeEnhancedConnectionComplete,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x0A;
+    payload[1] = event.status.into();
+    LittleEndian::write_u16(&mut payload[2..], event.conn_handle.0);
+    payload[4] = event.role as u8;
+    event.peer_bd_addr.copy_into_slice(&mut payload[5..12]);
+    payload[12..18].copy_from_slice(&event.local_resolvable_private_address.0);
+    payload[18..24].copy_from_slice(&event.peer_resolvable_private_address.0);
+    event.conn_interval.copy_into_slice(&mut payload[24..30]);
+    payload[30] = event.central_clock_accuracy as u8;
+
+    31
+}
+
+/// Indicates which channel selection algorithm is used on a data physical channel connection.
+///
+/// This event is generated after the [LE Connection Complete](Event::LeConnectionComplete) or
+/// [LE Enhanced Connection Complete](Event::LeEnhancedConnectionComplete) event when a connection
+/// is established.
+///
+/// Defined in Vol 2, Part E, Section 7.7.65.20 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeChannelSelectionAlgorithm {
+    /// Connection handle to be used to identify a connection between two Bluetooth devices.
+    pub conn_handle: ConnectionHandle,
+    /// Channel selection algorithm used on the connection.
+    pub algorithm: ChannelSelectionAlgorithm,
+}
+
+/// Channel selection algorithms, defined in Vol 6, Part B, Section 4.5.8 of the spec.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ChannelSelectionAlgorithm {
+    /// LE Channel Selection Algorithm #1
+    Algorithm1 = 0x00,
+    /// LE Channel Selection Algorithm #2
+    Algorithm2 = 0x01,
+}
+
+impl TryFrom<u8> for ChannelSelectionAlgorithm {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ChannelSelectionAlgorithm::Algorithm1),
+            1 => Ok(ChannelSelectionAlgorithm::Algorithm2),
+            other => Err(Error::BadChannelSelectionAlgorithm(other)),
+        }
+    }
+}
+
+fn to_le_channel_selection_algorithm(payload: &[u8]) -> Result<LeChannelSelectionAlgorithm, Error> {
+    require_len!(payload, 4);
+
+    Ok(LeChannelSelectionAlgorithm {
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&payload[1..])),
+        algorithm: payload[3].try_into()?,
+    })
+}
+
+fn write_le_channel_selection_algorithm(
+    event: &LeChannelSelectionAlgorithm,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x14;
+    LittleEndian::write_u16(&mut payload[1..], event.conn_handle.0);
+    payload[3] = event.algorithm as u8;
+
+    4
+}
+
+/// Indicates that the path loss of a connection has entered a different zone, as defined by the
+/// thresholds set with the
+/// [LE Set Path Loss Reporting Parameters](crate::host::HostHci::le_set_path_loss_reporting_parameters)
+/// command.
+///
+/// Defined in Vol 2, Part E, Section 7.7.65.32 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LePathLossThreshold {
+    /// Connection handle to be used to identify a connection between two Bluetooth devices.
+    pub conn_handle: ConnectionHandle,
+
+    /// Current path loss, in dB. If the controller sends the value 0xFF, `None` is returned here,
+    /// since that value indicates "path loss is not available".
+    pub current_path_loss: Option<u8>,
+
+    /// Zone the path loss has entered.
+    pub zone_entered: PathLossZone,
+}
+
+/// Zones of the path loss reported in the [LE Path Loss Threshold](Event::LePathLossThreshold)
+/// event.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PathLossZone {
+    /// The path loss is below the low threshold.
+    Low = 0x00,
+    /// The path loss is between the low and high thresholds.
+    Middle = 0x01,
+    /// The path loss is above the high threshold.
+    High = 0x02,
+}
+
+impl TryFrom<u8> for PathLossZone {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(PathLossZone::Low),
+            1 => Ok(PathLossZone::Middle),
+            2 => Ok(PathLossZone::High),
+            other => Err(Error::BadPathLossZone(other)),
+        }
+    }
+}
+
+fn to_le_path_loss_threshold(payload: &[u8]) -> Result<LePathLossThreshold, Error> {
+    require_len!(payload, 5);
+
+    Ok(LePathLossThreshold {
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&payload[1..])),
+        current_path_loss: match payload[3] {
+            0xFF => None,
+            value => Some(value),
+        },
+        zone_entered: PathLossZone::try_from(payload[4])?,
+    })
+}
+
+fn write_le_path_loss_threshold(event: &LePathLossThreshold, payload: &mut [u8]) -> usize {
+    payload[0] = 0x20;
+    LittleEndian::write_u16(&mut payload[1..], event.conn_handle.0);
+    payload[3] = event.current_path_loss.unwrap_or(0xFF);
+    payload[4] = event.zone_entered as u8;
+
+    5
+}
+
+/// Reports the transmit power level of the local or remote Controller on a connection, either
+/// because it changed or because it was read with the
+/// [LE Read Remote Transmit Power Level](crate::host::HostHci::le_read_remote_transmit_power_level)
+/// command.
+///
+/// Defined in Vol 2, Part E, Section 7.7.65.33 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeTransmitPowerReporting {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Connection handle to be used to identify a connection between two Bluetooth devices.
+    pub conn_handle: ConnectionHandle,
+
+    /// The reported transmit power level. Only present if the status is
+    /// [`Success`](Status::Success); otherwise the Controller does not report it.
+    pub report: Option<TransmitPowerReport>,
+}
+
+/// Transmit power level reported by the
+/// [LE Transmit Power Reporting](Event::LeTransmitPowerReporting) event.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TransmitPowerReport {
+    /// Why the transmit power level is reported, and whether it is the local or remote level.
+    pub reason: TransmitPowerReportingReason,
+
+    /// PHY whose transmit power level is reported.
+    pub phy: PowerControlPhy,
+
+    /// Transmit power level.
+    pub tx_power_level: ReportedTxPowerLevel,
+
+    /// Whether the transmit power level is at its minimum or maximum.
+    pub tx_power_level_flags: TxPowerLevelFlags,
+
+    /// Change in the transmit power level, in dB, or `None` if the change is not available. A
+    /// positive value means that the level increased.
+    pub delta: Option<i8>,
+}
+
+/// Reasons for the [LE Transmit Power Reporting](Event::LeTransmitPowerReporting) event.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TransmitPowerReportingReason {
+    /// The transmit power level of the local Controller changed.
+    LocalChanged = 0x00,
+    /// The transmit power level of the remote Controller changed.
+    RemoteChanged = 0x01,
+    /// The [LE Read Remote Transmit Power Level](crate::host::HostHci::le_read_remote_transmit_power_level)
+    /// command completed.
+    ReadRemoteCompleted = 0x02,
+}
+
+impl TryFrom<u8> for TransmitPowerReportingReason {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TransmitPowerReportingReason::LocalChanged),
+            1 => Ok(TransmitPowerReportingReason::RemoteChanged),
+            2 => Ok(TransmitPowerReportingReason::ReadRemoteCompleted),
+            other => Err(Error::BadTransmitPowerReportingReason(other)),
+        }
+    }
+}
+
+/// Transmit power level reported in the
+/// [LE Transmit Power Reporting](Event::LeTransmitPowerReporting) event.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ReportedTxPowerLevel {
+    /// Transmit power level, in dBm.
+    Dbm(i8),
+    /// The remote Controller does not manage its transmit power levels on the PHY.
+    NotManaged,
+    /// The transmit power level is not available.
+    NotAvailable,
+}
+
+#[cfg(not(feature = "defmt"))]
+bitflags::bitflags! {
+    /// Flags reported with the transmit power level in the
+    /// [LE Transmit Power Reporting](Event::LeTransmitPowerReporting) event.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct TxPowerLevelFlags: u8 {
+        /// The transmit power level is at its minimum.
+        const AT_MINIMUM = 0x01;
+        /// The transmit power level is at its maximum.
+        const AT_MAXIMUM = 0x02;
+    }
+}
+
+#[cfg(feature = "defmt")]
+defmt::bitflags! {
+    /// Flags reported with the transmit power level in the
+    /// [LE Transmit Power Reporting](Event::LeTransmitPowerReporting) event.
+    pub struct TxPowerLevelFlags: u8 {
+        /// The transmit power level is at its minimum.
+        const AT_MINIMUM = 0x01;
+        /// The transmit power level is at its maximum.
+        const AT_MAXIMUM = 0x02;
+    }
+}
+
+fn to_le_transmit_power_reporting(payload: &[u8]) -> Result<LeTransmitPowerReporting, Error> {
+    require_len!(payload, 9);
+
+    let status = payload[1].try_into().map_err(rewrap_bad_status)?;
+    Ok(LeTransmitPowerReporting {
+        status,
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&payload[2..])),
+        report: to_transmit_power_report(status, &payload[4..])?,
+    })
+}
+
+fn to_transmit_power_report(
+    status: Status,
+    bytes: &[u8],
+) -> Result<Option<TransmitPowerReport>, Error> {
+    // The remaining parameters are not valid if reading the remote transmit power level failed.
+    if status != Status::Success {
+        return Ok(None);
+    }
+
+    Ok(Some(TransmitPowerReport {
+        reason: TransmitPowerReportingReason::try_from(bytes[0])?,
+        phy: PowerControlPhy::try_from(bytes[1])?,
+        tx_power_level: match bytes[2] {
+            0x7E => ReportedTxPowerLevel::NotManaged,
+            0x7F => ReportedTxPowerLevel::NotAvailable,
+            value => ReportedTxPowerLevel::Dbm(value as i8),
+        },
+        tx_power_level_flags: TxPowerLevelFlags::from_bits_truncate(bytes[3]),
+        delta: match bytes[4] as i8 {
+            127 => None,
+            value => Some(value),
+        },
+    }))
+}
+
+fn write_le_transmit_power_reporting(
+    event: &LeTransmitPowerReporting,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x21;
+    payload[1] = event.status.into();
+    LittleEndian::write_u16(&mut payload[2..], event.conn_handle.0);
+    match event.report {
+        Some(report) => {
+            payload[4] = report.reason as u8;
+            payload[5] = report.phy as u8;
+            payload[6] = match report.tx_power_level {
+                ReportedTxPowerLevel::Dbm(value) => value as u8,
+                ReportedTxPowerLevel::NotManaged => 0x7E,
+                ReportedTxPowerLevel::NotAvailable => 0x7F,
+            };
+            payload[7] = report.tx_power_level_flags.bits();
+            payload[8] = report.delta.unwrap_or(127) as u8;
+        }
+        None => payload[4..9].fill(0),
+    }
+
+    9
+}
@@ -0,0 +1,195 @@
+//! Incremental decoder for the H4 (UART) packet stream.
+//!
+//! [`UartHci::read`](crate::host::uart::UartHci::read) expects the controller to return whole
+//! packets. Transports that deliver arbitrary chunks of the byte stream (a UART with DMA, a
+//! socket, a capture file, ...) can feed those chunks to an [`H4Decoder`] instead, which
+//! reassembles the packets. If the stream is corrupted, the decoder discards bytes until it finds
+//! something that looks like the start of a packet again, and reports how many bytes it skipped.
+
+use super::uart::{
+    parse_packet, AclDataHeader, Error, Packet, EVENT_PACKET_HEADER_LENGTH, MAX_ACL_DATA_LENGTH,
+    MAX_PACKET_LENGTH, PACKET_TYPE_ACL_DATA, PACKET_TYPE_HCI_EVENT,
+};
+use byteorder::{ByteOrder, LittleEndian};
+use core::convert::Infallible;
+
+/// Result of feeding bytes to an [`H4Decoder`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Decoded {
+    /// Number of input bytes consumed by the decoder. Bytes after these must be fed again.
+    pub consumed: usize,
+
+    /// Number of bytes discarded while looking for the start of a packet.
+    pub skipped: usize,
+
+    /// The packet completed by the input, if any. A packet that is correctly framed but cannot be
+    /// deserialized is returned as an error; the decoder is ready for the next packet in either
+    /// case. The decoder does not communicate with the controller, so the error is never
+    /// [`Comm`](Error::Comm).
+    pub packet: Option<Result<Packet, Error<Infallible>>>,
+}
+
+/// Reassembles H4 packets from arbitrary chunks of the byte stream read from the controller.
+///
+/// # Example
+///
+/// ```
+/// # extern crate stm32wb_hci as hci;
+/// # use hci::host::decoder::H4Decoder;
+/// let mut decoder = H4Decoder::new();
+///
+/// // Garbage, then a Data Buffer Overflow event split across two chunks
+/// let chunks: [&[u8]; 2] = [&[0xAA, 0x04, 0x1A], &[0x01, 0x01]];
+///
+/// let mut skipped = 0;
+/// let mut packets = 0;
+/// for chunk in chunks {
+///     let mut chunk = chunk;
+///     while !chunk.is_empty() {
+///         let decoded = decoder.decode(chunk);
+///         chunk = &chunk[decoded.consumed..];
+///         skipped += decoded.skipped;
+///         if let Some(packet) = decoded.packet {
+///             assert!(packet.is_ok());
+///             packets += 1;
+///         }
+///     }
+/// }
+/// assert_eq!(skipped, 1);
+/// assert_eq!(packets, 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct H4Decoder {
+    buffer: [u8; MAX_PACKET_LENGTH],
+    len: usize,
+}
+
+/// Outcome of inspecting the header of the buffered packet.
+enum Framing {
+    /// The header is incomplete.
+    NeedMore,
+    /// The header is complete, and the packet (including its header) is this long.
+    Length(usize),
+    /// The header cannot be the start of a packet.
+    Invalid,
+}
+
+fn is_packet_type(byte: u8) -> bool {
+    byte == PACKET_TYPE_HCI_EVENT || byte == PACKET_TYPE_ACL_DATA
+}
+
+impl H4Decoder {
+    /// Creates a decoder that expects the next byte to be the start of a packet.
+    pub fn new() -> H4Decoder {
+        H4Decoder {
+            buffer: [0; MAX_PACKET_LENGTH],
+            len: 0,
+        }
+    }
+
+    /// Returns the number of bytes buffered for an incomplete packet.
+    pub fn pending(&self) -> usize {
+        self.len
+    }
+
+    /// Discards any incomplete packet, for example after the controller is reset. Returns the
+    /// number of discarded bytes.
+    pub fn reset(&mut self) -> usize {
+        let discarded = self.len;
+        self.len = 0;
+        discarded
+    }
+
+    /// Feeds bytes from the stream to the decoder.
+    ///
+    /// The decoder consumes bytes until it completes a packet or runs out of input. If a packet is
+    /// completed, the remaining input must be fed again with another call.
+    pub fn decode(&mut self, data: &[u8]) -> Decoded {
+        let mut skipped = 0;
+        for (i, &byte) in data.iter().enumerate() {
+            if self.len == 0 && !is_packet_type(byte) {
+                skipped += 1;
+                continue;
+            }
+
+            self.buffer[self.len] = byte;
+            self.len += 1;
+
+            loop {
+                match self.framing() {
+                    Framing::NeedMore => break,
+                    Framing::Invalid => skipped += self.resync(),
+                    Framing::Length(packet_len) if self.len >= packet_len => {
+                        let packet = parse_packet(&self.buffer[..packet_len]);
+                        self.buffer.copy_within(packet_len..self.len, 0);
+                        self.len -= packet_len;
+                        if self.len > 0 && !is_packet_type(self.buffer[0]) {
+                            skipped += self.resync();
+                        }
+
+                        return Decoded {
+                            consumed: i + 1,
+                            skipped,
+                            packet: Some(packet),
+                        };
+                    }
+                    Framing::Length(_) => break,
+                }
+            }
+        }
+
+        Decoded {
+            consumed: data.len(),
+            skipped,
+            packet: None,
+        }
+    }
+
+    fn framing(&self) -> Framing {
+        if self.len == 0 {
+            return Framing::NeedMore;
+        }
+
+        match self.buffer[0] {
+            PACKET_TYPE_HCI_EVENT => {
+                if self.len < EVENT_PACKET_HEADER_LENGTH {
+                    return Framing::NeedMore;
+                }
+
+                Framing::Length(EVENT_PACKET_HEADER_LENGTH + self.buffer[2] as usize)
+            }
+            PACKET_TYPE_ACL_DATA => {
+                if self.len < AclDataHeader::HEADER_LENGTH {
+                    return Framing::NeedMore;
+                }
+
+                let data_len = LittleEndian::read_u16(&self.buffer[3..]) as usize;
+                if data_len > MAX_ACL_DATA_LENGTH {
+                    return Framing::Invalid;
+                }
+
+                Framing::Length(AclDataHeader::HEADER_LENGTH + data_len)
+            }
+            _ => Framing::Invalid,
+        }
+    }
+
+    /// Drops the first buffered byte, which cannot start a packet, and then every byte up to the
+    /// next possible packet type byte. Returns the number of dropped bytes.
+    fn resync(&mut self) -> usize {
+        let dropped = 1 + self.buffer[1..self.len]
+            .iter()
+            .position(|&b| is_packet_type(b))
+            .unwrap_or(self.len - 1);
+        self.buffer.copy_within(dropped..self.len, 0);
+        self.len -= dropped;
+        dropped
+    }
+}
+
+impl Default for H4Decoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,118 @@
+//! Correlation of HCI commands with the events that complete them.
+//!
+//! The controller reports the outcome of every command with either a Command Complete or a Command
+//! Status event that carries the command's opcode. Other events (advertising reports, connection
+//! events, vendor events, ...) may arrive between the command and its response. The
+//! [`CommandExecutor`] sends a command, reads packets until the matching response arrives, and hands
+//! every unrelated packet to an application-provided [`EventQueue`] so that nothing is lost.
+
+use super::uart::{Error, Packet, UartHci};
+use crate::event::command::ReturnParameters;
+use crate::event::Event;
+use crate::{Opcode, Status};
+
+/// Response of the controller to a command sent through a [`CommandExecutor`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[allow(clippy::large_enum_variant)]
+pub enum CommandResponse {
+    /// The controller generated a Command Complete event for the command. Contains the typed
+    /// return parameters of the command.
+    Complete(ReturnParameters),
+
+    /// The controller generated a Command Status event for the command. Contains the status of
+    /// the command. If the status is [`Success`](Status::Success), the controller will generate
+    /// another event later when the command completes.
+    Status(Status),
+}
+
+/// Destination of the packets that are received while waiting for the response to a command.
+///
+/// The queue is implemented for any `FnMut(Packet)`, so a closure that pushes into a
+/// fixed-capacity queue (or a channel) is enough. The implementor decides what to do if the queue
+/// is full.
+pub trait EventQueue {
+    /// Stores a packet that is not the response to the command being executed.
+    fn push(&mut self, packet: Packet);
+}
+
+impl<F> EventQueue for F
+where
+    F: FnMut(Packet),
+{
+    fn push(&mut self, packet: Packet) {
+        self(packet)
+    }
+}
+
+/// Trait for sending commands and waiting for their responses.
+///
+/// This trait is implemented for every [`crate::Controller`].
+pub trait CommandExecutor: UartHci {
+    /// Writes the command with the given `opcode` and `params` to the controller, then waits for
+    /// the matching response as [`wait_for_response`](CommandExecutor::wait_for_response) does.
+    ///
+    /// # Errors
+    ///
+    /// - [`Comm`](Error::Comm) if the command could not be written to the controller.
+    /// - Any error from [`UartHci::read`] while waiting for the response.
+    async fn execute<Q: EventQueue>(
+        &mut self,
+        opcode: Opcode,
+        params: &[u8],
+        queue: &mut Q,
+    ) -> Result<CommandResponse, Error<Self::Error>>;
+
+    /// Reads packets from the controller until a Command Complete or Command Status event with the
+    /// given `opcode` arrives, and returns its contents.
+    ///
+    /// This is meant to be called right after one of the [`crate::host::HostHci`] (or vendor)
+    /// command functions. Every other packet, including [spontaneous](ReturnParameters::Spontaneous)
+    /// Command Complete events and responses to other commands, is pushed into `queue` in the
+    /// order in which it was received.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`UartHci::read`]. Packets received before the error are still
+    /// pushed into `queue`.
+    async fn wait_for_response<Q: EventQueue>(
+        &mut self,
+        opcode: Opcode,
+        queue: &mut Q,
+    ) -> Result<CommandResponse, Error<Self::Error>>;
+}
+
+impl<T> CommandExecutor for T
+where
+    T: crate::Controller,
+{
+    async fn execute<Q: EventQueue>(
+        &mut self,
+        opcode: Opcode,
+        params: &[u8],
+        queue: &mut Q,
+    ) -> Result<CommandResponse, Error<Self::Error>> {
+        self.controller_write(opcode, params)
+            .await
+            .map_err(Error::Comm)?;
+        self.wait_for_response(opcode, queue).await
+    }
+
+    async fn wait_for_response<Q: EventQueue>(
+        &mut self,
+        opcode: Opcode,
+        queue: &mut Q,
+    ) -> Result<CommandResponse, Error<Self::Error>> {
+        loop {
+            match self.read().await? {
+                Packet::Event(Event::CommandComplete(event)) if event.opcode == opcode => {
+                    return Ok(CommandResponse::Complete(event.return_params));
+                }
+                Packet::Event(Event::CommandStatus(event)) if event.opcode == opcode => {
+                    return Ok(CommandResponse::Status(event.status));
+                }
+                other => queue.push(other),
+            }
+        }
+    }
+}
@@ -0,0 +1,383 @@
+//! HCI command flow control.
+//!
+//! The controller tells the host how many HCI command packets it may send with the
+//! `Num_HCI_Command_Packets` parameter of every Command Complete and Command Status event,
+//! including [spontaneous](crate::event::command::ReturnParameters::Spontaneous) ones that are not
+//! associated with any command. The host must not send a command while that number is zero.
+//!
+//! See the Bluetooth spec, Vol 2, Part E, Section 4.4.
+//!
+//! The host must likewise not send more ACL data packets than the controller has buffers for; see
+//! [`AclBufferCredits`].
+
+use super::executor::EventQueue;
+use super::uart::{
+    parse_packet, Error, UartHci, EVENT_PACKET_HEADER_LENGTH, MAX_PACKET_LENGTH,
+    PACKET_TYPE_HCI_EVENT,
+};
+use crate::event::command::{CommandComplete, LeReadBufferSize, ReturnParameters};
+use crate::event::{Event, NumberOfCompletedPackets};
+use crate::{ConnectionHandle, Status};
+use core::cell::Cell;
+
+const EVENT_COMMAND_COMPLETE: u8 = 0x0E;
+const EVENT_COMMAND_STATUS: u8 = 0x0F;
+
+/// Number of command packets the host may send after the controller is reset.
+const INITIAL_COMMAND_CREDITS: u8 = 1;
+
+/// Wraps a [`Controller`](crate::Controller) and tracks the number of HCI command packets the host
+/// may send to it.
+///
+/// The wrapper implements [`Controller`](crate::Controller) itself, so it can be used with
+/// [`HostHci`](crate::host::HostHci), [`UartHci`], the vendor commands and the
+/// [`CommandExecutor`](crate::host::executor::CommandExecutor) as a drop-in replacement of the
+/// wrapped controller:
+///
+/// - every command written consumes one credit;
+/// - every Command Complete or Command Status event read from the controller sets the number of
+///   credits to its `Num_HCI_Command_Packets` parameter;
+/// - a command written while the host has no credit is held back until the controller grants one.
+///   Every packet read while waiting is pushed into the `queue` given to
+///   [`new`](CommandFlowControl::new).
+///
+/// The wrapped controller's error type cannot report a packet that fails to deserialize, so such
+/// packets read while a command is held back are discarded and counted (see
+/// [`discarded`](CommandFlowControl::discarded)). Call [`acquire`](CommandFlowControl::acquire)
+/// before writing a command to get those errors instead.
+///
+/// Credits are tracked by inspecting the packets returned by
+/// [`controller_read_into`](crate::Controller::controller_read_into), so reads must be done one
+/// whole packet at a time, as [`UartHci::read`] does.
+pub struct CommandFlowControl<C, Q> {
+    controller: C,
+    queue: Q,
+    credits: Cell<u8>,
+    discarded: usize,
+}
+
+impl<C, Q> CommandFlowControl<C, Q> {
+    /// Wraps the controller. The host assumes it may send a single command until the controller
+    /// reports otherwise.
+    ///
+    /// Packets read while waiting for a credit are pushed into `queue`.
+    pub fn new(controller: C, queue: Q) -> CommandFlowControl<C, Q> {
+        CommandFlowControl {
+            controller,
+            queue,
+            credits: Cell::new(INITIAL_COMMAND_CREDITS),
+            discarded: 0,
+        }
+    }
+
+    /// Returns the number of HCI command packets the host may currently send.
+    pub fn credits(&self) -> u8 {
+        self.credits.get()
+    }
+
+    /// Returns true if the host may send a command now.
+    pub fn can_send(&self) -> bool {
+        self.credits.get() > 0
+    }
+
+    /// Returns the number of packets that were read while a command was held back, but could not
+    /// be deserialized.
+    pub fn discarded(&self) -> usize {
+        self.discarded
+    }
+
+    /// Returns a reference to the wrapped controller.
+    pub fn inner(&self) -> &C {
+        &self.controller
+    }
+
+    /// Returns a mutable reference to the wrapped controller. Commands written directly to the
+    /// wrapped controller are not counted.
+    pub fn inner_mut(&mut self) -> &mut C {
+        &mut self.controller
+    }
+
+    /// Returns a reference to the queue of packets read while waiting for a credit.
+    pub fn queue(&self) -> &Q {
+        &self.queue
+    }
+
+    /// Returns a mutable reference to the queue of packets read while waiting for a credit.
+    pub fn queue_mut(&mut self) -> &mut Q {
+        &mut self.queue
+    }
+
+    /// Unwraps the controller and the queue.
+    pub fn into_inner(self) -> (C, Q) {
+        (self.controller, self.queue)
+    }
+
+    fn update_credits(&self, packet: &[u8]) {
+        if packet.len() < EVENT_PACKET_HEADER_LENGTH || packet[0] != PACKET_TYPE_HCI_EVENT {
+            return;
+        }
+
+        let credits_index = match packet[1] {
+            EVENT_COMMAND_COMPLETE => EVENT_PACKET_HEADER_LENGTH,
+            EVENT_COMMAND_STATUS => EVENT_PACKET_HEADER_LENGTH + 1,
+            _ => return,
+        };
+        let param_len = packet[2] as usize;
+        if credits_index < EVENT_PACKET_HEADER_LENGTH + param_len {
+            if let Some(&credits) = packet.get(credits_index) {
+                self.credits.set(credits);
+            }
+        }
+    }
+}
+
+impl<C, Q> CommandFlowControl<C, Q>
+where
+    C: crate::Controller,
+    Q: EventQueue,
+{
+    /// Reads packets from the controller until it accepts at least one command. Returns
+    /// immediately if the host already has a credit.
+    ///
+    /// Every packet read while waiting, including the Command Complete or Command Status event that
+    /// grants the credit, is pushed into the queue.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`UartHci::read`].
+    pub async fn acquire(&mut self) -> Result<(), Error<C::Error>> {
+        while !self.can_send() {
+            let packet = self.read().await?;
+            self.queue.push(packet);
+        }
+
+        Ok(())
+    }
+}
+
+impl<C, Q> crate::Controller for CommandFlowControl<C, Q>
+where
+    C: crate::Controller,
+    Q: EventQueue,
+{
+    type Error = C::Error;
+
+    async fn controller_write(
+        &mut self,
+        opcode: crate::Opcode,
+        payload: &[u8],
+    ) -> Result<(), Self::Error> {
+        while !self.can_send() {
+            let mut packet = [0; MAX_PACKET_LENGTH];
+            self.controller_read_into(&mut packet).await?;
+            match parse_packet::<C::Error>(&packet) {
+                Ok(packet) => self.queue.push(packet),
+                Err(_) => self.discarded += 1,
+            }
+        }
+
+        self.credits.set(self.credits.get() - 1);
+        self.controller.controller_write(opcode, payload).await
+    }
+
+    async fn controller_read_into(&self, buf: &mut [u8]) -> Result<(), Self::Error> {
+        self.controller.controller_read_into(buf).await?;
+        self.update_credits(buf);
+
+        Ok(())
+    }
+}
+
+/// Errors that prevent sending an ACL data packet.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AclBufferError {
+    /// All of the controller's ACL data buffers are in use. Wait for a
+    /// [Number of Completed Packets](crate::event::Event::NumberOfCompletedPackets) event.
+    NoBuffers,
+    /// Packets are already outstanding on as many connections as the accounting can track.
+    /// Contains the handle of the connection that could not be tracked.
+    TooManyConnections(ConnectionHandle),
+}
+
+/// Number of the controller's ACL data buffers in use by a connection.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+struct Outstanding {
+    conn_handle: ConnectionHandle,
+    packets: usize,
+}
+
+/// Accounting of the controller's LE ACL data buffers.
+///
+/// The host may only have as many ACL data packets outstanding in the controller as the controller
+/// has buffers, as reported by the
+/// [LE Read Buffer Size](crate::host::HostHci::le_read_buffer_size) command. A buffer is released
+/// when the controller reports it with a
+/// [Number of Completed Packets](crate::event::Event::NumberOfCompletedPackets) event, or when the
+/// connection that used it is disconnected.
+///
+/// Up to `CONNECTIONS` connections may have packets outstanding at the same time.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 4.1.1.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AclBufferCredits<const CONNECTIONS: usize> {
+    data_packet_length: usize,
+    available: usize,
+    outstanding: [Option<Outstanding>; CONNECTIONS],
+}
+
+impl<const CONNECTIONS: usize> AclBufferCredits<CONNECTIONS> {
+    /// Creates the accounting without any buffer. No packet may be sent until the controller's
+    /// buffer size is known.
+    pub fn new() -> AclBufferCredits<CONNECTIONS> {
+        AclBufferCredits {
+            data_packet_length: 0,
+            available: 0,
+            outstanding: [None; CONNECTIONS],
+        }
+    }
+
+    /// Creates the accounting from the return parameters of the
+    /// [LE Read Buffer Size](crate::host::HostHci::le_read_buffer_size) command.
+    ///
+    /// If `data_packet_count` is 0, the controller shares its buffers with BR/EDR, and no LE packet
+    /// may be sent.
+    pub fn from_buffer_size(buffer_size: &LeReadBufferSize) -> AclBufferCredits<CONNECTIONS> {
+        let mut credits = AclBufferCredits::new();
+        credits.set_buffer_size(buffer_size);
+        credits
+    }
+
+    /// Resets the accounting to the return parameters of the
+    /// [LE Read Buffer Size](crate::host::HostHci::le_read_buffer_size) command. All buffers are
+    /// considered free.
+    pub fn set_buffer_size(&mut self, buffer_size: &LeReadBufferSize) {
+        self.data_packet_length = buffer_size.data_packet_length as usize;
+        self.available = buffer_size.data_packet_count as usize;
+        self.outstanding = [None; CONNECTIONS];
+    }
+
+    /// Returns the maximum number of data bytes in a single ACL data packet sent to the controller.
+    pub fn data_packet_length(&self) -> usize {
+        self.data_packet_length
+    }
+
+    /// Returns the number of ACL data packets that may currently be sent to the controller.
+    pub fn available(&self) -> usize {
+        self.available
+    }
+
+    /// Returns the number of ACL data packets sent over `conn_handle` that the controller has not
+    /// completed yet.
+    pub fn outstanding(&self, conn_handle: ConnectionHandle) -> usize {
+        self.outstanding
+            .iter()
+            .flatten()
+            .find(|o| o.conn_handle == conn_handle)
+            .map_or(0, |o| o.packets)
+    }
+
+    /// Consumes a buffer for an ACL data packet sent over `conn_handle`. Call this before
+    /// [`write_acl_data`](crate::host::uart::AclHci::write_acl_data).
+    ///
+    /// # Errors
+    ///
+    /// - [`NoBuffers`](AclBufferError::NoBuffers) if all buffers are in use.
+    /// - [`TooManyConnections`](AclBufferError::TooManyConnections) if `CONNECTIONS` other
+    ///   connections already have packets outstanding.
+    pub fn acquire(&mut self, conn_handle: ConnectionHandle) -> Result<(), AclBufferError> {
+        if self.available == 0 {
+            return Err(AclBufferError::NoBuffers);
+        }
+
+        if let Some(o) = self
+            .outstanding
+            .iter_mut()
+            .flatten()
+            .find(|o| o.conn_handle == conn_handle)
+        {
+            o.packets += 1;
+        } else {
+            let slot = self
+                .outstanding
+                .iter_mut()
+                .find(|o| o.is_none())
+                .ok_or(AclBufferError::TooManyConnections(conn_handle))?;
+            *slot = Some(Outstanding {
+                conn_handle,
+                packets: 1,
+            });
+        }
+        self.available -= 1;
+
+        Ok(())
+    }
+
+    /// Releases the buffers reported by a
+    /// [Number of Completed Packets](crate::event::Event::NumberOfCompletedPackets) event.
+    /// Completed packets on connections without outstanding packets are ignored.
+    pub fn release(&mut self, completed: &NumberOfCompletedPackets) {
+        for pair in completed.iter() {
+            for slot in self.outstanding.iter_mut() {
+                if let Some(o) = slot {
+                    if o.conn_handle == pair.conn_handle {
+                        let released = pair.num_completed_packets.min(o.packets);
+                        o.packets -= released;
+                        self.available += released;
+                        if o.packets == 0 {
+                            *slot = None;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Releases all of the buffers used by `conn_handle`. The controller flushes the packets of a
+    /// connection when it is disconnected, without reporting them as completed.
+    pub fn disconnect(&mut self, conn_handle: ConnectionHandle) {
+        for slot in self.outstanding.iter_mut() {
+            if let Some(o) = slot {
+                if o.conn_handle == conn_handle {
+                    self.available += o.packets;
+                    *slot = None;
+                }
+            }
+        }
+    }
+
+    /// Updates the accounting from an event read from the controller:
+    ///
+    /// - Successful [LE Read Buffer Size](ReturnParameters::LeReadBufferSize) return parameters
+    ///   reset it (see [`set_buffer_size`](AclBufferCredits::set_buffer_size)).
+    /// - [Number of Completed Packets](crate::event::Event::NumberOfCompletedPackets) events
+    ///   release buffers (see [`release`](AclBufferCredits::release)).
+    /// - Successful [Disconnection Complete](crate::event::Event::DisconnectionComplete) events
+    ///   release the buffers of the connection (see
+    ///   [`disconnect`](AclBufferCredits::disconnect)).
+    ///
+    /// Other events are ignored.
+    pub fn process_event(&mut self, event: &Event) {
+        match event {
+            Event::CommandComplete(CommandComplete {
+                return_params: ReturnParameters::LeReadBufferSize(buffer_size),
+                ..
+            }) if buffer_size.status == Status::Success => self.set_buffer_size(buffer_size),
+            Event::NumberOfCompletedPackets(completed) => self.release(completed),
+            Event::DisconnectionComplete(disconnection)
+                if disconnection.status == Status::Success =>
+            {
+                self.disconnect(disconnection.conn_handle)
+            }
+            _ => (),
+        }
+    }
+}
+
+impl<const CONNECTIONS: usize> Default for AclBufferCredits<CONNECTIONS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -8,18 +8,28 @@
 //! support sending the packet ID, as `uart` does. In that case, it would make sense to also remove
 //! `uart` and move its contents up one level.
 
-use crate::event::{NumberOfCompletedPackets, NUMBER_OF_COMPLETED_PACKETS_MAX_LEN};
+use crate::command::{write_command, Command};
+use crate::event::{
+    NumberOfCompletedPackets, PowerControlPhy, NUMBER_OF_COMPLETED_PACKETS_MAX_LEN,
+};
 use crate::ConnectionHandle;
 use byteorder::{ByteOrder, LittleEndian};
 use core::convert::Into;
 use core::fmt::{Debug, Formatter, Result as FmtResult};
 use core::time::Duration;
 
+pub mod decoder;
+pub mod executor;
+pub mod flow_control;
 pub mod uart;
 
 pub use super::types::{
-    AdvertisingInterval, AdvertisingType, ConnectionInterval, ConnectionIntervalBuilder,
-    ExpectedConnectionLength, ScanWindow,
+    AdvertisingInterval, AdvertisingType, AuthenticatedPayloadTimeout, ConnectionInterval,
+    ConnectionIntervalBuilder, ExpectedConnectionLength, MaxTxOctets, MaxTxTime, ScanWindow,
+};
+
+use super::types::extended_advertisement::{
+    AdvSet, AdvertisingEvent, AdvertisingOperation, AdvertisingPhy, ExtendedAdvertisingInterval,
 };
 
 use crate::Status;
@@ -54,10 +64,10 @@ pub trait HciHeader {
 /// Defines one function for each command in the Bluetooth Specification Vol 2, Part E, Sections
 /// 7.1-7.6.
 ///
-/// Specializations must define the error type `E`, used for communication errors.
+/// Communication errors are those of the underlying [`Controller`](super::Controller).
 ///
 /// An implementation is defined or all types that implement [`Controller`](super::Controller).
-pub trait HostHci {
+pub trait HostHci: crate::Controller {
     /// Terminates an existing connection.  All synchronous connections on a physical link should be
     /// disconnected before the ACL connection on the same physical connection is disconnected.
     ///
@@ -95,7 +105,7 @@ pub trait HostHci {
         &mut self,
         conn_handle: ConnectionHandle,
         reason: Status,
-    ) -> Result<(), Error>;
+    ) -> Result<(), Error<Self::Error>>;
 
     /// Obtains the values for the version information for the remote device identified by the
     /// `conn_handle` parameter, which must be a connection handle for an ACL or LE connection.
@@ -119,7 +129,10 @@ pub trait HostHci {
     /// Note: No Command Complete event will be sent by the Controller to indicate that this command
     /// has been completed. Instead, the [Read Remote Version Information Complete](crate::event::Event::ReadRemoteVersionInformationComplete)
     /// event will indicate that this command has been completed.
-    async fn read_remote_version_information(&mut self, conn_handle: ConnectionHandle);
+    async fn read_remote_version_information(
+        &mut self,
+        conn_handle: ConnectionHandle,
+    ) -> Result<(), Self::Error>;
 
     /// Controls which events are generated by the HCI for the Host. If the flag in the mask is set,
     /// then the event associated with that bit will be enabled. For an LE Controller, the
@@ -137,7 +150,7 @@ pub trait HostHci {
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::SetEventMask) event is
     /// generated.
-    async fn set_event_mask(&mut self, mask: EventFlags);
+    async fn set_event_mask(&mut self, mask: EventFlags) -> Result<(), Self::Error>;
 
     /// Resets the Controller and the Link Manager on the BR/EDR Controller, the PAL on an AMP
     /// Controller, or the Link Layer on an LE Controller. If the Controller supports both BR/EDR
@@ -166,7 +179,7 @@ pub trait HostHci {
     /// # Generated Events
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::Reset) event is generated.
-    async fn reset(&mut self);
+    async fn reset(&mut self) -> Result<(), Self::Error>;
 
     /// Reads the values for the transmit power level for the specified
     /// `conn_handle`. `conn_handle` shall be a connection handle for an ACL connection.
@@ -185,7 +198,7 @@ pub trait HostHci {
         &mut self,
         conn_handle: ConnectionHandle,
         power_level_type: TxPowerLevel,
-    );
+    ) -> Result<(), Self::Error>;
 
     /// This command is used by the Host to turn flow control on and off for data and/or voice
     /// sent in the direction from the Controller to the Host.
@@ -208,7 +221,10 @@ pub trait HostHci {
     /// the Host will send [Number of Completed Packets](HostHci::number_of_completed_packets)
     /// commands both for ACL connections and synchronous connections.
     /// The [Flow Control](FlowControl) parameter shall only be changed if no connections exist.
-    async fn set_controller_to_host_flow_control(&mut self, flow_control: FlowControl);
+    async fn set_controller_to_host_flow_control(
+        &mut self,
+        flow_control: FlowControl,
+    ) -> Result<(), Self::Error>;
 
     /// This command is used by the Host to notify the Controller about the Maximum size of the data portion
     /// of HCI ACL and Synchronous Sata Packets sent from the controller to the Host.
@@ -229,7 +245,7 @@ pub trait HostHci {
     ///
     /// The [Set Controller to Host Flow Control](HostHci::set_controller_to_host_flow_control) commad
     /// is used to turn flow control on or off.
-    async fn host_buffer_size(&mut self, params: HostBufferSize);
+    async fn host_buffer_size(&mut self, params: HostBufferSize) -> Result<(), Self::Error>;
 
     /// This command is used by the Host to indicate to the Controller the number of HCI Data Packets
     /// that have been completed for each [Connection Handle](ConnectionHandle) since the previous
@@ -260,7 +276,66 @@ pub trait HostHci {
     /// [Number of Complete Packets](HostHci::number_of_completed_packets) command.
     ///
     /// See Bluetooth spec. v.5.4 [Vol 4, Part E, 7.3.40].
-    async fn number_of_completed_packets(&mut self, params: NumberOfCompletedPackets);
+    async fn number_of_completed_packets(
+        &mut self,
+        params: NumberOfCompletedPackets,
+    ) -> Result<(), Self::Error>;
+
+    /// Controls which events on page 2 of the event mask are generated by the HCI for the Host.
+    /// If the flag in the mask is set, then the event associated with that bit will be enabled.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.3.69.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated Events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::SetEventMaskPage2) event is
+    /// generated.
+    async fn set_event_mask_page_2(&mut self, mask: EventFlagsPage2) -> Result<(), Self::Error>;
+
+    /// Reads the authenticated payload timeout of an encrypted connection: the maximum time
+    /// allowed between two packets containing a valid MIC before the Controller sends an LE Ping.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.3.93.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated Events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::ReadAuthenticatedPayloadTimeout)
+    /// event is generated.
+    async fn read_authenticated_payload_timeout(
+        &mut self,
+        conn_handle: ConnectionHandle,
+    ) -> Result<(), Self::Error>;
+
+    /// Writes the authenticated payload timeout of a connection. Use
+    /// [`AuthenticatedPayloadTimeout::for_connection`] to check the timeout against the
+    /// connection interval and latency.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.3.94.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated Events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::WriteAuthenticatedPayloadTimeout)
+    /// event is generated. The
+    /// [Authenticated Payload Timeout Expired](crate::event::Event::AuthenticatedPayloadTimeoutExpired)
+    /// event is generated whenever the timeout expires, if it is enabled with
+    /// [`set_event_mask_page_2`](HostHci::set_event_mask_page_2).
+    async fn write_authenticated_payload_timeout(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        timeout: AuthenticatedPayloadTimeout,
+    ) -> Result<(), Self::Error>;
 
     /// This command reads the values for the version information for the local Controller.
     ///
@@ -274,7 +349,7 @@ pub trait HostHci {
     ///
     /// A [Comand Complete](crate::event::command::ReturnParameters::ReadLocalVersionInformation)
     /// event is generated.
-    async fn read_local_version_information(&mut self);
+    async fn read_local_version_information(&mut self) -> Result<(), Self::Error>;
 
     /// Reads the list of HCI commands supported for the local Controller.
     ///
@@ -293,7 +368,7 @@ pub trait HostHci {
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::ReadLocalSupportedCommands)
     /// event is generated.
-    async fn read_local_supported_commands(&mut self);
+    async fn read_local_supported_commands(&mut self) -> Result<(), Self::Error>;
 
     /// Requests a list of the supported features for the local BR/EDR Controller.
     ///
@@ -309,7 +384,7 @@ pub trait HostHci {
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::ReadLocalSupportedFeatures)
     /// event is generated.
-    async fn read_local_supported_features(&mut self);
+    async fn read_local_supported_features(&mut self) -> Result<(), Self::Error>;
 
     /// On a BR/EDR Controller, this command reads the Bluetooth Controller address (BD_ADDR).
     ///
@@ -329,7 +404,7 @@ pub trait HostHci {
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::ReadBdAddr) event is
     /// generated.
-    async fn read_bd_addr(&mut self);
+    async fn read_bd_addr(&mut self) -> Result<(), Self::Error>;
 
     /// Reads the Received Signal Strength Indication (RSSI) value from a Controller.
     ///
@@ -368,7 +443,7 @@ pub trait HostHci {
     /// # Generated events
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::ReadRssi) event is generated.
-    async fn read_rssi(&mut self, conn_handle: ConnectionHandle);
+    async fn read_rssi(&mut self, conn_handle: ConnectionHandle) -> Result<(), Self::Error>;
 
     /// Controls which LE events are generated by the HCI for the Host. If the flag in `event_mask`
     /// is set, then the event associated with that flag will be enabled. The Host has to deal with
@@ -389,7 +464,7 @@ pub trait HostHci {
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::LeSetEventMask) event is
     /// generated.
-    async fn le_set_event_mask(&mut self, event_mask: LeEventFlags);
+    async fn le_set_event_mask(&mut self, event_mask: LeEventFlags) -> Result<(), Self::Error>;
 
     /// Reads the maximum size of the data portion of HCI LE ACL Data Packets sent from the Host to
     /// the Controller.  The Host will segment the data transmitted to the Controller according to
@@ -416,7 +491,7 @@ pub trait HostHci {
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::LeReadBufferSize) event is
     /// generated.
-    async fn le_read_buffer_size(&mut self);
+    async fn le_read_buffer_size(&mut self) -> Result<(), Self::Error>;
 
     /// Requests the list of the supported LE features for the Controller.
     ///
@@ -430,7 +505,7 @@ pub trait HostHci {
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::LeReadLocalSupportedFeatures)
     /// event is generated.
-    async fn le_read_local_supported_features(&mut self);
+    async fn le_read_local_supported_features(&mut self) -> Result<(), Self::Error>;
 
     /// Sets the LE Random Device Address in the Controller.
     ///
@@ -472,7 +547,10 @@ pub trait HostHci {
     ///
     /// (v5.0) If the Host issues this command when scanning or legacy advertising is enabled, the
     /// Controller shall return the error code [Command Disallowed](Status::CommandDisallowed).
-    async fn le_set_random_address(&mut self, bd_addr: crate::BdAddr) -> Result<(), Error>;
+    async fn le_set_random_address(
+        &mut self,
+        bd_addr: crate::BdAddr,
+    ) -> Result<(), Error<Self::Error>>;
 
     /// Sets the advertising parameters on the Controller.
     ///
@@ -493,7 +571,7 @@ pub trait HostHci {
     async fn le_set_advertising_parameters(
         &mut self,
         params: &AdvertisingParameters,
-    ) -> Result<(), Error>;
+    ) -> Result<(), Error<Self::Error>>;
 
     /// Reads the transmit power level used for LE advertising channel packets.
     ///
@@ -507,7 +585,7 @@ pub trait HostHci {
     ///
     /// A [Command Complete](crate::event::command::CommandComplete) event is
     /// generated.
-    async fn le_read_advertising_channel_tx_power(&mut self);
+    async fn le_read_advertising_channel_tx_power(&mut self) -> Result<(), Self::Error>;
 
     /// Sets the data used in advertising packets that have a data field.
     ///
@@ -530,7 +608,7 @@ pub trait HostHci {
     /// # Generated events
     ///
     /// A [Command Complete](crate::event::Event::CommandComplete) event is generated.
-    async fn le_set_advertising_data(&mut self, data: &[u8]) -> Result<(), Error>;
+    async fn le_set_advertising_data(&mut self, data: &[u8]) -> Result<(), Error<Self::Error>>;
 
     /// Provides data used in scanning packets that have a data field.
     ///
@@ -554,7 +632,7 @@ pub trait HostHci {
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::LeSetScanResponseData) event
     /// is generated.
-    async fn le_set_scan_response_data(&mut self, data: &[u8]) -> Result<(), Error>;
+    async fn le_set_scan_response_data(&mut self, data: &[u8]) -> Result<(), Error<Self::Error>>;
 
     /// Requests the Controller to start or stop advertising. The Controller manages the timing of
     /// advertisements as per the advertising parameters given in the
@@ -615,7 +693,7 @@ pub trait HostHci {
     /// Command Complete event and an LE Connection Complete event or an LE Enhanced Connection
     /// Complete event could be generated. This can also occur when high duty cycle directed
     /// advertising is timed out and this command disables advertising.
-    async fn le_set_advertising_enable(&mut self, enable: bool);
+    async fn le_set_advertising_enable(&mut self, enable: bool) -> Result<(), Self::Error>;
 
     /// Sets the scan parameters.
     ///
@@ -632,7 +710,7 @@ pub trait HostHci {
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::LeSetScanParameters) event is
     /// generated.
-    async fn le_set_scan_parameters(&mut self, params: &ScanParameters);
+    async fn le_set_scan_parameters(&mut self, params: &ScanParameters) -> Result<(), Self::Error>;
 
     /// Starts scanning. Scanning is used to discover advertising devices nearby.
     ///
@@ -665,7 +743,11 @@ pub trait HostHci {
     /// generated by the Controller based on advertising packets received and the duplicate
     /// filtering. More than one advertising packet may be reported in each LE Advertising Report
     /// event.
-    async fn le_set_scan_enable(&mut self, enable: bool, filter_duplicates: bool);
+    async fn le_set_scan_enable(
+        &mut self,
+        enable: bool,
+        filter_duplicates: bool,
+    ) -> Result<(), Self::Error>;
 
     /// Creates a Link Layer connection to a connectable advertiser.
     ///
@@ -688,10 +770,14 @@ pub trait HostHci {
     /// Note: No Command Complete event is sent by the Controller to indicate that this command has
     /// been completed. Instead, the LE Connection Complete event indicates that this command has
     /// been completed.
-    async fn le_create_connection(&mut self, params: &ConnectionParameters);
+    async fn le_create_connection(
+        &mut self,
+        params: &ConnectionParameters,
+    ) -> Result<(), Self::Error>;
 
     /// Cancels the [`le_create_connection`](HostHci::le_create_connection) or
-    /// `le_extended_create_connection` (for v5.0) command. This command shall only be issued after
+    /// [`le_extended_create_connection`](HostHci::le_extended_create_connection) (for v5.0)
+    /// command. This command shall only be issued after
     /// the [`le_create_connection`](HostHci::le_create_connection) command has been issued, a
     /// [`CommandStatus`](crate::event::Event::CommandStatus) event has been received for the
     /// [`le_create_connection`](HostHci::le_create_connection) command and before the
@@ -716,7 +802,7 @@ pub trait HostHci {
     /// The [LE Connection Complete](crate::event::Event::LeConnectionComplete) event with the error
     /// code [`UnknownConnectionId`](Status::UnknownConnectionId) shall be sent after the Command
     /// Complete event for this command if the cancellation was successful.
-    async fn le_create_connection_cancel(&mut self);
+    async fn le_create_connection_cancel(&mut self) -> Result<(), Self::Error>;
 
     /// Reads the total number of White List entries that can be stored in the Controller.
     ///
@@ -734,7 +820,7 @@ pub trait HostHci {
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::LeReadWhiteListSize) event is
     /// generated.
-    async fn le_read_white_list_size(&mut self);
+    async fn le_read_white_list_size(&mut self) -> Result<(), Self::Error>;
 
     /// Clears the white list stored in the Controller.
     ///
@@ -754,7 +840,7 @@ pub trait HostHci {
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::LeClearWhiteList) event is
     /// generated.
-    async fn le_clear_white_list(&mut self);
+    async fn le_clear_white_list(&mut self) -> Result<(), Self::Error>;
 
     /// Adds a single device to the white list stored in the Controller.
     ///
@@ -775,7 +861,10 @@ pub trait HostHci {
     /// A [Command Complete](crate::event::command::ReturnParameters::LeAddDeviceToWhiteList) event
     /// is generated. When a Controller cannot add a device to the White List because there is no
     /// space available, it shall return [`OutOfMemory`](Status::OutOfMemory).
-    async fn le_add_device_to_white_list(&mut self, addr: crate::BdAddrType);
+    async fn le_add_device_to_white_list(
+        &mut self,
+        addr: crate::BdAddrType,
+    ) -> Result<(), Self::Error>;
 
     /// Adds anonymous devices sending advertisements to the white list stored in the Controller.
     ///
@@ -796,7 +885,7 @@ pub trait HostHci {
     /// A [Command Complete](crate::event::command::ReturnParameters::LeAddDeviceToWhiteList) event
     /// is generated.  When a Controller cannot add a device to the White List because there is no
     /// space available, it shall return [`OutOfMemory`](Status::OutOfMemory).
-    async fn le_add_anon_advertising_devices_to_white_list(&mut self);
+    async fn le_add_anon_advertising_devices_to_white_list(&mut self) -> Result<(), Self::Error>;
 
     /// Removes a single device from the white list stored in the Controller.
     ///
@@ -816,7 +905,10 @@ pub trait HostHci {
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::LeRemoveDeviceFromWhiteList)
     /// event is generated.
-    async fn le_remove_device_from_white_list(&mut self, addr: crate::BdAddrType);
+    async fn le_remove_device_from_white_list(
+        &mut self,
+        addr: crate::BdAddrType,
+    ) -> Result<(), Self::Error>;
 
     /// Removes anonymous devices sending advertisements from the white list stored in the
     /// Controller.
@@ -837,7 +929,9 @@ pub trait HostHci {
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::LeRemoveDeviceFromWhiteList)
     /// event is generated.
-    async fn le_remove_anon_advertising_devices_from_white_list(&mut self);
+    async fn le_remove_anon_advertising_devices_from_white_list(
+        &mut self,
+    ) -> Result<(), Self::Error>;
 
     /// Changes the Link Layer connection parameters of a connection. This command may be issued on
     /// both the central and peripheral devices.
@@ -861,7 +955,10 @@ pub trait HostHci {
     /// Note: a Command Complete event is not sent by the Controller to indicate that this command
     /// has been completed. Instead, the LE Connection Update Complete event indicates that this
     /// command has been completed.
-    async fn le_connection_update(&mut self, params: &ConnectionUpdateParameters);
+    async fn le_connection_update(
+        &mut self,
+        params: &ConnectionUpdateParameters,
+    ) -> Result<(), Self::Error>;
 
     /// This command allows the Host to specify a channel classification for data channels based on
     /// its "local information". This classification persists until overwritten with a subsequent
@@ -888,7 +985,7 @@ pub trait HostHci {
     async fn le_set_host_channel_classification(
         &mut self,
         channels: crate::ChannelClassification,
-    ) -> Result<(), Error>;
+    ) -> Result<(), Error<Self::Error>>;
 
     /// Returns the current channel map for the specified connection handle. The returned value
     /// indicates the state of the channel map specified by the last transmitted or received channel
@@ -905,7 +1002,10 @@ pub trait HostHci {
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::LeReadChannelMap) event is
     /// generated.
-    async fn le_read_channel_map(&mut self, conn_handle: ConnectionHandle);
+    async fn le_read_channel_map(
+        &mut self,
+        conn_handle: ConnectionHandle,
+    ) -> Result<(), Self::Error>;
 
     /// Requests a list of the used LE features from the remote device.  This command shall return a
     /// list of the used LE features.
@@ -932,7 +1032,10 @@ pub trait HostHci {
     /// Note: A Command Complete event is not sent by the Controller to indicate that this command
     /// has been completed. Instead, the LE Read Remote Used Features Complete event indicates that
     /// this command has been completed.
-    async fn le_read_remote_used_features(&mut self, conn_handle: ConnectionHandle);
+    async fn le_read_remote_used_features(
+        &mut self,
+        conn_handle: ConnectionHandle,
+    ) -> Result<(), Self::Error>;
 
     /// Requests the Controller to encrypt the plaintext data in the command using the key given in
     /// the command and returns the encrypted data to the Host. The AES-128 bit block cypher is
@@ -948,7 +1051,7 @@ pub trait HostHci {
     /// # Generated events
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::LeEncrypt) event is generated.
-    async fn le_encrypt(&mut self, params: &AesParameters);
+    async fn le_encrypt(&mut self, params: &AesParameters) -> Result<(), Self::Error>;
 
     /// Requests the Controller to generate 8 octets of random data to be sent to the Host. The
     /// random number shall be generated according to the Bluetooth spec, Vol 2, Part H, Section 2
@@ -963,7 +1066,7 @@ pub trait HostHci {
     /// # Generated events
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::LeRand) event is generated.
-    async fn le_rand(&mut self);
+    async fn le_rand(&mut self) -> Result<(), Self::Error>;
 
     /// Authenticates the given encryption key associated with the remote device specified by the
     /// connection handle, and once authenticated will encrypt the connection. The parameters are as
@@ -996,7 +1099,10 @@ pub trait HostHci {
     /// Note: A Command Complete event is not sent by the Controller to indicate that this command
     /// has been completed. Instead, the Encryption Change or Encryption Key Refresh Complete events
     /// indicate that this command has been completed.
-    async fn le_start_encryption(&mut self, params: &EncryptionParameters);
+    async fn le_start_encryption(
+        &mut self,
+        params: &EncryptionParameters,
+    ) -> Result<(), Self::Error>;
 
     /// Replies to an [LE Long Term Key Request](crate::event::Event::LeLongTermKeyRequest) event
     /// from the Controller, and specifies the long term key parameter that shall be used for this
@@ -1016,7 +1122,7 @@ pub trait HostHci {
         &mut self,
         conn_handle: ConnectionHandle,
         key: &EncryptionKey,
-    );
+    ) -> Result<(), Self::Error>;
 
     /// Replies to an [LE Long Term Key Request](crate::event::Event::LeLongTermKeyRequest) event
     /// from the Controller if the Host cannot provide a Long Term Key for this connection handle.
@@ -1031,7 +1137,10 @@ pub trait HostHci {
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::LeLongTermKeyRequestNegativeReply) event
     /// is generated.
-    async fn le_long_term_key_request_negative_reply(&mut self, conn_handle: ConnectionHandle);
+    async fn le_long_term_key_request_negative_reply(
+        &mut self,
+        conn_handle: ConnectionHandle,
+    ) -> Result<(), Self::Error>;
 
     /// Reads the states and state combinations that the link layer supports.
     ///
@@ -1045,7 +1154,7 @@ pub trait HostHci {
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::LeReadSupportedStates) event
     /// is generated.
-    async fn le_read_supported_states(&mut self);
+    async fn le_read_supported_states(&mut self) -> Result<(), Self::Error>;
 
     /// Starts a test where the DUT receives test reference packets at a fixed interval. The tester
     /// generates the test reference packets.
@@ -1062,7 +1171,7 @@ pub trait HostHci {
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::LeReceiverTest) event is
     /// generated.
-    async fn le_receiver_test(&mut self, channel: u8) -> Result<(), Error>;
+    async fn le_receiver_test(&mut self, channel: u8) -> Result<(), Error<Self::Error>>;
 
     /// Starts a test where the DUT generates test reference packets at a fixed interval. The
     /// Controller shall transmit at maximum power.
@@ -1091,7 +1200,7 @@ pub trait HostHci {
         channel: u8,
         payload_length: usize,
         payload: TestPacketPayload,
-    ) -> Result<(), Error>;
+    ) -> Result<(), Error<Self::Error>>;
 
     /// Stops any test which is in progress.
     ///
@@ -1104,456 +1213,3046 @@ pub trait HostHci {
     /// # Generated events
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::LeTestEnd) event is generated.
-    async fn le_test_end(&mut self);
-
-    async fn le_set_data_length(&mut self, conn_handle: ConnectionHandle, max_tx_octets: u16, max_tx_time: u16);
-    async fn le_write_suggested_default_data_length(&mut self, max_tx_octets: u16, max_tx_time: u16);
-    async fn le_set_default_phy(&mut self, all_phys: u8, tx_phys: u8, rx_phys: u8);
-}
-
-/// Errors that may occur when sending commands to the controller.  Must be specialized on the types
-/// of communication errors.
-#[derive(Copy, Clone, Debug, PartialEq)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub enum Error {
-    /// For the [`disconnect`](HostHci::disconnect) command: The provided reason is not a valid
-    /// disconnection reason. Includes the reported reason.
-    BadDisconnectionReason(Status),
+    async fn le_test_end(&mut self) -> Result<(), Self::Error>;
 
-    /// For the [`le_set_random_address`](HostHci::le_set_random_address) command: The provided address
-    /// does not meet the rules for random addresses in the Bluetooth Spec, Vol 6, Part B, Section
-    /// 1.3.  Includes the invalid address.
-    BadRandomAddress(crate::BdAddr),
-
-    /// For the [`le_set_advertising_parameters`](HostHci::le_set_advertising_parameters) command: The
-    /// channel map did not include any enabled channels.  Includes the provided channel map.
-    BadChannelMap(Channels),
-
-    /// For the [`le_set_advertising_data`](HostHci::le_set_advertising_data) or
-    /// [`le_set_scan_response_data`](HostHci::le_set_scan_response_data) commands: The provided data is
-    /// too long to fit in the command.  The maximum allowed length is 31.  The actual length is
-    /// returned.
-    AdvertisingDataTooLong(usize),
-
-    /// For the [`le_create_connection`](HostHci::le_create_connection) command: the connection length
-    /// range is inverted (i.e, the minimum is greater than the maximum). Returns the range, min
-    /// first.
-    BadConnectionLengthRange(Duration, Duration),
-
-    /// For the [`le_set_host_channel_classification`](HostHci::le_set_host_channel_classification)
-    /// command: all channels were marked 'bad'.
-    NoValidChannel,
-
-    /// For the [`le_receiver_test`](HostHci::le_receiver_test) and
-    /// [`le_transmitter_test`](HostHci::le_transmitter_test) commands: the channel was out of
-    /// range. The maximum allowed channel is 39. Includes the invalid value.
-    InvalidTestChannel(u8),
-
-    /// For the [`le_transmitter_test`](HostHci::le_transmitter_test) command: The payload length is
-    /// invalid. The maximum value is 37. Includes the invalid value.
-    InvalidTestPayloadLength(usize),
-}
-
-async fn set_outbound_data<T>(
-    controller: &mut T,
-    opcode: crate::opcode::Opcode,
-    data: &[u8],
-) -> Result<(), Error>
-where
-    T: crate::Controller,
-{
-    const MAX_DATA_LEN: usize = 31;
-    if data.len() > MAX_DATA_LEN {
-        return Err(Error::AdvertisingDataTooLong(data.len()));
-    }
-    let mut params = [0; 32];
-    params[0] = data.len() as u8;
-    params[1..=data.len()].copy_from_slice(data);
-    controller.controller_write(opcode, &params).await;
-
-    Ok(())
-}
+    /// Replies to an
+    /// [LE Remote Connection Parameter Request](crate::event::Event::LeRemoteConnectionParameterRequest)
+    /// event from the Controller, accepting the connection parameters requested by the peer. The
+    /// parameters may differ from the requested ones, as long as they are within the requested
+    /// range.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.31.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeRemoteConnectionParameterRequestReply)
+    /// event is generated. The
+    /// [LE Connection Update Complete](crate::event::Event::LeConnectionUpdateComplete) event is
+    /// generated when the new connection parameters have been applied.
+    async fn le_remote_connection_parameter_request_reply(
+        &mut self,
+        params: &ConnectionUpdateParameters,
+    ) -> Result<(), Self::Error>;
 
-impl<T> HostHci for T
-where
-    T: crate::Controller,
-{
-    async fn disconnect(
+    /// Replies to an
+    /// [LE Remote Connection Parameter Request](crate::event::Event::LeRemoteConnectionParameterRequest)
+    /// event from the Controller, rejecting the connection parameters requested by the peer.
+    ///
+    /// `reason` is sent to the peer; it is usually
+    /// [`UnacceptableConnectionParameters`](Status::UnacceptableConnectionParameters).
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.32.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeRemoteConnectionParameterRequestNegativeReply)
+    /// event is generated.
+    async fn le_remote_connection_parameter_request_negative_reply(
         &mut self,
         conn_handle: ConnectionHandle,
         reason: Status,
-    ) -> Result<(), Error> {
-        match reason {
-            Status::AuthFailure
-            | Status::RemoteTerminationByUser
-            | Status::RemoteTerminationLowResources
-            | Status::RemoteTerminationPowerOff
-            | Status::UnsupportedRemoteFeature
-            | Status::PairingWithUnitKeyNotSupported
-            | Status::UnacceptableConnectionParameters => (),
-            _ => return Err(Error::BadDisconnectionReason(reason)),
-        }
+    ) -> Result<(), Self::Error>;
 
-        let mut params = [0; 3];
-        LittleEndian::write_u16(&mut params[0..], conn_handle.0);
-        params[2] = reason.into();
-        self.controller_write(crate::opcode::DISCONNECT, &params)
-            .await;
+    /// Suggests the maximum transmission payload size and time to be used for LL Data PDUs on a
+    /// connection. The controller may use smaller or larger values based on local information.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.33.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported. Out-of-range values are rejected when
+    /// the [`MaxTxOctets`] and [`MaxTxTime`] are created.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetDataLength) event is
+    /// generated. If the values actually used by the controller change, an
+    /// [LE Data Length Change](crate::event::Event::LeDataLengthChangeEvent) event is generated as
+    /// well.
+    async fn le_set_data_length(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        max_tx_octets: MaxTxOctets,
+        max_tx_time: MaxTxTime,
+    ) -> Result<(), Self::Error>;
 
-        Ok(())
-    }
+    /// Reads the suggested maximum transmission payload size and time that the controller uses
+    /// for new connections.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.34.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeReadSuggestedDefaultDataLength)
+    /// event is generated.
+    async fn le_read_suggested_default_data_length(&mut self) -> Result<(), Self::Error>;
 
-    async fn read_remote_version_information(&mut self, conn_handle: ConnectionHandle) {
-        let mut params = [0; 2];
-        LittleEndian::write_u16(&mut params, conn_handle.0);
-        self.controller_write(crate::opcode::READ_REMOTE_VERSION_INFO, &params)
-            .await;
-    }
+    /// Sets the suggested maximum transmission payload size and time that the controller uses for
+    /// new connections.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.35.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported. Out-of-range values are rejected when
+    /// the [`MaxTxOctets`] and [`MaxTxTime`] are created.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeWriteSuggestedDefaultDataLength)
+    /// event is generated.
+    async fn le_write_suggested_default_data_length(
+        &mut self,
+        max_tx_octets: MaxTxOctets,
+        max_tx_time: MaxTxTime,
+    ) -> Result<(), Self::Error>;
 
-    async fn set_event_mask(&mut self, mask: EventFlags) {
-        let mut params = [0; 8];
-        LittleEndian::write_u64(&mut params, mask.bits());
+    /// Reads the maximum payload size and time supported by the controller for transmitting and
+    /// receiving LL Data PDUs.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.46.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeReadMaximumDataLength) event
+    /// is generated.
+    async fn le_read_maximum_data_length(&mut self) -> Result<(), Self::Error>;
 
-        self.controller_write(crate::opcode::SET_EVENT_MASK, &params)
-            .await;
-    }
+    /// Reads the current transmitter and receiver PHY on a connection.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.47.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeReadPhy) event is generated.
+    async fn le_read_phy(&mut self, conn_handle: ConnectionHandle) -> Result<(), Self::Error>;
 
-    async fn reset(&mut self) {
-        self.controller_write(crate::opcode::RESET, &[]).await;
-    }
+    /// Sets the preferred transmitter and receiver PHYs for all subsequent connections. `None`
+    /// indicates that the Host has no preference for that direction.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.48.
+    ///
+    /// # Errors
+    ///
+    /// - [`EmptyPhyPreference`](Error::EmptyPhyPreference) if a preference does not include any
+    ///   PHY.
+    /// - Underlying communication errors
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetDefaultPhy) event is
+    /// generated.
+    async fn le_set_default_phy(
+        &mut self,
+        tx_phys: Option<PhyMask>,
+        rx_phys: Option<PhyMask>,
+    ) -> Result<(), Error<Self::Error>>;
 
-    async fn read_tx_power_level(
+    /// Sets the preferred transmitter and receiver PHYs for a connection. `None` indicates that
+    /// the Host has no preference for that direction. The Controller may not be able to make the
+    /// change, e.g. because the peer does not support the PHY.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.49.
+    ///
+    /// # Errors
+    ///
+    /// - [`EmptyPhyPreference`](Error::EmptyPhyPreference) if a preference does not include any
+    ///   PHY.
+    /// - Underlying communication errors
+    ///
+    /// # Generated events
+    ///
+    /// The Controller sends the [Command Status](crate::event::Event::CommandStatus) event to the
+    /// Host when the event is received. An
+    /// [LE PHY Update Complete](crate::event::Event::LePhyUpdateComplete) event is generated when
+    /// the PHY update procedure has completed, or if the Controller decides not to change the
+    /// PHYs.
+    async fn le_set_phy(
         &mut self,
         conn_handle: ConnectionHandle,
-        power_level_type: TxPowerLevel,
-    ) {
-        let mut params = [0; 3];
-        LittleEndian::write_u16(&mut params, conn_handle.0);
-        params[2] = power_level_type as u8;
-        self.controller_write(crate::opcode::READ_TX_POWER_LEVEL, &params)
-            .await;
-    }
-
-    async fn set_controller_to_host_flow_control(&mut self, flow_control: FlowControl) {
-        self.controller_write(
-            crate::opcode::SET_CONTROLLER_TO_HOST_FLOW_CONTROL,
-            &[flow_control as u8],
-        )
-        .await;
-    }
-
-    async fn host_buffer_size(&mut self, params: HostBufferSize) {
-        let mut bytes = [0; 6];
-        params.copy_into_slice(&mut bytes);
-        self.controller_write(crate::opcode::HOST_BUFFER_SIZE, &bytes)
-            .await;
-    }
-
-    async fn number_of_completed_packets(&mut self, params: NumberOfCompletedPackets) {
-        let mut bytes = [0; NUMBER_OF_COMPLETED_PACKETS_MAX_LEN + 1];
-        bytes[0] = params.num_handles as u8;
-        bytes[1..].copy_from_slice(&params.data_buf);
-        self.controller_write(crate::opcode::NUMBER_OF_COMPLETED_PACKETS, &bytes)
-            .await;
-    }
-
-    async fn read_local_version_information(&mut self) {
-        self.controller_write(crate::opcode::READ_LOCAL_VERSION_INFO, &[])
-            .await;
-    }
+        tx_phys: Option<PhyMask>,
+        rx_phys: Option<PhyMask>,
+        coded_phy_options: CodedPhyOptions,
+    ) -> Result<(), Error<Self::Error>>;
 
-    async fn read_local_supported_commands(&mut self) {
-        self.controller_write(crate::opcode::READ_LOCAL_SUPPORTED_COMMANDS, &[])
-            .await;
-    }
-
-    async fn read_local_supported_features(&mut self) {
-        self.controller_write(crate::opcode::READ_LOCAL_SUPPORTED_FEATURES, &[])
-            .await;
-    }
+    /// Sets the parameters of an extended advertising set, creating the set if it does not exist.
+    ///
+    /// The Host shall not issue this command when advertising is enabled for the set; if it is the
+    /// [Command Disallowed](Status::CommandDisallowed) error code shall be used.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.53.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadChannelMap`](Error::BadChannelMap) if the primary advertising channel map does not
+    ///   include any enabled channels.
+    /// - [`BadPrimaryAdvertisingPhy`](Error::BadPrimaryAdvertisingPhy) if the primary advertising
+    ///   PHY is [`Le2M`](AdvertisingPhy::Le2M).
+    /// - [`BadAdvertisingSid`](Error::BadAdvertisingSid) if the advertising SID is greater than
+    ///   0x0F.
+    /// - Underlying communication errors
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetExtendedAdvertisingParameters)
+    /// event is generated. It includes the transmit power selected by the Controller.
+    async fn le_set_extended_advertising_parameters(
+        &mut self,
+        params: &ExtendedAdvertisingParameters,
+    ) -> Result<(), Error<Self::Error>>;
 
-    async fn read_bd_addr(&mut self) {
-        self.controller_write(crate::opcode::READ_BD_ADDR, &[])
-            .await;
-    }
+    /// Sets one fragment of the data used in the advertising PDUs of an extended advertising set.
+    ///
+    /// Each command carries at most 251 bytes of data, so longer data is sent as a sequence of
+    /// commands, as returned by [`ExtendedAdvertisingData::fragments`]. Send the next fragment only
+    /// once the Command Complete event for the previous one reports success, for example with
+    /// [`wait_for_response`](executor::CommandExecutor::wait_for_response).
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.54.
+    ///
+    /// # Errors
+    ///
+    /// - [`ExtendedAdvertisingDataTooLong`](Error::ExtendedAdvertisingDataTooLong) if the fragment
+    ///   carries more than 251 bytes.
+    /// - Underlying communication errors
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetExtendedAdvertisingData)
+    /// event is generated.
+    async fn le_set_extended_advertising_data(
+        &mut self,
+        data: &ExtendedAdvertisingData<'_>,
+    ) -> Result<(), Error<Self::Error>>;
 
-    async fn read_rssi(&mut self, conn_handle: ConnectionHandle) {
-        let mut params = [0; 2];
-        LittleEndian::write_u16(&mut params, conn_handle.0);
-        self.controller_write(crate::opcode::READ_RSSI, &params)
-            .await;
-    }
+    /// Sets one fragment of the data used in the scan response PDUs of an extended advertising
+    /// set.
+    ///
+    /// The data is fragmented and sent as for
+    /// [`le_set_extended_advertising_data`](HostHci::le_set_extended_advertising_data).
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.55.
+    ///
+    /// # Errors
+    ///
+    /// - [`ExtendedAdvertisingDataTooLong`](Error::ExtendedAdvertisingDataTooLong) if the fragment
+    ///   carries more than 251 bytes.
+    /// - Underlying communication errors
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetExtendedScanResponseData)
+    /// event is generated.
+    async fn le_set_extended_scan_response_data(
+        &mut self,
+        data: &ExtendedAdvertisingData<'_>,
+    ) -> Result<(), Error<Self::Error>>;
 
-    async fn le_set_event_mask(&mut self, event_mask: LeEventFlags) {
-        let mut params = [0; 8];
-        LittleEndian::write_u64(&mut params, event_mask.bits());
+    /// Enables or disables one or more extended advertising sets.
+    ///
+    /// Disabling with an empty list of `sets` disables all advertising sets.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.56.
+    ///
+    /// # Errors
+    ///
+    /// - [`TooManyAdvertisingSets`](Error::TooManyAdvertisingSets) if more than 63 sets are given.
+    /// - Underlying communication errors
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetExtendedAdvertisingEnable)
+    /// event is generated.
+    async fn le_set_extended_advertising_enable(
+        &mut self,
+        enable: bool,
+        sets: &[AdvSet],
+    ) -> Result<(), Error<Self::Error>>;
 
-        self.controller_write(crate::opcode::LE_SET_EVENT_MASK, &params)
-            .await;
+    /// Reads the maximum length of the advertising or scan response data supported by the
+    /// Controller for an advertising set.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.57.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeReadMaximumAdvertisingDataLength)
+    /// event is generated.
+    async fn le_read_maximum_advertising_data_length(&mut self) -> Result<(), Self::Error>;
+
+    /// Reads the maximum number of advertising sets supported by the Controller at the same time.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.58.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeReadNumberOfSupportedAdvertisingSets)
+    /// event is generated.
+    async fn le_read_number_of_supported_advertising_sets(&mut self) -> Result<(), Self::Error>;
+
+    /// Removes an advertising set from the Controller.
+    ///
+    /// If advertising is enabled for the set, the Controller shall return the
+    /// [Command Disallowed](Status::CommandDisallowed) error code.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.59.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeRemoveAdvertisingSet) event
+    /// is generated.
+    async fn le_remove_advertising_set(
+        &mut self,
+        adv_handle: crate::AdvertisingHandle,
+    ) -> Result<(), Self::Error>;
+
+    /// Removes all advertising sets from the Controller.
+    ///
+    /// If advertising is enabled for any set, the Controller shall return the
+    /// [Command Disallowed](Status::CommandDisallowed) error code.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.60.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeClearAdvertisingSets) event
+    /// is generated.
+    async fn le_clear_advertising_sets(&mut self) -> Result<(), Self::Error>;
+
+    /// Sets the parameters for periodic advertising of an advertising set.
+    ///
+    /// The advertising set shall have been created with
+    /// [`le_set_extended_advertising_parameters`](HostHci::le_set_extended_advertising_parameters)
+    /// as non-connectable and non-scannable, without anonymous advertising. The Host shall not
+    /// issue this command when periodic advertising is enabled for the set; if it is the
+    /// [Command Disallowed](Status::CommandDisallowed) error code shall be used.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.61.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadPeriodicAdvertisingInterval`](Error::BadPeriodicAdvertisingInterval) if the interval
+    ///   range is inverted, or either end of it is not between 7.5 ms and 81.91875 s.
+    /// - Underlying communication errors
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetPeriodicAdvertisingParameters)
+    /// event is generated.
+    async fn le_set_periodic_advertising_parameters(
+        &mut self,
+        params: &PeriodicAdvertisingParameters,
+    ) -> Result<(), Error<Self::Error>>;
+
+    /// Sets one fragment of the data used in the periodic advertising PDUs of an advertising set.
+    ///
+    /// Each command carries at most 252 bytes of data, so longer data is sent as a sequence of
+    /// commands, as returned by [`PeriodicAdvertisingData::fragments`]. As for
+    /// [`le_set_extended_advertising_data`](HostHci::le_set_extended_advertising_data), send the
+    /// next fragment only once the Command Complete event for the previous one reports success.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.62.
+    ///
+    /// # Errors
+    ///
+    /// - [`PeriodicAdvertisingDataTooLong`](Error::PeriodicAdvertisingDataTooLong) if the fragment
+    ///   carries more than 252 bytes.
+    /// - Underlying communication errors
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetPeriodicAdvertisingData)
+    /// event is generated.
+    async fn le_set_periodic_advertising_data(
+        &mut self,
+        data: &PeriodicAdvertisingData<'_>,
+    ) -> Result<(), Error<Self::Error>>;
+
+    /// Enables or disables periodic advertising of an advertising set.
+    ///
+    /// Periodic advertising may be enabled before extended advertising of the set, but the
+    /// Controller only starts sending periodic advertising PDUs once both are enabled.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.63.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetPeriodicAdvertisingEnable)
+    /// event is generated.
+    async fn le_set_periodic_advertising_enable(
+        &mut self,
+        enable: bool,
+        adv_handle: crate::AdvertisingHandle,
+    ) -> Result<(), Self::Error>;
+
+    /// Sets the scan parameters for each of the PHYs on which advertisements are scanned for.
+    ///
+    /// The Host shall not issue this command when scanning is enabled in the Controller; if it is
+    /// the [`CommandDisallowed`](Status::CommandDisallowed) error code shall be used.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.64.
+    ///
+    /// # Errors
+    ///
+    /// - [`NoScanningPhy`](Error::NoScanningPhy) if neither the LE 1M nor the LE Coded PHY is
+    ///   scanned.
+    /// - Underlying communication errors
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetExtendedScanParameters)
+    /// event is generated.
+    async fn le_set_extended_scan_parameters(
+        &mut self,
+        params: &ExtendedScanParameters,
+    ) -> Result<(), Error<Self::Error>>;
+
+    /// Starts or stops scanning with the parameters given by
+    /// [`le_set_extended_scan_parameters`](HostHci::le_set_extended_scan_parameters).
+    ///
+    /// Scanning may be limited to a duration, and repeated every period; see
+    /// [`ExtendedScanEnable`].
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.65.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadScanDuration`](Error::BadScanDuration) if the duration is not between 10 ms and
+    ///   655.35 s.
+    /// - [`BadScanPeriod`](Error::BadScanPeriod) if the period is not between 1.28 s and 83,884.8
+    ///   s, or is not longer than the duration.
+    /// - Underlying communication errors
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetExtendedScanEnable) event
+    /// is generated.
+    ///
+    /// Zero or more [LE Extended Advertising Reports](crate::event::Event::LeExtendedAdvertisingReport)
+    /// are generated by the Controller based on advertising packets received and the duplicate
+    /// filtering. When the scan duration expires and no period is set, an
+    /// [LE Scan Timeout](crate::event::Event::LeScanTimeout) event is generated.
+    async fn le_set_extended_scan_enable(
+        &mut self,
+        params: &ExtendedScanEnable,
+    ) -> Result<(), Error<Self::Error>>;
+
+    /// Creates a Link Layer connection to a connectable advertiser, which may be advertising with
+    /// extended advertising PDUs. Unlike [`le_create_connection`](HostHci::le_create_connection),
+    /// the Controller may initiate the connection on several PHYs, each with its own scan and
+    /// connection parameters.
+    ///
+    /// The Host shall not issue this command when another create connection command is pending in
+    /// the Controller; if this does occur the Controller shall return the
+    /// [`CommandDisallowed`](Status::CommandDisallowed) error code.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.66.
+    ///
+    /// # Errors
+    ///
+    /// - [`NoInitiatingPhy`](Error::NoInitiatingPhy) if neither the LE 1M nor the LE Coded PHY
+    ///   has parameters. Advertisements are only scanned for on these PHYs.
+    /// - Underlying communication errors
+    ///
+    /// # Generated events
+    ///
+    /// The Controller sends the [Command Status](crate::event::Event::CommandStatus) event to the
+    /// Host when the event is received. An
+    /// [LE Enhanced Connection Complete](crate::event::Event::LeEnhancedConnectionComplete) event
+    /// shall be generated when a connection is created or the connection creation procedure is
+    /// cancelled. If a connection is created, it is followed by an
+    /// [LE Channel Selection Algorithm](crate::event::Event::LeChannelSelectionAlgorithm) event.
+    ///
+    /// Note: No Command Complete event is sent by the Controller to indicate that this command has
+    /// been completed. Instead, the LE Enhanced Connection Complete event indicates that this
+    /// command has been completed.
+    async fn le_extended_create_connection(
+        &mut self,
+        params: &ExtendedConnectionParameters,
+    ) -> Result<(), Error<Self::Error>>;
+
+    /// Synchronizes with the periodic advertising of an advertiser, and starts receiving its
+    /// periodic advertising PDUs.
+    ///
+    /// The Controller scans for the advertiser with the parameters given by
+    /// [`le_set_extended_scan_parameters`](HostHci::le_set_extended_scan_parameters) while
+    /// scanning is enabled. The Host shall not issue this command when another create sync command
+    /// is pending; if it is the [Command Disallowed](Status::CommandDisallowed) error code shall be
+    /// used.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.67.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadAdvertisingSid`](Error::BadAdvertisingSid) if the advertising SID is greater than
+    ///   0x0F.
+    /// - [`BadPeriodicAdvertisingSkip`](Error::BadPeriodicAdvertisingSkip) if the skip is greater
+    ///   than 0x01F3.
+    /// - [`BadSyncTimeout`](Error::BadSyncTimeout) if the sync timeout is not between 100 ms and
+    ///   163.84 s.
+    /// - Underlying communication errors
+    ///
+    /// # Generated events
+    ///
+    /// The Controller sends the [Command Status](crate::event::Event::CommandStatus) event to the
+    /// Host when the event is received. An
+    /// [LE Periodic Advertising Sync Established](crate::event::Event::LePeriodicAdvertisingSyncEstablished)
+    /// event is generated when the Controller synchronizes with the periodic advertising, or the
+    /// synchronization is cancelled. It is followed by an
+    /// [LE Periodic Advertising Report](crate::event::Event::LePeriodicAdvertisingReport) event for
+    /// each periodic advertising PDU received, unless reporting is initially disabled.
+    async fn le_periodic_advertising_create_sync(
+        &mut self,
+        params: &PeriodicAdvertisingSyncParameters,
+    ) -> Result<(), Error<Self::Error>>;
+
+    /// Cancels a pending [`le_periodic_advertising_create_sync`](HostHci::le_periodic_advertising_create_sync)
+    /// command.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.68.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LePeriodicAdvertisingCreateSyncCancel)
+    /// event is generated. If the create sync command was pending, it is followed by an
+    /// [LE Periodic Advertising Sync Established](crate::event::Event::LePeriodicAdvertisingSyncEstablished)
+    /// event with the status [`OperationCancelledByHost`](Status::OperationCancelledByHost).
+    async fn le_periodic_advertising_create_sync_cancel(&mut self) -> Result<(), Self::Error>;
+
+    /// Stops reception of the periodic advertising identified by `sync_handle`.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.69.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LePeriodicAdvertisingTerminateSync)
+    /// event is generated.
+    async fn le_periodic_advertising_terminate_sync(
+        &mut self,
+        sync_handle: crate::SyncHandle,
+    ) -> Result<(), Self::Error>;
+
+    /// Sends synchronization information about the periodic advertising identified by
+    /// `sync_handle` to the peer of a connection, so that the peer can synchronize with it without
+    /// scanning.
+    ///
+    /// `service_data` is passed to the Host of the peer in the
+    /// [LE Periodic Advertising Sync Transfer Received](crate::event::Event::LePeriodicAdvertisingSyncTransferReceived)
+    /// event. Its meaning is defined by the application.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.89.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LePeriodicAdvertisingSyncTransfer)
+    /// event is generated.
+    async fn le_periodic_advertising_sync_transfer(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        service_data: u16,
+        sync_handle: crate::SyncHandle,
+    ) -> Result<(), Self::Error>;
+
+    /// Sends synchronization information about the periodic advertising of a local advertising
+    /// set to the peer of a connection, so that the peer can synchronize with it without
+    /// scanning.
+    ///
+    /// `service_data` is passed to the Host of the peer as for
+    /// [`le_periodic_advertising_sync_transfer`](HostHci::le_periodic_advertising_sync_transfer).
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.90.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LePeriodicAdvertisingSetInfoTransfer)
+    /// event is generated.
+    async fn le_periodic_advertising_set_info_transfer(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        service_data: u16,
+        adv_handle: crate::AdvertisingHandle,
+    ) -> Result<(), Self::Error>;
+
+    /// Specifies how the Controller processes periodic advertising synchronization information
+    /// received from the peer of a connection.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.91.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadPeriodicAdvertisingSkip`](Error::BadPeriodicAdvertisingSkip) if the skip is greater
+    ///   than 0x01F3.
+    /// - [`BadSyncTimeout`](Error::BadSyncTimeout) if the sync timeout is not between 100 ms and
+    ///   163.84 s.
+    /// - Underlying communication errors
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetPeriodicAdvertisingSyncTransferParameters)
+    /// event is generated. Depending on the [mode](SyncTransferMode), an
+    /// [LE Periodic Advertising Sync Transfer Received](crate::event::Event::LePeriodicAdvertisingSyncTransferReceived)
+    /// event is generated when synchronization information is received from the peer.
+    async fn le_set_periodic_advertising_sync_transfer_parameters(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        params: &PeriodicAdvertisingSyncTransferParameters,
+    ) -> Result<(), Error<Self::Error>>;
+
+    /// Specifies the initial parameters used by
+    /// [`le_set_periodic_advertising_sync_transfer_parameters`](HostHci::le_set_periodic_advertising_sync_transfer_parameters)
+    /// for new connections. Existing connections are not affected.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.92.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadPeriodicAdvertisingSkip`](Error::BadPeriodicAdvertisingSkip) if the skip is greater
+    ///   than 0x01F3.
+    /// - [`BadSyncTimeout`](Error::BadSyncTimeout) if the sync timeout is not between 100 ms and
+    ///   163.84 s.
+    /// - Underlying communication errors
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetDefaultPeriodicAdvertisingSyncTransferParameters)
+    /// event is generated.
+    async fn le_set_default_periodic_advertising_sync_transfer_parameters(
+        &mut self,
+        params: &PeriodicAdvertisingSyncTransferParameters,
+    ) -> Result<(), Error<Self::Error>>;
+
+    /// Adds one device to the resolving list used to generate and resolve Resolvable Private
+    /// Addresses in the Controller.
+    ///
+    /// This command can be used at any time except when:
+    /// - address resolution is enabled in the Controller and advertising, scanning or an
+    ///   [`le_create_connection`](HostHci::le_create_connection) command is outstanding.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.38.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeAddDeviceToResolvingList)
+    /// event is generated. When a Controller cannot add a device to the resolving list because
+    /// there is no space available, it shall return [`OutOfMemory`](Status::OutOfMemory).
+    async fn le_add_device_to_resolving_list(
+        &mut self,
+        entry: &ResolvingListEntry,
+    ) -> Result<(), Self::Error>;
+
+    /// Removes one device from the resolving list used to resolve Resolvable Private Addresses in
+    /// the Controller.
+    ///
+    /// This command has the same restrictions as
+    /// [`le_add_device_to_resolving_list`](HostHci::le_add_device_to_resolving_list).
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.39.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeRemoveDeviceFromResolvingList)
+    /// event is generated. If the device is not in the resolving list, the Controller shall return
+    /// [`UnknownConnectionId`](Status::UnknownConnectionId).
+    async fn le_remove_device_from_resolving_list(
+        &mut self,
+        peer_identity_address: crate::BdAddrType,
+    ) -> Result<(), Self::Error>;
+
+    /// Removes all devices from the resolving list used to resolve Resolvable Private Addresses in
+    /// the Controller.
+    ///
+    /// This command has the same restrictions as
+    /// [`le_add_device_to_resolving_list`](HostHci::le_add_device_to_resolving_list).
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.40.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeClearResolvingList) event
+    /// is generated.
+    async fn le_clear_resolving_list(&mut self) -> Result<(), Self::Error>;
+
+    /// Reads the total number of entries of the resolving list that can be stored in the
+    /// Controller.
+    ///
+    /// Note: The number of entries that can be stored is not fixed and the Controller can change it
+    /// at any time (e.g. because the memory used to store the list can also be used for other
+    /// purposes).
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.41.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeReadResolvingListSize)
+    /// event is generated.
+    async fn le_read_resolving_list_size(&mut self) -> Result<(), Self::Error>;
+
+    /// Gets the current peer Resolvable Private Address being used for the corresponding peer
+    /// Public and Random (static) Identity Address. The peer's resolvable address being used may
+    /// change after the command is called.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.42.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeReadPeerResolvableAddress)
+    /// event is generated. If the device is not in the resolving list, the Controller shall return
+    /// [`UnknownConnectionId`](Status::UnknownConnectionId).
+    async fn le_read_peer_resolvable_address(
+        &mut self,
+        peer_identity_address: crate::BdAddrType,
+    ) -> Result<(), Self::Error>;
+
+    /// Gets the current local Resolvable Private Address being used for the corresponding peer
+    /// Identity Address. The local's resolvable address being used may change after the command is
+    /// called.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.43.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeReadLocalResolvableAddress)
+    /// event is generated. If the device is not in the resolving list, the Controller shall return
+    /// [`UnknownConnectionId`](Status::UnknownConnectionId).
+    async fn le_read_local_resolvable_address(
+        &mut self,
+        peer_identity_address: crate::BdAddrType,
+    ) -> Result<(), Self::Error>;
+
+    /// Enables resolution of Resolvable Private Addresses in the Controller. This causes the
+    /// Controller to use the resolving list whenever the Controller receives a local or peer
+    /// Resolvable Private Address. Address resolution is disabled by default.
+    ///
+    /// This command can be used at any time except when advertising, scanning or an
+    /// [`le_create_connection`](HostHci::le_create_connection) command is outstanding.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.44.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetAddressResolutionEnable)
+    /// event is generated.
+    async fn le_set_address_resolution_enable(&mut self, enable: bool) -> Result<(), Self::Error>;
+
+    /// Sets the length of time the Controller uses a Resolvable Private Address before a new
+    /// resolvable private address is generated and starts being used. The default timeout is 900
+    /// seconds.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.45.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadResolvablePrivateAddressTimeout`](Error::BadResolvablePrivateAddressTimeout) if the
+    ///   timeout is not between 1 second and 1 hour.
+    /// - Underlying communication errors
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetResolvablePrivateAddressTimeout)
+    /// event is generated.
+    async fn le_set_resolvable_private_address_timeout(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<(), Error<Self::Error>>;
+
+    /// Selects the privacy mode used by the Controller for a peer device in the resolving list.
+    /// The default is [network privacy mode](PrivacyMode::Network).
+    ///
+    /// This command has the same restrictions as
+    /// [`le_set_address_resolution_enable`](HostHci::le_set_address_resolution_enable).
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.77.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetPrivacyMode) event is
+    /// generated. If the device is not in the resolving list, the Controller shall return
+    /// [`UnknownConnectionId`](Status::UnknownConnectionId).
+    async fn le_set_privacy_mode(
+        &mut self,
+        peer_identity_address: crate::BdAddrType,
+        privacy_mode: PrivacyMode,
+    ) -> Result<(), Self::Error>;
+
+    /// Reads the current and maximum transmit power levels of the local Controller on a connection,
+    /// for the given PHY.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.117.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeEnhancedReadTransmitPowerLevel)
+    /// event is generated.
+    async fn le_enhanced_read_transmit_power_level(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        phy: PowerControlPhy,
+    ) -> Result<(), Self::Error>;
+
+    /// Reads the transmit power level used by the peer of a connection, for the given PHY.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.118.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// When the Controller receives the command, the Controller sends the
+    /// [Command Status](crate::event::Event::CommandStatus) event to the Host. The
+    /// [LE Transmit Power Reporting](crate::event::Event::LeTransmitPowerReporting) event is
+    /// generated when the remote transmit power level has been read.
+    async fn le_read_remote_transmit_power_level(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        phy: PowerControlPhy,
+    ) -> Result<(), Self::Error>;
+
+    /// Sets the path loss thresholds of a connection. The path loss is divided into low, middle,
+    /// and high zones; the Controller reports when the path loss enters a different zone.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.119.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadPathLossThresholds`](Error::BadPathLossThresholds) if the hysteresis ranges around
+    ///   the low and high thresholds overlap.
+    /// - Underlying communication errors
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetPathLossReportingParameters)
+    /// event is generated.
+    async fn le_set_path_loss_reporting_parameters(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        params: &PathLossReportingParameters,
+    ) -> Result<(), Error<Self::Error>>;
+
+    /// Enables or disables path loss reporting on a connection. The thresholds must have been set
+    /// with
+    /// [`le_set_path_loss_reporting_parameters`](HostHci::le_set_path_loss_reporting_parameters).
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.120.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetPathLossReportingEnable)
+    /// event is generated. While reporting is enabled, the
+    /// [LE Path Loss Threshold](crate::event::Event::LePathLossThreshold) event is generated when
+    /// the path loss enters a different zone.
+    async fn le_set_path_loss_reporting_enable(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        enable: bool,
+    ) -> Result<(), Self::Error>;
+
+    /// Enables or disables reporting of changes to the local and remote transmit power levels on a
+    /// connection.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.121.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetTransmitPowerReportingEnable)
+    /// event is generated. While reporting is enabled, the
+    /// [LE Transmit Power Reporting](crate::event::Event::LeTransmitPowerReporting) event is
+    /// generated when the transmit power level changes.
+    async fn le_set_transmit_power_reporting_enable(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        local_enable: bool,
+        remote_enable: bool,
+    ) -> Result<(), Self::Error>;
+
+    /// Generates a new P-256 public/private key pair in the Controller, and returns the public
+    /// key. The private key is kept in the Controller, and used by
+    /// [`le_generate_dhkey`](HostHci::le_generate_dhkey).
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.36.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// The Controller sends the [Command Status](crate::event::Event::CommandStatus) event to the
+    /// Host when the event is received. When the key pair is generated, an
+    /// [LE Read Local P-256 Public Key Complete](crate::event::Event::LeReadLocalP256PublicKeyComplete)
+    /// event is generated.
+    async fn le_read_local_p256_public_key(&mut self) -> Result<(), Self::Error>;
+
+    /// Computes the Diffie-Hellman key from the P-256 public key of the remote device and the
+    /// private key of the local device, generated by
+    /// [`le_read_local_p256_public_key`](HostHci::le_read_local_p256_public_key).
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.37.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// The Controller sends the [Command Status](crate::event::Event::CommandStatus) event to the
+    /// Host when the event is received. When the key is computed, an
+    /// [LE Generate DHKey Complete](crate::event::Event::LeGenerateDHKeyComplete) event is
+    /// generated. If the remote public key is not a valid point on the P-256 curve, the event
+    /// has the status [`InvalidParameters`](Status::InvalidParameters).
+    async fn le_generate_dhkey(
+        &mut self,
+        remote_public_key: &P256PublicKey,
+    ) -> Result<(), Self::Error>;
+
+    /// Computes the Diffie-Hellman key, like [`le_generate_dhkey`](HostHci::le_generate_dhkey),
+    /// and lets the Host select the private key used: either the generated private key or the
+    /// debug private key defined in Vol 3, Part H, Section 2.3.5.6.1 of the spec.
+    ///
+    /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.94.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// The Controller sends the [Command Status](crate::event::Event::CommandStatus) event to the
+    /// Host when the event is received. When the key is computed, an
+    /// [LE Generate DHKey Complete](crate::event::Event::LeGenerateDHKeyComplete) event is
+    /// generated.
+    async fn le_generate_dhkey_v2(
+        &mut self,
+        remote_public_key: &P256PublicKey,
+        private_key: DhKeyPrivateKey,
+    ) -> Result<(), Self::Error>;
+}
+
+/// Errors that may occur when sending commands to the controller.  Must be specialized on the types
+/// of communication errors.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Error<E> {
+    /// For the [`disconnect`](HostHci::disconnect) command: The provided reason is not a valid
+    /// disconnection reason. Includes the reported reason.
+    BadDisconnectionReason(Status),
+
+    /// For the [`le_set_random_address`](HostHci::le_set_random_address) command: The provided address
+    /// does not meet the rules for random addresses in the Bluetooth Spec, Vol 6, Part B, Section
+    /// 1.3.  Includes the invalid address.
+    BadRandomAddress(crate::BdAddr),
+
+    /// For the [`le_set_advertising_parameters`](HostHci::le_set_advertising_parameters) command: The
+    /// channel map did not include any enabled channels.  Includes the provided channel map.
+    BadChannelMap(Channels),
+
+    /// For the [`le_set_advertising_data`](HostHci::le_set_advertising_data) or
+    /// [`le_set_scan_response_data`](HostHci::le_set_scan_response_data) commands: The provided data is
+    /// too long to fit in the command.  The maximum allowed length is 31.  The actual length is
+    /// returned.
+    AdvertisingDataTooLong(usize),
+
+    /// For the [`le_create_connection`](HostHci::le_create_connection) command: the connection length
+    /// range is inverted (i.e, the minimum is greater than the maximum). Returns the range, min
+    /// first.
+    BadConnectionLengthRange(Duration, Duration),
+
+    /// For the [`le_set_host_channel_classification`](HostHci::le_set_host_channel_classification)
+    /// command: all channels were marked 'bad'.
+    NoValidChannel,
+
+    /// For the [`le_receiver_test`](HostHci::le_receiver_test) and
+    /// [`le_transmitter_test`](HostHci::le_transmitter_test) commands: the channel was out of
+    /// range. The maximum allowed channel is 39. Includes the invalid value.
+    InvalidTestChannel(u8),
+
+    /// For the [`le_transmitter_test`](HostHci::le_transmitter_test) command: The payload length is
+    /// invalid. The maximum value is 37. Includes the invalid value.
+    InvalidTestPayloadLength(usize),
+
+    /// For the [`le_set_extended_advertising_parameters`](HostHci::le_set_extended_advertising_parameters)
+    /// command: the primary advertising PHY is not LE 1M or LE Coded. Includes the provided PHY.
+    BadPrimaryAdvertisingPhy(AdvertisingPhy),
+
+    /// For the [`le_set_extended_advertising_parameters`](HostHci::le_set_extended_advertising_parameters)
+    /// and [`le_periodic_advertising_create_sync`](HostHci::le_periodic_advertising_create_sync)
+    /// commands: the advertising SID is out of range. The maximum value is 0x0F. Includes the
+    /// invalid value.
+    BadAdvertisingSid(u8),
+
+    /// For the [`le_set_extended_advertising_data`](HostHci::le_set_extended_advertising_data) or
+    /// [`le_set_extended_scan_response_data`](HostHci::le_set_extended_scan_response_data)
+    /// commands: The [fragment](ExtendedAdvertisingData) carries too much data. The maximum allowed
+    /// length is 251. The actual length is returned.
+    ExtendedAdvertisingDataTooLong(usize),
+
+    /// For the [`le_set_extended_advertising_enable`](HostHci::le_set_extended_advertising_enable)
+    /// command: too many advertising sets were provided. The maximum is 63. The actual number is
+    /// returned.
+    TooManyAdvertisingSets(usize),
+
+    /// For the [`le_set_extended_scan_parameters`](HostHci::le_set_extended_scan_parameters)
+    /// command: no PHY was selected for scanning.
+    NoScanningPhy,
+
+    /// For the [`le_set_extended_scan_enable`](HostHci::le_set_extended_scan_enable) command: the
+    /// scan duration is out of range. Includes the invalid value.
+    BadScanDuration(Duration),
+
+    /// For the [`le_set_extended_scan_enable`](HostHci::le_set_extended_scan_enable) command: the
+    /// scan period is out of range, or is not longer than the scan duration. Includes the invalid
+    /// value.
+    BadScanPeriod(Duration),
+
+    /// For the [`le_extended_create_connection`](HostHci::le_extended_create_connection) command:
+    /// neither the LE 1M nor the LE Coded PHY was selected for initiating the connection.
+    NoInitiatingPhy,
+
+    /// For the
+    /// [`le_set_resolvable_private_address_timeout`](HostHci::le_set_resolvable_private_address_timeout)
+    /// command: the timeout is out of range. Includes the invalid value.
+    BadResolvablePrivateAddressTimeout(Duration),
+
+    /// For the [`le_set_default_phy`](HostHci::le_set_default_phy) and
+    /// [`le_set_phy`](HostHci::le_set_phy) commands: a PHY preference does not include any PHY.
+    EmptyPhyPreference,
+
+    /// For the
+    /// [`le_set_periodic_advertising_parameters`](HostHci::le_set_periodic_advertising_parameters)
+    /// command: the periodic advertising interval range is inverted, or out of range. Returns the
+    /// range, min first.
+    BadPeriodicAdvertisingInterval(Duration, Duration),
+
+    /// For the [`le_set_periodic_advertising_data`](HostHci::le_set_periodic_advertising_data)
+    /// command: The [fragment](PeriodicAdvertisingData) carries too much data. The maximum allowed
+    /// length is 252. The actual length is returned.
+    PeriodicAdvertisingDataTooLong(usize),
+
+    /// For the [`le_periodic_advertising_create_sync`](HostHci::le_periodic_advertising_create_sync)
+    /// and periodic advertising sync transfer parameters commands: the number of periodic
+    /// advertising events that may be skipped is out of range. The maximum value is 0x01F3.
+    /// Includes the invalid value.
+    BadPeriodicAdvertisingSkip(u16),
+
+    /// For the [`le_periodic_advertising_create_sync`](HostHci::le_periodic_advertising_create_sync)
+    /// and periodic advertising sync transfer parameters commands: the sync timeout is out of
+    /// range. Includes the invalid value.
+    BadSyncTimeout(Duration),
+
+    /// For the
+    /// [`le_set_path_loss_reporting_parameters`](HostHci::le_set_path_loss_reporting_parameters)
+    /// command: the low threshold plus its hysteresis is greater than the high threshold minus its
+    /// hysteresis, so the zones are not well defined. Includes the low and high thresholds.
+    BadPathLossThresholds(u8, u8),
+
+    /// Underlying communication error.
+    Comm(E),
+}
+
+impl<T> HostHci for T
+where
+    T: crate::Controller,
+{
+    async fn disconnect(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        reason: Status,
+    ) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::Disconnect {
+            conn_handle,
+            reason,
+        };
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
+    }
+
+    async fn read_remote_version_information(
+        &mut self,
+        conn_handle: ConnectionHandle,
+    ) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::ReadRemoteVersionInformation(conn_handle)).await
+    }
+
+    async fn set_event_mask(&mut self, mask: EventFlags) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::SetEventMask(mask)).await
+    }
+
+    async fn reset(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::Reset).await
+    }
+
+    async fn read_tx_power_level(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        power_level_type: TxPowerLevel,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::ReadTxPowerLevel {
+                conn_handle,
+                power_level_type,
+            },
+        )
+        .await
+    }
+
+    async fn set_controller_to_host_flow_control(
+        &mut self,
+        flow_control: FlowControl,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::SetControllerToHostFlowControl(flow_control),
+        )
+        .await
+    }
+
+    async fn host_buffer_size(&mut self, params: HostBufferSize) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::HostBufferSize(&params)).await
+    }
+
+    async fn number_of_completed_packets(
+        &mut self,
+        params: NumberOfCompletedPackets,
+    ) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::NumberOfCompletedPackets(&params)).await
+    }
+
+    async fn set_event_mask_page_2(&mut self, mask: EventFlagsPage2) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::SetEventMaskPage2(mask)).await
+    }
+
+    async fn read_authenticated_payload_timeout(
+        &mut self,
+        conn_handle: ConnectionHandle,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::ReadAuthenticatedPayloadTimeout(conn_handle),
+        )
+        .await
+    }
+
+    async fn write_authenticated_payload_timeout(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        timeout: AuthenticatedPayloadTimeout,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::WriteAuthenticatedPayloadTimeout {
+                conn_handle,
+                timeout,
+            },
+        )
+        .await
+    }
+
+    async fn read_local_version_information(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::ReadLocalVersionInformation).await
+    }
+
+    async fn read_local_supported_commands(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::ReadLocalSupportedCommands).await
+    }
+
+    async fn read_local_supported_features(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::ReadLocalSupportedFeatures).await
+    }
+
+    async fn read_bd_addr(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::ReadBdAddr).await
+    }
+
+    async fn read_rssi(&mut self, conn_handle: ConnectionHandle) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::ReadRssi(conn_handle)).await
+    }
+
+    async fn le_set_event_mask(&mut self, event_mask: LeEventFlags) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeSetEventMask(event_mask)).await
+    }
+
+    async fn le_read_buffer_size(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeReadBufferSize).await
+    }
+
+    async fn le_read_local_supported_features(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeReadLocalSupportedFeatures).await
+    }
+
+    async fn le_set_random_address(
+        &mut self,
+        bd_addr: crate::BdAddr,
+    ) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeSetRandomAddress(bd_addr);
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
+    }
+
+    async fn le_set_advertising_parameters(
+        &mut self,
+        params: &AdvertisingParameters,
+    ) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeSetAdvertisingParameters(params);
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
+    }
+
+    async fn le_read_advertising_channel_tx_power(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeReadAdvertisingChannelTxPower).await
+    }
+
+    async fn le_set_advertising_data(&mut self, data: &[u8]) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeSetAdvertisingData(data);
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
+    }
+
+    async fn le_set_scan_response_data(&mut self, data: &[u8]) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeSetScanResponseData(data);
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
+    }
+
+    async fn le_set_advertising_enable(&mut self, enable: bool) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeSetAdvertisingEnable(enable)).await
+    }
+
+    async fn le_set_scan_parameters(&mut self, params: &ScanParameters) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeSetScanParameters(params)).await
+    }
+
+    async fn le_set_scan_enable(
+        &mut self,
+        enable: bool,
+        filter_duplicates: bool,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::LeSetScanEnable {
+                enable,
+                filter_duplicates,
+            },
+        )
+        .await
+    }
+
+    async fn le_create_connection(
+        &mut self,
+        params: &ConnectionParameters,
+    ) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeCreateConnection(params)).await
+    }
+
+    async fn le_create_connection_cancel(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeCreateConnectionCancel).await
+    }
+
+    async fn le_read_white_list_size(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeReadWhiteListSize).await
+    }
+
+    async fn le_clear_white_list(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeClearWhiteList).await
+    }
+
+    async fn le_add_device_to_white_list(
+        &mut self,
+        addr: crate::BdAddrType,
+    ) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeAddDeviceToWhiteList(addr)).await
+    }
+
+    async fn le_add_anon_advertising_devices_to_white_list(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeAddAnonAdvertisingDevicesToWhiteList).await
+    }
+
+    async fn le_remove_device_from_white_list(
+        &mut self,
+        addr: crate::BdAddrType,
+    ) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeRemoveDeviceFromWhiteList(addr)).await
+    }
+
+    async fn le_remove_anon_advertising_devices_from_white_list(
+        &mut self,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::LeRemoveAnonAdvertisingDevicesFromWhiteList,
+        )
+        .await
+    }
+
+    async fn le_connection_update(
+        &mut self,
+        params: &ConnectionUpdateParameters,
+    ) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeConnectionUpdate(params)).await
+    }
+
+    async fn le_set_host_channel_classification(
+        &mut self,
+        channels: crate::ChannelClassification,
+    ) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeSetHostChannelClassification(channels);
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
+    }
+
+    async fn le_read_channel_map(
+        &mut self,
+        conn_handle: ConnectionHandle,
+    ) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeReadChannelMap(conn_handle)).await
+    }
+
+    async fn le_read_remote_used_features(
+        &mut self,
+        conn_handle: ConnectionHandle,
+    ) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeReadRemoteUsedFeatures(conn_handle)).await
+    }
+
+    async fn le_encrypt(&mut self, params: &AesParameters) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeEncrypt(params)).await
+    }
+
+    async fn le_rand(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeRand).await
+    }
+
+    async fn le_start_encryption(
+        &mut self,
+        params: &EncryptionParameters,
+    ) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeStartEncryption(params)).await
+    }
+
+    async fn le_long_term_key_request_reply(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        key: &EncryptionKey,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::LeLongTermKeyRequestReply { conn_handle, key },
+        )
+        .await
+    }
+
+    async fn le_long_term_key_request_negative_reply(
+        &mut self,
+        conn_handle: ConnectionHandle,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::LeLongTermKeyRequestNegativeReply(conn_handle),
+        )
+        .await
+    }
+
+    async fn le_read_supported_states(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeReadSupportedStates).await
+    }
+
+    async fn le_receiver_test(&mut self, channel: u8) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeReceiverTest(channel);
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
+    }
+
+    async fn le_transmitter_test(
+        &mut self,
+        channel: u8,
+        payload_length: usize,
+        payload: TestPacketPayload,
+    ) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeTransmitterTest {
+            channel,
+            payload_length,
+            payload,
+        };
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
+    }
+
+    async fn le_test_end(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeTestEnd).await
+    }
+
+    async fn le_remote_connection_parameter_request_reply(
+        &mut self,
+        params: &ConnectionUpdateParameters,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::LeRemoteConnectionParameterRequestReply(params),
+        )
+        .await
+    }
+
+    async fn le_remote_connection_parameter_request_negative_reply(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        reason: Status,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::LeRemoteConnectionParameterRequestNegativeReply {
+                conn_handle,
+                reason,
+            },
+        )
+        .await
+    }
+
+    async fn le_set_data_length(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        max_tx_octets: MaxTxOctets,
+        max_tx_time: MaxTxTime,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::LeSetDataLength {
+                conn_handle,
+                max_tx_octets,
+                max_tx_time,
+            },
+        )
+        .await
     }
 
-    async fn le_read_buffer_size(&mut self) {
-        self.controller_write(crate::opcode::LE_READ_BUFFER_SIZE, &[])
-            .await;
+    async fn le_read_suggested_default_data_length(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeReadSuggestedDefaultDataLength).await
     }
 
-    async fn le_read_local_supported_features(&mut self) {
-        self.controller_write(crate::opcode::LE_READ_LOCAL_SUPPORTED_FEATURES, &[])
-            .await;
+    async fn le_write_suggested_default_data_length(
+        &mut self,
+        max_tx_octets: MaxTxOctets,
+        max_tx_time: MaxTxTime,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::LeWriteSuggestedDefaultDataLength {
+                max_tx_octets,
+                max_tx_time,
+            },
+        )
+        .await
     }
 
-    async fn le_set_random_address(&mut self, bd_addr: crate::BdAddr) -> Result<(), Error> {
-        validate_random_address(bd_addr)?;
-        self.controller_write(crate::opcode::LE_SET_RANDOM_ADDRESS, &bd_addr.0)
-            .await;
+    async fn le_read_maximum_data_length(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeReadMaximumDataLength).await
+    }
 
-        Ok(())
+    async fn le_read_phy(&mut self, conn_handle: ConnectionHandle) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeReadPhy(conn_handle)).await
     }
 
-    async fn le_set_advertising_parameters(
+    async fn le_set_default_phy(
         &mut self,
-        params: &AdvertisingParameters,
-    ) -> Result<(), Error> {
-        let mut bytes = [0; 15];
-        params.copy_into_slice(&mut bytes)?;
-        self.controller_write(crate::opcode::LE_SET_ADVERTISING_PARAMETERS, &bytes)
-            .await;
+        tx_phys: Option<PhyMask>,
+        rx_phys: Option<PhyMask>,
+    ) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeSetDefaultPhy { tx_phys, rx_phys };
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
+    }
 
-        Ok(())
+    async fn le_set_phy(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        tx_phys: Option<PhyMask>,
+        rx_phys: Option<PhyMask>,
+        coded_phy_options: CodedPhyOptions,
+    ) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeSetPhy {
+            conn_handle,
+            tx_phys,
+            rx_phys,
+            coded_phy_options,
+        };
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
+    }
+
+    async fn le_set_extended_advertising_parameters(
+        &mut self,
+        params: &ExtendedAdvertisingParameters,
+    ) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeSetExtendedAdvertisingParameters(params);
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
+    }
+
+    async fn le_set_extended_advertising_data(
+        &mut self,
+        data: &ExtendedAdvertisingData<'_>,
+    ) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeSetExtendedAdvertisingData(*data);
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
+    }
+
+    async fn le_set_extended_scan_response_data(
+        &mut self,
+        data: &ExtendedAdvertisingData<'_>,
+    ) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeSetExtendedScanResponseData(*data);
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
+    }
+
+    async fn le_set_extended_advertising_enable(
+        &mut self,
+        enable: bool,
+        sets: &[AdvSet],
+    ) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeSetExtendedAdvertisingEnable { enable, sets };
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
+    }
+
+    async fn le_read_maximum_advertising_data_length(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeReadMaximumAdvertisingDataLength).await
     }
 
-    async fn le_read_advertising_channel_tx_power(&mut self) {
-        self.controller_write(crate::opcode::LE_READ_ADVERTISING_CHANNEL_TX_POWER, &[])
-            .await;
+    async fn le_read_number_of_supported_advertising_sets(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeReadNumberOfSupportedAdvertisingSets).await
     }
 
-    async fn le_set_advertising_data(&mut self, data: &[u8]) -> Result<(), Error> {
-        set_outbound_data::<T>(self, crate::opcode::LE_SET_ADVERTISING_DATA, data).await
+    async fn le_remove_advertising_set(
+        &mut self,
+        adv_handle: crate::AdvertisingHandle,
+    ) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeRemoveAdvertisingSet(adv_handle)).await
     }
 
-    async fn le_set_scan_response_data(&mut self, data: &[u8]) -> Result<(), Error> {
-        set_outbound_data::<T>(self, crate::opcode::LE_SET_SCAN_RESPONSE_DATA, data).await
+    async fn le_clear_advertising_sets(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeClearAdvertisingSets).await
     }
 
-    async fn le_set_advertising_enable(&mut self, enable: bool) {
-        self.controller_write(crate::opcode::LE_SET_ADVERTISE_ENABLE, &[enable as u8])
-            .await
+    async fn le_set_periodic_advertising_parameters(
+        &mut self,
+        params: &PeriodicAdvertisingParameters,
+    ) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeSetPeriodicAdvertisingParameters(params);
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
     }
 
-    async fn le_set_scan_parameters(&mut self, params: &ScanParameters) {
-        let mut bytes = [0; 7];
-        params.copy_into_slice(&mut bytes);
-        self.controller_write(crate::opcode::LE_SET_SCAN_PARAMETERS, &bytes)
-            .await;
+    async fn le_set_periodic_advertising_data(
+        &mut self,
+        data: &PeriodicAdvertisingData<'_>,
+    ) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeSetPeriodicAdvertisingData(*data);
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
     }
 
-    async fn le_set_scan_enable(&mut self, enable: bool, filter_duplicates: bool) {
-        self.controller_write(
-            crate::opcode::LE_SET_SCAN_ENABLE,
-            &[enable as u8, filter_duplicates as u8],
+    async fn le_set_periodic_advertising_enable(
+        &mut self,
+        enable: bool,
+        adv_handle: crate::AdvertisingHandle,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::LeSetPeriodicAdvertisingEnable { enable, adv_handle },
         )
-        .await;
+        .await
     }
 
-    async fn le_create_connection(&mut self, params: &ConnectionParameters) {
-        let mut bytes = [0; 25];
-        params.copy_into_slice(&mut bytes);
-        self.controller_write(crate::opcode::LE_CREATE_CONNECTION, &bytes)
-            .await;
+    async fn le_set_extended_scan_parameters(
+        &mut self,
+        params: &ExtendedScanParameters,
+    ) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeSetExtendedScanParameters(params);
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
     }
 
-    async fn le_create_connection_cancel(&mut self) {
-        self.controller_write(crate::opcode::LE_CREATE_CONNECTION_CANCEL, &[])
-            .await;
+    async fn le_set_extended_scan_enable(
+        &mut self,
+        params: &ExtendedScanEnable,
+    ) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeSetExtendedScanEnable(params);
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
     }
 
-    async fn le_read_white_list_size(&mut self) {
-        self.controller_write(crate::opcode::LE_READ_WHITE_LIST_SIZE, &[])
-            .await;
+    async fn le_extended_create_connection(
+        &mut self,
+        params: &ExtendedConnectionParameters,
+    ) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeExtendedCreateConnection(params);
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
     }
 
-    async fn le_clear_white_list(&mut self) {
-        self.controller_write(crate::opcode::LE_CLEAR_WHITE_LIST, &[])
-            .await;
+    async fn le_periodic_advertising_create_sync(
+        &mut self,
+        params: &PeriodicAdvertisingSyncParameters,
+    ) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LePeriodicAdvertisingCreateSync(params);
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
     }
 
-    async fn le_add_device_to_white_list(&mut self, addr: crate::BdAddrType) {
-        let mut params = [0; 7];
-        addr.copy_into_slice(&mut params);
-        self.controller_write(crate::opcode::LE_ADD_DEVICE_TO_WHITE_LIST, &params)
-            .await;
+    async fn le_periodic_advertising_create_sync_cancel(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LePeriodicAdvertisingCreateSyncCancel).await
     }
 
-    async fn le_add_anon_advertising_devices_to_white_list(&mut self) {
-        self.controller_write(
-            crate::opcode::LE_ADD_DEVICE_TO_WHITE_LIST,
-            &[0xFF, 0, 0, 0, 0, 0, 0],
+    async fn le_periodic_advertising_terminate_sync(
+        &mut self,
+        sync_handle: crate::SyncHandle,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::LePeriodicAdvertisingTerminateSync(sync_handle),
         )
-        .await;
+        .await
     }
 
-    async fn le_remove_device_from_white_list(&mut self, addr: crate::BdAddrType) {
-        let mut params = [0; 7];
-        addr.copy_into_slice(&mut params);
-        self.controller_write(crate::opcode::LE_REMOVE_DEVICE_FROM_WHITE_LIST, &params)
-            .await;
+    async fn le_periodic_advertising_sync_transfer(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        service_data: u16,
+        sync_handle: crate::SyncHandle,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::LePeriodicAdvertisingSyncTransfer {
+                conn_handle,
+                service_data,
+                sync_handle,
+            },
+        )
+        .await
     }
 
-    async fn le_remove_anon_advertising_devices_from_white_list(&mut self) {
-        self.controller_write(
-            crate::opcode::LE_REMOVE_DEVICE_FROM_WHITE_LIST,
-            &[0xFF, 0, 0, 0, 0, 0, 0],
+    async fn le_periodic_advertising_set_info_transfer(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        service_data: u16,
+        adv_handle: crate::AdvertisingHandle,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::LePeriodicAdvertisingSetInfoTransfer {
+                conn_handle,
+                service_data,
+                adv_handle,
+            },
         )
-        .await;
+        .await
     }
 
-    async fn le_connection_update(&mut self, params: &ConnectionUpdateParameters) {
-        let mut bytes = [0; 14];
-        params.copy_into_slice(&mut bytes);
-        self.controller_write(crate::opcode::LE_CONNECTION_UPDATE, &bytes)
-            .await;
+    async fn le_set_periodic_advertising_sync_transfer_parameters(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        params: &PeriodicAdvertisingSyncTransferParameters,
+    ) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeSetPeriodicAdvertisingSyncTransferParameters {
+            conn_handle,
+            params,
+        };
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
     }
 
-    async fn le_set_host_channel_classification(
+    async fn le_set_default_periodic_advertising_sync_transfer_parameters(
         &mut self,
-        channels: crate::ChannelClassification,
-    ) -> Result<(), Error> {
-        if channels.is_empty() {
-            return Err(Error::NoValidChannel);
-        }
+        params: &PeriodicAdvertisingSyncTransferParameters,
+    ) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeSetDefaultPeriodicAdvertisingSyncTransferParameters(params);
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
+    }
 
-        let mut bytes = [0; 5];
-        channels.copy_into_slice(&mut bytes);
-        self.controller_write(crate::opcode::LE_SET_HOST_CHANNEL_CLASSIFICATION, &bytes)
-            .await;
+    async fn le_add_device_to_resolving_list(
+        &mut self,
+        entry: &ResolvingListEntry,
+    ) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeAddDeviceToResolvingList(entry)).await
+    }
 
-        Ok(())
+    async fn le_remove_device_from_resolving_list(
+        &mut self,
+        peer_identity_address: crate::BdAddrType,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::LeRemoveDeviceFromResolvingList(peer_identity_address),
+        )
+        .await
     }
 
-    async fn le_read_channel_map(&mut self, conn_handle: ConnectionHandle) {
-        let mut bytes = [0; 2];
-        LittleEndian::write_u16(&mut bytes, conn_handle.0);
-        self.controller_write(crate::opcode::LE_READ_CHANNEL_MAP, &bytes)
-            .await;
+    async fn le_clear_resolving_list(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeClearResolvingList).await
     }
 
-    async fn le_read_remote_used_features(&mut self, conn_handle: ConnectionHandle) {
-        let mut bytes = [0; 2];
-        LittleEndian::write_u16(&mut bytes, conn_handle.0);
-        self.controller_write(crate::opcode::LE_READ_REMOTE_USED_FEATURES, &bytes)
-            .await;
+    async fn le_read_resolving_list_size(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeReadResolvingListSize).await
     }
 
-    async fn le_encrypt(&mut self, params: &AesParameters) {
-        let mut bytes = [0; 32];
-        bytes[..16].copy_from_slice(&params.key.0);
-        bytes[16..].copy_from_slice(&params.plaintext_data.0);
-        self.controller_write(crate::opcode::LE_ENCRYPT, &bytes)
-            .await;
+    async fn le_read_peer_resolvable_address(
+        &mut self,
+        peer_identity_address: crate::BdAddrType,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::LeReadPeerResolvableAddress(peer_identity_address),
+        )
+        .await
     }
 
-    async fn le_rand(&mut self) {
-        self.controller_write(crate::opcode::LE_RAND, &[]).await;
+    async fn le_read_local_resolvable_address(
+        &mut self,
+        peer_identity_address: crate::BdAddrType,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::LeReadLocalResolvableAddress(peer_identity_address),
+        )
+        .await
     }
 
-    async fn le_start_encryption(&mut self, params: &EncryptionParameters) {
-        let mut bytes = [0; 28];
-        LittleEndian::write_u16(&mut bytes[0..], params.conn_handle.0);
-        LittleEndian::write_u64(&mut bytes[2..], params.random_number);
-        LittleEndian::write_u16(&mut bytes[10..], params.encrypted_diversifier);
-        bytes[12..].copy_from_slice(&params.long_term_key.0);
-        self.controller_write(crate::opcode::LE_START_ENCRYPTION, &bytes)
-            .await;
+    async fn le_set_address_resolution_enable(&mut self, enable: bool) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeSetAddressResolutionEnable(enable)).await
     }
 
-    async fn le_long_term_key_request_reply(
+    async fn le_set_resolvable_private_address_timeout(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeSetResolvablePrivateAddressTimeout(timeout);
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
+    }
+
+    async fn le_set_privacy_mode(
+        &mut self,
+        peer_identity_address: crate::BdAddrType,
+        privacy_mode: PrivacyMode,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::LeSetPrivacyMode {
+                peer_identity_address,
+                privacy_mode,
+            },
+        )
+        .await
+    }
+
+    async fn le_enhanced_read_transmit_power_level(
         &mut self,
         conn_handle: ConnectionHandle,
-        key: &EncryptionKey,
-    ) {
-        let mut bytes = [0; 18];
-        LittleEndian::write_u16(&mut bytes[0..], conn_handle.0);
-        bytes[2..].copy_from_slice(&key.0);
-        self.controller_write(crate::opcode::LE_LTK_REQUEST_REPLY, &bytes)
-            .await;
+        phy: PowerControlPhy,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::LeEnhancedReadTransmitPowerLevel { conn_handle, phy },
+        )
+        .await
     }
 
-    async fn le_long_term_key_request_negative_reply(&mut self, conn_handle: ConnectionHandle) {
-        let mut bytes = [0; 2];
-        LittleEndian::write_u16(&mut bytes[0..], conn_handle.0);
-        self.controller_write(crate::opcode::LE_LTK_REQUEST_NEGATIVE_REPLY, &bytes)
-            .await;
+    async fn le_read_remote_transmit_power_level(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        phy: PowerControlPhy,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::LeReadRemoteTransmitPowerLevel { conn_handle, phy },
+        )
+        .await
+    }
+
+    async fn le_set_path_loss_reporting_parameters(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        params: &PathLossReportingParameters,
+    ) -> Result<(), Error<Self::Error>> {
+        let command = HciCommand::LeSetPathLossReportingParameters {
+            conn_handle,
+            params,
+        };
+        command.validate()?;
+        write_command(self, &command).await.map_err(Error::Comm)
+    }
+
+    async fn le_set_path_loss_reporting_enable(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        enable: bool,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::LeSetPathLossReportingEnable {
+                conn_handle,
+                enable,
+            },
+        )
+        .await
+    }
+
+    async fn le_set_transmit_power_reporting_enable(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        local_enable: bool,
+        remote_enable: bool,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::LeSetTransmitPowerReportingEnable {
+                conn_handle,
+                local_enable,
+                remote_enable,
+            },
+        )
+        .await
+    }
+
+    async fn le_read_local_p256_public_key(&mut self) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeReadLocalP256PublicKey).await
+    }
+
+    async fn le_generate_dhkey(
+        &mut self,
+        remote_public_key: &P256PublicKey,
+    ) -> Result<(), Self::Error> {
+        write_command(self, &HciCommand::LeGenerateDhKey(remote_public_key)).await
+    }
+
+    async fn le_generate_dhkey_v2(
+        &mut self,
+        remote_public_key: &P256PublicKey,
+        private_key: DhKeyPrivateKey,
+    ) -> Result<(), Self::Error> {
+        write_command(
+            self,
+            &HciCommand::LeGenerateDhKeyV2 {
+                remote_public_key,
+                private_key,
+            },
+        )
+        .await
     }
+}
+
+/// The commands of [`HostHci`], as values.
+///
+/// Each variant holds the parameters of the [`HostHci`] function of the same name, and encodes them
+/// exactly as that function would write them to the controller. Encoding does not validate the
+/// parameters; use [`validate`](HciCommand::validate) to perform the same checks as the [`HostHci`]
+/// functions.
+///
+/// ```
+/// # extern crate stm32wb_hci as hci;
+/// use hci::command::Command;
+/// use hci::host::HciCommand;
+///
+/// let command = HciCommand::LeSetAdvertisingEnable(true);
+/// let mut buf = [0; 8];
+/// let len = command.encode_into(&mut buf);
+/// assert_eq!(command.opcode(), hci::opcode::LE_SET_ADVERTISE_ENABLE);
+/// assert_eq!(buf[..len], [1]);
+/// ```
+#[derive(Clone, Debug)]
+pub enum HciCommand<'a> {
+    /// See [`HostHci::disconnect`].
+    Disconnect {
+        /// Connection to disconnect.
+        conn_handle: ConnectionHandle,
+        /// Reason for the disconnection.
+        reason: Status,
+    },
+
+    /// See [`HostHci::read_remote_version_information`].
+    ReadRemoteVersionInformation(ConnectionHandle),
+
+    /// See [`HostHci::set_event_mask`].
+    SetEventMask(EventFlags),
+
+    /// See [`HostHci::reset`].
+    Reset,
+
+    /// See [`HostHci::read_tx_power_level`].
+    ReadTxPowerLevel {
+        /// Connection whose power level is read.
+        conn_handle: ConnectionHandle,
+        /// Current or maximum power level.
+        power_level_type: TxPowerLevel,
+    },
+
+    /// See [`HostHci::set_controller_to_host_flow_control`].
+    SetControllerToHostFlowControl(FlowControl),
+
+    /// See [`HostHci::host_buffer_size`].
+    HostBufferSize(&'a HostBufferSize),
+
+    /// See [`HostHci::number_of_completed_packets`].
+    NumberOfCompletedPackets(&'a NumberOfCompletedPackets),
+
+    /// See [`HostHci::set_event_mask_page_2`].
+    SetEventMaskPage2(EventFlagsPage2),
+
+    /// See [`HostHci::read_authenticated_payload_timeout`].
+    ReadAuthenticatedPayloadTimeout(ConnectionHandle),
+
+    /// See [`HostHci::write_authenticated_payload_timeout`].
+    WriteAuthenticatedPayloadTimeout {
+        /// Connection to configure.
+        conn_handle: ConnectionHandle,
+        /// New authenticated payload timeout.
+        timeout: AuthenticatedPayloadTimeout,
+    },
+
+    /// See [`HostHci::read_local_version_information`].
+    ReadLocalVersionInformation,
+
+    /// See [`HostHci::read_local_supported_commands`].
+    ReadLocalSupportedCommands,
+
+    /// See [`HostHci::read_local_supported_features`].
+    ReadLocalSupportedFeatures,
+
+    /// See [`HostHci::read_bd_addr`].
+    ReadBdAddr,
+
+    /// See [`HostHci::read_rssi`].
+    ReadRssi(ConnectionHandle),
+
+    /// See [`HostHci::le_set_event_mask`].
+    LeSetEventMask(LeEventFlags),
+
+    /// See [`HostHci::le_read_buffer_size`].
+    LeReadBufferSize,
+
+    /// See [`HostHci::le_read_local_supported_features`].
+    LeReadLocalSupportedFeatures,
+
+    /// See [`HostHci::le_set_random_address`].
+    LeSetRandomAddress(crate::BdAddr),
+
+    /// See [`HostHci::le_set_advertising_parameters`].
+    LeSetAdvertisingParameters(&'a AdvertisingParameters),
+
+    /// See [`HostHci::le_read_advertising_channel_tx_power`].
+    LeReadAdvertisingChannelTxPower,
+
+    /// See [`HostHci::le_set_advertising_data`].
+    LeSetAdvertisingData(&'a [u8]),
+
+    /// See [`HostHci::le_set_scan_response_data`].
+    LeSetScanResponseData(&'a [u8]),
+
+    /// See [`HostHci::le_set_advertising_enable`].
+    LeSetAdvertisingEnable(bool),
+
+    /// See [`HostHci::le_set_scan_parameters`].
+    LeSetScanParameters(&'a ScanParameters),
+
+    /// See [`HostHci::le_set_scan_enable`].
+    LeSetScanEnable {
+        /// Enables or disables scanning.
+        enable: bool,
+        /// Filters duplicate advertising reports.
+        filter_duplicates: bool,
+    },
+
+    /// See [`HostHci::le_create_connection`].
+    LeCreateConnection(&'a ConnectionParameters),
 
-    async fn le_read_supported_states(&mut self) {
-        self.controller_write(crate::opcode::LE_READ_STATES, &[])
-            .await;
+    /// See [`HostHci::le_create_connection_cancel`].
+    LeCreateConnectionCancel,
+
+    /// See [`HostHci::le_read_white_list_size`].
+    LeReadWhiteListSize,
+
+    /// See [`HostHci::le_clear_white_list`].
+    LeClearWhiteList,
+
+    /// See [`HostHci::le_add_device_to_white_list`].
+    LeAddDeviceToWhiteList(crate::BdAddrType),
+
+    /// See [`HostHci::le_add_anon_advertising_devices_to_white_list`].
+    LeAddAnonAdvertisingDevicesToWhiteList,
+
+    /// See [`HostHci::le_remove_device_from_white_list`].
+    LeRemoveDeviceFromWhiteList(crate::BdAddrType),
+
+    /// See [`HostHci::le_remove_anon_advertising_devices_from_white_list`].
+    LeRemoveAnonAdvertisingDevicesFromWhiteList,
+
+    /// See [`HostHci::le_connection_update`].
+    LeConnectionUpdate(&'a ConnectionUpdateParameters),
+
+    /// See [`HostHci::le_set_host_channel_classification`].
+    LeSetHostChannelClassification(crate::ChannelClassification),
+
+    /// See [`HostHci::le_read_channel_map`].
+    LeReadChannelMap(ConnectionHandle),
+
+    /// See [`HostHci::le_read_remote_used_features`].
+    LeReadRemoteUsedFeatures(ConnectionHandle),
+
+    /// See [`HostHci::le_encrypt`].
+    LeEncrypt(&'a AesParameters),
+
+    /// See [`HostHci::le_rand`].
+    LeRand,
+
+    /// See [`HostHci::le_start_encryption`].
+    LeStartEncryption(&'a EncryptionParameters),
+
+    /// See [`HostHci::le_long_term_key_request_reply`].
+    LeLongTermKeyRequestReply {
+        /// Connection that requested the key.
+        conn_handle: ConnectionHandle,
+        /// Long term key for the connection.
+        key: &'a EncryptionKey,
+    },
+
+    /// See [`HostHci::le_long_term_key_request_negative_reply`].
+    LeLongTermKeyRequestNegativeReply(ConnectionHandle),
+
+    /// See [`HostHci::le_read_supported_states`].
+    LeReadSupportedStates,
+
+    /// See [`HostHci::le_receiver_test`].
+    LeReceiverTest(u8),
+
+    /// See [`HostHci::le_transmitter_test`].
+    LeTransmitterTest {
+        /// Channel to transmit on.
+        channel: u8,
+        /// Length of the test payload.
+        payload_length: usize,
+        /// Contents of the test payload.
+        payload: TestPacketPayload,
+    },
+
+    /// See [`HostHci::le_test_end`].
+    LeTestEnd,
+
+    /// See [`HostHci::le_remote_connection_parameter_request_reply`].
+    LeRemoteConnectionParameterRequestReply(&'a ConnectionUpdateParameters),
+
+    /// See [`HostHci::le_remote_connection_parameter_request_negative_reply`].
+    LeRemoteConnectionParameterRequestNegativeReply {
+        /// Connection that requested the parameters.
+        conn_handle: ConnectionHandle,
+        /// Reason for rejecting the parameters.
+        reason: Status,
+    },
+
+    /// See [`HostHci::le_set_data_length`].
+    LeSetDataLength {
+        /// Connection to configure.
+        conn_handle: ConnectionHandle,
+        /// Preferred maximum number of payload octets per packet.
+        max_tx_octets: MaxTxOctets,
+        /// Preferred maximum time per packet.
+        max_tx_time: MaxTxTime,
+    },
+
+    /// See [`HostHci::le_read_suggested_default_data_length`].
+    LeReadSuggestedDefaultDataLength,
+
+    /// See [`HostHci::le_write_suggested_default_data_length`].
+    LeWriteSuggestedDefaultDataLength {
+        /// Suggested maximum number of payload octets per packet.
+        max_tx_octets: MaxTxOctets,
+        /// Suggested maximum time per packet.
+        max_tx_time: MaxTxTime,
+    },
+
+    /// See [`HostHci::le_read_maximum_data_length`].
+    LeReadMaximumDataLength,
+
+    /// See [`HostHci::le_read_phy`].
+    LeReadPhy(ConnectionHandle),
+
+    /// See [`HostHci::le_set_default_phy`].
+    LeSetDefaultPhy {
+        /// Preferred transmitter PHYs, or `None` if the host has no preference.
+        tx_phys: Option<PhyMask>,
+        /// Preferred receiver PHYs, or `None` if the host has no preference.
+        rx_phys: Option<PhyMask>,
+    },
+
+    /// See [`HostHci::le_set_phy`].
+    LeSetPhy {
+        /// Connection to change the PHYs of
+        conn_handle: ConnectionHandle,
+        /// Preferred transmitter PHYs, or `None` if the host has no preference.
+        tx_phys: Option<PhyMask>,
+        /// Preferred receiver PHYs, or `None` if the host has no preference.
+        rx_phys: Option<PhyMask>,
+        /// Preferred coding when transmitting on the LE Coded PHY
+        coded_phy_options: CodedPhyOptions,
+    },
+
+    /// See [`HostHci::le_set_extended_advertising_parameters`].
+    LeSetExtendedAdvertisingParameters(&'a ExtendedAdvertisingParameters),
+
+    /// See [`HostHci::le_set_extended_advertising_data`].
+    LeSetExtendedAdvertisingData(ExtendedAdvertisingData<'a>),
+
+    /// See [`HostHci::le_set_extended_scan_response_data`].
+    LeSetExtendedScanResponseData(ExtendedAdvertisingData<'a>),
+
+    /// See [`HostHci::le_set_extended_advertising_enable`].
+    LeSetExtendedAdvertisingEnable {
+        /// Enables or disables advertising.
+        enable: bool,
+        /// Advertising sets to enable or disable.
+        sets: &'a [AdvSet],
+    },
+
+    /// See [`HostHci::le_read_maximum_advertising_data_length`].
+    LeReadMaximumAdvertisingDataLength,
+
+    /// See [`HostHci::le_read_number_of_supported_advertising_sets`].
+    LeReadNumberOfSupportedAdvertisingSets,
+
+    /// See [`HostHci::le_remove_advertising_set`].
+    LeRemoveAdvertisingSet(crate::AdvertisingHandle),
+
+    /// See [`HostHci::le_clear_advertising_sets`].
+    LeClearAdvertisingSets,
+
+    /// See [`HostHci::le_set_periodic_advertising_parameters`].
+    LeSetPeriodicAdvertisingParameters(&'a PeriodicAdvertisingParameters),
+
+    /// See [`HostHci::le_set_periodic_advertising_data`].
+    LeSetPeriodicAdvertisingData(PeriodicAdvertisingData<'a>),
+
+    /// See [`HostHci::le_set_periodic_advertising_enable`].
+    LeSetPeriodicAdvertisingEnable {
+        /// Enables or disables periodic advertising.
+        enable: bool,
+        /// Advertising set to enable or disable.
+        adv_handle: crate::AdvertisingHandle,
+    },
+
+    /// See [`HostHci::le_set_extended_scan_parameters`].
+    LeSetExtendedScanParameters(&'a ExtendedScanParameters),
+
+    /// See [`HostHci::le_set_extended_scan_enable`].
+    LeSetExtendedScanEnable(&'a ExtendedScanEnable),
+
+    /// See [`HostHci::le_extended_create_connection`].
+    LeExtendedCreateConnection(&'a ExtendedConnectionParameters),
+
+    /// See [`HostHci::le_periodic_advertising_create_sync`].
+    LePeriodicAdvertisingCreateSync(&'a PeriodicAdvertisingSyncParameters),
+
+    /// See [`HostHci::le_periodic_advertising_create_sync_cancel`].
+    LePeriodicAdvertisingCreateSyncCancel,
+
+    /// See [`HostHci::le_periodic_advertising_terminate_sync`].
+    LePeriodicAdvertisingTerminateSync(crate::SyncHandle),
+
+    /// See [`HostHci::le_periodic_advertising_sync_transfer`].
+    LePeriodicAdvertisingSyncTransfer {
+        /// Connection to the peer that receives the synchronization information.
+        conn_handle: ConnectionHandle,
+        /// Value passed to the Host of the peer.
+        service_data: u16,
+        /// Periodic advertising to transfer.
+        sync_handle: crate::SyncHandle,
+    },
+
+    /// See [`HostHci::le_periodic_advertising_set_info_transfer`].
+    LePeriodicAdvertisingSetInfoTransfer {
+        /// Connection to the peer that receives the synchronization information.
+        conn_handle: ConnectionHandle,
+        /// Value passed to the Host of the peer.
+        service_data: u16,
+        /// Advertising set whose periodic advertising is transferred.
+        adv_handle: crate::AdvertisingHandle,
+    },
+
+    /// See [`HostHci::le_set_periodic_advertising_sync_transfer_parameters`].
+    LeSetPeriodicAdvertisingSyncTransferParameters {
+        /// Connection to configure.
+        conn_handle: ConnectionHandle,
+        /// How synchronization information received on the connection is processed.
+        params: &'a PeriodicAdvertisingSyncTransferParameters,
+    },
+
+    /// See [`HostHci::le_set_default_periodic_advertising_sync_transfer_parameters`].
+    LeSetDefaultPeriodicAdvertisingSyncTransferParameters(
+        &'a PeriodicAdvertisingSyncTransferParameters,
+    ),
+
+    /// See [`HostHci::le_add_device_to_resolving_list`].
+    LeAddDeviceToResolvingList(&'a ResolvingListEntry),
+
+    /// See [`HostHci::le_remove_device_from_resolving_list`].
+    LeRemoveDeviceFromResolvingList(crate::BdAddrType),
+
+    /// See [`HostHci::le_clear_resolving_list`].
+    LeClearResolvingList,
+
+    /// See [`HostHci::le_read_resolving_list_size`].
+    LeReadResolvingListSize,
+
+    /// See [`HostHci::le_read_peer_resolvable_address`].
+    LeReadPeerResolvableAddress(crate::BdAddrType),
+
+    /// See [`HostHci::le_read_local_resolvable_address`].
+    LeReadLocalResolvableAddress(crate::BdAddrType),
+
+    /// See [`HostHci::le_set_address_resolution_enable`].
+    LeSetAddressResolutionEnable(bool),
+
+    /// See [`HostHci::le_set_resolvable_private_address_timeout`].
+    LeSetResolvablePrivateAddressTimeout(Duration),
+
+    /// See [`HostHci::le_set_privacy_mode`].
+    LeSetPrivacyMode {
+        /// Identity address of the peer device in the resolving list
+        peer_identity_address: crate::BdAddrType,
+        /// Privacy mode to use for the peer device
+        privacy_mode: PrivacyMode,
+    },
+
+    /// See [`HostHci::le_enhanced_read_transmit_power_level`].
+    LeEnhancedReadTransmitPowerLevel {
+        /// Connection whose power levels are read.
+        conn_handle: ConnectionHandle,
+        /// PHY whose power levels are read.
+        phy: PowerControlPhy,
+    },
+
+    /// See [`HostHci::le_read_remote_transmit_power_level`].
+    LeReadRemoteTransmitPowerLevel {
+        /// Connection whose remote power level is read.
+        conn_handle: ConnectionHandle,
+        /// PHY whose power level is read.
+        phy: PowerControlPhy,
+    },
+
+    /// See [`HostHci::le_set_path_loss_reporting_parameters`].
+    LeSetPathLossReportingParameters {
+        /// Connection to configure.
+        conn_handle: ConnectionHandle,
+        /// Path loss thresholds.
+        params: &'a PathLossReportingParameters,
+    },
+
+    /// See [`HostHci::le_set_path_loss_reporting_enable`].
+    LeSetPathLossReportingEnable {
+        /// Connection to configure.
+        conn_handle: ConnectionHandle,
+        /// Whether path loss reporting is enabled.
+        enable: bool,
+    },
+
+    /// See [`HostHci::le_set_transmit_power_reporting_enable`].
+    LeSetTransmitPowerReportingEnable {
+        /// Connection to configure.
+        conn_handle: ConnectionHandle,
+        /// Whether changes to the local transmit power level are reported.
+        local_enable: bool,
+        /// Whether changes to the remote transmit power level are reported.
+        remote_enable: bool,
+    },
+
+    /// See [`HostHci::le_read_local_p256_public_key`].
+    LeReadLocalP256PublicKey,
+
+    /// See [`HostHci::le_generate_dhkey`].
+    LeGenerateDhKey(&'a P256PublicKey),
+
+    /// See [`HostHci::le_generate_dhkey_v2`].
+    LeGenerateDhKeyV2 {
+        /// P-256 public key of the remote device
+        remote_public_key: &'a P256PublicKey,
+        /// Private key used to compute the Diffie-Hellman key
+        private_key: DhKeyPrivateKey,
+    },
+}
+
+impl HciCommand<'_> {
+    /// Checks the parameters of the command, as the corresponding [`HostHci`] function does before
+    /// writing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as the corresponding [`HostHci`] function, except for communication
+    /// errors.
+    pub fn validate<E>(&self) -> Result<(), Error<E>> {
+        match *self {
+            HciCommand::Disconnect { reason, .. } => match reason {
+                Status::AuthFailure
+                | Status::RemoteTerminationByUser
+                | Status::RemoteTerminationLowResources
+                | Status::RemoteTerminationPowerOff
+                | Status::UnsupportedRemoteFeature
+                | Status::PairingWithUnitKeyNotSupported
+                | Status::UnacceptableConnectionParameters => Ok(()),
+                _ => Err(Error::BadDisconnectionReason(reason)),
+            },
+            HciCommand::LeSetRandomAddress(bd_addr) => validate_random_address(bd_addr),
+            HciCommand::LeSetAdvertisingParameters(params) => params.validate(),
+            HciCommand::LeSetAdvertisingData(data) | HciCommand::LeSetScanResponseData(data) => {
+                if data.len() > MAX_ADVERTISING_DATA_LENGTH {
+                    return Err(Error::AdvertisingDataTooLong(data.len()));
+                }
+
+                Ok(())
+            }
+            HciCommand::LeSetHostChannelClassification(channels) => {
+                if channels.is_empty() {
+                    return Err(Error::NoValidChannel);
+                }
+
+                Ok(())
+            }
+            HciCommand::LeReceiverTest(channel) => validate_test_channel(channel),
+            HciCommand::LeSetExtendedAdvertisingParameters(params) => params.validate(),
+            HciCommand::LeSetExtendedAdvertisingData(fragment)
+            | HciCommand::LeSetExtendedScanResponseData(fragment) => {
+                if fragment.data.len() > MAX_EXTENDED_ADVERTISING_FRAGMENT_LENGTH {
+                    return Err(Error::ExtendedAdvertisingDataTooLong(fragment.data.len()));
+                }
+
+                Ok(())
+            }
+            HciCommand::LeSetExtendedAdvertisingEnable { sets, .. } => {
+                if sets.len() > MAX_ADVERTISING_SETS {
+                    return Err(Error::TooManyAdvertisingSets(sets.len()));
+                }
+
+                Ok(())
+            }
+            HciCommand::LeSetExtendedScanParameters(params) => params.validate(),
+            HciCommand::LeSetExtendedScanEnable(params) => params.validate(),
+            HciCommand::LeSetPeriodicAdvertisingParameters(params) => params.validate(),
+            HciCommand::LeSetPeriodicAdvertisingData(fragment) => {
+                if fragment.data.len() > MAX_PERIODIC_ADVERTISING_FRAGMENT_LENGTH {
+                    return Err(Error::PeriodicAdvertisingDataTooLong(fragment.data.len()));
+                }
+
+                Ok(())
+            }
+            HciCommand::LeExtendedCreateConnection(params) => params.validate(),
+            HciCommand::LePeriodicAdvertisingCreateSync(params) => params.validate(),
+            HciCommand::LeSetPathLossReportingParameters { params, .. } => params.validate(),
+            HciCommand::LeSetPeriodicAdvertisingSyncTransferParameters { params, .. }
+            | HciCommand::LeSetDefaultPeriodicAdvertisingSyncTransferParameters(params) => {
+                params.validate()
+            }
+            HciCommand::LeSetDefaultPhy { tx_phys, rx_phys }
+            | HciCommand::LeSetPhy {
+                tx_phys, rx_phys, ..
+            } => {
+                for phys in [tx_phys, rx_phys].into_iter().flatten() {
+                    if phys.is_empty() {
+                        return Err(Error::EmptyPhyPreference);
+                    }
+                }
+
+                Ok(())
+            }
+            HciCommand::LeSetResolvablePrivateAddressTimeout(timeout) => {
+                const MIN_TIMEOUT: Duration = Duration::from_secs(1);
+                const MAX_TIMEOUT: Duration = Duration::from_secs(3600);
+                if !(MIN_TIMEOUT..=MAX_TIMEOUT).contains(&timeout) {
+                    return Err(Error::BadResolvablePrivateAddressTimeout(timeout));
+                }
+
+                Ok(())
+            }
+            HciCommand::LeTransmitterTest {
+                channel,
+                payload_length,
+                ..
+            } => {
+                validate_test_channel(channel)?;
+
+                const MAX_PAYLOAD_LENGTH: usize = 0x25;
+                if payload_length > MAX_PAYLOAD_LENGTH {
+                    return Err(Error::InvalidTestPayloadLength(payload_length));
+                }
+
+                Ok(())
+            }
+            _ => Ok(()),
+        }
     }
+}
 
-    async fn le_receiver_test(&mut self, channel: u8) -> Result<(), Error> {
-        if channel > MAX_TEST_CHANNEL {
-            return Err(Error::InvalidTestChannel(channel));
+impl Command for HciCommand<'_> {
+    fn opcode(&self) -> crate::opcode::Opcode {
+        match self {
+            HciCommand::Disconnect { .. } => crate::opcode::DISCONNECT,
+            HciCommand::ReadRemoteVersionInformation(_) => crate::opcode::READ_REMOTE_VERSION_INFO,
+            HciCommand::SetEventMask(_) => crate::opcode::SET_EVENT_MASK,
+            HciCommand::Reset => crate::opcode::RESET,
+            HciCommand::ReadTxPowerLevel { .. } => crate::opcode::READ_TX_POWER_LEVEL,
+            HciCommand::SetControllerToHostFlowControl(_) => {
+                crate::opcode::SET_CONTROLLER_TO_HOST_FLOW_CONTROL
+            }
+            HciCommand::HostBufferSize(_) => crate::opcode::HOST_BUFFER_SIZE,
+            HciCommand::NumberOfCompletedPackets(_) => crate::opcode::NUMBER_OF_COMPLETED_PACKETS,
+            HciCommand::SetEventMaskPage2(_) => crate::opcode::SET_EVENT_MASK_PAGE_2,
+            HciCommand::ReadAuthenticatedPayloadTimeout(_) => {
+                crate::opcode::READ_AUTHENTICATED_PAYLOAD_TIMEOUT
+            }
+            HciCommand::WriteAuthenticatedPayloadTimeout { .. } => {
+                crate::opcode::WRITE_AUTHENTICATED_PAYLOAD_TIMEOUT
+            }
+            HciCommand::ReadLocalVersionInformation => crate::opcode::READ_LOCAL_VERSION_INFO,
+            HciCommand::ReadLocalSupportedCommands => crate::opcode::READ_LOCAL_SUPPORTED_COMMANDS,
+            HciCommand::ReadLocalSupportedFeatures => crate::opcode::READ_LOCAL_SUPPORTED_FEATURES,
+            HciCommand::ReadBdAddr => crate::opcode::READ_BD_ADDR,
+            HciCommand::ReadRssi(_) => crate::opcode::READ_RSSI,
+            HciCommand::LeSetEventMask(_) => crate::opcode::LE_SET_EVENT_MASK,
+            HciCommand::LeReadBufferSize => crate::opcode::LE_READ_BUFFER_SIZE,
+            HciCommand::LeReadLocalSupportedFeatures => {
+                crate::opcode::LE_READ_LOCAL_SUPPORTED_FEATURES
+            }
+            HciCommand::LeSetRandomAddress(_) => crate::opcode::LE_SET_RANDOM_ADDRESS,
+            HciCommand::LeSetAdvertisingParameters(_) => {
+                crate::opcode::LE_SET_ADVERTISING_PARAMETERS
+            }
+            HciCommand::LeReadAdvertisingChannelTxPower => {
+                crate::opcode::LE_READ_ADVERTISING_CHANNEL_TX_POWER
+            }
+            HciCommand::LeSetAdvertisingData(_) => crate::opcode::LE_SET_ADVERTISING_DATA,
+            HciCommand::LeSetScanResponseData(_) => crate::opcode::LE_SET_SCAN_RESPONSE_DATA,
+            HciCommand::LeSetAdvertisingEnable(_) => crate::opcode::LE_SET_ADVERTISE_ENABLE,
+            HciCommand::LeSetScanParameters(_) => crate::opcode::LE_SET_SCAN_PARAMETERS,
+            HciCommand::LeSetScanEnable { .. } => crate::opcode::LE_SET_SCAN_ENABLE,
+            HciCommand::LeCreateConnection(_) => crate::opcode::LE_CREATE_CONNECTION,
+            HciCommand::LeCreateConnectionCancel => crate::opcode::LE_CREATE_CONNECTION_CANCEL,
+            HciCommand::LeReadWhiteListSize => crate::opcode::LE_READ_WHITE_LIST_SIZE,
+            HciCommand::LeClearWhiteList => crate::opcode::LE_CLEAR_WHITE_LIST,
+            HciCommand::LeAddDeviceToWhiteList(_)
+            | HciCommand::LeAddAnonAdvertisingDevicesToWhiteList => {
+                crate::opcode::LE_ADD_DEVICE_TO_WHITE_LIST
+            }
+            HciCommand::LeRemoveDeviceFromWhiteList(_)
+            | HciCommand::LeRemoveAnonAdvertisingDevicesFromWhiteList => {
+                crate::opcode::LE_REMOVE_DEVICE_FROM_WHITE_LIST
+            }
+            HciCommand::LeConnectionUpdate(_) => crate::opcode::LE_CONNECTION_UPDATE,
+            HciCommand::LeSetHostChannelClassification(_) => {
+                crate::opcode::LE_SET_HOST_CHANNEL_CLASSIFICATION
+            }
+            HciCommand::LeReadChannelMap(_) => crate::opcode::LE_READ_CHANNEL_MAP,
+            HciCommand::LeReadRemoteUsedFeatures(_) => crate::opcode::LE_READ_REMOTE_USED_FEATURES,
+            HciCommand::LeEncrypt(_) => crate::opcode::LE_ENCRYPT,
+            HciCommand::LeRand => crate::opcode::LE_RAND,
+            HciCommand::LeStartEncryption(_) => crate::opcode::LE_START_ENCRYPTION,
+            HciCommand::LeLongTermKeyRequestReply { .. } => crate::opcode::LE_LTK_REQUEST_REPLY,
+            HciCommand::LeLongTermKeyRequestNegativeReply(_) => {
+                crate::opcode::LE_LTK_REQUEST_NEGATIVE_REPLY
+            }
+            HciCommand::LeReadSupportedStates => crate::opcode::LE_READ_STATES,
+            HciCommand::LeReceiverTest(_) => crate::opcode::LE_RECEIVER_TEST,
+            HciCommand::LeTransmitterTest { .. } => crate::opcode::LE_TRANSMITTER_TEST,
+            HciCommand::LeTestEnd => crate::opcode::LE_TEST_END,
+            HciCommand::LeRemoteConnectionParameterRequestReply(_) => {
+                crate::opcode::LE_REMOTE_CONNECTION_PARAMETER_REQUEST_REPLY
+            }
+            HciCommand::LeRemoteConnectionParameterRequestNegativeReply { .. } => {
+                crate::opcode::LE_REMOTE_CONNECTION_PARAMETER_REQUEST_NEGATIVE_REPLY
+            }
+            HciCommand::LeSetDataLength { .. } => crate::opcode::LE_SET_DATA_LENGTH,
+            HciCommand::LeReadSuggestedDefaultDataLength => {
+                crate::opcode::LE_READ_SUGGESTED_DEFAULT_DATA_LENGTH
+            }
+            HciCommand::LeWriteSuggestedDefaultDataLength { .. } => {
+                crate::opcode::LE_WRITE_SUGGESTED_DEFAULT_DATA_LENGTH
+            }
+            HciCommand::LeReadMaximumDataLength => crate::opcode::LE_READ_MAXIMUM_DATA_LENGTH,
+            HciCommand::LeReadPhy(_) => crate::opcode::LE_READ_PHY,
+            HciCommand::LeSetDefaultPhy { .. } => crate::opcode::LE_SET_DEFAULT_PHY,
+            HciCommand::LeSetPhy { .. } => crate::opcode::LE_SET_PHY,
+            HciCommand::LeSetExtendedAdvertisingParameters(_) => {
+                crate::opcode::LE_SET_EXTENDED_ADVERTISING_PARAMETERS
+            }
+            HciCommand::LeSetExtendedAdvertisingData(_) => {
+                crate::opcode::LE_SET_EXTENDED_ADVERTISING_DATA
+            }
+            HciCommand::LeSetExtendedScanResponseData(_) => {
+                crate::opcode::LE_SET_EXTENDED_SCAN_RESPONSE_DATA
+            }
+            HciCommand::LeSetExtendedAdvertisingEnable { .. } => {
+                crate::opcode::LE_SET_EXTENDED_ADVERTISING_ENABLE
+            }
+            HciCommand::LeReadMaximumAdvertisingDataLength => {
+                crate::opcode::LE_READ_MAXIMUM_ADVERTISING_DATA_LENGTH
+            }
+            HciCommand::LeReadNumberOfSupportedAdvertisingSets => {
+                crate::opcode::LE_READ_NUMBER_OF_SUPPORTED_ADVERTISING_SETS
+            }
+            HciCommand::LeRemoveAdvertisingSet(_) => crate::opcode::LE_REMOVE_ADVERTISING_SET,
+            HciCommand::LeClearAdvertisingSets => crate::opcode::LE_CLEAR_ADVERTISING_SETS,
+            HciCommand::LeSetPeriodicAdvertisingParameters(_) => {
+                crate::opcode::LE_SET_PERIODIC_ADVERTISING_PARAMETERS
+            }
+            HciCommand::LeSetPeriodicAdvertisingData(_) => {
+                crate::opcode::LE_SET_PERIODIC_ADVERTISING_DATA
+            }
+            HciCommand::LeSetPeriodicAdvertisingEnable { .. } => {
+                crate::opcode::LE_SET_PERIODIC_ADVERTISING_ENABLE
+            }
+            HciCommand::LeSetExtendedScanParameters(_) => {
+                crate::opcode::LE_SET_EXTENDED_SCAN_PARAMETERS
+            }
+            HciCommand::LeSetExtendedScanEnable(_) => crate::opcode::LE_SET_EXTENDED_SCAN_ENABLE,
+            HciCommand::LeExtendedCreateConnection(_) => {
+                crate::opcode::LE_EXTENDED_CREATE_CONNECTION
+            }
+            HciCommand::LePeriodicAdvertisingCreateSync(_) => {
+                crate::opcode::LE_PERIODIC_ADVERTISING_CREATE_SYNC
+            }
+            HciCommand::LePeriodicAdvertisingCreateSyncCancel => {
+                crate::opcode::LE_PERIODIC_ADVERTISING_CREATE_SYNC_CANCEL
+            }
+            HciCommand::LePeriodicAdvertisingTerminateSync(_) => {
+                crate::opcode::LE_PERIODIC_ADVERTISING_TERMINATE_SYNC
+            }
+            HciCommand::LePeriodicAdvertisingSyncTransfer { .. } => {
+                crate::opcode::LE_PERIODIC_ADVERTISING_SYNC_TRANSFER
+            }
+            HciCommand::LePeriodicAdvertisingSetInfoTransfer { .. } => {
+                crate::opcode::LE_PERIODIC_ADVERTISING_SET_INFO_TRANSFER
+            }
+            HciCommand::LeSetPeriodicAdvertisingSyncTransferParameters { .. } => {
+                crate::opcode::LE_SET_PERIODIC_ADVERTISING_SYNC_TRANSFER_PARAMETERS
+            }
+            HciCommand::LeSetDefaultPeriodicAdvertisingSyncTransferParameters(_) => {
+                crate::opcode::LE_SET_DEFAULT_PERIODIC_ADVERTISING_SYNC_TRANSFER_PARAMETERS
+            }
+            HciCommand::LeAddDeviceToResolvingList(_) => {
+                crate::opcode::LE_ADD_DEVICE_TO_RESOLVING_LIST
+            }
+            HciCommand::LeRemoveDeviceFromResolvingList(_) => {
+                crate::opcode::LE_REMOVE_DEVICE_FROM_RESOLVING_LIST
+            }
+            HciCommand::LeClearResolvingList => crate::opcode::LE_CLEAR_RESOLVING_LIST,
+            HciCommand::LeReadResolvingListSize => crate::opcode::LE_READ_RESOLVING_LIST_SIZE,
+            HciCommand::LeReadPeerResolvableAddress(_) => {
+                crate::opcode::LE_READ_PEER_RESOLVABLE_ADDRESS
+            }
+            HciCommand::LeReadLocalResolvableAddress(_) => {
+                crate::opcode::LE_READ_LOCAL_RESOLVABLE_ADDRESS
+            }
+            HciCommand::LeSetAddressResolutionEnable(_) => {
+                crate::opcode::LE_SET_ADDRESS_RESOLUTION_ENABLE
+            }
+            HciCommand::LeSetResolvablePrivateAddressTimeout(_) => {
+                crate::opcode::LE_SET_RESOLVABLE_PRIVATE_ADDRESS_TIMEOUT
+            }
+            HciCommand::LeSetPrivacyMode { .. } => crate::opcode::LE_SET_PRIVACY_MODE,
+            HciCommand::LeEnhancedReadTransmitPowerLevel { .. } => {
+                crate::opcode::LE_ENHANCED_READ_TRANSMIT_POWER_LEVEL
+            }
+            HciCommand::LeReadRemoteTransmitPowerLevel { .. } => {
+                crate::opcode::LE_READ_REMOTE_TRANSMIT_POWER_LEVEL
+            }
+            HciCommand::LeSetPathLossReportingParameters { .. } => {
+                crate::opcode::LE_SET_PATH_LOSS_REPORTING_PARAMETERS
+            }
+            HciCommand::LeSetPathLossReportingEnable { .. } => {
+                crate::opcode::LE_SET_PATH_LOSS_REPORTING_ENABLE
+            }
+            HciCommand::LeSetTransmitPowerReportingEnable { .. } => {
+                crate::opcode::LE_SET_TRANSMIT_POWER_REPORTING_ENABLE
+            }
+            HciCommand::LeReadLocalP256PublicKey => crate::opcode::LE_READ_LOCAL_P256_PUBLIC_KEY,
+            HciCommand::LeGenerateDhKey(_) => crate::opcode::LE_GENERATE_DHKEY,
+            HciCommand::LeGenerateDhKeyV2 { .. } => crate::opcode::LE_GENERATE_DHKEY_V2,
         }
-
-        self.controller_write(crate::opcode::LE_RECEIVER_TEST, &[channel])
-            .await;
-
-        Ok(())
     }
 
-    async fn le_transmitter_test(
-        &mut self,
-        channel: u8,
-        payload_length: usize,
-        payload: TestPacketPayload,
-    ) -> Result<(), Error> {
-        if channel > MAX_TEST_CHANNEL {
-            return Err(Error::InvalidTestChannel(channel));
-        }
-
-        const MAX_PAYLOAD_LENGTH: usize = 0x25;
-        if payload_length > MAX_PAYLOAD_LENGTH {
-            return Err(Error::InvalidTestPayloadLength(payload_length));
+    fn encode_into(&self, buf: &mut [u8]) -> usize {
+        match *self {
+            HciCommand::Disconnect {
+                conn_handle,
+                reason,
+            } => {
+                LittleEndian::write_u16(&mut buf[0..], conn_handle.0);
+                buf[2] = reason.into();
+                3
+            }
+            HciCommand::ReadRemoteVersionInformation(conn_handle)
+            | HciCommand::ReadRssi(conn_handle)
+            | HciCommand::LeReadChannelMap(conn_handle)
+            | HciCommand::LeReadRemoteUsedFeatures(conn_handle)
+            | HciCommand::LeLongTermKeyRequestNegativeReply(conn_handle)
+            | HciCommand::ReadAuthenticatedPayloadTimeout(conn_handle) => {
+                LittleEndian::write_u16(&mut buf[0..], conn_handle.0);
+                2
+            }
+            HciCommand::SetEventMask(mask) => {
+                LittleEndian::write_u64(&mut buf[0..], mask.bits());
+                8
+            }
+            HciCommand::SetEventMaskPage2(mask) => {
+                LittleEndian::write_u64(&mut buf[0..], mask.bits());
+                8
+            }
+            HciCommand::WriteAuthenticatedPayloadTimeout {
+                conn_handle,
+                timeout,
+            } => {
+                LittleEndian::write_u16(&mut buf[0..], conn_handle.0);
+                timeout.copy_into_slice(&mut buf[2..4]);
+                4
+            }
+            HciCommand::ReadTxPowerLevel {
+                conn_handle,
+                power_level_type,
+            } => {
+                LittleEndian::write_u16(&mut buf[0..], conn_handle.0);
+                buf[2] = power_level_type as u8;
+                3
+            }
+            HciCommand::SetControllerToHostFlowControl(flow_control) => {
+                buf[0] = flow_control as u8;
+                1
+            }
+            HciCommand::HostBufferSize(params) => {
+                params.copy_into_slice(&mut buf[..6]);
+                6
+            }
+            HciCommand::NumberOfCompletedPackets(params) => {
+                buf[0] = params.num_handles as u8;
+                buf[1..=NUMBER_OF_COMPLETED_PACKETS_MAX_LEN].copy_from_slice(&params.data_buf);
+                NUMBER_OF_COMPLETED_PACKETS_MAX_LEN + 1
+            }
+            HciCommand::LeSetEventMask(event_mask) => {
+                LittleEndian::write_u64(&mut buf[0..], event_mask.bits());
+                8
+            }
+            HciCommand::LeSetRandomAddress(bd_addr) => {
+                buf[..6].copy_from_slice(&bd_addr.0);
+                6
+            }
+            HciCommand::LeSetAdvertisingParameters(params) => {
+                params.copy_into_slice(&mut buf[..15]);
+                15
+            }
+            HciCommand::LeSetAdvertisingData(data) | HciCommand::LeSetScanResponseData(data) => {
+                // The data is always sent in a 31-byte field, padded with zeros.
+                let buf = crate::command::zeroed(buf, MAX_ADVERTISING_DATA_LENGTH + 1);
+                buf[0] = data.len() as u8;
+                buf[1..=data.len()].copy_from_slice(data);
+                MAX_ADVERTISING_DATA_LENGTH + 1
+            }
+            HciCommand::LeSetAdvertisingEnable(enable) => {
+                buf[0] = enable as u8;
+                1
+            }
+            HciCommand::LeSetScanParameters(params) => {
+                params.copy_into_slice(&mut buf[..7]);
+                7
+            }
+            HciCommand::LeSetScanEnable {
+                enable,
+                filter_duplicates,
+            } => {
+                buf[0] = enable as u8;
+                buf[1] = filter_duplicates as u8;
+                2
+            }
+            HciCommand::LeCreateConnection(params) => {
+                params.copy_into_slice(&mut buf[..25]);
+                25
+            }
+            HciCommand::LeAddDeviceToWhiteList(addr)
+            | HciCommand::LeRemoveDeviceFromWhiteList(addr) => {
+                addr.copy_into_slice(&mut buf[..7]);
+                7
+            }
+            HciCommand::LeAddAnonAdvertisingDevicesToWhiteList
+            | HciCommand::LeRemoveAnonAdvertisingDevicesFromWhiteList => {
+                buf[..7].copy_from_slice(&[0xFF, 0, 0, 0, 0, 0, 0]);
+                7
+            }
+            HciCommand::LeConnectionUpdate(params)
+            | HciCommand::LeRemoteConnectionParameterRequestReply(params) => {
+                params.copy_into_slice(&mut buf[..14]);
+                14
+            }
+            HciCommand::LeRemoteConnectionParameterRequestNegativeReply {
+                conn_handle,
+                reason,
+            } => {
+                LittleEndian::write_u16(&mut buf[0..], conn_handle.0);
+                buf[2] = reason.into();
+                3
+            }
+            HciCommand::LeSetHostChannelClassification(channels) => {
+                channels.copy_into_slice(&mut buf[..5]);
+                5
+            }
+            HciCommand::LeEncrypt(params) => {
+                buf[..16].copy_from_slice(&params.key.0);
+                buf[16..32].copy_from_slice(&params.plaintext_data.0);
+                32
+            }
+            HciCommand::LeStartEncryption(params) => {
+                LittleEndian::write_u16(&mut buf[0..], params.conn_handle.0);
+                LittleEndian::write_u64(&mut buf[2..], params.random_number);
+                LittleEndian::write_u16(&mut buf[10..], params.encrypted_diversifier);
+                buf[12..28].copy_from_slice(&params.long_term_key.0);
+                28
+            }
+            HciCommand::LeLongTermKeyRequestReply { conn_handle, key } => {
+                LittleEndian::write_u16(&mut buf[0..], conn_handle.0);
+                buf[2..18].copy_from_slice(&key.0);
+                18
+            }
+            HciCommand::LeReceiverTest(channel) => {
+                buf[0] = channel;
+                1
+            }
+            HciCommand::LeTransmitterTest {
+                channel,
+                payload_length,
+                payload,
+            } => {
+                buf[0] = channel;
+                buf[1] = payload_length as u8;
+                buf[2] = payload as u8;
+                3
+            }
+            HciCommand::LeSetDataLength {
+                conn_handle,
+                max_tx_octets,
+                max_tx_time,
+            } => {
+                LittleEndian::write_u16(&mut buf[0..], conn_handle.0);
+                LittleEndian::write_u16(&mut buf[2..], max_tx_octets.value());
+                LittleEndian::write_u16(&mut buf[4..], max_tx_time.as_micros());
+                6
+            }
+            HciCommand::LeWriteSuggestedDefaultDataLength {
+                max_tx_octets,
+                max_tx_time,
+            } => {
+                LittleEndian::write_u16(&mut buf[0..], max_tx_octets.value());
+                LittleEndian::write_u16(&mut buf[2..], max_tx_time.as_micros());
+                4
+            }
+            HciCommand::LeReadPhy(conn_handle) => {
+                LittleEndian::write_u16(buf, conn_handle.0);
+                2
+            }
+            HciCommand::LeSetDefaultPhy { tx_phys, rx_phys } => {
+                copy_phy_preferences(tx_phys, rx_phys, &mut buf[..3]);
+                3
+            }
+            HciCommand::LeSetPhy {
+                conn_handle,
+                tx_phys,
+                rx_phys,
+                coded_phy_options,
+            } => {
+                LittleEndian::write_u16(buf, conn_handle.0);
+                copy_phy_preferences(tx_phys, rx_phys, &mut buf[2..5]);
+                LittleEndian::write_u16(&mut buf[5..], coded_phy_options as u16);
+                7
+            }
+            HciCommand::LeSetExtendedAdvertisingParameters(params) => {
+                params.copy_into_slice(&mut buf[..ExtendedAdvertisingParameters::LENGTH]);
+                ExtendedAdvertisingParameters::LENGTH
+            }
+            HciCommand::LeSetExtendedAdvertisingData(fragment)
+            | HciCommand::LeSetExtendedScanResponseData(fragment) => fragment.copy_into_slice(buf),
+            HciCommand::LeSetExtendedAdvertisingEnable { enable, sets } => {
+                buf[0] = enable as u8;
+                buf[1] = sets.len() as u8;
+                for (set, bytes) in sets.iter().zip(buf[2..].chunks_mut(4)) {
+                    set.copy_into_slice(bytes);
+                }
+                2 + 4 * sets.len()
+            }
+            HciCommand::LeRemoveAdvertisingSet(adv_handle) => {
+                buf[0] = adv_handle.0;
+                1
+            }
+            HciCommand::LeSetExtendedScanParameters(params) => params.copy_into_slice(buf),
+            HciCommand::LeSetExtendedScanEnable(params) => {
+                params.copy_into_slice(&mut buf[..6]);
+                6
+            }
+            HciCommand::LeSetPeriodicAdvertisingParameters(params) => {
+                params.copy_into_slice(&mut buf[..7]);
+                7
+            }
+            HciCommand::LeSetPeriodicAdvertisingData(fragment) => fragment.copy_into_slice(buf),
+            HciCommand::LeSetPeriodicAdvertisingEnable { enable, adv_handle } => {
+                buf[0] = enable as u8;
+                buf[1] = adv_handle.0;
+                2
+            }
+            HciCommand::LeExtendedCreateConnection(params) => params.copy_into_slice(buf),
+            HciCommand::LePeriodicAdvertisingCreateSync(params) => {
+                params.copy_into_slice(&mut buf[..14]);
+                14
+            }
+            HciCommand::LePeriodicAdvertisingTerminateSync(sync_handle) => {
+                LittleEndian::write_u16(buf, sync_handle.0);
+                2
+            }
+            HciCommand::LePeriodicAdvertisingSyncTransfer {
+                conn_handle,
+                service_data,
+                sync_handle,
+            } => {
+                LittleEndian::write_u16(&mut buf[0..], conn_handle.0);
+                LittleEndian::write_u16(&mut buf[2..], service_data);
+                LittleEndian::write_u16(&mut buf[4..], sync_handle.0);
+                6
+            }
+            HciCommand::LePeriodicAdvertisingSetInfoTransfer {
+                conn_handle,
+                service_data,
+                adv_handle,
+            } => {
+                LittleEndian::write_u16(&mut buf[0..], conn_handle.0);
+                LittleEndian::write_u16(&mut buf[2..], service_data);
+                buf[4] = adv_handle.0;
+                5
+            }
+            HciCommand::LeSetPeriodicAdvertisingSyncTransferParameters {
+                conn_handle,
+                params,
+            } => {
+                LittleEndian::write_u16(buf, conn_handle.0);
+                params.copy_into_slice(&mut buf[2..8]);
+                8
+            }
+            HciCommand::LeSetDefaultPeriodicAdvertisingSyncTransferParameters(params) => {
+                params.copy_into_slice(&mut buf[..6]);
+                6
+            }
+            HciCommand::LeAddDeviceToResolvingList(entry) => {
+                entry.copy_into_slice(&mut buf[..39]);
+                39
+            }
+            HciCommand::LeRemoveDeviceFromResolvingList(addr)
+            | HciCommand::LeReadPeerResolvableAddress(addr)
+            | HciCommand::LeReadLocalResolvableAddress(addr) => {
+                addr.copy_into_slice(&mut buf[..7]);
+                7
+            }
+            HciCommand::LeSetAddressResolutionEnable(enable) => {
+                buf[0] = enable as u8;
+                1
+            }
+            HciCommand::LeSetResolvablePrivateAddressTimeout(timeout) => {
+                LittleEndian::write_u16(buf, timeout.as_secs() as u16);
+                2
+            }
+            HciCommand::LeSetPrivacyMode {
+                peer_identity_address,
+                privacy_mode,
+            } => {
+                peer_identity_address.copy_into_slice(&mut buf[..7]);
+                buf[7] = privacy_mode as u8;
+                8
+            }
+            HciCommand::LeEnhancedReadTransmitPowerLevel { conn_handle, phy }
+            | HciCommand::LeReadRemoteTransmitPowerLevel { conn_handle, phy } => {
+                LittleEndian::write_u16(&mut buf[0..], conn_handle.0);
+                buf[2] = phy as u8;
+                3
+            }
+            HciCommand::LeSetPathLossReportingParameters {
+                conn_handle,
+                params,
+            } => {
+                LittleEndian::write_u16(&mut buf[0..], conn_handle.0);
+                params.copy_into_slice(&mut buf[2..8]);
+                8
+            }
+            HciCommand::LeSetPathLossReportingEnable {
+                conn_handle,
+                enable,
+            } => {
+                LittleEndian::write_u16(&mut buf[0..], conn_handle.0);
+                buf[2] = enable as u8;
+                3
+            }
+            HciCommand::LeSetTransmitPowerReportingEnable {
+                conn_handle,
+                local_enable,
+                remote_enable,
+            } => {
+                LittleEndian::write_u16(&mut buf[0..], conn_handle.0);
+                buf[2] = local_enable as u8;
+                buf[3] = remote_enable as u8;
+                4
+            }
+            HciCommand::LeGenerateDhKey(remote_public_key) => {
+                buf[..64].copy_from_slice(&remote_public_key.0);
+                64
+            }
+            HciCommand::LeGenerateDhKeyV2 {
+                remote_public_key,
+                private_key,
+            } => {
+                buf[..64].copy_from_slice(&remote_public_key.0);
+                buf[64] = private_key as u8;
+                65
+            }
+            HciCommand::Reset
+            | HciCommand::ReadLocalVersionInformation
+            | HciCommand::ReadLocalSupportedCommands
+            | HciCommand::ReadLocalSupportedFeatures
+            | HciCommand::ReadBdAddr
+            | HciCommand::LeReadBufferSize
+            | HciCommand::LeReadLocalSupportedFeatures
+            | HciCommand::LeReadAdvertisingChannelTxPower
+            | HciCommand::LeCreateConnectionCancel
+            | HciCommand::LeReadWhiteListSize
+            | HciCommand::LeClearWhiteList
+            | HciCommand::LeRand
+            | HciCommand::LeReadSupportedStates
+            | HciCommand::LeTestEnd
+            | HciCommand::LeReadSuggestedDefaultDataLength
+            | HciCommand::LeReadMaximumDataLength
+            | HciCommand::LeReadMaximumAdvertisingDataLength
+            | HciCommand::LeReadNumberOfSupportedAdvertisingSets
+            | HciCommand::LeClearAdvertisingSets
+            | HciCommand::LePeriodicAdvertisingCreateSyncCancel
+            | HciCommand::LeClearResolvingList
+            | HciCommand::LeReadResolvingListSize
+            | HciCommand::LeReadLocalP256PublicKey => 0,
         }
+    }
+}
 
-        self.controller_write(
-            crate::opcode::LE_TRANSMITTER_TEST,
-            &[channel, payload_length as u8, payload as u8],
-        )
-        .await;
+const MAX_ADVERTISING_DATA_LENGTH: usize = 31;
 
-        Ok(())
-    }
+const MAX_EXTENDED_ADVERTISING_FRAGMENT_LENGTH: usize = 251;
 
-    async fn le_test_end(&mut self) {
-        self.controller_write(crate::opcode::LE_TEST_END, &[]).await;
-    }
-    async fn le_set_data_length(&mut self, conn_handle: ConnectionHandle, max_tx_octets: u16, max_tx_time: u16) {
-        let mut bytes = [0; 6];
-        LittleEndian::write_u16(&mut bytes[0..], conn_handle.0);
-        LittleEndian::write_u16(&mut bytes[2..], max_tx_octets);
-        LittleEndian::write_u16(&mut bytes[4..], max_tx_time);
-        self.controller_write(crate::opcode::LE_SET_DATA_LENGTH, &bytes)
-            .await;
-    }
+const MAX_PERIODIC_ADVERTISING_FRAGMENT_LENGTH: usize = 252;
 
-    async fn le_write_suggested_default_data_length(&mut self, max_tx_octets: u16, max_tx_time: u16) {
-        let mut bytes = [0; 4];
-        LittleEndian::write_u16(&mut bytes[0..], max_tx_octets);
-        LittleEndian::write_u16(&mut bytes[2..], max_tx_time);
-        self.controller_write(crate::opcode::LE_WRITE_SUGGESTED_DEFAULT_DATA_LENGTH, &bytes)
-            .await;
-    }
+const MAX_ADVERTISING_SETS: usize = 0x3F;
 
-    async fn le_set_default_phy(&mut self, all_phys: u8, tx_phys: u8, rx_phys: u8) {
-        let mut bytes = [0; 3];
-        bytes[0] = all_phys;
-        bytes[1] = tx_phys;
-        bytes[2] = rx_phys;
-        self.controller_write(crate::opcode::LE_SET_DEFAULT_PHY, &bytes)
-            .await;
+fn validate_test_channel<E>(channel: u8) -> Result<(), Error<E>> {
+    if channel > MAX_TEST_CHANNEL {
+        return Err(Error::InvalidTestChannel(channel));
     }
 
+    Ok(())
 }
 
 const MAX_TEST_CHANNEL: u8 = 0x27;
@@ -1561,7 +4260,7 @@ const MAX_TEST_CHANNEL: u8 = 0x27;
 #[cfg(not(feature = "defmt"))]
 bitflags::bitflags! {
     /// Event flags defined for the [`set_event_mask`](HostHci::set_event_mask) command.
-    #[derive(Default)]
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
     pub struct EventFlags : u64 {
         /// Inquiry complete event
         const INQUIRY_COMPLETE = 0x0000_0000_0000_0001;
@@ -1772,6 +4471,28 @@ defmt::bitflags! {
     }
 }
 
+#[cfg(not(feature = "defmt"))]
+bitflags::bitflags! {
+    /// Event flags defined for the [`set_event_mask_page_2`](HostHci::set_event_mask_page_2)
+    /// command.
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct EventFlagsPage2 : u64 {
+        /// Authenticated payload timeout expired event
+        const AUTHENTICATED_PAYLOAD_TIMEOUT_EXPIRED = 0x0000_0000_0080_0000;
+    }
+}
+
+#[cfg(feature = "defmt")]
+defmt::bitflags! {
+    /// Event flags defined for the [`set_event_mask_page_2`](HostHci::set_event_mask_page_2)
+    /// command.
+    #[derive(Default)]
+    pub struct EventFlagsPage2 : u64 {
+        /// Authenticated payload timeout expired event
+        const AUTHENTICATED_PAYLOAD_TIMEOUT_EXPIRED = 0x0000_0000_0080_0000;
+    }
+}
+
 /// For the [`read_tx_power_level`](HostHci::read_tx_power_level) command, the allowed values for the
 /// type of power level to read.
 ///
@@ -1861,7 +4582,7 @@ impl HostBufferSize {
 #[cfg(not(feature = "defmt"))]
 bitflags::bitflags! {
     /// Event flags defined for the [`le_set_event_mask`](HostHci::le_set_event_mask) command.
-    #[derive(Default)]
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
     pub struct LeEventFlags : u64 {
         /// LE connection complete event
         const CONNECTION_COMPLETE = 1 << 0;
@@ -1903,6 +4624,12 @@ bitflags::bitflags! {
         const SCAN_REQUEST_RECEIVED = 1 << 18;
         /// LE channel selection algorithm event
         const CHANNEL_SELECTION_ALGORITHM = 1 << 19;
+        /// LE periodic advertising sync transfer received event
+        const PERIODIC_ADVERTISING_SYNC_TRANSFER_RECEIVED = 1 << 23;
+        /// LE path loss threshold event
+        const PATH_LOSS_THRESHOLD = 1 << 31;
+        /// LE transmit power reporting event
+        const TRANSMIT_POWER_REPORTING = 1 << 32;
     }
 }
 
@@ -1951,10 +4678,16 @@ defmt::bitflags! {
         const SCAN_REQUEST_RECEIVED = 1 << 18;
         /// LE channel selection algorithm event
         const CHANNEL_SELECTION_ALGORITHM = 1 << 19;
+        /// LE periodic advertising sync transfer received event
+        const PERIODIC_ADVERTISING_SYNC_TRANSFER_RECEIVED = 1 << 23;
+        /// LE path loss threshold event
+        const PATH_LOSS_THRESHOLD = 1 << 31;
+        /// LE transmit power reporting event
+        const TRANSMIT_POWER_REPORTING = 1 << 32;
     }
 }
 
-fn validate_random_address(bd_addr: crate::BdAddr) -> Result<(), Error> {
+fn validate_random_address<E>(bd_addr: crate::BdAddr) -> Result<(), Error<E>> {
     let (pop_count, bit_count) = match (bd_addr.0[5] & 0b1100_0000) >> 6 {
         0b00 | 0b11 => (pop_count_except_top_2_bits(&bd_addr.0[0..]), 46),
         0b10 => (pop_count_except_top_2_bits(&bd_addr.0[3..]), 22),
@@ -2018,20 +4751,22 @@ pub struct AdvertisingParameters {
 }
 
 impl AdvertisingParameters {
-    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<(), Error> {
-        assert_eq!(bytes.len(), 15);
-
+    fn validate<E>(&self) -> Result<(), Error<E>> {
         if self.advertising_channel_map.is_empty() {
             return Err(Error::BadChannelMap(self.advertising_channel_map));
         }
 
+        Ok(())
+    }
+
+    fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), 15);
+
         self.advertising_interval.copy_into_slice(&mut bytes[0..5]);
         bytes[5] = self.own_address_type as u8;
         self.peer_address.copy_into_slice(&mut bytes[6..13]);
         bytes[13] = self.advertising_channel_map.bits();
         bytes[14] = self.advertising_filter_policy as u8;
-
-        Ok(())
     }
 }
 
@@ -2106,34 +4841,546 @@ pub enum AdvertisingFilterPolicy {
     WhiteListConnectionAndScan = 0x03,
 }
 
-/// Parameters for the [`le_set_scan_parameters`](HostHci::le_set_scan_parameters) command.
+/// Parameters for the
+/// [`le_set_extended_advertising_parameters`](HostHci::le_set_extended_advertising_parameters)
+/// command.
+#[derive(Clone, Debug)]
+pub struct ExtendedAdvertisingParameters {
+    /// Advertising set to configure.
+    pub adv_handle: crate::AdvertisingHandle,
+
+    /// Type of advertising event.
+    pub adv_event_properties: AdvertisingEvent,
+
+    /// Advertising interval on the primary advertising channels.
+    pub primary_adv_interval: ExtendedAdvertisingInterval,
+
+    /// Bit field that indicates the primary advertising channels that shall be used when
+    /// transmitting advertising packets. At least one channel bit shall be set in the bitfield.
+    pub primary_adv_channel_map: Channels,
+
+    /// Indicates the type of address being used in the advertising packets.
+    pub own_address_type: OwnAddressType,
+
+    /// Address of the peer for directed advertising, or the peer's identity address used to
+    /// locate the local IRK in the resolving list.
+    pub peer_address: crate::BdAddrType,
+
+    /// This parameter shall be ignored when directed advertising is enabled.
+    pub adv_filter_policy: AdvertisingFilterPolicy,
+
+    /// Maximum transmit power for the advertising packets, in dBm. The Controller reports the
+    /// power it selected in the Command Complete event. `None` indicates that the Host has no
+    /// preference.
+    pub adv_tx_power: Option<i8>,
+
+    /// PHY used on the primary advertising channels. LE 2M is not allowed.
+    pub primary_adv_phy: AdvertisingPhy,
+
+    /// Maximum number of advertising events that can be skipped before the `AUX_ADV_IND` PDU is
+    /// sent on the secondary advertising channel.
+    pub secondary_adv_max_skip: u8,
+
+    /// PHY used on the secondary advertising channel.
+    pub secondary_adv_phy: AdvertisingPhy,
+
+    /// Value of the Advertising SID subfield in the ADI field of the PDU. Range: 0x00 - 0x0F.
+    pub adv_sid: u8,
+
+    /// Enables the LE Scan Request Received event when a scan request is received.
+    pub scan_request_notification_enable: bool,
+}
+
+impl ExtendedAdvertisingParameters {
+    const LENGTH: usize = 25;
+
+    fn validate<E>(&self) -> Result<(), Error<E>> {
+        if self.primary_adv_channel_map.is_empty() {
+            return Err(Error::BadChannelMap(self.primary_adv_channel_map));
+        }
+
+        if self.primary_adv_phy == AdvertisingPhy::Le2M {
+            return Err(Error::BadPrimaryAdvertisingPhy(self.primary_adv_phy));
+        }
+
+        const MAX_ADV_SID: u8 = 0x0F;
+        if self.adv_sid > MAX_ADV_SID {
+            return Err(Error::BadAdvertisingSid(self.adv_sid));
+        }
+
+        Ok(())
+    }
+
+    fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), Self::LENGTH);
+
+        // Indicates that the Host has no preference for the transmit power.
+        const NO_TX_POWER_PREFERENCE: u8 = 0x7F;
+
+        bytes[0] = self.adv_handle.0;
+        LittleEndian::write_u16(&mut bytes[1..], self.adv_event_properties.bits());
+        self.primary_adv_interval
+            .copy_into_hci_slice(&mut bytes[3..9]);
+        bytes[9] = self.primary_adv_channel_map.bits();
+        bytes[10] = self.own_address_type as u8;
+        self.peer_address.copy_into_slice(&mut bytes[11..18]);
+        bytes[18] = self.adv_filter_policy as u8;
+        bytes[19] = self
+            .adv_tx_power
+            .map_or(NO_TX_POWER_PREFERENCE, |power| power as u8);
+        bytes[20] = self.primary_adv_phy as u8;
+        bytes[21] = self.secondary_adv_max_skip;
+        bytes[22] = self.secondary_adv_phy as u8;
+        bytes[23] = self.adv_sid;
+        bytes[24] = self.scan_request_notification_enable as u8;
+    }
+}
+
+/// Parameters of a single LE Set Extended Advertising Data or LE Set Extended Scan Response Data
+/// command.
+///
+/// Each command carries at most 251 bytes of data. [`fragments`](ExtendedAdvertisingData::fragments)
+/// splits longer data into the sequence of commands that sets it:
+///
+/// ```
+/// # extern crate stm32wb_hci as hci;
+/// use hci::host::ExtendedAdvertisingData;
+/// use hci::types::extended_advertisement::AdvertisingOperation;
+/// use hci::AdvertisingHandle;
+///
+/// let data = [0; 600];
+/// let operations: Vec<_> = ExtendedAdvertisingData::fragments(AdvertisingHandle(1), true, &data)
+///     .map(|fragment| (fragment.operation, fragment.data.len()))
+///     .collect();
+/// assert!(matches!(
+///     operations[..],
+///     [
+///         (AdvertisingOperation::FirstFragment, 251),
+///         (AdvertisingOperation::IntermediateFragment, 251),
+///         (AdvertisingOperation::LastFragment, 98),
+///     ]
+/// ));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct ExtendedAdvertisingData<'a> {
+    /// Advertising set whose data is set.
+    pub adv_handle: crate::AdvertisingHandle,
+
+    /// Which part of the data this command carries.
+    pub operation: AdvertisingOperation,
+
+    /// If true, the Controller may fragment the data over several PDUs; otherwise it should not
+    /// fragment it, or minimize fragmentation.
+    pub fragment: bool,
+
+    /// The data, formatted as defined in the Bluetooth spec, Vol 3, Part C, Section 11. At most
+    /// 251 bytes.
+    pub data: &'a [u8],
+}
+
+impl<'a> ExtendedAdvertisingData<'a> {
+    /// Returns the commands that set `data` for the advertising set. Data of up to 251 bytes is
+    /// sent as [`CompleteData`](AdvertisingOperation::CompleteData) in a single command; longer
+    /// data is split into a first fragment, intermediate fragments and a last fragment.
+    pub fn fragments(
+        adv_handle: crate::AdvertisingHandle,
+        fragment: bool,
+        data: &'a [u8],
+    ) -> ExtendedAdvertisingFragments<'a> {
+        ExtendedAdvertisingFragments {
+            adv_handle,
+            fragment,
+            fragments: Fragments::new(data, MAX_EXTENDED_ADVERTISING_FRAGMENT_LENGTH),
+        }
+    }
+
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> usize {
+        let len = self.data.len();
+        bytes[0] = self.adv_handle.0;
+        bytes[1] = self.operation as u8;
+        bytes[2] = (!self.fragment) as u8;
+        bytes[3] = len as u8;
+        bytes[4..4 + len].copy_from_slice(self.data);
+
+        4 + len
+    }
+}
+
+/// Iterator over the fragments of extended advertising data, as returned by
+/// [`ExtendedAdvertisingData::fragments`].
+#[derive(Clone, Debug)]
+pub struct ExtendedAdvertisingFragments<'a> {
+    adv_handle: crate::AdvertisingHandle,
+    fragment: bool,
+    fragments: Fragments<'a>,
+}
+
+impl<'a> Iterator for ExtendedAdvertisingFragments<'a> {
+    type Item = ExtendedAdvertisingData<'a>;
+
+    fn next(&mut self) -> Option<ExtendedAdvertisingData<'a>> {
+        let (operation, data) = self.fragments.next()?;
+
+        Some(ExtendedAdvertisingData {
+            adv_handle: self.adv_handle,
+            operation,
+            fragment: self.fragment,
+            data,
+        })
+    }
+}
+
+/// Splits advertising data into fragments of at most `max_len` bytes, each with the operation
+/// that sets it.
+#[derive(Clone, Debug)]
+struct Fragments<'a> {
+    data: &'a [u8],
+    max_len: usize,
+    first: bool,
+}
+
+impl<'a> Fragments<'a> {
+    fn new(data: &'a [u8], max_len: usize) -> Self {
+        Fragments {
+            data,
+            max_len,
+            first: true,
+        }
+    }
+}
+
+impl<'a> Iterator for Fragments<'a> {
+    type Item = (AdvertisingOperation, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Empty data is still sent once, to clear the data of the set.
+        if self.data.is_empty() && !self.first {
+            return None;
+        }
+
+        let len = self.data.len().min(self.max_len);
+        let (data, rest) = self.data.split_at(len);
+        let operation = match (self.first, rest.is_empty()) {
+            (true, true) => AdvertisingOperation::CompleteData,
+            (true, false) => AdvertisingOperation::FirstFragment,
+            (false, false) => AdvertisingOperation::IntermediateFragment,
+            (false, true) => AdvertisingOperation::LastFragment,
+        };
+        self.data = rest;
+        self.first = false;
+
+        Some((operation, data))
+    }
+}
+
+/// Parameters for the
+/// [`le_set_periodic_advertising_parameters`](HostHci::le_set_periodic_advertising_parameters)
+/// command.
+#[derive(Clone, Debug)]
+pub struct PeriodicAdvertisingParameters {
+    /// Advertising set to configure.
+    pub adv_handle: crate::AdvertisingHandle,
+
+    /// Minimum interval between periodic advertising events.
+    ///
+    /// - Range: 7.5 ms to 81.91875 s
+    /// - Resolution: 1.25 ms
+    pub min_interval: Duration,
+
+    /// Maximum interval between periodic advertising events. Shall not be less than
+    /// `min_interval`.
+    ///
+    /// - Range: 7.5 ms to 81.91875 s
+    /// - Resolution: 1.25 ms
+    pub max_interval: Duration,
+
+    /// Includes the transmit power in the periodic advertising PDUs.
+    pub include_tx_power: bool,
+}
+
+impl PeriodicAdvertisingParameters {
+    const INTERVAL_UNIT: Duration = Duration::from_micros(1250);
+
+    fn validate<E>(&self) -> Result<(), Error<E>> {
+        let range = Self::INTERVAL_UNIT * 0x0006..=Self::INTERVAL_UNIT * 0xFFFF;
+        if self.min_interval > self.max_interval
+            || !range.contains(&self.min_interval)
+            || !range.contains(&self.max_interval)
+        {
+            return Err(Error::BadPeriodicAdvertisingInterval(
+                self.min_interval,
+                self.max_interval,
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), 7);
+
+        // Bit of the Periodic_Advertising_Properties parameter
+        const INCLUDE_TX_POWER: u16 = 1 << 6;
+
+        bytes[0] = self.adv_handle.0;
+        LittleEndian::write_u16(
+            &mut bytes[1..],
+            (self.min_interval.as_micros() / Self::INTERVAL_UNIT.as_micros()) as u16,
+        );
+        LittleEndian::write_u16(
+            &mut bytes[3..],
+            (self.max_interval.as_micros() / Self::INTERVAL_UNIT.as_micros()) as u16,
+        );
+        LittleEndian::write_u16(
+            &mut bytes[5..],
+            if self.include_tx_power {
+                INCLUDE_TX_POWER
+            } else {
+                0
+            },
+        );
+    }
+}
+
+/// Parameters of a single LE Set Periodic Advertising Data command.
+///
+/// Each command carries at most 252 bytes of data. [`fragments`](PeriodicAdvertisingData::fragments)
+/// splits longer data into the sequence of commands that sets it, like
+/// [`ExtendedAdvertisingData::fragments`].
+#[derive(Copy, Clone, Debug)]
+pub struct PeriodicAdvertisingData<'a> {
+    /// Advertising set whose data is set.
+    pub adv_handle: crate::AdvertisingHandle,
+
+    /// Which part of the data this command carries.
+    pub operation: AdvertisingOperation,
+
+    /// The data, formatted as defined in the Bluetooth spec, Vol 3, Part C, Section 11. At most
+    /// 252 bytes.
+    pub data: &'a [u8],
+}
+
+impl<'a> PeriodicAdvertisingData<'a> {
+    /// Returns the commands that set `data` for the advertising set. Data of up to 252 bytes is
+    /// sent as [`CompleteData`](AdvertisingOperation::CompleteData) in a single command; longer
+    /// data is split into a first fragment, intermediate fragments and a last fragment.
+    pub fn fragments(
+        adv_handle: crate::AdvertisingHandle,
+        data: &'a [u8],
+    ) -> PeriodicAdvertisingFragments<'a> {
+        PeriodicAdvertisingFragments {
+            adv_handle,
+            fragments: Fragments::new(data, MAX_PERIODIC_ADVERTISING_FRAGMENT_LENGTH),
+        }
+    }
+
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> usize {
+        let len = self.data.len();
+        bytes[0] = self.adv_handle.0;
+        bytes[1] = self.operation as u8;
+        bytes[2] = len as u8;
+        bytes[3..3 + len].copy_from_slice(self.data);
+
+        3 + len
+    }
+}
+
+/// Iterator over the fragments of periodic advertising data, as returned by
+/// [`PeriodicAdvertisingData::fragments`].
+#[derive(Clone, Debug)]
+pub struct PeriodicAdvertisingFragments<'a> {
+    adv_handle: crate::AdvertisingHandle,
+    fragments: Fragments<'a>,
+}
+
+impl<'a> Iterator for PeriodicAdvertisingFragments<'a> {
+    type Item = PeriodicAdvertisingData<'a>;
+
+    fn next(&mut self) -> Option<PeriodicAdvertisingData<'a>> {
+        let (operation, data) = self.fragments.next()?;
+
+        Some(PeriodicAdvertisingData {
+            adv_handle: self.adv_handle,
+            operation,
+            data,
+        })
+    }
+}
+
+/// Parameters for the [`le_set_scan_parameters`](HostHci::le_set_scan_parameters) command.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScanParameters {
+    /// The type of scan to perform
+    pub scan_type: ScanType,
+
+    /// Recommendation from the host on how frequently the controller should scan.  See the
+    /// Bluetooth spec, Vol 6, Part B, Section 4.5.3.
+    pub scan_window: ScanWindow,
+
+    /// Indicates the type of address being used in the scan request packets.
+    pub own_address_type: OwnAddressType,
+
+    /// Indicates which advertising packets to accept.
+    pub filter_policy: ScanFilterPolicy,
+}
+
+impl ScanParameters {
+    fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), 7);
+
+        bytes[0] = self.scan_type as u8;
+        self.scan_window.copy_into_slice(&mut bytes[1..5]);
+        bytes[5] = self.own_address_type as u8;
+        bytes[6] = self.filter_policy as u8;
+    }
+}
+
+/// Parameters for the [`le_set_extended_scan_parameters`](HostHci::le_set_extended_scan_parameters)
+/// command.
+///
+/// Advertisements are scanned for on each PHY that has parameters. At least one PHY shall be
+/// scanned.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExtendedScanParameters {
+    /// Indicates the type of address being used in the scan request packets.
+    pub own_address_type: OwnAddressType,
+
+    /// Indicates which advertising packets to accept.
+    pub filter_policy: ScanFilterPolicy,
+
+    /// Scan parameters for the LE 1M PHY, if it is scanned.
+    pub le_1m: Option<PhyScanParameters>,
+
+    /// Scan parameters for the LE Coded PHY, if it is scanned. Scanning the LE Coded PHY receives
+    /// long-range advertisements.
+    pub le_coded: Option<PhyScanParameters>,
+}
+
+/// Scan parameters for a single PHY. See [`ExtendedScanParameters`].
 #[derive(Clone, Debug, PartialEq)]
-pub struct ScanParameters {
+pub struct PhyScanParameters {
     /// The type of scan to perform
     pub scan_type: ScanType,
 
     /// Recommendation from the host on how frequently the controller should scan.  See the
     /// Bluetooth spec, Vol 6, Part B, Section 4.5.3.
     pub scan_window: ScanWindow,
+}
 
-    /// Indicates the type of address being used in the scan request packets.
-    pub own_address_type: OwnAddressType,
+impl ExtendedScanParameters {
+    fn validate<E>(&self) -> Result<(), Error<E>> {
+        if self.le_1m.is_none() && self.le_coded.is_none() {
+            return Err(Error::NoScanningPhy);
+        }
 
-    /// Indicates which advertising packets to accept.
-    pub filter_policy: ScanFilterPolicy,
+        Ok(())
+    }
+
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> usize {
+        // Bits of the Scanning_PHYs parameter
+        const LE_1M: u8 = 1 << 0;
+        const LE_CODED: u8 = 1 << 2;
+
+        bytes[0] = self.own_address_type as u8;
+        bytes[1] = self.filter_policy as u8;
+        bytes[2] = 0;
+
+        let mut len = 3;
+        for (phy, params) in [(LE_1M, &self.le_1m), (LE_CODED, &self.le_coded)] {
+            if let Some(params) = params {
+                bytes[2] |= phy;
+                bytes[len] = params.scan_type as u8;
+                params
+                    .scan_window
+                    .copy_into_slice(&mut bytes[len + 1..len + 5]);
+                len += 5;
+            }
+        }
+
+        len
+    }
 }
 
-impl ScanParameters {
+/// Parameters for the [`le_set_extended_scan_enable`](HostHci::le_set_extended_scan_enable)
+/// command.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExtendedScanEnable {
+    /// Enables or disables scanning.
+    pub enable: bool,
+
+    /// Controls whether the Link Layer filters duplicate advertising reports.
+    pub filter_duplicates: FilterDuplicates,
+
+    /// How long to scan for. `None` scans until scanning is disabled.
+    ///
+    /// - Range: 10 ms to 655.35 s
+    /// - Resolution: 10 ms
+    pub duration: Option<Duration>,
+
+    /// Time from the start of one scan duration to the start of the next. `None` scans only once.
+    /// A period requires a duration that is shorter than the period.
+    ///
+    /// - Range: 1.28 s to 83,884.8 s
+    /// - Resolution: 1.28 s
+    pub period: Option<Duration>,
+}
+
+impl ExtendedScanEnable {
+    const DURATION_UNIT: Duration = Duration::from_millis(10);
+    const PERIOD_UNIT: Duration = Duration::from_millis(1280);
+
+    fn validate<E>(&self) -> Result<(), Error<E>> {
+        if let Some(duration) = self.duration {
+            if !(Self::DURATION_UNIT..=Self::DURATION_UNIT * 0xFFFF).contains(&duration) {
+                return Err(Error::BadScanDuration(duration));
+            }
+        }
+        if let Some(period) = self.period {
+            if !(Self::PERIOD_UNIT..=Self::PERIOD_UNIT * 0xFFFF).contains(&period) {
+                return Err(Error::BadScanPeriod(period));
+            }
+            if self.duration.map_or(false, |duration| duration >= period) {
+                return Err(Error::BadScanPeriod(period));
+            }
+        }
+
+        Ok(())
+    }
+
     fn copy_into_slice(&self, bytes: &mut [u8]) {
-        assert_eq!(bytes.len(), 7);
+        assert_eq!(bytes.len(), 6);
 
-        bytes[0] = self.scan_type as u8;
-        self.scan_window.copy_into_slice(&mut bytes[1..5]);
-        bytes[5] = self.own_address_type as u8;
-        bytes[6] = self.filter_policy as u8;
+        bytes[0] = self.enable as u8;
+        bytes[1] = self.filter_duplicates as u8;
+        LittleEndian::write_u16(
+            &mut bytes[2..],
+            self.duration.map_or(0, |duration| {
+                (duration.as_micros() / Self::DURATION_UNIT.as_micros()) as u16
+            }),
+        );
+        LittleEndian::write_u16(
+            &mut bytes[4..],
+            self.period.map_or(0, |period| {
+                (period.as_micros() / Self::PERIOD_UNIT.as_micros()) as u16
+            }),
+        );
     }
 }
 
+/// Duplicate filtering for the [`le_set_extended_scan_enable`](HostHci::le_set_extended_scan_enable)
+/// command.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u8)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FilterDuplicates {
+    /// Report every advertising packet received.
+    Disabled = 0x00,
+    /// Filter duplicate advertising reports until scanning is disabled.
+    Enabled = 0x01,
+    /// Filter duplicate advertising reports within each scan period.
+    ResetEachPeriod = 0x02,
+}
+
 /// Types of scan to perform.
 ///
 /// See [`ScanParameters`] and [`le_set_scan_parameters`](HostHci::le_set_scan_parameters).
@@ -2243,6 +5490,301 @@ impl ConnectionParameters {
     }
 }
 
+/// Parameters for the [`le_extended_create_connection`](HostHci::le_extended_create_connection)
+/// command.
+///
+/// The connection may be initiated on each PHY that has parameters. At least one of the LE 1M and
+/// LE Coded PHYs shall have parameters.
+#[derive(Clone, Debug)]
+pub struct ExtendedConnectionParameters {
+    /// Determines whether the White List is used.  If the White List is not used, `peer_address`
+    /// specifies the address type and address of the advertising device to connect to.
+    pub initiator_filter_policy: ConnectionFilterPolicy,
+
+    /// The type of address being used in the connection request packets. See
+    /// [`ConnectionParameters::own_address_type`].
+    pub own_address_type: OwnAddressType,
+
+    /// Indicates the type and value of the address used in the connectable advertisement sent by
+    /// the peer. See [`ConnectionParameters::peer_address`].
+    pub peer_address: PeerAddrType,
+
+    /// Parameters for initiating the connection on the LE 1M PHY, if it is used.
+    pub le_1m: Option<PhyConnectionParameters>,
+
+    /// Parameters for the connection if it is established on the LE 2M PHY, if it is used. No
+    /// advertisements are scanned for on the LE 2M PHY, so the scan window is ignored.
+    pub le_2m: Option<PhyConnectionParameters>,
+
+    /// Parameters for initiating the connection on the LE Coded PHY, if it is used.
+    pub le_coded: Option<PhyConnectionParameters>,
+}
+
+/// Connection parameters for a single PHY. See [`ExtendedConnectionParameters`].
+#[derive(Clone, Debug)]
+pub struct PhyConnectionParameters {
+    /// Recommendation from the host on how frequently the Controller should scan.
+    pub scan_window: ScanWindow,
+
+    /// Defines the minimum and maximum allowed connection interval, latency, and supervision
+    /// timeout.
+    pub conn_interval: ConnectionInterval,
+
+    /// Informative parameters providing the Controller with the expected minimum and maximum length
+    /// of the connection events.
+    pub expected_connection_length: ExpectedConnectionLength,
+}
+
+impl ExtendedConnectionParameters {
+    fn validate<E>(&self) -> Result<(), Error<E>> {
+        if self.le_1m.is_none() && self.le_coded.is_none() {
+            return Err(Error::NoInitiatingPhy);
+        }
+
+        Ok(())
+    }
+
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> usize {
+        // Bits of the Initiating_PHYs parameter
+        const LE_1M: u8 = 1 << 0;
+        const LE_2M: u8 = 1 << 1;
+        const LE_CODED: u8 = 1 << 2;
+
+        bytes[0] = self.initiator_filter_policy as u8;
+        bytes[1] = self.own_address_type as u8;
+        match self.initiator_filter_policy {
+            ConnectionFilterPolicy::UseAddress => {
+                self.peer_address.copy_into_slice(&mut bytes[2..9]);
+            }
+            ConnectionFilterPolicy::WhiteList => {
+                bytes[2..9].copy_from_slice(&[0; 7]);
+            }
+        }
+        bytes[9] = 0;
+
+        let mut len = 10;
+        for (phy, params) in [
+            (LE_1M, &self.le_1m),
+            (LE_2M, &self.le_2m),
+            (LE_CODED, &self.le_coded),
+        ] {
+            if let Some(params) = params {
+                bytes[9] |= phy;
+                params.scan_window.copy_into_slice(&mut bytes[len..len + 4]);
+                params
+                    .conn_interval
+                    .copy_into_slice(&mut bytes[len + 4..len + 12]);
+                params
+                    .expected_connection_length
+                    .copy_into_slice(&mut bytes[len + 12..len + 16]);
+                len += 16;
+            }
+        }
+
+        len
+    }
+}
+
+/// Parameters for the
+/// [`le_periodic_advertising_create_sync`](HostHci::le_periodic_advertising_create_sync) command.
+#[derive(Clone, Debug)]
+pub struct PeriodicAdvertisingSyncParameters {
+    /// Options for synchronizing and reporting.
+    pub options: PeriodicSyncOptions,
+
+    /// Advertising SID of the periodic advertising to synchronize with. Ignored when the periodic
+    /// advertiser list is used. Range: 0x00 - 0x0F.
+    pub adv_sid: u8,
+
+    /// Public or random (static) identity address of the advertiser. Ignored when the periodic
+    /// advertiser list is used.
+    pub advertiser_address: crate::BdAddrType,
+
+    /// Maximum number of periodic advertising events that may be skipped after a successful
+    /// receive. Range: 0x0000 - 0x01F3.
+    pub skip: u16,
+
+    /// Synchronization timeout for the periodic advertising.
+    ///
+    /// - Range: 100 ms to 163.84 s
+    /// - Resolution: 10 ms
+    pub sync_timeout: Duration,
+
+    /// Types of Constant Tone Extension of the periodic advertising PDUs that the Controller
+    /// should not synchronize to.
+    pub sync_cte_type: SyncCteType,
+}
+
+impl PeriodicAdvertisingSyncParameters {
+    fn validate<E>(&self) -> Result<(), Error<E>> {
+        const MAX_ADV_SID: u8 = 0x0F;
+        if self.adv_sid > MAX_ADV_SID {
+            return Err(Error::BadAdvertisingSid(self.adv_sid));
+        }
+
+        validate_sync_skip_and_timeout(self.skip, self.sync_timeout)
+    }
+
+    fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), 14);
+
+        bytes[0] = self.options.bits();
+        bytes[1] = self.adv_sid;
+        self.advertiser_address.copy_into_slice(&mut bytes[2..9]);
+        LittleEndian::write_u16(&mut bytes[9..], self.skip);
+        LittleEndian::write_u16(&mut bytes[11..], sync_timeout_as_u16(self.sync_timeout));
+        bytes[13] = self.sync_cte_type.bits();
+    }
+}
+
+const SYNC_TIMEOUT_UNIT: Duration = Duration::from_millis(10);
+
+fn validate_sync_skip_and_timeout<E>(skip: u16, sync_timeout: Duration) -> Result<(), Error<E>> {
+    const MAX_SKIP: u16 = 0x01F3;
+    if skip > MAX_SKIP {
+        return Err(Error::BadPeriodicAdvertisingSkip(skip));
+    }
+
+    if !(SYNC_TIMEOUT_UNIT * 0x000A..=SYNC_TIMEOUT_UNIT * 0x4000).contains(&sync_timeout) {
+        return Err(Error::BadSyncTimeout(sync_timeout));
+    }
+
+    Ok(())
+}
+
+fn sync_timeout_as_u16(sync_timeout: Duration) -> u16 {
+    (sync_timeout.as_micros() / SYNC_TIMEOUT_UNIT.as_micros()) as u16
+}
+
+/// Parameters for the
+/// [`le_set_periodic_advertising_sync_transfer_parameters`](HostHci::le_set_periodic_advertising_sync_transfer_parameters)
+/// and
+/// [`le_set_default_periodic_advertising_sync_transfer_parameters`](HostHci::le_set_default_periodic_advertising_sync_transfer_parameters)
+/// commands.
+#[derive(Clone, Debug)]
+pub struct PeriodicAdvertisingSyncTransferParameters {
+    /// How the Controller processes synchronization information received from the peer.
+    pub mode: SyncTransferMode,
+
+    /// Maximum number of periodic advertising events that may be skipped after a successful
+    /// receive. Range: 0x0000 - 0x01F3.
+    pub skip: u16,
+
+    /// Synchronization timeout for the periodic advertising.
+    ///
+    /// - Range: 100 ms to 163.84 s
+    /// - Resolution: 10 ms
+    pub sync_timeout: Duration,
+
+    /// Types of Constant Tone Extension of the periodic advertising PDUs that the Controller
+    /// should not synchronize to.
+    pub sync_cte_type: SyncCteType,
+}
+
+impl PeriodicAdvertisingSyncTransferParameters {
+    fn validate<E>(&self) -> Result<(), Error<E>> {
+        validate_sync_skip_and_timeout(self.skip, self.sync_timeout)
+    }
+
+    fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), 6);
+
+        bytes[0] = self.mode as u8;
+        LittleEndian::write_u16(&mut bytes[1..], self.skip);
+        LittleEndian::write_u16(&mut bytes[3..], sync_timeout_as_u16(self.sync_timeout));
+        bytes[5] = self.sync_cte_type.bits();
+    }
+}
+
+/// How the Controller processes periodic advertising synchronization information received from
+/// the peer of a connection. See [`PeriodicAdvertisingSyncTransferParameters`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u8)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SyncTransferMode {
+    /// Ignore the synchronization information. No events are sent to the Host.
+    NoSync = 0x00,
+    /// Synchronize with the periodic advertising and report the
+    /// [LE Periodic Advertising Sync Transfer Received](crate::event::Event::LePeriodicAdvertisingSyncTransferReceived)
+    /// event, but do not report periodic advertising PDUs.
+    SyncReportsDisabled = 0x01,
+    /// Synchronize with the periodic advertising and report every periodic advertising PDU.
+    SyncReportsEnabled = 0x02,
+    /// Synchronize with the periodic advertising and report periodic advertising PDUs, filtering
+    /// duplicates.
+    SyncReportsEnabledFilterDuplicates = 0x03,
+}
+
+#[cfg(not(feature = "defmt"))]
+bitflags::bitflags! {
+    /// Options for the
+    /// [`le_periodic_advertising_create_sync`](HostHci::le_periodic_advertising_create_sync)
+    /// command.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct PeriodicSyncOptions: u8 {
+        /// Use the periodic advertiser list to determine which advertiser to synchronize with,
+        /// instead of the advertising SID and address.
+        const USE_PERIODIC_ADVERTISER_LIST = 1 << 0;
+        /// Do not report periodic advertising PDUs until reporting is enabled.
+        const REPORTING_INITIALLY_DISABLED = 1 << 1;
+        /// Filter duplicate periodic advertising reports.
+        const DUPLICATE_FILTERING = 1 << 2;
+    }
+}
+
+#[cfg(feature = "defmt")]
+defmt::bitflags! {
+    /// Options for the
+    /// [`le_periodic_advertising_create_sync`](HostHci::le_periodic_advertising_create_sync)
+    /// command.
+    pub struct PeriodicSyncOptions: u8 {
+        /// Use the periodic advertiser list to determine which advertiser to synchronize with,
+        /// instead of the advertising SID and address.
+        const USE_PERIODIC_ADVERTISER_LIST = 1 << 0;
+        /// Do not report periodic advertising PDUs until reporting is enabled.
+        const REPORTING_INITIALLY_DISABLED = 1 << 1;
+        /// Filter duplicate periodic advertising reports.
+        const DUPLICATE_FILTERING = 1 << 2;
+    }
+}
+
+#[cfg(not(feature = "defmt"))]
+bitflags::bitflags! {
+    /// Types of Constant Tone Extension that prevent the Controller from synchronizing to periodic
+    /// advertising. With no flags set, the Controller synchronizes regardless of the CTE.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct SyncCteType: u8 {
+        /// Do not synchronize to packets with an AoA Constant Tone Extension.
+        const NO_AOA = 1 << 0;
+        /// Do not synchronize to packets with an AoD Constant Tone Extension with 1 µs slots.
+        const NO_AOD_1US = 1 << 1;
+        /// Do not synchronize to packets with an AoD Constant Tone Extension with 2 µs slots.
+        const NO_AOD_2US = 1 << 2;
+        /// Do not synchronize to packets with a type 3 Constant Tone Extension.
+        const NO_TYPE_3 = 1 << 3;
+        /// Do not synchronize to packets without a Constant Tone Extension.
+        const CTE_REQUIRED = 1 << 4;
+    }
+}
+
+#[cfg(feature = "defmt")]
+defmt::bitflags! {
+    /// Types of Constant Tone Extension that prevent the Controller from synchronizing to periodic
+    /// advertising. With no flags set, the Controller synchronizes regardless of the CTE.
+    pub struct SyncCteType: u8 {
+        /// Do not synchronize to packets with an AoA Constant Tone Extension.
+        const NO_AOA = 1 << 0;
+        /// Do not synchronize to packets with an AoD Constant Tone Extension with 1 µs slots.
+        const NO_AOD_1US = 1 << 1;
+        /// Do not synchronize to packets with an AoD Constant Tone Extension with 2 µs slots.
+        const NO_AOD_2US = 1 << 2;
+        /// Do not synchronize to packets with a type 3 Constant Tone Extension.
+        const NO_TYPE_3 = 1 << 3;
+        /// Do not synchronize to packets without a Constant Tone Extension.
+        const CTE_REQUIRED = 1 << 4;
+    }
+}
+
 /// Possible values for the initiator filter policy in the
 /// [`le_create_connection`](HostHci::le_create_connection) command.
 #[derive(Copy, Clone, Debug)]
@@ -2306,9 +5848,12 @@ impl PeerAddrType {
     }
 }
 
-/// Parameters for the [`le_connection_update`](HostHci::le_connection_update) command.
+/// Parameters for the [`le_connection_update`](HostHci::le_connection_update) and
+/// [`le_remote_connection_parameter_request_reply`](HostHci::le_remote_connection_parameter_request_reply)
+/// commands.
 ///
 /// See the Bluetooth spec, Vol 2, Part E, Section 7.8.18.
+#[derive(Clone, Debug)]
 pub struct ConnectionUpdateParameters {
     /// Handle for identifying a connection.
     pub conn_handle: ConnectionHandle,
@@ -2348,6 +5893,162 @@ pub struct AesParameters {
     pub plaintext_data: PlaintextBlock,
 }
 
+/// Parameters for the [`le_add_device_to_resolving_list`](HostHci::le_add_device_to_resolving_list)
+/// command.
+#[derive(Clone, Debug)]
+pub struct ResolvingListEntry {
+    /// Public or Random (static) Identity Address of the peer device.
+    pub peer_identity_address: crate::BdAddrType,
+
+    /// IRK of the peer device, used to resolve its Resolvable Private Addresses.
+    pub peer_irk: IdentityResolvingKey,
+
+    /// IRK of the local device, used to generate the Resolvable Private Addresses of the local
+    /// device for this peer.
+    pub local_irk: IdentityResolvingKey,
+}
+
+impl ResolvingListEntry {
+    fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), 39);
+
+        self.peer_identity_address.copy_into_slice(&mut bytes[0..7]);
+        bytes[7..23].copy_from_slice(&self.peer_irk.0);
+        bytes[23..39].copy_from_slice(&self.local_irk.0);
+    }
+}
+
+/// Newtype for an Identity Resolving Key (IRK), used to generate and resolve Resolvable Private
+/// Addresses.
+///
+/// See [`ResolvingListEntry`].
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct IdentityResolvingKey(pub [u8; 16]);
+
+impl Debug for IdentityResolvingKey {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "IRK (REDACTED)")
+    }
+}
+
+/// Privacy modes for the [`le_set_privacy_mode`](HostHci::le_set_privacy_mode) command.
+///
+/// See Vol 6, Part B, Section 4.7 of the spec.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u8)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PrivacyMode {
+    /// Only Resolvable Private Addresses are accepted from the peer device (default).
+    Network = 0x00,
+    /// The identity address of the peer device is accepted as well as its Resolvable Private
+    /// Addresses.
+    Device = 0x01,
+}
+
+/// Parameters for the
+/// [`le_set_path_loss_reporting_parameters`](HostHci::le_set_path_loss_reporting_parameters)
+/// command.
+///
+/// The path loss is in the low zone while it is below the low threshold, in the high zone while
+/// it is above the high threshold, and in the middle zone otherwise. To avoid reporting small
+/// changes, the path loss must cross a threshold by its hysteresis before it enters another zone.
+#[derive(Clone, Debug)]
+pub struct PathLossReportingParameters {
+    /// Path loss, in dB, above which the path loss is in the high zone. 0xFF means that there is
+    /// no high zone.
+    pub high_threshold: u8,
+
+    /// Hysteresis of the high threshold, in dB.
+    pub high_hysteresis: u8,
+
+    /// Path loss, in dB, below which the path loss is in the low zone.
+    pub low_threshold: u8,
+
+    /// Hysteresis of the low threshold, in dB.
+    pub low_hysteresis: u8,
+
+    /// Minimum number of connection events that the path loss must stay in a zone before it is
+    /// reported.
+    pub min_time_spent: u16,
+}
+
+impl PathLossReportingParameters {
+    fn validate<E>(&self) -> Result<(), Error<E>> {
+        if u16::from(self.low_threshold) + u16::from(self.low_hysteresis)
+            > u16::from(self.high_threshold).saturating_sub(u16::from(self.high_hysteresis))
+        {
+            return Err(Error::BadPathLossThresholds(
+                self.low_threshold,
+                self.high_threshold,
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), 6);
+
+        bytes[0] = self.high_threshold;
+        bytes[1] = self.high_hysteresis;
+        bytes[2] = self.low_threshold;
+        bytes[3] = self.low_hysteresis;
+        LittleEndian::write_u16(&mut bytes[4..], self.min_time_spent);
+    }
+}
+
+/// Newtype for a P-256 public key, as used by LE Secure Connections: the X coordinate followed by
+/// the Y coordinate, each in little-endian order.
+///
+/// See [`le_generate_dhkey`](HostHci::le_generate_dhkey).
+#[derive(Clone, PartialEq)]
+pub struct P256PublicKey(pub [u8; 64]);
+
+impl Debug for P256PublicKey {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "P-256 Public Key (REDACTED)")
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for P256PublicKey {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "P-256 Public Key (REDACTED)")
+    }
+}
+
+/// Newtype for a Diffie-Hellman key computed by the Controller, in little-endian order.
+///
+/// See [`le_generate_dhkey`](HostHci::le_generate_dhkey).
+#[derive(Clone, PartialEq)]
+pub struct DhKey(pub [u8; 32]);
+
+impl Debug for DhKey {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "DHKey (REDACTED)")
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for DhKey {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "DHKey (REDACTED)")
+    }
+}
+
+/// Private keys for the [`le_generate_dhkey_v2`](HostHci::le_generate_dhkey_v2) command.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u8)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DhKeyPrivateKey {
+    /// Use the private key generated by
+    /// [`le_read_local_p256_public_key`](HostHci::le_read_local_p256_public_key).
+    Generated = 0x00,
+    /// Use the debug private key, defined in Vol 3, Part H, Section 2.3.5.6.1 of the spec.
+    Debug = 0x01,
+}
+
 /// Newtype for the encryption key.
 ///
 /// See [`AesParameters`]
@@ -2374,6 +6075,7 @@ impl Debug for PlaintextBlock {
 }
 
 /// Parameters for the [`le_start_encryption`](HostHci::le_start_encryption) command.
+#[derive(Clone, Debug)]
 pub struct EncryptionParameters {
     /// ID for the connection.
     pub conn_handle: ConnectionHandle,
@@ -2411,3 +6113,54 @@ pub enum TestPacketPayload {
     /// Pattern of alternating bits `0101'
     Bits01 = 0x07,
 }
+
+#[cfg(not(feature = "defmt"))]
+bitflags::bitflags! {
+    /// PHYs preferred by the Host, for the [`le_set_default_phy`](HostHci::le_set_default_phy) and
+    /// [`le_set_phy`](HostHci::le_set_phy) commands.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct PhyMask : u8 {
+        /// The LE 1M PHY
+        const LE_1M = 0b0000_0001;
+        /// The LE 2M PHY
+        const LE_2M = 0b0000_0010;
+        /// The LE Coded PHY
+        const LE_CODED = 0b0000_0100;
+    }
+}
+
+#[cfg(feature = "defmt")]
+defmt::bitflags! {
+    /// PHYs preferred by the Host, for the [`le_set_default_phy`](HostHci::le_set_default_phy) and
+    /// [`le_set_phy`](HostHci::le_set_phy) commands.
+    pub struct PhyMask : u8 {
+        /// The LE 1M PHY
+        const LE_1M = 0b0000_0001;
+        /// The LE 2M PHY
+        const LE_2M = 0b0000_0010;
+        /// The LE Coded PHY
+        const LE_CODED = 0b0000_0100;
+    }
+}
+
+/// Writes the ALL_PHYS, TX_PHYS and RX_PHYS parameters. A direction without a preference sets its
+/// bit in ALL_PHYS, and its mask is ignored by the Controller.
+fn copy_phy_preferences(tx_phys: Option<PhyMask>, rx_phys: Option<PhyMask>, bytes: &mut [u8]) {
+    bytes[0] = tx_phys.map_or(0b01, |_| 0) | rx_phys.map_or(0b10, |_| 0);
+    bytes[1] = tx_phys.map_or(0, |phys| phys.bits());
+    bytes[2] = rx_phys.map_or(0, |phys| phys.bits());
+}
+
+/// Preferred coding when transmitting on the LE Coded PHY, for the
+/// [`le_set_phy`](HostHci::le_set_phy) command.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u16)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CodedPhyOptions {
+    /// The Host has no preferred coding.
+    NoPreference = 0x0000,
+    /// The Host prefers S=2 coding (500 kBit/s).
+    S2 = 0x0001,
+    /// The Host prefers S=8 coding (125 kBit/s).
+    S8 = 0x0002,
+}
@@ -1,36 +1,214 @@
 //! Implementation of the HCI that includes the packet ID byte in the header.
 
 use byteorder::{ByteOrder, LittleEndian};
+use core::fmt::{Debug, Formatter, Result as FmtResult};
 
-const PACKET_TYPE_HCI_COMMAND: u8 = 0x01;
-// const PACKET_TYPE_ACL_DATA: u8 = 0x02;
+pub(crate) const PACKET_TYPE_HCI_COMMAND: u8 = 0x01;
+pub(crate) const PACKET_TYPE_ACL_DATA: u8 = 0x02;
 // const PACKET_TYPE_SYNC_DATA: u8 = 0x03;
-const PACKET_TYPE_HCI_EVENT: u8 = 0x04;
+pub(crate) const PACKET_TYPE_HCI_EVENT: u8 = 0x04;
+
+/// Length of the header of a command packet, including the packet type byte.
+pub(crate) const COMMAND_PACKET_HEADER_LENGTH: usize = 4;
+
+/// Length of the header of an event packet, including the packet type byte.
+pub(crate) const EVENT_PACKET_HEADER_LENGTH: usize = 3;
+
+/// Maximum length of a packet read from the controller, including the packet type byte.
+pub const MAX_PACKET_LENGTH: usize = 259;
 
 /// Potential errors from reading or writing packets to the controller.
 ///
-/// Must be specialized both for communication errors (`E`) and vendor-specific errors (`VE`).
+/// Must be specialized for communication errors (`E`).
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub enum Error {
+pub enum Error<E> {
     /// The host expected the controller to begin a packet, but the next byte is not a valid packet
     /// type byte. Contains the value of the byte.
     BadPacketType(u8),
     /// There was an error deserializing an event. Contains the underlying error.
     BLE(crate::event::Error),
+    /// The length of an ACL data packet exceeds [`MAX_ACL_DATA_LENGTH`]. Contains the length.
+    AclDataTooLong(usize),
+    /// The broadcast flag of an ACL data packet header is reserved. Contains the value of the
+    /// flag.
+    BadBroadcastFlag(u8),
+    /// There was an error communicating with the controller. Contains the underlying error.
+    Comm(E),
 }
 
+/// Maximum number of data bytes in a single ACL data packet.
+pub const MAX_ACL_DATA_LENGTH: usize = 251;
+
 /// Packet types that may be read from the controller.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[allow(clippy::large_enum_variant)]
 pub enum Packet {
-    // AclData(AclData),
+    /// The HCI ACL Data Packet is used to exchange data between the Host and Controller.
+    AclData(AclData),
     // SyncData(SyncData),
     /// The HCI Event Packet is used by the Controller to notify the Host when events
     /// occur. The event is specialized to support vendor-specific events.
     Event(crate::Event),
 }
 
+impl Packet {
+    /// Serializes the packet into `buffer`, including the packet type byte, in the format read by
+    /// [`UartHci::read`]. Returns the number of bytes written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` is too short for the packet. [`MAX_PACKET_LENGTH`] bytes are always
+    /// enough.
+    pub fn copy_into_slice(&self, buffer: &mut [u8]) -> usize {
+        match self {
+            Packet::AclData(acl_data) => {
+                let data = acl_data.data();
+                acl_data.header.copy_into_slice(buffer);
+                buffer[AclDataHeader::HEADER_LENGTH..AclDataHeader::HEADER_LENGTH + data.len()]
+                    .copy_from_slice(data);
+                AclDataHeader::HEADER_LENGTH + data.len()
+            }
+            Packet::Event(event) => {
+                buffer[0] = PACKET_TYPE_HCI_EVENT;
+                1 + event.copy_into_slice(&mut buffer[1..])
+            }
+        }
+    }
+}
+
+/// Packet boundary flag of an ACL data packet.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 5.4.2.
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PacketBoundary {
+    /// First non-automatically-flushable packet of a higher layer message. This is the only
+    /// value allowed for the first packet sent from the host to an LE controller.
+    FirstNonFlushable = 0b00,
+    /// Continuing fragment of a higher layer message.
+    Continuing = 0b01,
+    /// First automatically-flushable packet of a higher layer message. LE controllers use this
+    /// value for the first packet they send to the host.
+    FirstFlushable = 0b10,
+    /// A complete L2CAP PDU. Automatically flushable.
+    Complete = 0b11,
+}
+
+impl From<u8> for PacketBoundary {
+    fn from(value: u8) -> PacketBoundary {
+        match value & 0b11 {
+            0b00 => PacketBoundary::FirstNonFlushable,
+            0b01 => PacketBoundary::Continuing,
+            0b10 => PacketBoundary::FirstFlushable,
+            _ => PacketBoundary::Complete,
+        }
+    }
+}
+
+/// Broadcast flag of an ACL data packet.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 5.4.2.
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum BroadcastFlag {
+    /// Point-to-point packet. This is the only value allowed on LE connections.
+    PointToPoint = 0b00,
+    /// BR/EDR broadcast packet.
+    BrEdrBroadcast = 0b01,
+}
+
+/// Header of an ACL data packet.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 5.4.2.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AclDataHeader {
+    /// Connection the data is sent over. Only the 12 least significant bits are used.
+    pub conn_handle: crate::ConnectionHandle,
+    /// Position of the packet in the higher layer message.
+    pub packet_boundary: PacketBoundary,
+    /// Whether the packet is point-to-point or broadcast.
+    pub broadcast: BroadcastFlag,
+    /// Number of data bytes that follow the header.
+    pub data_len: u16,
+}
+
+impl AclDataHeader {
+    /// Length of the header on the wire, including the packet type byte.
+    pub const HEADER_LENGTH: usize = 5;
+
+    /// Deserializes the header from the 4 bytes that follow the packet type byte.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadBroadcastFlag`](Error::BadBroadcastFlag) if the broadcast flag is reserved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is shorter than 4 bytes.
+    pub fn from_bytes<E>(bytes: &[u8]) -> Result<AclDataHeader, Error<E>> {
+        let handle_and_flags = LittleEndian::read_u16(&bytes[0..]);
+        let broadcast = match handle_and_flags >> 14 {
+            0b00 => BroadcastFlag::PointToPoint,
+            0b01 => BroadcastFlag::BrEdrBroadcast,
+            other => return Err(Error::BadBroadcastFlag(other as u8)),
+        };
+
+        Ok(AclDataHeader {
+            conn_handle: crate::ConnectionHandle(handle_and_flags & 0x0FFF),
+            packet_boundary: PacketBoundary::from((handle_and_flags >> 12) as u8),
+            broadcast,
+            data_len: LittleEndian::read_u16(&bytes[2..]),
+        })
+    }
+
+    /// Serializes the header, including the packet type byte, into the first
+    /// [`HEADER_LENGTH`](AclDataHeader::HEADER_LENGTH) bytes of `buffer`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` is shorter than [`HEADER_LENGTH`](AclDataHeader::HEADER_LENGTH).
+    pub fn copy_into_slice(&self, buffer: &mut [u8]) {
+        buffer[0] = PACKET_TYPE_ACL_DATA;
+        LittleEndian::write_u16(
+            &mut buffer[1..=2],
+            (self.conn_handle.0 & 0x0FFF)
+                | ((self.packet_boundary as u16) << 12)
+                | ((self.broadcast as u16) << 14),
+        );
+        LittleEndian::write_u16(&mut buffer[3..=4], self.data_len);
+    }
+}
+
+/// ACL data packet received from the controller.
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AclData {
+    /// Header of the packet.
+    pub header: AclDataHeader,
+    data_buf: [u8; MAX_ACL_DATA_LENGTH],
+}
+
+impl AclData {
+    /// Returns the data carried by the packet.
+    pub fn data(&self) -> &[u8] {
+        &self.data_buf[..self.header.data_len as usize]
+    }
+}
+
+impl Debug for AclData {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("AclData")
+            .field("header", &self.header)
+            .field("data", &self.data())
+            .finish()
+    }
+}
+
 /// Header for HCI Commands.
 pub struct CommandHeader {
     opcode: crate::opcode::Opcode,
@@ -58,7 +236,39 @@ pub trait UartHci: super::HostHci {
     ///   event). See [`crate::event::Error`] for possible values of `e`.
     /// - Returns [`Error::Comm`] if there is an error reading from the
     ///   controller.
-    async fn read(&mut self) -> Result<Packet, Error>;
+    async fn read(&mut self) -> Result<Packet, Error<Self::Error>>;
+}
+
+/// Trait for writing ACL data packets to the controller.
+///
+/// This trait is implemented for every [`crate::AclController`].
+pub trait AclHci: crate::Controller {
+    /// Sends `data` to the peer over the connection `conn_handle` in a single point-to-point ACL
+    /// data packet.
+    ///
+    /// The host is responsible for fragmenting higher layer messages to the controller's buffer
+    /// size (see [`crate::host::HostHci::le_read_buffer_size`]). The first fragment of a message
+    /// uses [`PacketBoundary::FirstNonFlushable`] and the others use
+    /// [`PacketBoundary::Continuing`].
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 5.4.2.
+    ///
+    /// # Errors
+    ///
+    /// - [`AclDataTooLong`](Error::AclDataTooLong) if `data` is longer than
+    ///   [`MAX_ACL_DATA_LENGTH`]. Nothing is written to the controller in this case.
+    /// - [`Comm`](Error::Comm) if the packet could not be written to the controller.
+    ///
+    /// # Generated events
+    ///
+    /// A [Number of Completed Packets](crate::event::Event::NumberOfCompletedPackets) event is
+    /// generated when the controller has transmitted or flushed the packet.
+    async fn write_acl_data(
+        &mut self,
+        conn_handle: crate::ConnectionHandle,
+        packet_boundary: PacketBoundary,
+        data: &[u8],
+    ) -> Result<(), Error<Self::Error>>;
 }
 
 impl super::HciHeader for CommandHeader {
@@ -82,32 +292,99 @@ impl<T> UartHci for T
 where
     T: crate::Controller,
 {
-    async fn read(&mut self) -> Result<Packet, Error> {
-        const MAX_EVENT_LENGTH: usize = 256;
-        const PACKET_HEADER_LENGTH: usize = 1;
-        const EVENT_PACKET_HEADER_LENGTH: usize = 3;
-        const PARAM_LEN_BYTE: usize = 2;
-
-        let mut packet = [0u8; MAX_EVENT_LENGTH + EVENT_PACKET_HEADER_LENGTH];
-        self.controller_read_into(&mut packet).await;
-
-        let packet_type = packet[0];
-        match packet_type {
-            PACKET_TYPE_HCI_EVENT => {
-                let param_len = packet[PARAM_LEN_BYTE] as usize;
-
-                let mut buf = [0; MAX_EVENT_LENGTH + EVENT_PACKET_HEADER_LENGTH];
-                buf[..EVENT_PACKET_HEADER_LENGTH + param_len]
-                    .copy_from_slice(&packet[..EVENT_PACKET_HEADER_LENGTH + param_len]);
-
-                Ok(Packet::Event(
-                    crate::event::Event::new(crate::event::Packet(
-                        &buf[PACKET_HEADER_LENGTH..EVENT_PACKET_HEADER_LENGTH + param_len],
-                    ))
-                    .map_err(Error::BLE)?,
+    async fn read(&mut self) -> Result<Packet, Error<Self::Error>> {
+        let mut packet = [0u8; MAX_PACKET_LENGTH];
+        self.controller_read_into(&mut packet)
+            .await
+            .map_err(Error::Comm)?;
+
+        parse_packet(&packet)
+    }
+}
+
+/// Returns the length of the command, ACL data or event packet at the beginning of `packet`,
+/// including the packet type byte, as given by its header. Returns `Ok(None)` if `packet` is
+/// shorter than the header.
+pub(crate) fn packet_length<E>(packet: &[u8]) -> Result<Option<usize>, Error<E>> {
+    let header_len = match packet.first() {
+        None => return Ok(None),
+        Some(&PACKET_TYPE_HCI_COMMAND) => COMMAND_PACKET_HEADER_LENGTH,
+        Some(&PACKET_TYPE_ACL_DATA) => AclDataHeader::HEADER_LENGTH,
+        Some(&PACKET_TYPE_HCI_EVENT) => EVENT_PACKET_HEADER_LENGTH,
+        Some(&other) => return Err(Error::BadPacketType(other)),
+    };
+    if packet.len() < header_len {
+        return Ok(None);
+    }
+
+    let param_len = match packet[0] {
+        PACKET_TYPE_ACL_DATA => LittleEndian::read_u16(&packet[3..]) as usize,
+        _ => packet[header_len - 1] as usize,
+    };
+
+    Ok(Some(header_len + param_len))
+}
+
+/// Deserializes the packet at the beginning of `packet`, which starts with the packet type byte.
+/// Any bytes after the packet are ignored.
+pub(crate) fn parse_packet<E>(packet: &[u8]) -> Result<Packet, Error<E>> {
+    const PACKET_HEADER_LENGTH: usize = 1;
+    const PARAM_LEN_BYTE: usize = 2;
+
+    let packet_type = packet[0];
+    match packet_type {
+        PACKET_TYPE_HCI_EVENT => {
+            let param_len = packet[PARAM_LEN_BYTE] as usize;
+
+            Ok(Packet::Event(
+                crate::event::Event::new(crate::event::Packet(
+                    &packet[PACKET_HEADER_LENGTH..EVENT_PACKET_HEADER_LENGTH + param_len],
                 ))
+                .map_err(Error::BLE)?,
+            ))
+        }
+        PACKET_TYPE_ACL_DATA => {
+            let header = AclDataHeader::from_bytes(
+                &packet[PACKET_HEADER_LENGTH..AclDataHeader::HEADER_LENGTH],
+            )?;
+            let data_len = header.data_len as usize;
+            if data_len > MAX_ACL_DATA_LENGTH {
+                return Err(Error::AclDataTooLong(data_len));
             }
-            x => Err(Error::BadPacketType(x)),
+
+            let mut data_buf = [0; MAX_ACL_DATA_LENGTH];
+            data_buf[..data_len].copy_from_slice(
+                &packet[AclDataHeader::HEADER_LENGTH..AclDataHeader::HEADER_LENGTH + data_len],
+            );
+
+            Ok(Packet::AclData(AclData { header, data_buf }))
+        }
+        x => Err(Error::BadPacketType(x)),
+    }
+}
+
+impl<T> AclHci for T
+where
+    T: crate::AclController,
+{
+    async fn write_acl_data(
+        &mut self,
+        conn_handle: crate::ConnectionHandle,
+        packet_boundary: PacketBoundary,
+        data: &[u8],
+    ) -> Result<(), Error<Self::Error>> {
+        if data.len() > MAX_ACL_DATA_LENGTH {
+            return Err(Error::AclDataTooLong(data.len()));
         }
+
+        let header = AclDataHeader {
+            conn_handle,
+            packet_boundary,
+            broadcast: BroadcastFlag::PointToPoint,
+            data_len: data.len() as u16,
+        };
+        self.controller_write_acl_data(&header, data)
+            .await
+            .map_err(Error::Comm)
     }
 }
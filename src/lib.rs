@@ -15,12 +15,26 @@
 //! that implement the core [`Controller`] trait. The traits also make use of async in traits, so the
 //! #![feature(async_fn_in_trait)] feature is required.
 //! support different asynchronous or synchronous operation modes.
+//! Applications without an async executor can use the blocking controller interface of the
+//! [`blocking`] module.
+//!
+//! Commands are also available as values that can be encoded without a [`Controller`]; see the
+//! [`command`] module.
+//!
+//! The [`decode`] module describes raw command and event packets in a human-readable form. With
+//! the `std` feature, the `hci-decode` binary prints these descriptions for hex dumps or btsnoop
+//! captures read from its standard input.
+//!
+//! With the `std` feature, the packets exchanged with a [`Controller`] can be recorded to and read
+//! back from capture files; see the `btsnoop` module. The `testing` feature adds a controller that
+//! replays such captures, and a software model of the STM32WB coprocessor, so that hosts can be
+//! tested without hardware; see the `replay` and `simulator` modules.
 //!
 //! ## Commands
 //!
 //! The [`host::Hci`] trait defines all of the functions that communicate from the host to the
 //! controller. The [`host::uart::Hci`] trait defines a read function that returns a
-//! [`host::uart::Packet`], which can contain an [`Event`], `AclData` (TODO), or `SyncData`
+//! [`host::uart::Packet`], which can contain an [`Event`], [`host::uart::AclData`], or `SyncData`
 //! (TODO). Both of these traits have default implementations in terms of the [`Controller`], so
 //! calling code does not need to implement any commands or event parsing code.
 //!
@@ -78,6 +92,8 @@
 #![allow(async_fn_in_trait)]
 
 extern crate byteorder;
+#[cfg(feature = "std")]
+extern crate std;
 
 // This must go FIRST so that all the other modules see its macros.
 mod fmt;
@@ -85,9 +101,18 @@ mod fmt;
 #[macro_use]
 pub mod bitflag_array;
 
+pub mod blocking;
+#[cfg(feature = "std")]
+pub mod btsnoop;
+pub mod command;
+pub mod decode;
 pub mod event;
 pub mod host;
-mod opcode;
+pub mod opcode;
+#[cfg(feature = "testing")]
+pub mod replay;
+#[cfg(feature = "testing")]
+pub mod simulator;
 pub mod types;
 pub mod vendor;
 
@@ -103,9 +128,18 @@ use core::fmt::Debug;
 /// which enables full access to all of the functions and events of the HCI through [`host::Hci`]
 /// and [`host::uart::Hci`], respectively.
 pub trait Controller {
+    /// Enumeration of communication errors of the controller, such as bus or transport errors.
+    /// Use [`core::convert::Infallible`] if the controller cannot fail.
+    type Error;
+
     /// Writes the bytes to the controller, in a single transaction if possible. All of `header`
     /// shall be written, followed by all of `payload`.
-    async fn controller_write(&mut self, opcode: Opcode, payload: &[u8]);
+    ///
+    /// # Errors
+    ///
+    /// Returns any error that prevented the command from being written to the controller.
+    async fn controller_write(&mut self, opcode: Opcode, payload: &[u8])
+        -> Result<(), Self::Error>;
 
     /// Reads data from the controller into the provided `buffer`. The length of the buffer
     /// indicates the number of bytes to read. The implementor must not return bytes in an order
@@ -127,8 +161,13 @@ pub trait Controller {
     /// # use hci::Controller as HciController;
     /// # struct Controller;
     /// # impl HciController for Controller {
-    /// #     async fn controller_write(&mut self, opcode: hci::Opcode, _payload: &[u8]) {}
-    /// #     async fn controller_read_into(&self, _buf: &mut [u8]) {}
+    /// #     type Error = core::convert::Infallible;
+    /// #     async fn controller_write(&mut self, opcode: hci::Opcode, _payload: &[u8]) -> Result<(), Self::Error> {
+    /// #         Ok(())
+    /// #     }
+    /// #     async fn controller_read_into(&self, _buf: &mut [u8]) -> Result<(), Self::Error> {
+    /// #         Ok(())
+    /// #     }
     /// # }
     /// # fn main() {
     /// # let mut controller = Controller;
@@ -149,7 +188,32 @@ pub trait Controller {
     /// // +------+------+------+------+
     /// # }
     /// ```
-    async fn controller_read_into(&self, buf: &mut [u8]);
+    ///
+    /// # Errors
+    ///
+    /// Returns any error that prevented the bytes from being read from the controller. The
+    /// contents of `buffer` are unspecified in that case.
+    async fn controller_read_into(&self, buf: &mut [u8]) -> Result<(), Self::Error>;
+}
+
+/// Interface to write ACL data packets to the Bluetooth controller.
+///
+/// Controllers that can carry connection data implement this trait in addition to
+/// [`Controller`], which enables [`host::uart::AclHci`]. ACL data packets read from the controller
+/// are returned by [`host::uart::UartHci::read`] and need no additional support.
+pub trait AclController: Controller {
+    /// Writes an ACL data packet to the controller, in a single transaction if possible. The
+    /// serialized `header` (see [`host::uart::AclDataHeader::copy_into_slice`]) shall be written,
+    /// followed by all of `data`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error that prevented the packet from being written to the controller.
+    async fn controller_write_acl_data(
+        &mut self,
+        header: &host::uart::AclDataHeader,
+        data: &[u8],
+    ) -> Result<(), Self::Error>;
 }
 
 /// List of possible error codes, Bluetooth Spec, Vol 2, Part D, Section 2.
@@ -494,6 +558,14 @@ pub struct ConnectionHandle(pub u16);
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct AdvertisingHandle(pub u8);
 
+/// Newtype for a periodic advertising sync handle.
+///
+/// Values:
+/// - 0x0000 .. 0x0EFF
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SyncHandle(pub u16);
+
 /// Newtype for BDADDR.
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
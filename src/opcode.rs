@@ -1,10 +1,18 @@
+//! Opcodes of the standard HCI commands, and the [`Opcode`] type used to identify them.
+
 /// Newtype wrapper for a Bluetooth Opcode. Opcodes are used to indicate which command to send to
 /// the Controller as well as which command results are returned by the Command Complete and Command
 /// Status events.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Opcode(pub u16);
 
+impl core::fmt::Debug for Opcode {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Opcode({:#06x})", self.0)
+    }
+}
+
 impl Opcode {
     /// Create an opcode from the OGF (Opcode group field) and OCF (Opcode command field).
     pub const fn new(ogf: u16, ocf: u16) -> Opcode {
@@ -34,6 +42,18 @@ macro_rules! opcodes {
         $($(
             pub const $var: Opcode = Opcode::new($ogf, $ocf);
         )+)+
+
+        /// Returns the name of the standard command with the given opcode, e.g. `"RESET"`, or
+        /// `None` if the opcode is not one of the constants of this module.
+        pub fn name(opcode: Opcode) -> Option<&'static str> {
+            $($(
+                if opcode == $var {
+                    return Some(stringify!($var));
+                }
+            )+)+
+
+            None
+        }
     }
 }
 
@@ -52,6 +72,9 @@ opcodes! {
         pub const SET_CONTROLLER_TO_HOST_FLOW_CONTROL = 0x031;
         pub const HOST_BUFFER_SIZE = 0x033;
         pub const NUMBER_OF_COMPLETED_PACKETS = 0x035;
+        pub const SET_EVENT_MASK_PAGE_2 = 0x0063;
+        pub const READ_AUTHENTICATED_PAYLOAD_TIMEOUT = 0x007B;
+        pub const WRITE_AUTHENTICATED_PAYLOAD_TIMEOUT = 0x007C;
     }
 
     InfoParam = 0x0004;
@@ -99,8 +122,52 @@ opcodes! {
         pub const LE_RECEIVER_TEST = 0x001D;
         pub const LE_TRANSMITTER_TEST = 0x001E;
         pub const LE_TEST_END = 0x001F;
+        pub const LE_REMOTE_CONNECTION_PARAMETER_REQUEST_REPLY = 0x0020;
+        pub const LE_REMOTE_CONNECTION_PARAMETER_REQUEST_NEGATIVE_REPLY = 0x0021;
         pub const LE_SET_DATA_LENGTH = 0x0022;
+        pub const LE_READ_SUGGESTED_DEFAULT_DATA_LENGTH = 0x0023;
         pub const LE_WRITE_SUGGESTED_DEFAULT_DATA_LENGTH = 0x0024;
+        pub const LE_READ_LOCAL_P256_PUBLIC_KEY = 0x0025;
+        pub const LE_GENERATE_DHKEY = 0x0026;
+        pub const LE_ADD_DEVICE_TO_RESOLVING_LIST = 0x0027;
+        pub const LE_REMOVE_DEVICE_FROM_RESOLVING_LIST = 0x0028;
+        pub const LE_CLEAR_RESOLVING_LIST = 0x0029;
+        pub const LE_READ_RESOLVING_LIST_SIZE = 0x002A;
+        pub const LE_READ_PEER_RESOLVABLE_ADDRESS = 0x002B;
+        pub const LE_READ_LOCAL_RESOLVABLE_ADDRESS = 0x002C;
+        pub const LE_SET_ADDRESS_RESOLUTION_ENABLE = 0x002D;
+        pub const LE_SET_RESOLVABLE_PRIVATE_ADDRESS_TIMEOUT = 0x002E;
+        pub const LE_READ_MAXIMUM_DATA_LENGTH = 0x002F;
+        pub const LE_READ_PHY = 0x0030;
         pub const LE_SET_DEFAULT_PHY = 0x0031;
+        pub const LE_SET_PHY = 0x0032;
+        pub const LE_SET_EXTENDED_ADVERTISING_PARAMETERS = 0x0036;
+        pub const LE_SET_EXTENDED_ADVERTISING_DATA = 0x0037;
+        pub const LE_SET_EXTENDED_SCAN_RESPONSE_DATA = 0x0038;
+        pub const LE_SET_EXTENDED_ADVERTISING_ENABLE = 0x0039;
+        pub const LE_READ_MAXIMUM_ADVERTISING_DATA_LENGTH = 0x003A;
+        pub const LE_READ_NUMBER_OF_SUPPORTED_ADVERTISING_SETS = 0x003B;
+        pub const LE_REMOVE_ADVERTISING_SET = 0x003C;
+        pub const LE_CLEAR_ADVERTISING_SETS = 0x003D;
+        pub const LE_SET_PERIODIC_ADVERTISING_PARAMETERS = 0x003E;
+        pub const LE_SET_PERIODIC_ADVERTISING_DATA = 0x003F;
+        pub const LE_SET_PERIODIC_ADVERTISING_ENABLE = 0x0040;
+        pub const LE_SET_EXTENDED_SCAN_PARAMETERS = 0x0041;
+        pub const LE_SET_EXTENDED_SCAN_ENABLE = 0x0042;
+        pub const LE_EXTENDED_CREATE_CONNECTION = 0x0043;
+        pub const LE_PERIODIC_ADVERTISING_CREATE_SYNC = 0x0044;
+        pub const LE_PERIODIC_ADVERTISING_CREATE_SYNC_CANCEL = 0x0045;
+        pub const LE_PERIODIC_ADVERTISING_TERMINATE_SYNC = 0x0046;
+        pub const LE_SET_PRIVACY_MODE = 0x004E;
+        pub const LE_PERIODIC_ADVERTISING_SYNC_TRANSFER = 0x005A;
+        pub const LE_PERIODIC_ADVERTISING_SET_INFO_TRANSFER = 0x005B;
+        pub const LE_SET_PERIODIC_ADVERTISING_SYNC_TRANSFER_PARAMETERS = 0x005C;
+        pub const LE_SET_DEFAULT_PERIODIC_ADVERTISING_SYNC_TRANSFER_PARAMETERS = 0x005D;
+        pub const LE_GENERATE_DHKEY_V2 = 0x005E;
+        pub const LE_ENHANCED_READ_TRANSMIT_POWER_LEVEL = 0x0076;
+        pub const LE_READ_REMOTE_TRANSMIT_POWER_LEVEL = 0x0077;
+        pub const LE_SET_PATH_LOSS_REPORTING_PARAMETERS = 0x0078;
+        pub const LE_SET_PATH_LOSS_REPORTING_ENABLE = 0x0079;
+        pub const LE_SET_TRANSMIT_POWER_REPORTING_ENABLE = 0x007A;
     }
 }
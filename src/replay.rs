@@ -0,0 +1,204 @@
+//! Controller that plays back a recorded exchange with a controller.
+//!
+//! A [`Replay`] is a [`Controller`] driven by a script of packets, built by hand or loaded from a
+//! [btsnoop](crate::btsnoop) capture. Every packet the host writes must match the next packet the
+//! script expects from the host, and every read returns the next packet the script expects from
+//! the controller. Whole bring-up sequences can then be tested without hardware:
+//!
+//! ```
+//! # extern crate stm32wb_hci as hci;
+//! use hci::blocking::block_on;
+//! use hci::host::{uart::UartHci, HciCommand, HostHci};
+//! use hci::replay::Replay;
+//!
+//! let mut controller = Replay::new()
+//!     .expect_command(&HciCommand::Reset)
+//!     .respond(&[0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00]);
+//!
+//! block_on(controller.reset()).unwrap();
+//! block_on(controller.read()).unwrap();
+//! assert!(controller.is_finished());
+//! ```
+//!
+//! Responses are returned in order, one packet per read. A response is only returned once the
+//! host has written every packet that precedes it in the script, so a host that forgets a command
+//! does not silently read the response to it.
+
+use crate::btsnoop::{self, acl_data_packet, command_packet, Direction, Reader, Record};
+use crate::command::{Command, MAX_PARAMETERS_LENGTH};
+use crate::event::MAX_EVENT_LENGTH;
+use crate::host::uart::{AclDataHeader, PACKET_TYPE_HCI_EVENT};
+use crate::{AclController, Controller, Event, Opcode};
+use core::cell::Cell;
+use std::io::Read;
+use std::vec::Vec;
+
+/// Errors returned by the [`Replay`] controller.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReplayError {
+    /// The host wrote a packet other than the one expected by the script.
+    UnexpectedPacket {
+        /// The next packet the script expects from the host, or `None` if the script does not
+        /// expect any more packets from the host.
+        expected: Option<Vec<u8>>,
+        /// The packet written by the host.
+        written: Vec<u8>,
+    },
+    /// The host read a packet, but the next response follows a packet that the host has not
+    /// written yet.
+    ResponseNotReady,
+    /// The host read a packet, but the script has no more responses.
+    Exhausted,
+}
+
+/// Controller that checks the packets written to it against a script, and returns the scripted
+/// responses.
+///
+/// Packets in the script start with their H4 packet type byte, as in a btsnoop capture.
+#[derive(Clone, Debug, Default)]
+pub struct Replay {
+    script: Vec<(Direction, Vec<u8>)>,
+    write_cursor: usize,
+    read_cursor: Cell<usize>,
+    read_offset: Cell<usize>,
+}
+
+impl Replay {
+    /// Creates a controller with an empty script.
+    pub fn new() -> Replay {
+        Replay::default()
+    }
+
+    /// Creates a controller whose script is the packets of `records`: packets sent by the host
+    /// are expected, and packets received by the host are returned.
+    pub fn from_records<I>(records: I) -> Replay
+    where
+        I: IntoIterator<Item = Record>,
+    {
+        Replay {
+            script: records
+                .into_iter()
+                .map(|record| (record.direction, record.data))
+                .collect(),
+            ..Replay::default()
+        }
+    }
+
+    /// Creates a controller whose script is the btsnoop capture read from `capture`. See
+    /// [`from_records`](Replay::from_records).
+    ///
+    /// # Errors
+    ///
+    /// Returns any error reading the capture.
+    pub fn from_capture<R: Read>(capture: R) -> Result<Replay, btsnoop::Error> {
+        Ok(Replay::from_records(
+            Reader::new(capture)?.collect::<Result<Vec<_>, _>>()?,
+        ))
+    }
+
+    /// Appends a packet the host is expected to write. `packet` starts with its H4 packet type
+    /// byte.
+    pub fn expect(mut self, packet: &[u8]) -> Replay {
+        self.script.push((Direction::Sent, packet.to_vec()));
+        self
+    }
+
+    /// Appends a command the host is expected to write.
+    pub fn expect_command<T: Command + ?Sized>(self, command: &T) -> Replay {
+        let mut params = [0; MAX_PARAMETERS_LENGTH];
+        let len = command.encode_into(&mut params);
+        self.expect(&command_packet(command.opcode(), &params[..len]))
+    }
+
+    /// Appends a packet to return to the host. `packet` starts with its H4 packet type byte.
+    pub fn respond(mut self, packet: &[u8]) -> Replay {
+        self.script.push((Direction::Received, packet.to_vec()));
+        self
+    }
+
+    /// Appends an event to return to the host.
+    pub fn respond_event(self, event: &Event) -> Replay {
+        let mut packet = [0; 1 + MAX_EVENT_LENGTH];
+        packet[0] = PACKET_TYPE_HCI_EVENT;
+        let len = 1 + event.copy_into_slice(&mut packet[1..]);
+        self.respond(&packet[..len])
+    }
+
+    /// Returns true if the host has written every expected packet and read every response.
+    pub fn is_finished(&self) -> bool {
+        self.next_step(self.write_cursor, Direction::Sent).is_none()
+            && self
+                .next_step(self.read_cursor.get(), Direction::Received)
+                .is_none()
+    }
+
+    fn next_step(&self, from: usize, direction: Direction) -> Option<usize> {
+        self.script[from.min(self.script.len())..]
+            .iter()
+            .position(|(d, _)| *d == direction)
+            .map(|i| from + i)
+    }
+
+    fn write_packet(&mut self, packet: Vec<u8>) -> Result<(), ReplayError> {
+        match self.next_step(self.write_cursor, Direction::Sent) {
+            Some(i) if self.script[i].1 == packet => {
+                self.write_cursor = i + 1;
+                Ok(())
+            }
+            next => Err(ReplayError::UnexpectedPacket {
+                expected: next.map(|i| self.script[i].1.clone()),
+                written: packet,
+            }),
+        }
+    }
+}
+
+impl Controller for Replay {
+    type Error = ReplayError;
+
+    async fn controller_write(
+        &mut self,
+        opcode: Opcode,
+        payload: &[u8],
+    ) -> Result<(), ReplayError> {
+        self.write_packet(command_packet(opcode, payload))
+    }
+
+    /// Copies the next response into `buf`. If `buf` is shorter than the response, the rest of
+    /// the response is returned by the next read. If it is longer, the bytes after the response
+    /// are left unchanged.
+    async fn controller_read_into(&self, buf: &mut [u8]) -> Result<(), ReplayError> {
+        let i = self
+            .next_step(self.read_cursor.get(), Direction::Received)
+            .ok_or(ReplayError::Exhausted)?;
+        if self
+            .next_step(self.write_cursor, Direction::Sent)
+            .is_some_and(|j| j < i)
+        {
+            return Err(ReplayError::ResponseNotReady);
+        }
+
+        let response = &self.script[i].1[self.read_offset.get()..];
+        let len = response.len().min(buf.len());
+        buf[..len].copy_from_slice(&response[..len]);
+        if len < response.len() {
+            self.read_cursor.set(i);
+            self.read_offset.set(self.read_offset.get() + len);
+        } else {
+            self.read_cursor.set(i + 1);
+            self.read_offset.set(0);
+        }
+
+        Ok(())
+    }
+}
+
+impl AclController for Replay {
+    async fn controller_write_acl_data(
+        &mut self,
+        header: &AclDataHeader,
+        data: &[u8],
+    ) -> Result<(), ReplayError> {
+        self.write_packet(acl_data_packet(header, data))
+    }
+}
@@ -0,0 +1,454 @@
+//! Software model of the STM32WB BLE coprocessor.
+//!
+//! [`Simulator`] is a [`Controller`] that answers the commands of a typical bring-up sequence the
+//! way the coprocessor firmware does, so that application logic can be tested on the host without
+//! a board attached:
+//!
+//! - HCI Reset clears the state of the simulator, and is followed by a
+//!   [`CoprocessorReady`](VendorEvent::CoprocessorReady) event.
+//! - HAL Get Firmware Revision returns the configured revision.
+//! - GATT Init, GAP Init, GATT Add Service and GATT Add Characteristic allocate attribute handles.
+//! - GAP Set Discoverable, Set Limited Discoverable, Set Direct Connectable, Set Undirected
+//!   Connectable, Set Nonconnectable and Set Nondiscoverable move between the advertising
+//!   [modes](Mode).
+//! - HAL Write Config Data and HAL Set Tx Power Level succeed without effect.
+//!
+//! Other commands are rejected with a [Command Status](crate::event::Event::CommandStatus) event
+//! carrying [`UnknownCommand`](crate::Status::UnknownCommand).
+//!
+//! Commands that are not allowed in the current state fail with the same vendor status codes as
+//! the firmware:
+//!
+//! - [`Failed`](VendorStatus::Failed) if GATT or GAP is initialized twice, if GAP is initialized
+//!   before GATT, if a service is added before GATT is initialized, or if advertising is started
+//!   before GAP is initialized with a role that allows it.
+//! - [`NotAllowed`](VendorStatus::NotAllowed) if advertising is started while the device is
+//!   already advertising in another mode.
+//! - [`InvalidHandle`](VendorStatus::InvalidHandle) if a characteristic is added to an unknown
+//!   service.
+//! - [`OutOfHandle`](VendorStatus::OutOfHandle) if the handles reserved for a service are used up.
+//! - [`InsufficientResources`](VendorStatus::InsufficientResources) if the attribute table is
+//!   full.
+//! - [`InvalidParameters`](VendorStatus::InvalidParameters) if the parameters are too short.
+//!
+//! The codes of [`Failed`](VendorStatus::Failed) and
+//! [`InvalidParameters`](VendorStatus::InvalidParameters) are also standard status codes, so they
+//! read back as [`Type0SubmapNotDefined`](Status::Type0SubmapNotDefined) and
+//! [`UnknownAdvertisingId`](Status::UnknownAdvertisingId).
+//!
+//! ```
+//! # extern crate stm32wb_hci as hci;
+//! use hci::blocking::block_on;
+//! use hci::host::uart::{Packet, UartHci};
+//! use hci::simulator::{Mode, Simulator};
+//! use hci::vendor::command::gap::{GapCommands, Role};
+//! use hci::vendor::command::gatt::GattCommands;
+//!
+//! let mut controller = Simulator::new();
+//! block_on(controller.init_gatt()).unwrap();
+//! block_on(controller.init_gap(Role::PERIPHERAL, false, 8)).unwrap();
+//! assert!(matches!(block_on(controller.read()), Ok(Packet::Event(_))));
+//! assert!(matches!(block_on(controller.read()), Ok(Packet::Event(_))));
+//! assert_eq!(controller.mode(), Mode::Nondiscoverable);
+//! ```
+
+use crate::event::command::{CommandComplete, ReturnParameters};
+use crate::event::{CommandStatus, MAX_EVENT_LENGTH};
+use crate::host::uart::PACKET_TYPE_HCI_EVENT;
+use crate::vendor::command::gap::Role;
+use crate::vendor::command::gatt::CharacteristicProperty;
+use crate::vendor::event::response::{
+    GapInit, GattCharacteristic, GattService, HalFirmwareRevision, VendorReturnParameters,
+};
+use crate::vendor::event::{AttributeHandle, FirmwareKind, VendorEvent, VendorStatus};
+use crate::vendor::opcode;
+use crate::{Controller, Event, Opcode, Status};
+use byteorder::{ByteOrder, LittleEndian};
+use core::cell::RefCell;
+use std::collections::VecDeque;
+use std::vec::Vec;
+
+/// Firmware revision returned by HAL Get Firmware Revision unless another one is configured.
+pub const DEFAULT_FIRMWARE_REVISION: u16 = 0x0113;
+
+/// Number of attribute handles used by the GATT service created by GATT Init.
+const GATT_SERVICE_HANDLES: u16 = 4;
+
+/// Error returned by the [`Simulator`] when the host reads, but no packet is pending. The
+/// coprocessor would not answer; the simulator fails instead of waiting forever.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NoPendingPacket;
+
+/// Advertising mode of the simulated device.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Mode {
+    /// The device is not advertising.
+    Nondiscoverable,
+    /// The device is in general discoverable mode.
+    Discoverable,
+    /// The device is in limited discoverable mode.
+    LimitedDiscoverable,
+    /// The device is in direct connectable mode.
+    DirectConnectable,
+    /// The device is in undirected connectable mode.
+    UndirectedConnectable,
+    /// The device is advertising, but cannot be connected to.
+    Nonconnectable,
+}
+
+#[derive(Clone, Debug)]
+struct Service {
+    handle: u16,
+    end_handle: u16,
+    next_handle: u16,
+}
+
+/// Controller that simulates the BLE stack of the STM32WB coprocessor.
+///
+/// Each command queues its response, and each read returns one queued packet. Reads fail with
+/// [`NoPendingPacket`] if no packet is queued.
+#[derive(Clone, Debug)]
+pub struct Simulator {
+    firmware_revision: u16,
+    pending: RefCell<VecDeque<Vec<u8>>>,
+    gatt_initialized: bool,
+    role: Option<Role>,
+    mode: Mode,
+    next_handle: u16,
+    services: Vec<Service>,
+}
+
+impl Simulator {
+    /// Creates a simulator in the state of a coprocessor that has just started.
+    pub fn new() -> Simulator {
+        Simulator {
+            firmware_revision: DEFAULT_FIRMWARE_REVISION,
+            pending: RefCell::new(VecDeque::new()),
+            gatt_initialized: false,
+            role: None,
+            mode: Mode::Nondiscoverable,
+            next_handle: 1,
+            services: Vec::new(),
+        }
+    }
+
+    /// Sets the revision returned by HAL Get Firmware Revision.
+    pub fn firmware_revision(mut self, revision: u16) -> Simulator {
+        self.firmware_revision = revision;
+        self
+    }
+
+    /// Returns the advertising mode of the device.
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Returns the number of packets waiting to be read.
+    pub fn pending(&self) -> usize {
+        self.pending.borrow().len()
+    }
+
+    fn reset(&mut self) {
+        *self = Simulator::new().firmware_revision(self.firmware_revision);
+    }
+
+    fn queue(&self, event: Event) {
+        let mut packet = [0; 1 + MAX_EVENT_LENGTH];
+        packet[0] = PACKET_TYPE_HCI_EVENT;
+        let len = 1 + event.copy_into_slice(&mut packet[1..]);
+        self.pending.borrow_mut().push_back(packet[..len].to_vec());
+    }
+
+    fn complete(&self, opcode: Opcode, return_params: ReturnParameters) {
+        self.queue(Event::CommandComplete(CommandComplete {
+            num_hci_command_packets: 1,
+            opcode,
+            return_params,
+        }));
+    }
+
+    fn complete_vendor(&self, opcode: Opcode, return_params: VendorReturnParameters) {
+        self.complete(opcode, ReturnParameters::Vendor(return_params));
+    }
+
+    /// Reserves `count` attribute handles, and returns the first one.
+    fn allocate(&mut self, count: u16) -> Result<u16, VendorStatus> {
+        let handle = self.next_handle;
+        self.next_handle = handle
+            .checked_add(count)
+            .ok_or(VendorStatus::InsufficientResources)?;
+
+        Ok(handle)
+    }
+
+    fn gatt_init(&mut self) -> Result<(), VendorStatus> {
+        if self.gatt_initialized {
+            return Err(VendorStatus::Failed);
+        }
+
+        self.allocate(GATT_SERVICE_HANDLES)?;
+        self.gatt_initialized = true;
+        Ok(())
+    }
+
+    fn gap_init(&mut self, payload: &[u8]) -> Result<GapInit, VendorStatus> {
+        if payload.len() < 3 {
+            return Err(VendorStatus::InvalidParameters);
+        }
+        if !self.gatt_initialized || self.role.is_some() {
+            return Err(VendorStatus::Failed);
+        }
+
+        // Service, then declaration and value of the device name, the appearance, and, for
+        // peripherals, the peripheral preferred connection parameters.
+        let role = Role::from_bits_truncate(payload[0]);
+        let handles = if role.contains(Role::PERIPHERAL) {
+            7
+        } else {
+            5
+        };
+        let service_handle = self.allocate(handles)?;
+        self.role = Some(role);
+
+        Ok(GapInit {
+            status: Status::Success,
+            service_handle: AttributeHandle(service_handle),
+            dev_name_handle: AttributeHandle(service_handle + 1),
+            appearance_handle: AttributeHandle(service_handle + 3),
+        })
+    }
+
+    fn add_service(&mut self, payload: &[u8]) -> Result<AttributeHandle, VendorStatus> {
+        let uuid_len = uuid_length(payload)?;
+        if payload.len() < uuid_len + 2 {
+            return Err(VendorStatus::InvalidParameters);
+        }
+        if !self.gatt_initialized {
+            return Err(VendorStatus::Failed);
+        }
+
+        // The service reserves its own handle, and the handles of its attribute records.
+        let max_attribute_records = payload[uuid_len + 1] as u16;
+        let handle = self.allocate(1 + max_attribute_records)?;
+        self.services.push(Service {
+            handle,
+            end_handle: handle + max_attribute_records,
+            next_handle: handle + 1,
+        });
+
+        Ok(AttributeHandle(handle))
+    }
+
+    fn add_characteristic(&mut self, payload: &[u8]) -> Result<AttributeHandle, VendorStatus> {
+        if payload.len() < 2 {
+            return Err(VendorStatus::InvalidParameters);
+        }
+        let uuid_len = uuid_length(&payload[2..])?;
+        let properties_index = 2 + uuid_len + 2;
+        if payload.len() < properties_index + 5 {
+            return Err(VendorStatus::InvalidParameters);
+        }
+
+        let service_handle = LittleEndian::read_u16(payload);
+        let service = self
+            .services
+            .iter_mut()
+            .find(|service| service.handle == service_handle)
+            .ok_or(VendorStatus::InvalidHandle)?;
+
+        // Declaration and value, then the client configuration and extended properties
+        // descriptors if the properties need them.
+        let properties = CharacteristicProperty::from_bits_truncate(payload[properties_index]);
+        let mut handles = 2;
+        if properties.intersects(CharacteristicProperty::NOTIFY | CharacteristicProperty::INDICATE)
+        {
+            handles += 1;
+        }
+        if properties.contains(CharacteristicProperty::EXTENDED_PROPERTIES) {
+            handles += 1;
+        }
+
+        let handle = service.next_handle;
+        if u32::from(handle) + handles - 1 > u32::from(service.end_handle) {
+            return Err(VendorStatus::OutOfHandle);
+        }
+        service.next_handle += handles as u16;
+
+        Ok(AttributeHandle(handle))
+    }
+
+    fn set_mode(&mut self, mode: Mode) -> Result<(), VendorStatus> {
+        let required = match mode {
+            Mode::Nonconnectable => Role::PERIPHERAL | Role::BROADCASTER,
+            _ => Role::PERIPHERAL,
+        };
+        match self.role {
+            Some(role) if role.intersects(required) => (),
+            _ => return Err(VendorStatus::Failed),
+        }
+
+        if mode != Mode::Nondiscoverable && self.mode != Mode::Nondiscoverable && self.mode != mode
+        {
+            return Err(VendorStatus::NotAllowed);
+        }
+
+        self.mode = mode;
+        Ok(())
+    }
+}
+
+impl Default for Simulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the length of the UUID at the beginning of `payload`, including its type byte.
+fn uuid_length(payload: &[u8]) -> Result<usize, VendorStatus> {
+    match payload.first() {
+        Some(0x01) => Ok(3),
+        Some(0x02) => Ok(17),
+        _ => Err(VendorStatus::InvalidParameters),
+    }
+}
+
+fn to_status(result: Result<(), VendorStatus>) -> Status {
+    match result {
+        Ok(()) => Status::Success,
+        Err(status) => Status::Vendor(status),
+    }
+}
+
+impl Controller for Simulator {
+    type Error = NoPendingPacket;
+
+    async fn controller_write(
+        &mut self,
+        opcode: Opcode,
+        payload: &[u8],
+    ) -> Result<(), NoPendingPacket> {
+        match opcode {
+            crate::opcode::RESET => {
+                self.reset();
+                self.complete(opcode, ReturnParameters::Reset(Status::Success));
+                self.queue(Event::Vendor(VendorEvent::CoprocessorReady(
+                    FirmwareKind::Wireless,
+                )));
+            }
+            opcode::HAL_GET_FIRMWARE_REVISION => self.complete_vendor(
+                opcode,
+                VendorReturnParameters::HalGetFirmwareRevision(HalFirmwareRevision {
+                    status: Status::Success,
+                    revision: self.firmware_revision,
+                }),
+            ),
+            opcode::HAL_WRITE_CONFIG_DATA => self.complete_vendor(
+                opcode,
+                VendorReturnParameters::HalWriteConfigData(Status::Success),
+            ),
+            opcode::HAL_SET_TX_POWER_LEVEL => self.complete_vendor(
+                opcode,
+                VendorReturnParameters::HalSetTxPowerLevel(Status::Success),
+            ),
+            opcode::GATT_INIT => {
+                let status = to_status(self.gatt_init());
+                self.complete_vendor(opcode, VendorReturnParameters::GattInit(status));
+            }
+            opcode::GAP_INIT => {
+                let params = self.gap_init(payload).unwrap_or_else(|status| GapInit {
+                    status: Status::Vendor(status),
+                    service_handle: AttributeHandle(0),
+                    dev_name_handle: AttributeHandle(0),
+                    appearance_handle: AttributeHandle(0),
+                });
+                self.complete_vendor(opcode, VendorReturnParameters::GapInit(params));
+            }
+            opcode::GATT_ADD_SERVICE => {
+                let params = match self.add_service(payload) {
+                    Ok(service_handle) => GattService {
+                        status: Status::Success,
+                        service_handle,
+                    },
+                    Err(status) => GattService {
+                        status: Status::Vendor(status),
+                        service_handle: AttributeHandle(0),
+                    },
+                };
+                self.complete_vendor(opcode, VendorReturnParameters::GattAddService(params));
+            }
+            opcode::GATT_ADD_CHARACTERISTIC => {
+                let params = match self.add_characteristic(payload) {
+                    Ok(characteristic_handle) => GattCharacteristic {
+                        status: Status::Success,
+                        characteristic_handle,
+                    },
+                    Err(status) => GattCharacteristic {
+                        status: Status::Vendor(status),
+                        characteristic_handle: AttributeHandle(0),
+                    },
+                };
+                self.complete_vendor(
+                    opcode,
+                    VendorReturnParameters::GattAddCharacteristic(params),
+                );
+            }
+            opcode::GAP_SET_NONDISCOVERABLE => {
+                let status = to_status(self.set_mode(Mode::Nondiscoverable));
+                self.complete_vendor(
+                    opcode,
+                    VendorReturnParameters::GapSetNonDiscoverable(status),
+                );
+            }
+            opcode::GAP_SET_LIMITED_DISCOVERABLE => {
+                // Unlike the other modes, limited discoverable mode is acknowledged by a Command
+                // Status event.
+                let status = to_status(self.set_mode(Mode::LimitedDiscoverable));
+                self.queue(Event::CommandStatus(CommandStatus {
+                    status,
+                    num_hci_command_packets: 1,
+                    opcode,
+                }));
+            }
+            opcode::GAP_SET_DISCOVERABLE => {
+                let status = to_status(self.set_mode(Mode::Discoverable));
+                self.complete_vendor(opcode, VendorReturnParameters::GapSetDiscoverable(status));
+            }
+            opcode::GAP_SET_DIRECT_CONNECTABLE => {
+                let status = to_status(self.set_mode(Mode::DirectConnectable));
+                self.complete_vendor(
+                    opcode,
+                    VendorReturnParameters::GapSetDirectConnectable(status),
+                );
+            }
+            opcode::GAP_SET_UNDIRECTED_CONNECTABLE => {
+                let status = to_status(self.set_mode(Mode::UndirectedConnectable));
+                self.complete_vendor(
+                    opcode,
+                    VendorReturnParameters::GapSetUndirectedConnectable(status),
+                );
+            }
+            opcode::GAP_SET_NONCONNECTABLE => {
+                let status = to_status(self.set_mode(Mode::Nonconnectable));
+                self.complete_vendor(opcode, VendorReturnParameters::GapSetNonConnectable(status));
+            }
+            _ => self.queue(Event::CommandStatus(CommandStatus {
+                status: Status::UnknownCommand,
+                num_hci_command_packets: 1,
+                opcode,
+            })),
+        }
+
+        Ok(())
+    }
+
+    async fn controller_read_into(&self, buf: &mut [u8]) -> Result<(), NoPendingPacket> {
+        let packet = self
+            .pending
+            .borrow_mut()
+            .pop_front()
+            .ok_or(NoPendingPacket)?;
+        buf[..packet.len()].copy_from_slice(&packet);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+//! Types related to the authenticated payload timeout (LE Ping).
+
+use crate::types::FixedConnectionInterval;
+use byteorder::{ByteOrder, LittleEndian};
+use core::time::Duration;
+
+/// Maximum time allowed between two packets containing a valid MIC on an encrypted connection,
+/// after which the controller sends an LE Ping to the peer.
+///
+/// The range is 10 ms to 655.35 s, with a resolution of 10 ms.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AuthenticatedPayloadTimeout(u16);
+
+impl AuthenticatedPayloadTimeout {
+    const UNIT: Duration = Duration::from_millis(10);
+
+    /// Creates a new AuthenticatedPayloadTimeout, or returns an error if the timeout is out of
+    /// range. The timeout is truncated to a multiple of 10 ms.
+    ///
+    /// # Errors
+    ///
+    /// - [TooShort](AuthenticatedPayloadTimeoutError::TooShort) if `timeout` is shorter than
+    ///   10 ms.
+    /// - [TooLong](AuthenticatedPayloadTimeoutError::TooLong) if `timeout` is longer than
+    ///   655.35 s.
+    pub fn new(timeout: Duration) -> Result<Self, AuthenticatedPayloadTimeoutError> {
+        if timeout < Self::UNIT {
+            return Err(AuthenticatedPayloadTimeoutError::TooShort(timeout));
+        }
+
+        if timeout > Self::UNIT * u32::from(u16::MAX) {
+            return Err(AuthenticatedPayloadTimeoutError::TooLong(timeout));
+        }
+
+        Ok(AuthenticatedPayloadTimeout(
+            (timeout.as_millis() / Self::UNIT.as_millis()) as u16,
+        ))
+    }
+
+    /// Creates a new AuthenticatedPayloadTimeout for a connection with the given connection
+    /// interval, or returns an error if the timeout is out of range.
+    ///
+    /// The timeout must be at least as long as the connection interval multiplied by one more than
+    /// the connection latency, so that the peripheral can skip the allowed number of connection
+    /// events without the timeout expiring.
+    ///
+    /// # Errors
+    ///
+    /// - Any of the errors from [`new`](AuthenticatedPayloadTimeout::new).
+    /// - [TooShortForConnection](AuthenticatedPayloadTimeoutError::TooShortForConnection) if the
+    ///   timeout is shorter than the connection interval multiplied by one more than the connection
+    ///   latency.
+    pub fn for_connection(
+        timeout: Duration,
+        conn_interval: &FixedConnectionInterval,
+    ) -> Result<Self, AuthenticatedPayloadTimeoutError> {
+        let timeout = Self::new(timeout)?;
+
+        let min_timeout = conn_interval.interval() * (1 + u32::from(conn_interval.conn_latency()));
+        if timeout.duration() < min_timeout {
+            return Err(AuthenticatedPayloadTimeoutError::TooShortForConnection(
+                timeout.duration(),
+                min_timeout,
+            ));
+        }
+
+        Ok(timeout)
+    }
+
+    /// Deserializes the timeout from the given byte buffer, which contains the number of 10 ms
+    /// units (2 bytes).
+    ///
+    /// # Panics
+    ///
+    /// The provided buffer must be at least 2 bytes long.
+    ///
+    /// # Errors
+    ///
+    /// [TooShort](AuthenticatedPayloadTimeoutError::TooShort) if the timeout is 0.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AuthenticatedPayloadTimeoutError> {
+        assert!(bytes.len() >= 2);
+
+        Self::new(Self::UNIT * u32::from(LittleEndian::read_u16(bytes)))
+    }
+
+    /// Serializes the timeout into the given byte buffer, in the format read by
+    /// [`from_bytes`](AuthenticatedPayloadTimeout::from_bytes).
+    ///
+    /// # Panics
+    ///
+    /// The provided buffer must be at least 2 bytes long.
+    pub fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert!(bytes.len() >= 2);
+
+        LittleEndian::write_u16(bytes, self.0);
+    }
+
+    /// Returns the timeout.
+    pub fn duration(&self) -> Duration {
+        Self::UNIT * u32::from(self.0)
+    }
+}
+
+/// Types of errors that can occur when creating an [`AuthenticatedPayloadTimeout`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AuthenticatedPayloadTimeoutError {
+    /// The timeout is shorter than 10 ms. Includes the requested timeout.
+    TooShort(Duration),
+    /// The timeout is longer than 655.35 s. Includes the requested timeout.
+    TooLong(Duration),
+    /// The timeout is shorter than the connection interval multiplied by one more than the
+    /// connection latency. Includes the timeout and the minimum timeout for the connection.
+    TooShortForConnection(Duration, Duration),
+}
@@ -87,13 +87,9 @@ impl ConnectionInterval {
     }
 
     fn interval_as_u16(d: Duration) -> u16 {
-        // T ms = N * 1.25 ms
-        // N = T / 1.25 ms
-        //   = T / (5/4) ms
-        //   = 4 * T ms / 5 ms
-        //
-        // Note: 1000 * 4 / 5 = 800
-        ((800 * d.as_secs()) as u32 + 4 * d.subsec_millis() / 5) as u16
+        // T us = N * 1250 us
+        // N = T us / 1250 us
+        (d.as_micros() / 1_250) as u16
     }
 
     fn timeout_as_u16(d: Duration) -> u16 {
@@ -329,6 +325,26 @@ impl FixedConnectionInterval {
         })
     }
 
+    /// Serializes the connection interval into the given byte buffer, in the format read by
+    /// [`from_bytes`](FixedConnectionInterval::from_bytes).
+    ///
+    /// # Panics
+    ///
+    /// The provided buffer must be at least 6 bytes long.
+    pub fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert!(bytes.len() >= 6);
+
+        LittleEndian::write_u16(
+            &mut bytes[0..2],
+            ConnectionInterval::interval_as_u16(self.interval_),
+        );
+        LittleEndian::write_u16(&mut bytes[2..4], self.conn_latency_);
+        LittleEndian::write_u16(
+            &mut bytes[4..6],
+            ConnectionInterval::timeout_as_u16(self.supervision_timeout_),
+        );
+    }
+
     /// Returns the connection interval.
     pub fn interval(&self) -> Duration {
         self.interval_
@@ -0,0 +1,90 @@
+//! Types related to the LE data length extension.
+
+use core::time::Duration;
+
+/// Maximum number of payload octets the controller should send in a single LL Data PDU.
+///
+/// The range is 27 to 251 octets.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MaxTxOctets(u16);
+
+impl MaxTxOctets {
+    /// Creates a new MaxTxOctets, or returns an error if the number of octets is out of range.
+    ///
+    /// # Errors
+    ///
+    /// - [TooFewOctets](DataLengthError::TooFewOctets) if `octets` is less than 27.
+    /// - [TooManyOctets](DataLengthError::TooManyOctets) if `octets` is greater than 251.
+    pub fn new(octets: u16) -> Result<MaxTxOctets, DataLengthError> {
+        const MIN: u16 = 0x001B;
+        if octets < MIN {
+            return Err(DataLengthError::TooFewOctets(octets));
+        }
+
+        const MAX: u16 = 0x00FB;
+        if octets > MAX {
+            return Err(DataLengthError::TooManyOctets(octets));
+        }
+
+        Ok(MaxTxOctets(octets))
+    }
+
+    /// Returns the number of octets.
+    pub fn value(&self) -> u16 {
+        self.0
+    }
+}
+
+/// Maximum time the controller should take to send a single LL Data PDU.
+///
+/// The range is 328 µs to 17040 µs, with a resolution of 1 µs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MaxTxTime(u16);
+
+impl MaxTxTime {
+    /// Creates a new MaxTxTime, or returns an error if the duration is out of range.
+    ///
+    /// # Errors
+    ///
+    /// - [TooShort](DataLengthError::TooShort) if `time` is shorter than 328 µs.
+    /// - [TooLong](DataLengthError::TooLong) if `time` is longer than 17040 µs.
+    pub fn new(time: Duration) -> Result<MaxTxTime, DataLengthError> {
+        const MIN: Duration = Duration::from_micros(0x0148);
+        if time < MIN {
+            return Err(DataLengthError::TooShort(time));
+        }
+
+        const MAX: Duration = Duration::from_micros(0x4290);
+        if time > MAX {
+            return Err(DataLengthError::TooLong(time));
+        }
+
+        Ok(MaxTxTime(time.as_micros() as u16))
+    }
+
+    /// Returns the time, truncated to whole microseconds.
+    pub fn duration(&self) -> Duration {
+        Duration::from_micros(self.0 as u64)
+    }
+
+    /// Returns the time in microseconds, as it is sent to the controller.
+    pub fn as_micros(&self) -> u16 {
+        self.0
+    }
+}
+
+/// Types of errors that can occur when creating a [`MaxTxOctets`] or [`MaxTxTime`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DataLengthError {
+    /// The number of octets is less than 27. Includes the requested number.
+    TooFewOctets(u16),
+    /// The number of octets is greater than 251. Includes the requested number.
+    TooManyOctets(u16),
+    /// The time is shorter than 328 µs. Includes the requested time.
+    TooShort(Duration),
+    /// The time is longer than 17040 µs. Includes the requested time.
+    TooLong(Duration),
+}
@@ -7,6 +7,7 @@ use crate::AdvertisingHandle;
 #[cfg(not(feature = "defmt"))]
 bitflags::bitflags! {
     /// Extended advertising modes
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub struct AdvertisingMode: u8 {
         /// Use specific random address
         const SPECIFIC = 0x01;
@@ -25,6 +26,7 @@ defmt::bitflags! {
 #[cfg(not(feature = "defmt"))]
 bitflags::bitflags! {
     /// Advertising event types
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub struct AdvertisingEvent: u16 {
         /// Connectable advertising
         const CONNECTABLE = 0x0001;
@@ -70,6 +72,7 @@ defmt::bitflags! {
 /// max. The advertising interval min and advertising interval max should not be the same
 /// values to enable the Controller to determine the best advertising interval given other
 /// adctivities, through this implementation allows them to be equal.
+#[derive(Clone, Copy, Debug)]
 pub struct ExtendedAdvertisingInterval {
     /// The first field is the min, the second is the max
     interval: (Duration, Duration),
@@ -131,6 +134,20 @@ impl ExtendedAdvertisingInterval {
         LittleEndian::write_u32(&mut bytes[0..], Self::duration_as_u32(self.interval.0));
         LittleEndian::write_u32(&mut bytes[4..], Self::duration_as_u32(self.interval.1));
     }
+
+    /// Serialize the interval into the given buffer, in the format of the LE Set Extended
+    /// Advertising Parameters command.
+    ///
+    /// Serializes the minimum range of the interval (3 bytes), the maximum range of the
+    /// interval (3 bytes)
+    ///
+    /// # Panics
+    ///
+    /// - If the provided buffer is not at least 6 bytes long.
+    pub(crate) fn copy_into_hci_slice(&self, bytes: &mut [u8]) {
+        LittleEndian::write_u24(&mut bytes[0..], Self::duration_as_u32(self.interval.0));
+        LittleEndian::write_u24(&mut bytes[3..], Self::duration_as_u32(self.interval.1));
+    }
 }
 
 /// Potential errors that can occur when specifying an [`ExtendedAdvertisingInterval`].
@@ -147,16 +164,20 @@ pub enum ExtendedAdvertisingIntervalError {
 }
 
 /// Advertising PHY
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum AdvertisingPhy {
     /// Advertisement PHY is LE 1M
     Le1M = 0x01,
-    /// Advertisement PHY is LE 2M
+    /// Advertisement PHY is LE 2M. Only allowed on the secondary advertising channel.
     Le2M = 0x02,
+    /// Advertisement PHY is LE Coded
+    LeCoded = 0x03,
 }
 
 /// Advertising set
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct AdvSet {
     /// Used to identify an advertising set
     pub handle: AdvertisingHandle,
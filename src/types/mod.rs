@@ -2,15 +2,19 @@
 
 mod advertisement;
 mod advertising_interval;
+mod authenticated_payload_timeout;
 mod common;
 mod connection_interval;
+mod data_length;
 mod expected_connection_length;
 pub mod extended_advertisement;
 mod scan_window;
 
 pub use self::advertisement::*;
 pub use self::advertising_interval::*;
+pub use self::authenticated_payload_timeout::*;
 pub use self::common::*;
 pub use self::connection_interval::*;
+pub use self::data_length::*;
 pub use self::expected_connection_length::*;
 pub use self::scan_window::*;
@@ -0,0 +1,92 @@
+extern crate stm32wb_hci as hci;
+
+use hci::types::{
+    AuthenticatedPayloadTimeout, AuthenticatedPayloadTimeoutError, FixedConnectionInterval,
+};
+use std::time::Duration;
+
+#[test]
+fn timeout_in_range() {
+    let timeout = AuthenticatedPayloadTimeout::new(Duration::from_millis(10)).unwrap();
+    assert_eq!(timeout.duration(), Duration::from_millis(10));
+
+    let timeout = AuthenticatedPayloadTimeout::new(Duration::from_millis(655_350)).unwrap();
+    assert_eq!(timeout.duration(), Duration::from_millis(655_350));
+}
+
+#[test]
+fn timeout_truncated_to_10_ms() {
+    let timeout = AuthenticatedPayloadTimeout::new(Duration::from_millis(30_009)).unwrap();
+    assert_eq!(timeout.duration(), Duration::from_secs(30));
+}
+
+#[test]
+fn timeout_too_short() {
+    let err = AuthenticatedPayloadTimeout::new(Duration::from_millis(9))
+        .err()
+        .unwrap();
+    assert_eq!(
+        err,
+        AuthenticatedPayloadTimeoutError::TooShort(Duration::from_millis(9))
+    );
+}
+
+#[test]
+fn timeout_too_long() {
+    let err = AuthenticatedPayloadTimeout::new(Duration::from_millis(655_360))
+        .err()
+        .unwrap();
+    assert_eq!(
+        err,
+        AuthenticatedPayloadTimeoutError::TooLong(Duration::from_millis(655_360))
+    );
+}
+
+fn conn_interval() -> FixedConnectionInterval {
+    // Interval: 50 ms, latency: 3, supervision timeout: 1 s
+    FixedConnectionInterval::from_bytes(&[0x28, 0x00, 0x03, 0x00, 0x64, 0x00]).unwrap()
+}
+
+#[test]
+fn timeout_for_connection() {
+    let timeout =
+        AuthenticatedPayloadTimeout::for_connection(Duration::from_millis(200), &conn_interval())
+            .unwrap();
+    assert_eq!(timeout.duration(), Duration::from_millis(200));
+}
+
+#[test]
+fn timeout_too_short_for_connection() {
+    let err =
+        AuthenticatedPayloadTimeout::for_connection(Duration::from_millis(190), &conn_interval())
+            .err()
+            .unwrap();
+    assert_eq!(
+        err,
+        AuthenticatedPayloadTimeoutError::TooShortForConnection(
+            Duration::from_millis(190),
+            Duration::from_millis(200)
+        )
+    );
+}
+
+#[test]
+fn timeout_round_trip() {
+    let timeout = AuthenticatedPayloadTimeout::from_bytes(&[0xB8, 0x0B]).unwrap();
+    assert_eq!(timeout.duration(), Duration::from_secs(30));
+
+    let mut bytes = [0; 2];
+    timeout.copy_into_slice(&mut bytes);
+    assert_eq!(bytes, [0xB8, 0x0B]);
+}
+
+#[test]
+fn zero_timeout_from_bytes() {
+    let err = AuthenticatedPayloadTimeout::from_bytes(&[0x00, 0x00])
+        .err()
+        .unwrap();
+    assert_eq!(
+        err,
+        AuthenticatedPayloadTimeoutError::TooShort(Duration::ZERO)
+    );
+}
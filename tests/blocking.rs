@@ -0,0 +1,169 @@
+extern crate stm32wb_hci as hci;
+
+use hci::blocking::{block_on, block_on_with_idle, Blocking};
+use hci::blocking::{AclHci, CommandExecutor, GapCommands, HalCommands, HostHci, UartHci};
+use hci::event::command::ReturnParameters;
+use hci::host::executor::CommandResponse;
+use hci::host::uart::{AclDataHeader, Packet, PacketBoundary};
+use hci::vendor::command::gap::IoCapability;
+use hci::{ConnectionHandle, Event, Opcode};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct NoResponse;
+
+struct BlockingController {
+    written: Vec<Vec<u8>>,
+    responses: RefCell<VecDeque<Vec<u8>>>,
+}
+
+impl BlockingController {
+    fn new(responses: &[&[u8]]) -> BlockingController {
+        BlockingController {
+            written: Vec::new(),
+            responses: RefCell::new(responses.iter().map(|r| r.to_vec()).collect()),
+        }
+    }
+}
+
+impl hci::blocking::Controller for BlockingController {
+    type Error = NoResponse;
+
+    fn controller_write(&mut self, opcode: Opcode, payload: &[u8]) -> Result<(), NoResponse> {
+        let mut packet = vec![
+            0x01,
+            opcode.0 as u8,
+            (opcode.0 >> 8) as u8,
+            payload.len() as u8,
+        ];
+        packet.extend_from_slice(payload);
+        self.written.push(packet);
+
+        Ok(())
+    }
+
+    fn controller_read_into(&self, buf: &mut [u8]) -> Result<(), NoResponse> {
+        let packet = self.responses.borrow_mut().pop_front().ok_or(NoResponse)?;
+        buf[..packet.len()].copy_from_slice(&packet);
+
+        Ok(())
+    }
+}
+
+impl hci::blocking::AclController for BlockingController {
+    fn controller_write_acl_data(
+        &mut self,
+        header: &AclDataHeader,
+        data: &[u8],
+    ) -> Result<(), NoResponse> {
+        let mut packet = vec![0; AclDataHeader::HEADER_LENGTH];
+        header.copy_into_slice(&mut packet);
+        packet.extend_from_slice(data);
+        self.written.push(packet);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn write_commands() {
+    let mut hci = Blocking(BlockingController::new(&[]));
+    hci.reset().unwrap();
+    hci.le_read_buffer_size().unwrap();
+    hci.get_firmware_revision().unwrap();
+    assert_eq!(
+        hci.0.written,
+        [
+            vec![0x01, 0x03, 0x0C, 0],
+            vec![0x01, 0x02, 0x20, 0],
+            vec![0x01, 0x00, 0xFC, 0],
+        ]
+    );
+}
+
+#[test]
+fn write_acl_data() {
+    let mut hci = Blocking(BlockingController::new(&[]));
+    hci.write_acl_data(
+        ConnectionHandle(0x0201),
+        PacketBoundary::FirstNonFlushable,
+        &[1, 2],
+    )
+    .unwrap();
+    assert_eq!(hci.0.written, [vec![0x02, 0x01, 0x02, 0x02, 0x00, 1, 2]]);
+}
+
+#[test]
+fn read_events() {
+    let mut hci = Blocking(BlockingController::new(&[&[
+        0x04, 0x0E, 4, 1, 0x03, 0x0C, 0x00,
+    ]]));
+    match hci.read() {
+        Ok(Packet::Event(Event::CommandComplete(event))) => {
+            assert!(matches!(event.return_params, ReturnParameters::Reset(_)))
+        }
+        other => panic!("Did not get command complete: {:?}", other),
+    }
+
+    match hci.read() {
+        Err(hci::host::uart::Error::Comm(NoResponse)) => (),
+        other => panic!("Did not get communication error: {:?}", other),
+    }
+}
+
+#[test]
+fn same_bytes_as_async_commands() {
+    let mut blocking = Blocking(BlockingController::new(&[]));
+    blocking.le_set_scan_enable(true, false).unwrap();
+    blocking
+        .set_io_capability(IoCapability::KeyboardDisplay)
+        .unwrap();
+
+    let mut async_hci = Blocking(BlockingController::new(&[]));
+    block_on(hci::host::HostHci::le_set_scan_enable(
+        &mut async_hci,
+        true,
+        false,
+    ))
+    .unwrap();
+    block_on(hci::vendor::command::gap::GapCommands::set_io_capability(
+        &mut async_hci,
+        IoCapability::KeyboardDisplay,
+    ))
+    .unwrap();
+
+    assert_eq!(blocking.0.written, async_hci.0.written);
+}
+
+#[test]
+fn execute_command() {
+    let mut hci = Blocking(BlockingController::new(&[
+        &[0x04, 0x3E, 3, 0x11, 0x00, 0x00],
+        &[0x04, 0x0E, 4, 1, 0x03, 0x0C, 0x00],
+    ]));
+    let mut queued = Vec::new();
+    match hci.execute(Opcode(0x0C03), &[], &mut |packet| queued.push(packet)) {
+        Ok(CommandResponse::Complete(ReturnParameters::Reset(_))) => (),
+        other => panic!("Did not get reset response: {:?}", other),
+    }
+    assert_eq!(hci.0.written, [vec![0x01, 0x03, 0x0C, 0]]);
+    assert_eq!(queued.len(), 1);
+}
+
+#[test]
+fn idle_between_polls() {
+    let mut polls = 0;
+    let future = core::future::poll_fn(|_| {
+        polls += 1;
+        if polls < 3 {
+            core::task::Poll::Pending
+        } else {
+            core::task::Poll::Ready(polls)
+        }
+    });
+
+    let mut idles = 0;
+    assert_eq!(block_on_with_idle(future, || idles += 1), 3);
+    assert_eq!(idles, 2);
+}
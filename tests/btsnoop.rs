@@ -0,0 +1,155 @@
+extern crate stm32wb_hci as hci;
+
+mod vendor;
+
+use hci::btsnoop::*;
+use hci::event::command::ReturnParameters;
+use hci::host::uart::{Packet, UartHci};
+use hci::host::HostHci;
+use hci::{Event, Opcode};
+use std::time::{Duration, SystemTime};
+use vendor::{ScriptExhausted, ScriptedController};
+
+const RESET_COMPLETE: [u8; 7] = [0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00];
+
+#[tokio::test]
+async fn recorder_captures_commands_and_packets() {
+    let mut recorder =
+        Recorder::new(ScriptedController::new(&[&RESET_COMPLETE]), Vec::new()).unwrap();
+    recorder.reset().await.unwrap();
+    recorder.read().await.unwrap();
+    let (controller, capture) = recorder.into_inner();
+    assert_eq!(controller.written, [(Opcode(0x0C03), vec![])]);
+
+    let records: Vec<_> = Reader::new(capture.as_slice())
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].direction, Direction::Sent);
+    assert_eq!(records[0].data, [0x01, 0x03, 0x0C, 0x00]);
+    assert_eq!(records[1].direction, Direction::Received);
+    assert_eq!(records[1].data, RESET_COMPLETE);
+
+    match records[0].decode().unwrap() {
+        Payload::Command { opcode, params } => {
+            assert_eq!(opcode, Opcode(0x0C03));
+            assert!(params.is_empty());
+        }
+        other => panic!("Did not get command: {:?}", other),
+    }
+    match records[1].decode().unwrap() {
+        Payload::Packet(Packet::Event(Event::CommandComplete(event))) => {
+            assert!(matches!(event.return_params, ReturnParameters::Reset(_)));
+        }
+        other => panic!("Did not get command complete: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn recorder_does_not_record_failed_reads() {
+    let mut recorder = Recorder::new(ScriptedController::new(&[]), Vec::new()).unwrap();
+    match recorder.read().await {
+        Err(hci::host::uart::Error::Comm(RecorderError::Comm(ScriptExhausted))) => (),
+        other => panic!("Did not get comm error: {:?}", other),
+    }
+
+    let (_, capture) = recorder.into_inner();
+    assert_eq!(Reader::new(capture.as_slice()).unwrap().count(), 0);
+}
+
+#[test]
+fn writer_produces_wireshark_layout() {
+    let timestamp = SystemTime::UNIX_EPOCH + Duration::from_micros(1);
+    let mut writer = Writer::new(Vec::new()).unwrap();
+    writer
+        .write_packet(Direction::Received, timestamp, &RESET_COMPLETE)
+        .unwrap();
+    let capture = writer.into_inner();
+
+    assert_eq!(capture[..8], *b"btsnoop\0");
+    assert_eq!(capture[8..16], [0, 0, 0, 1, 0, 0, 0x03, 0xEA]);
+    assert_eq!(
+        capture[16..40],
+        [
+            0, 0, 0, 7, 0, 0, 0, 7, 0, 0, 0, 3, 0, 0, 0, 0, 0x00, 0xDC, 0xDD, 0xB3, 0x0F, 0x2F,
+            0x80, 0x01
+        ]
+    );
+    assert_eq!(capture[40..], RESET_COMPLETE);
+
+    let records: Vec<_> = Reader::new(capture.as_slice())
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        records,
+        [Record {
+            direction: Direction::Received,
+            timestamp,
+            data: RESET_COMPLETE.to_vec(),
+        }]
+    );
+}
+
+#[test]
+fn reader_rejects_other_datalinks() {
+    let mut capture = b"btsnoop\0".to_vec();
+    capture.extend_from_slice(&[0, 0, 0, 1, 0, 0, 0x03, 0xE9]);
+    match Reader::new(capture.as_slice()) {
+        Err(Error::UnsupportedDatalink(1001)) => (),
+        other => panic!("Did not get unsupported datalink: {:?}", other),
+    }
+}
+
+#[test]
+fn reader_reports_truncated_record() {
+    let mut writer = Writer::new(Vec::new()).unwrap();
+    writer
+        .write_packet(Direction::Received, SystemTime::now(), &RESET_COMPLETE)
+        .unwrap();
+    let capture = writer.into_inner();
+
+    let mut reader = Reader::new(&capture[..capture.len() - 1]).unwrap();
+    assert!(matches!(reader.next(), Some(Err(Error::Truncated))));
+    assert!(reader.next().is_none());
+}
+
+#[test]
+fn decode_reports_truncated_packet() {
+    let record = Record {
+        direction: Direction::Received,
+        timestamp: SystemTime::now(),
+        data: RESET_COMPLETE[..6].to_vec(),
+    };
+    assert!(matches!(record.decode(), Err(Error::Truncated)));
+}
+
+fn capture_with_record_header(original_len: u32, included_len: u32) -> Vec<u8> {
+    let mut capture = Writer::new(Vec::new()).unwrap().into_inner();
+    capture.extend_from_slice(&original_len.to_be_bytes());
+    capture.extend_from_slice(&included_len.to_be_bytes());
+    capture.extend_from_slice(&[0; 16]);
+    capture
+}
+
+#[test]
+fn reader_rejects_record_longer_than_original() {
+    let capture = capture_with_record_header(4, 7);
+    let mut reader = Reader::new(capture.as_slice()).unwrap();
+    assert!(matches!(
+        reader.next(),
+        Some(Err(Error::BadRecordLength(7)))
+    ));
+    assert!(reader.next().is_none());
+}
+
+#[test]
+fn reader_rejects_record_longer_than_any_packet() {
+    let capture = capture_with_record_header(u32::MAX, u32::MAX);
+    let mut reader = Reader::new(capture.as_slice()).unwrap();
+    assert!(matches!(
+        reader.next(),
+        Some(Err(Error::BadRecordLength(u32::MAX)))
+    ));
+}
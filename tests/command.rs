@@ -0,0 +1,233 @@
+extern crate stm32wb_hci as hci;
+
+mod vendor;
+
+use hci::command::{Command, MAX_PARAMETERS_LENGTH};
+use hci::host::{HciCommand, HostHci};
+use hci::types::extended_advertisement::{AdvSet, AdvertisingOperation};
+use hci::vendor::command::gap::{AdvSetAdvertisingData, AdvSetEnable, GapCommand};
+use hci::vendor::command::gatt::{
+    AddCharacteristicParameters, AddServiceParameters, CharacteristicEvent,
+    CharacteristicPermission, CharacteristicProperty, EncryptionKeySize, GattCommand, GattCommands,
+    IncludeServiceParameters, ReadByTypeParameters, ServiceType, Uuid,
+};
+use hci::vendor::command::hal::{ConfigData, HalCommand, HalCommands};
+use hci::vendor::command::l2cap::{L2CapCocReconfig, L2CapCocTxData, L2capCommand};
+use hci::vendor::event::AttributeHandle;
+use hci::{AdvertisingHandle, BdAddr, ConnectionHandle, Opcode, Status};
+use vendor::RecordingSink;
+
+fn encode<T: Command>(command: &T) -> (Opcode, Vec<u8>) {
+    let mut buf = [0; MAX_PARAMETERS_LENGTH];
+    let len = command.encode_into(&mut buf);
+    (command.opcode(), buf[..len].to_vec())
+}
+
+fn packet(opcode: Opcode, params: &[u8]) -> Vec<u8> {
+    let mut packet = vec![
+        0x01,
+        opcode.0 as u8,
+        (opcode.0 >> 8) as u8,
+        params.len() as u8,
+    ];
+    packet.extend_from_slice(params);
+    packet
+}
+
+#[tokio::test]
+async fn host_command_matches_written_command() {
+    let command = HciCommand::Disconnect {
+        conn_handle: ConnectionHandle(0x0201),
+        reason: Status::AuthFailure,
+    };
+    let (opcode, params) = encode(&command);
+    assert_eq!(opcode, hci::opcode::DISCONNECT);
+    assert_eq!(params, [0x01, 0x02, 0x05]);
+
+    let mut sink = RecordingSink::new();
+    sink.disconnect(ConnectionHandle(0x0201), Status::AuthFailure)
+        .await
+        .unwrap();
+    assert_eq!(sink.written_data, packet(opcode, &params));
+}
+
+#[tokio::test]
+async fn vendor_command_matches_written_command() {
+    let config = ConfigData::public_address(BdAddr([1, 2, 3, 4, 5, 6])).build();
+    let (opcode, params) = encode(&HalCommand::WriteConfigData(&config));
+    assert_eq!(opcode, Opcode(0xFC0C));
+    assert_eq!(params, [0x00, 0x06, 1, 2, 3, 4, 5, 6]);
+
+    let mut sink = RecordingSink::new();
+    sink.write_config_data(&config).await.unwrap();
+    assert_eq!(sink.written_data, packet(opcode, &params));
+}
+
+#[test]
+fn encoding_ignores_stale_buffer_contents() {
+    let command = HciCommand::LeSetAdvertisingData(&[0x01, 0x02, 0x03]);
+    let mut buf = [0xFF; MAX_PARAMETERS_LENGTH];
+    let len = command.encode_into(&mut buf);
+
+    let mut expected = [0; 32];
+    expected[..4].copy_from_slice(&[0x03, 0x01, 0x02, 0x03]);
+    assert_eq!(buf[..len], expected);
+}
+
+#[test]
+fn validate_reports_parameter_errors() {
+    let data = [0; 32];
+    let err = HciCommand::LeSetAdvertisingData(&data)
+        .validate::<()>()
+        .err()
+        .unwrap();
+    assert_eq!(err, hci::host::Error::AdvertisingDataTooLong(32));
+    assert!(HciCommand::LeSetAdvertisingData(&data[..31])
+        .validate::<()>()
+        .is_ok());
+}
+
+#[test]
+fn execute_and_cancel_write_request_share_an_opcode() {
+    let (execute_opcode, execute) =
+        encode(&GattCommand::ExecuteWriteRequest(ConnectionHandle(0x0201)));
+    let (cancel_opcode, cancel) =
+        encode(&GattCommand::CancelWriteRequest(ConnectionHandle(0x0201)));
+    assert_eq!(execute_opcode, cancel_opcode);
+    assert_eq!(execute, [0x01, 0x02, 0x01]);
+    assert_eq!(cancel, [0x01, 0x02, 0x00]);
+}
+
+#[test]
+fn send_multiple_notification_counts_handles() {
+    let handles = [AttributeHandle(0x0403), AttributeHandle(0x0605)];
+    let (opcode, params) = encode(&GattCommand::SendMultipleNotification {
+        conn_handle: ConnectionHandle(0x0201),
+        handles: &handles,
+    });
+    assert_eq!(opcode, Opcode(0xFD31));
+    assert_eq!(params, [0x01, 0x02, 2, 0x03, 0x04, 0x05, 0x06]);
+}
+
+#[tokio::test]
+async fn add_characteristic_sends_is_variable() {
+    let params = AddCharacteristicParameters {
+        service_handle: AttributeHandle(0x0201),
+        characteristic_uuid: Uuid::Uuid16(0x2A37),
+        characteristic_value_len: 20,
+        characteristic_properties: CharacteristicProperty::READ,
+        security_permissions: CharacteristicPermission::empty(),
+        gatt_event_mask: CharacteristicEvent::ATTRIBUTE_WRITE,
+        encryption_key_size: EncryptionKeySize::with_value(16).unwrap(),
+        is_variable: true,
+    };
+    let (opcode, params_bytes) = encode(&GattCommand::AddCharacteristic(&params));
+    assert_eq!(opcode, hci::vendor::opcode::GATT_ADD_CHARACTERISTIC);
+    assert_eq!(
+        params_bytes,
+        [0x01, 0x02, 0x01, 0x37, 0x2A, 20, 0x00, 0x02, 0x00, 0x01, 16, 0x01]
+    );
+
+    let mut sink = RecordingSink::new();
+    sink.add_characteristic(&params).await.unwrap();
+    assert_eq!(sink.written_data, packet(opcode, &params_bytes));
+}
+
+#[test]
+fn add_service_sends_uuid_length() {
+    let (_, params) = encode(&GattCommand::AddService(&AddServiceParameters {
+        uuid: Uuid::Uuid16(0x180D),
+        service_type: ServiceType::Primary,
+        max_attribute_records: 5,
+    }));
+    assert_eq!(params, [0x01, 0x0D, 0x18, 0x01, 5]);
+}
+
+#[test]
+fn include_service_sends_uuid_length() {
+    let (_, params) = encode(&GattCommand::IncludeService(&IncludeServiceParameters {
+        service_handle: AttributeHandle(0x0201),
+        include_handle_range: AttributeHandle(0x0403)..AttributeHandle(0x0605),
+        include_uuid: Uuid::Uuid16(0x180F),
+    }));
+    assert_eq!(
+        params,
+        [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x01, 0x0F, 0x18]
+    );
+}
+
+#[test]
+fn read_by_type_sends_uuid_length() {
+    let params = ReadByTypeParameters {
+        conn_handle: ConnectionHandle(0x0201),
+        attribute_handle_range: AttributeHandle(0x0403)..AttributeHandle(0x0605),
+        uuid: Uuid::Uuid16(0x2803),
+    };
+    let expected = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x01, 0x03, 0x28];
+
+    let (opcode, by_type) = encode(&GattCommand::ReadByTypeRequest(&params));
+    assert_eq!(opcode, hci::vendor::opcode::GATT_READ_BY_TYPE_REQUEST);
+    assert_eq!(by_type, expected);
+
+    let (opcode, by_group_type) = encode(&GattCommand::ReadByGroupTypeRequest(&params));
+    assert_eq!(opcode, hci::vendor::opcode::GATT_READ_BY_GROUP_TYPE_REQUEST);
+    assert_eq!(by_group_type, expected);
+}
+
+#[test]
+fn adv_set_enable_sends_set_count() {
+    let sets = [AdvSet {
+        handle: AdvertisingHandle(0x01),
+        duration: 0x0302,
+        max_extended_adv_events: 4,
+    }];
+    let (_, params) = encode(&GapCommand::AdvSetEnable(&AdvSetEnable {
+        enable: true,
+        num_sets: 1,
+        adv_set: &sets,
+    }));
+    assert_eq!(params, [0x01, 0x01, 0x01, 0x02, 0x03, 0x04]);
+}
+
+#[test]
+fn adv_set_advertising_data_sends_data_length() {
+    let (_, params) = encode(&GapCommand::AdvSetAdvertisingData(&AdvSetAdvertisingData {
+        adv_handle: AdvertisingHandle(0x01),
+        operation: AdvertisingOperation::CompleteData,
+        fragment: false,
+        data: &[0x02, 0x01, 0x06],
+    }));
+    assert_eq!(params, [0x01, 0x03, 0x01, 0x03, 0x02, 0x01, 0x06]);
+}
+
+#[test]
+fn coc_reconfig_sends_channel_count() {
+    let (_, params) = encode(&L2capCommand::CocReconfig(&L2CapCocReconfig {
+        conn_handle: ConnectionHandle(0x0201),
+        mtu: 0x0403,
+        mps: 0x0605,
+        channel_number: 2,
+        channel_index_list: [0x11, 0x12, 0x13, 0x14, 0x15],
+    }));
+    assert_eq!(
+        params,
+        [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x02, 0x11, 0x12]
+    );
+}
+
+#[test]
+fn coc_tx_data_sends_data_length() {
+    let (_, params) = encode(&L2capCommand::CocTxData(&L2CapCocTxData {
+        channel_index: 0x01,
+        length: 3,
+        data: &[0x07, 0x08, 0x09],
+    }));
+    assert_eq!(params, [0x01, 0x03, 0x00, 0x07, 0x08, 0x09]);
+
+    let (_, params) = encode(&L2capCommand::CocTxData(&L2CapCocTxData {
+        channel_index: 0x01,
+        length: 0,
+        data: &[],
+    }));
+    assert_eq!(params, [0x01, 0x00, 0x00]);
+}
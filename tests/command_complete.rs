@@ -31,6 +31,13 @@ impl TryFrom<u8> for VendorStatus {
     }
 }
 
+fn assert_round_trip(buffer: &[u8]) {
+    let event = Event::new(Packet(buffer)).unwrap();
+    let mut bytes = [0; MAX_EVENT_LENGTH];
+    let len = event.copy_into_slice(&mut bytes);
+    assert_eq!(bytes[..len], *buffer);
+}
+
 #[test]
 fn command_complete_failed() {
     let buffer = [0x0E, 3, 1, 0x67, 0x43];
@@ -53,6 +60,7 @@ fn unsolicited_command_complete() {
         }
         other => panic!("Did not get command complete event: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 macro_rules! status_only {
@@ -77,6 +85,7 @@ macro_rules! status_only {
                     }
                     other => panic!("Did not get command complete event: {:?}", other),
                 }
+                assert_round_trip(&buffer);
             }
         )*
     }
@@ -85,6 +94,7 @@ macro_rules! status_only {
 status_only! {
     set_event_mask(0x01, 0x0C, ReturnParameters::SetEventMask);
     reset(0x03, 0x0C, ReturnParameters::Reset);
+    set_event_mask_page_2(0x63, 0x0C, ReturnParameters::SetEventMaskPage2);
     le_set_event_mask(0x01, 0x20, ReturnParameters::LeSetEventMask);
     le_set_random_address(0x05, 0x20, ReturnParameters::LeSetRandomAddress);
     le_set_advertising_parameters(0x06, 0x20, ReturnParameters::LeSetAdvertisingParameters);
@@ -101,6 +111,61 @@ status_only! {
                                        ReturnParameters::LeSetHostChannelClassification);
     le_receiver_test(0x1D, 0x20, ReturnParameters::LeReceiverTest);
     le_transmitter_test(0x1E, 0x20, ReturnParameters::LeTransmitterTest);
+    le_set_data_length(0x22, 0x20, ReturnParameters::LeSetDataLength);
+    le_write_suggested_default_data_length(
+        0x24,
+        0x20,
+        ReturnParameters::LeWriteSuggestedDefaultDataLength
+    );
+    le_set_extended_advertising_data(0x37, 0x20, ReturnParameters::LeSetExtendedAdvertisingData);
+    le_set_extended_scan_response_data(0x38, 0x20,
+                                       ReturnParameters::LeSetExtendedScanResponseData);
+    le_set_extended_advertising_enable(0x39, 0x20,
+                                       ReturnParameters::LeSetExtendedAdvertisingEnable);
+    le_remove_advertising_set(0x3C, 0x20, ReturnParameters::LeRemoveAdvertisingSet);
+    le_clear_advertising_sets(0x3D, 0x20, ReturnParameters::LeClearAdvertisingSets);
+    le_set_periodic_advertising_parameters(
+        0x3E,
+        0x20,
+        ReturnParameters::LeSetPeriodicAdvertisingParameters
+    );
+    le_set_periodic_advertising_data(0x3F, 0x20, ReturnParameters::LeSetPeriodicAdvertisingData);
+    le_set_periodic_advertising_enable(
+        0x40,
+        0x20,
+        ReturnParameters::LeSetPeriodicAdvertisingEnable
+    );
+    le_set_extended_scan_parameters(0x41, 0x20, ReturnParameters::LeSetExtendedScanParameters);
+    le_set_extended_scan_enable(0x42, 0x20, ReturnParameters::LeSetExtendedScanEnable);
+    le_periodic_advertising_create_sync_cancel(
+        0x45,
+        0x20,
+        ReturnParameters::LePeriodicAdvertisingCreateSyncCancel
+    );
+    le_periodic_advertising_terminate_sync(
+        0x46,
+        0x20,
+        ReturnParameters::LePeriodicAdvertisingTerminateSync
+    );
+    le_add_device_to_resolving_list(0x27, 0x20, ReturnParameters::LeAddDeviceToResolvingList);
+    le_remove_device_from_resolving_list(
+        0x28,
+        0x20,
+        ReturnParameters::LeRemoveDeviceFromResolvingList
+    );
+    le_clear_resolving_list(0x29, 0x20, ReturnParameters::LeClearResolvingList);
+    le_set_address_resolution_enable(0x2D, 0x20, ReturnParameters::LeSetAddressResolutionEnable);
+    le_set_resolvable_private_address_timeout(
+        0x2E,
+        0x20,
+        ReturnParameters::LeSetResolvablePrivateAddressTimeout
+    );
+    le_set_privacy_mode(0x4E, 0x20, ReturnParameters::LeSetPrivacyMode);
+    le_set_default_periodic_advertising_sync_transfer_parameters(
+        0x5D,
+        0x20,
+        ReturnParameters::LeSetDefaultPeriodicAdvertisingSyncTransferParameters
+    );
 }
 
 #[test]
@@ -120,6 +185,7 @@ fn read_tx_power_level() {
         }
         other => panic!("Did not get command complete event: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -147,6 +213,7 @@ fn read_local_version_information() {
         }
         other => panic!("Did not get command complete event: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -217,6 +284,7 @@ fn read_local_supported_commands() {
         }
         other => panic!("Did not get command complete event: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -265,6 +333,7 @@ fn read_local_supported_features() {
         }
         other => panic!("Did not get command complete event: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -288,6 +357,7 @@ fn read_bd_addr() {
         }
         other => panic!("Did not get command complete event: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -307,6 +377,7 @@ fn read_rssi() {
         }
         other => panic!("Did not get command complete event: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -326,6 +397,7 @@ fn le_read_buffer_size() {
         }
         other => panic!("Did not get command complete event: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -351,6 +423,7 @@ fn le_read_local_supported_features() {
         }
         other => panic!("Did not get command complete event: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -372,6 +445,7 @@ fn le_read_advertising_channel_tx_power() {
         }
         other => panic!("Did not get command complete event: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -393,6 +467,7 @@ fn le_read_white_list_size() {
         }
         other => panic!("Did not get command complete event: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -429,6 +504,7 @@ fn le_read_channel_map() {
         }
         other => panic!("Did not get command complete event: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -465,6 +541,7 @@ fn le_encrypt() {
         }
         other => panic!("Did not get command complete event: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -485,6 +562,188 @@ fn le_rand() {
         }
         other => panic!("Did not get command complete event: {:?}", other),
     }
+    assert_round_trip(&buffer);
+}
+
+macro_rules! connection_handle_only {
+    {
+        $($(#[$inner:ident $($args:tt)*])*
+        $fn:ident($oc0:expr, $oc1:expr, $return_value:path);)*
+    } => {
+        $(
+            $(#[$inner $($args)*])*
+            #[test]
+            fn $fn() {
+                let buffer = [0x0E, 6, 1, $oc0, $oc1, 0x00, 0x01, 0x02];
+                match Event::new(Packet(&buffer)) {
+                    Ok(Event::CommandComplete(event)) => {
+                        assert_eq!(event.num_hci_command_packets, 1);
+                        match event.return_params {
+                            $return_value(params) => {
+                                assert_eq!(params.status, hci::Status::Success);
+                                assert_eq!(params.conn_handle, hci::ConnectionHandle(0x0201));
+                            }
+                            other => panic!("Wrong return parameters: {:?}", other),
+                        }
+                    }
+                    other => panic!("Did not get command complete event: {:?}", other),
+                }
+                assert_round_trip(&buffer);
+            }
+        )*
+    }
+}
+
+connection_handle_only! {
+    write_authenticated_payload_timeout(
+        0x7C,
+        0x0C,
+        ReturnParameters::WriteAuthenticatedPayloadTimeout
+    );
+    le_remote_connection_parameter_request_reply(
+        0x20,
+        0x20,
+        ReturnParameters::LeRemoteConnectionParameterRequestReply
+    );
+    le_remote_connection_parameter_request_negative_reply(
+        0x21,
+        0x20,
+        ReturnParameters::LeRemoteConnectionParameterRequestNegativeReply
+    );
+    le_periodic_advertising_sync_transfer(
+        0x5A,
+        0x20,
+        ReturnParameters::LePeriodicAdvertisingSyncTransfer
+    );
+    le_periodic_advertising_set_info_transfer(
+        0x5B,
+        0x20,
+        ReturnParameters::LePeriodicAdvertisingSetInfoTransfer
+    );
+    le_set_periodic_advertising_sync_transfer_parameters(
+        0x5C,
+        0x20,
+        ReturnParameters::LeSetPeriodicAdvertisingSyncTransferParameters
+    );
+    le_set_path_loss_reporting_parameters(
+        0x78,
+        0x20,
+        ReturnParameters::LeSetPathLossReportingParameters
+    );
+    le_set_path_loss_reporting_enable(
+        0x79,
+        0x20,
+        ReturnParameters::LeSetPathLossReportingEnable
+    );
+    le_set_transmit_power_reporting_enable(
+        0x7A,
+        0x20,
+        ReturnParameters::LeSetTransmitPowerReportingEnable
+    );
+}
+
+#[test]
+fn le_enhanced_read_transmit_power_level() {
+    let buffer = [0x0E, 9, 1, 0x76, 0x20, 0x00, 0x01, 0x02, 0x03, 0xF6, 0x08];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => {
+            assert_eq!(event.num_hci_command_packets, 1);
+            match event.return_params {
+                ReturnParameters::LeEnhancedReadTransmitPowerLevel(params) => {
+                    assert_eq!(params.status, hci::Status::Success);
+                    assert_eq!(params.conn_handle, hci::ConnectionHandle(0x0201));
+                    let levels = params.levels.unwrap();
+                    assert_eq!(levels.phy, PowerControlPhy::LeCodedS8);
+                    assert_eq!(levels.current_tx_power_level, -10);
+                    assert_eq!(levels.max_tx_power_level, 8);
+                }
+                other => panic!(
+                    "Did not get LE Enhanced Read Transmit Power Level return params: {:?}",
+                    other
+                ),
+            }
+        }
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_enhanced_read_transmit_power_level_failed() {
+    let buffer = [0x0E, 9, 1, 0x76, 0x20, 0x02, 0x01, 0x02, 0x00, 0x00, 0x00];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => match event.return_params {
+            ReturnParameters::LeEnhancedReadTransmitPowerLevel(params) => {
+                assert_eq!(params.status, hci::Status::UnknownConnectionId);
+                assert_eq!(params.conn_handle, hci::ConnectionHandle(0x0201));
+                assert!(params.levels.is_none());
+            }
+            other => panic!(
+                "Did not get LE Enhanced Read Transmit Power Level return params: {:?}",
+                other
+            ),
+        },
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn read_authenticated_payload_timeout() {
+    let buffer = [0x0E, 8, 1, 0x7B, 0x0C, 0x00, 0x01, 0x02, 0xB8, 0x0B];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => {
+            assert_eq!(event.num_hci_command_packets, 1);
+            match event.return_params {
+                ReturnParameters::ReadAuthenticatedPayloadTimeout(params) => {
+                    assert_eq!(params.status, hci::Status::Success);
+                    assert_eq!(params.conn_handle, hci::ConnectionHandle(0x0201));
+                    assert_eq!(
+                        params.timeout.unwrap().duration(),
+                        std::time::Duration::from_secs(30)
+                    );
+                }
+                other => panic!(
+                    "Did not get Read Authenticated Payload Timeout return params: {:?}",
+                    other
+                ),
+            }
+        }
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn read_authenticated_payload_timeout_failed() {
+    let buffer = [0x0E, 8, 1, 0x7B, 0x0C, 0x02, 0x01, 0x02, 0x00, 0x00];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => match event.return_params {
+            ReturnParameters::ReadAuthenticatedPayloadTimeout(params) => {
+                assert_eq!(params.status, hci::Status::UnknownConnectionId);
+                assert_eq!(params.conn_handle, hci::ConnectionHandle(0x0201));
+                assert!(params.timeout.is_none());
+            }
+            other => panic!(
+                "Did not get Read Authenticated Payload Timeout return params: {:?}",
+                other
+            ),
+        },
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn read_authenticated_payload_timeout_failed_zero_timeout() {
+    let buffer = [0x0E, 8, 1, 0x7B, 0x0C, 0x00, 0x01, 0x02, 0x00, 0x00];
+    match Event::new(Packet(&buffer)) {
+        Err(Error::BadAuthenticatedPayloadTimeout(err)) => assert_eq!(
+            err,
+            hci::types::AuthenticatedPayloadTimeoutError::TooShort(std::time::Duration::ZERO)
+        ),
+        other => panic!("Did not get bad authenticated payload timeout: {:?}", other),
+    }
 }
 
 #[test]
@@ -506,6 +765,7 @@ fn le_long_term_key_request_reply() {
         }
         other => panic!("Did not get command complete event: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -527,6 +787,7 @@ fn le_long_term_key_request_negative_reply() {
         }
         other => panic!("Did not get command complete event: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -558,6 +819,7 @@ fn le_read_supported_states() {
         }
         other => panic!("Did not get command complete event: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -589,6 +851,73 @@ fn le_test_end() {
         }
         other => panic!("Did not get command complete event: {:?}", other),
     }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_set_extended_advertising_parameters() {
+    let buffer = [0x0E, 5, 1, 0x36, 0x20, 0x00, 0xF8];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => {
+            assert_eq!(event.num_hci_command_packets, 1);
+            match event.return_params {
+                ReturnParameters::LeSetExtendedAdvertisingParameters(params) => {
+                    assert_eq!(params.status, hci::Status::Success);
+                    assert_eq!(params.selected_tx_power, -8);
+                }
+                other => panic!(
+                    "Did not get LE Set Extended Advertising Parameters return params: {:?}",
+                    other
+                ),
+            }
+        }
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_read_maximum_advertising_data_length() {
+    let buffer = [0x0E, 6, 1, 0x3A, 0x20, 0x00, 0x72, 0x06];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => {
+            assert_eq!(event.num_hci_command_packets, 1);
+            match event.return_params {
+                ReturnParameters::LeReadMaximumAdvertisingDataLength(params) => {
+                    assert_eq!(params.status, hci::Status::Success);
+                    assert_eq!(params.max_advertising_data_length, 1650);
+                }
+                other => panic!(
+                    "Did not get LE Read Maximum Advertising Data Length return params: {:?}",
+                    other
+                ),
+            }
+        }
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_read_number_of_supported_advertising_sets() {
+    let buffer = [0x0E, 5, 1, 0x3B, 0x20, 0x00, 0x08];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => {
+            assert_eq!(event.num_hci_command_packets, 1);
+            match event.return_params {
+                ReturnParameters::LeReadNumberOfSupportedAdvertisingSets(params) => {
+                    assert_eq!(params.status, hci::Status::Success);
+                    assert_eq!(params.num_supported_advertising_sets, 8);
+                }
+                other => panic!(
+                    "Did not get LE Read Number of Supported Advertising Sets return params: {:?}",
+                    other
+                ),
+            }
+        }
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -612,4 +941,133 @@ fn vendor_command() {
         }
         other => panic!("Did not get command complete event: {:04X?}", other),
     }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_read_resolving_list_size() {
+    let buffer = [0x0E, 5, 1, 0x2A, 0x20, 0x00, 0x10];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => {
+            assert_eq!(event.num_hci_command_packets, 1);
+            match event.return_params {
+                ReturnParameters::LeReadResolvingListSize(params) => {
+                    assert_eq!(params.status, hci::Status::Success);
+                    assert_eq!(params.resolving_list_size, 16);
+                }
+                other => panic!(
+                    "Did not get LE Read Resolving List Size return params: {:?}",
+                    other
+                ),
+            }
+        }
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_read_peer_resolvable_address() {
+    let buffer = [
+        0x0E, 10, 1, 0x2B, 0x20, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x46,
+    ];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => match event.return_params {
+            ReturnParameters::LeReadPeerResolvableAddress(params) => {
+                assert_eq!(params.status, hci::Status::Success);
+                assert_eq!(
+                    params.resolvable_address,
+                    hci::BdAddr([0x01, 0x02, 0x03, 0x04, 0x05, 0x46])
+                );
+            }
+            other => panic!(
+                "Did not get LE Read Peer Resolvable Address return params: {:?}",
+                other
+            ),
+        },
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_read_local_resolvable_address_failed() {
+    let buffer = [
+        0x0E, 10, 1, 0x2C, 0x20, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => match event.return_params {
+            ReturnParameters::LeReadLocalResolvableAddress(params) => {
+                assert_eq!(params.status, hci::Status::UnknownConnectionId);
+            }
+            other => panic!(
+                "Did not get LE Read Local Resolvable Address return params: {:?}",
+                other
+            ),
+        },
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_read_suggested_default_data_length() {
+    let buffer = [0x0E, 8, 1, 0x23, 0x20, 0x00, 0xFB, 0x00, 0x48, 0x08];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => match event.return_params {
+            ReturnParameters::LeReadSuggestedDefaultDataLength(params) => {
+                assert_eq!(params.status, hci::Status::Success);
+                assert_eq!(params.max_tx_octets, 251);
+                assert_eq!(params.max_tx_time, 2120);
+            }
+            other => panic!(
+                "Did not get LE Read Suggested Default Data Length return params: {:?}",
+                other
+            ),
+        },
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_read_maximum_data_length() {
+    let buffer = [
+        0x0E, 12, 1, 0x2F, 0x20, 0x00, 0xFB, 0x00, 0x90, 0x42, 0x1B, 0x00, 0x48, 0x01,
+    ];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => match event.return_params {
+            ReturnParameters::LeReadMaximumDataLength(params) => {
+                assert_eq!(params.status, hci::Status::Success);
+                assert_eq!(params.max_tx_octets, 251);
+                assert_eq!(params.max_tx_time, 17040);
+                assert_eq!(params.max_rx_octets, 27);
+                assert_eq!(params.max_rx_time, 328);
+            }
+            other => panic!(
+                "Did not get LE Read Maximum Data Length return params: {:?}",
+                other
+            ),
+        },
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_read_phy() {
+    let buffer = [0x0E, 8, 1, 0x30, 0x20, 0x00, 0x01, 0x02, 0x02, 0x03];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => match event.return_params {
+            ReturnParameters::LeReadPhy(params) => {
+                assert_eq!(params.status, hci::Status::Success);
+                assert_eq!(params.conn_handle, hci::ConnectionHandle(0x0201));
+                assert_eq!(params.tx_phy, Phy::Le2M);
+                assert_eq!(params.rx_phy, Phy::LeCoded);
+            }
+            other => panic!("Did not get LE Read PHY return params: {:?}", other),
+        },
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+    assert_round_trip(&buffer);
 }
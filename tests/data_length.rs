@@ -0,0 +1,50 @@
+extern crate stm32wb_hci as hci;
+
+use hci::types::{DataLengthError, MaxTxOctets, MaxTxTime};
+use std::time::Duration;
+
+#[test]
+fn octets_in_range() {
+    assert_eq!(MaxTxOctets::new(27).unwrap().value(), 27);
+    assert_eq!(MaxTxOctets::new(251).unwrap().value(), 251);
+}
+
+#[test]
+fn too_few_octets() {
+    let err = MaxTxOctets::new(26).err().unwrap();
+    assert_eq!(err, DataLengthError::TooFewOctets(26));
+}
+
+#[test]
+fn too_many_octets() {
+    let err = MaxTxOctets::new(252).err().unwrap();
+    assert_eq!(err, DataLengthError::TooManyOctets(252));
+}
+
+#[test]
+fn time_in_range() {
+    let time = MaxTxTime::new(Duration::from_micros(328)).unwrap();
+    assert_eq!(time.as_micros(), 328);
+    assert_eq!(time.duration(), Duration::from_micros(328));
+
+    let time = MaxTxTime::new(Duration::from_micros(17040)).unwrap();
+    assert_eq!(time.as_micros(), 17040);
+}
+
+#[test]
+fn time_truncated_to_microseconds() {
+    let time = MaxTxTime::new(Duration::from_nanos(2_120_999)).unwrap();
+    assert_eq!(time.as_micros(), 2120);
+}
+
+#[test]
+fn time_too_short() {
+    let err = MaxTxTime::new(Duration::from_micros(327)).err().unwrap();
+    assert_eq!(err, DataLengthError::TooShort(Duration::from_micros(327)));
+}
+
+#[test]
+fn time_too_long() {
+    let err = MaxTxTime::new(Duration::from_micros(17041)).err().unwrap();
+    assert_eq!(err, DataLengthError::TooLong(Duration::from_micros(17041)));
+}
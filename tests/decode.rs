@@ -0,0 +1,306 @@
+extern crate stm32wb_hci as hci;
+
+use hci::decode::{describe, opcode_name, packets};
+use hci::Opcode;
+
+#[test]
+fn command() {
+    assert_eq!(
+        describe(&[0x01, 0x06, 0x04, 0x03, 0x01, 0x02, 0x13]).to_string(),
+        "HCI Command: DISCONNECT (0x01|0x0006) plen 3\n    \
+         Handle: 0x0201\n    \
+         Reason: RemoteTerminationByUser (0x13)"
+    );
+}
+
+#[test]
+fn command_with_address_and_data() {
+    let mut packet = vec![0x01, 0x08, 0x20, 32, 3, 0x02, 0x01, 0x06];
+    packet.resize(4 + 32, 0);
+    assert_eq!(
+        describe(&packet).to_string(),
+        "HCI Command: LE_SET_ADVERTISING_DATA (0x08|0x0008) plen 32\n    \
+         Data: 3 bytes\n        \
+         02 01 06"
+    );
+
+    assert_eq!(
+        describe(&[0x01, 0x05, 0x20, 6, 0x01, 0x02, 0x03, 0x04, 0x05, 0xC6]).to_string(),
+        "HCI Command: LE_SET_RANDOM_ADDRESS (0x08|0x0005) plen 6\n    \
+         Address: C6:05:04:03:02:01"
+    );
+}
+
+#[test]
+fn extended_advertising_command() {
+    assert_eq!(
+        describe(&[0x01, 0x37, 0x20, 7, 0x01, 0x03, 0x01, 3, 0x02, 0x01, 0x06]).to_string(),
+        "HCI Command: LE_SET_EXTENDED_ADVERTISING_DATA (0x08|0x0037) plen 7\n    \
+         Advertising handle: 1\n    \
+         Operation: 3\n    \
+         Fragment preference: 1\n    \
+         Data: 3 bytes\n        \
+         02 01 06"
+    );
+}
+
+#[test]
+fn command_with_unexpected_length() {
+    assert_eq!(
+        describe(&[0x01, 0x06, 0x04, 0x01, 0x01]).to_string(),
+        "HCI Command: DISCONNECT (0x01|0x0006) plen 1\n    \
+         Error: Handle is truncated\n    \
+         01"
+    );
+    assert_eq!(
+        describe(&[0x01, 0x03, 0x0C, 0x01, 0xAA]).to_string(),
+        "HCI Command: RESET (0x03|0x0003) plen 1\n    \
+         Trailing bytes:\n    \
+         aa"
+    );
+}
+
+#[test]
+fn vendor_command() {
+    assert_eq!(
+        describe(&[0x01, 0x8A, 0xFC, 0x03, 0x01, 0x00, 0x08]).to_string(),
+        "HCI Command: GAP_INIT (0x3f|0x008a) plen 3\n    \
+         Role: 0x01\n    \
+         Privacy: false\n    \
+         Device name length: 8"
+    );
+}
+
+#[test]
+fn vendor_command_with_uuid() {
+    assert_eq!(
+        describe(&[0x01, 0x02, 0xFD, 5, 0x01, 0x0D, 0x18, 0x01, 0x06]).to_string(),
+        "HCI Command: GATT_ADD_SERVICE (0x3f|0x0102) plen 5\n    \
+         UUID: 0x180d\n    \
+         Service type: 1\n    \
+         Maximum attribute records: 6"
+    );
+}
+
+#[test]
+fn vendor_command_without_known_layout() {
+    assert_eq!(
+        describe(&[0x01, 0x9C, 0xFC, 0x02, 0xAA, 0xBB]).to_string(),
+        "HCI Command: GAP_CREATE_CONNECTION (0x3f|0x009c) plen 2\n    aa bb"
+    );
+}
+
+#[test]
+fn unknown_command() {
+    assert_eq!(
+        describe(&[0x01, 0xFF, 0xFF, 0x00]).to_string(),
+        "HCI Command: Unknown (0x3f|0x03ff) plen 0"
+    );
+}
+
+#[test]
+fn command_complete() {
+    assert_eq!(
+        describe(&[0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00]).to_string(),
+        "HCI Event: Command Complete (0x0e) plen 4\n    \
+         Command: RESET (0x03|0x0003) ncmd 1\n    \
+         Status: Success (0x00)"
+    );
+}
+
+#[test]
+fn command_complete_with_return_parameters() {
+    assert_eq!(
+        describe(&[0x04, 0x0E, 0x0A, 0x01, 0x09, 0x10, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06])
+            .to_string(),
+        "HCI Event: Command Complete (0x0e) plen 10\n    \
+         Command: READ_BD_ADDR (0x04|0x0009) ncmd 1\n    \
+         Status: Success (0x00)\n    \
+         Address: 06:05:04:03:02:01"
+    );
+}
+
+#[test]
+fn command_complete_with_signed_return_parameters() {
+    assert_eq!(
+        describe(&[0x04, 0x0E, 0x09, 0x01, 0x76, 0x20, 0x00, 0x01, 0x00, 0x01, 0xF6, 0x0A])
+            .to_string(),
+        "HCI Event: Command Complete (0x0e) plen 9\n    \
+         Command: LE_ENHANCED_READ_TRANSMIT_POWER_LEVEL (0x08|0x0076) ncmd 1\n    \
+         Status: Success (0x00)\n    \
+         Handle: 0x0001\n    \
+         PHY: 1\n    \
+         Current TX power: -10\n    \
+         Maximum TX power: 10"
+    );
+}
+
+#[test]
+fn command_status() {
+    assert_eq!(
+        describe(&[0x04, 0x0F, 0x04, 0x00, 0x01, 0x0D, 0x20]).to_string(),
+        "HCI Event: Command Status (0x0f) plen 4\n    \
+         Command: LE_CREATE_CONNECTION (0x08|0x000d) ncmd 1\n    \
+         Status: Success (0x00)"
+    );
+}
+
+#[test]
+fn spontaneous_command_complete() {
+    assert_eq!(
+        describe(&[0x04, 0x0E, 0x03, 0x01, 0x00, 0x00]).to_string(),
+        "HCI Event: Command Complete (0x0e) plen 3\n    Command: None ncmd 1"
+    );
+}
+
+#[test]
+fn vendor_status_takes_precedence_for_vendor_commands() {
+    let description = describe(&[
+        0x04, 0x0E, 0x0A, 0x01, 0x8A, 0xFC, 0x41, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ])
+    .to_string();
+    assert!(description.contains("    Status: Failed (0x41)\n"));
+
+    let description = describe(&[0x04, 0x0F, 0x04, 0x41, 0x01, 0x0D, 0x20]).to_string();
+    assert!(description.ends_with("    Status: Type0SubmapNotDefined (0x41)"));
+}
+
+#[test]
+fn event_that_cannot_be_parsed() {
+    assert_eq!(
+        describe(&[0x04, 0x99, 0x02, 0xAA, 0xBB]).to_string(),
+        "HCI Event: Unknown (0x99) plen 2\n    Error: UnknownEvent(153)\n    aa bb"
+    );
+}
+
+#[test]
+fn event() {
+    assert_eq!(
+        describe(&[0x04, 0x05, 0x04, 0x00, 0x01, 0x02, 0x13]).to_string(),
+        "HCI Event: Disconnection Complete (0x05) plen 4\n    \
+         Status: Success (0x00)\n    \
+         Handle: 0x0201\n    \
+         Reason: RemoteTerminationByUser (0x13)"
+    );
+}
+
+#[test]
+fn le_meta_event() {
+    assert_eq!(
+        describe(&[0x04, 0x3E, 0x0A, 0x03, 0x00, 0x01, 0x02, 0x18, 0x00, 0x00, 0x00, 0xC8, 0x00])
+            .to_string(),
+        "HCI Event: LE Meta (0x3e) plen 10\n    \
+         Subevent: LE Connection Update Complete (0x03)\n    \
+         Status: Success (0x00)\n    \
+         Handle: 0x0201\n    \
+         Interval: 24\n    \
+         Latency: 0\n    \
+         Supervision timeout: 200"
+    );
+}
+
+#[test]
+fn le_meta_event_with_signed_parameters() {
+    assert_eq!(
+        describe(&[0x04, 0x3E, 0x09, 0x21, 0x00, 0x01, 0x00, 0x00, 0x01, 0xF6, 0x00, 0xFE])
+            .to_string(),
+        "HCI Event: LE Meta (0x3e) plen 9\n    \
+         Subevent: LE Transmit Power Reporting (0x21)\n    \
+         Status: Success (0x00)\n    \
+         Handle: 0x0001\n    \
+         Reason: 0\n    \
+         PHY: 1\n    \
+         TX power level: -10\n    \
+         Flags: 0x00\n    \
+         Delta: -2"
+    );
+}
+
+#[test]
+fn le_meta_event_with_data() {
+    assert_eq!(
+        describe(&[0x04, 0x3E, 0x0A, 0x0F, 0x01, 0x00, 0xF6, 0xC4, 0xFF, 0x00, 2, 0x01, 0xAA])
+            .to_string(),
+        "HCI Event: LE Meta (0x3e) plen 10\n    \
+         Subevent: LE Periodic Advertising Report (0x0f)\n    \
+         Sync handle: 0x0001\n    \
+         TX power: -10\n    \
+         RSSI: -60\n    \
+         CTE type: 255\n    \
+         Data status: 0\n    \
+         Data: 2 bytes\n        \
+         01 aa"
+    );
+}
+
+#[test]
+fn le_meta_event_without_parameters() {
+    assert_eq!(
+        describe(&[0x04, 0x3E, 0x01, 0x11]).to_string(),
+        "HCI Event: LE Meta (0x3e) plen 1\n    \
+         Subevent: LE Scan Timeout (0x11)"
+    );
+}
+
+#[test]
+fn event_that_cannot_be_parsed_is_described_by_its_layout() {
+    assert_eq!(
+        describe(&[0x04, 0x05, 0x04, 0x00, 0x01, 0x02, 0x70]).to_string(),
+        "HCI Event: Disconnection Complete (0x05) plen 4\n    \
+         Error: BadReason(112)\n    \
+         Status: Success (0x00)\n    \
+         Handle: 0x0201\n    \
+         Reason: Unknown (0x70)"
+    );
+}
+
+#[test]
+fn vendor_event_without_known_layout() {
+    let description = describe(&[0x04, 0xFF, 0x03, 0x00, 0x92, 0x01]).to_string();
+    let mut lines = description.lines();
+    assert_eq!(lines.next(), Some("HCI Event: Vendor (0xff) plen 3"));
+    assert_eq!(lines.next(), Some("    Vendor("));
+}
+
+#[test]
+fn acl_data() {
+    assert_eq!(
+        describe(&[0x02, 0x01, 0x22, 0x02, 0x00, 0xAA, 0xBB]).to_string(),
+        "ACL Data: handle 0x0201 FirstFlushable PointToPoint dlen 2\n    aa bb"
+    );
+}
+
+#[test]
+fn truncated_and_unknown_packets() {
+    assert_eq!(
+        describe(&[0x04, 0x0E, 0x04, 0x01]).to_string(),
+        "Truncated packet: 4 bytes\n    04 0e 04 01"
+    );
+    assert_eq!(
+        describe(&[0x07, 0x01]).to_string(),
+        "Unknown packet type 0x07\n    07 01"
+    );
+}
+
+#[test]
+fn packets_splits_stream() {
+    let stream = [
+        0x01, 0x03, 0x0C, 0x00, 0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00, 0x04, 0x0E,
+    ];
+    let split: Vec<_> = packets(&stream).collect();
+    assert_eq!(
+        split,
+        [
+            &stream[..4],
+            &stream[4..11],
+            &stream[11..] // truncated
+        ]
+    );
+}
+
+#[test]
+fn opcode_names() {
+    assert_eq!(opcode_name(hci::opcode::RESET), Some("RESET"));
+    assert_eq!(opcode_name(Opcode(0xFD01)), Some("GATT_INIT"));
+    assert_eq!(opcode_name(Opcode(0x0000)), None);
+}
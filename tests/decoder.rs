@@ -0,0 +1,144 @@
+extern crate stm32wb_hci as hci;
+
+use hci::host::decoder::*;
+use hci::host::uart::{Error, Packet};
+use hci::Event;
+use std::convert::Infallible;
+
+/// Feeds all of `data` to the decoder, one chunk at a time, and returns the total number of
+/// skipped bytes and the decoded packets.
+fn decode_all(
+    decoder: &mut H4Decoder,
+    chunks: &[&[u8]],
+) -> (usize, Vec<Result<Packet, Error<Infallible>>>) {
+    let mut skipped = 0;
+    let mut packets = Vec::new();
+    for &chunk in chunks {
+        let mut chunk = chunk;
+        while !chunk.is_empty() {
+            let decoded = decoder.decode(chunk);
+            assert!(decoded.consumed > 0);
+            chunk = &chunk[decoded.consumed..];
+            skipped += decoded.skipped;
+            packets.extend(decoded.packet);
+        }
+    }
+
+    (skipped, packets)
+}
+
+const COMMAND_COMPLETE: [u8; 7] = [0x04, 0x0E, 4, 1, 0x03, 0x0C, 0x00];
+const ACL_DATA: [u8; 8] = [0x02, 0x01, 0x22, 0x03, 0x00, 1, 2, 3];
+
+#[test]
+fn whole_packet() {
+    let mut decoder = H4Decoder::new();
+    let decoded = decoder.decode(&COMMAND_COMPLETE);
+    assert_eq!(decoded.consumed, COMMAND_COMPLETE.len());
+    assert_eq!(decoded.skipped, 0);
+    match decoded.packet {
+        Some(Ok(Packet::Event(Event::CommandComplete(_)))) => (),
+        other => panic!("Did not get command complete: {:?}", other),
+    }
+    assert_eq!(decoder.pending(), 0);
+}
+
+#[test]
+fn byte_by_byte() {
+    let mut decoder = H4Decoder::new();
+    let chunks: Vec<&[u8]> = COMMAND_COMPLETE
+        .chunks(1)
+        .chain(ACL_DATA.chunks(1))
+        .collect();
+    let (skipped, packets) = decode_all(&mut decoder, &chunks);
+    assert_eq!(skipped, 0);
+    assert_eq!(packets.len(), 2);
+    assert!(matches!(
+        packets[0],
+        Ok(Packet::Event(Event::CommandComplete(_)))
+    ));
+    match &packets[1] {
+        Ok(Packet::AclData(acl)) => assert_eq!(acl.data(), [1, 2, 3]),
+        other => panic!("Did not get ACL data: {:?}", other),
+    }
+}
+
+#[test]
+fn several_packets_in_one_chunk() {
+    let mut decoder = H4Decoder::new();
+    let mut stream = Vec::new();
+    stream.extend_from_slice(&ACL_DATA);
+    stream.extend_from_slice(&COMMAND_COMPLETE);
+    stream.extend_from_slice(&COMMAND_COMPLETE[..3]);
+
+    let decoded = decoder.decode(&stream);
+    assert_eq!(decoded.consumed, ACL_DATA.len());
+    assert!(matches!(decoded.packet, Some(Ok(Packet::AclData(_)))));
+
+    let (skipped, packets) = decode_all(&mut decoder, &[&stream[ACL_DATA.len()..]]);
+    assert_eq!(skipped, 0);
+    assert_eq!(packets.len(), 1);
+    assert_eq!(decoder.pending(), 3);
+    assert_eq!(decoder.reset(), 3);
+    assert_eq!(decoder.pending(), 0);
+}
+
+#[test]
+fn skips_leading_garbage() {
+    let mut decoder = H4Decoder::new();
+    let (skipped, packets) = decode_all(&mut decoder, &[&[0x00, 0xFF, 0x01], &COMMAND_COMPLETE]);
+    assert_eq!(skipped, 3);
+    assert_eq!(packets.len(), 1);
+    assert!(packets[0].is_ok());
+}
+
+#[test]
+fn resynchronizes_after_bad_acl_header() {
+    let mut decoder = H4Decoder::new();
+
+    // An ACL header that claims 0xFFFF bytes of data cannot be valid.
+    let (skipped, packets) = decode_all(
+        &mut decoder,
+        &[&[0x02, 0x55, 0x66, 0xFF], &[0xFF], &COMMAND_COMPLETE],
+    );
+    assert_eq!(skipped, 5);
+    assert_eq!(packets.len(), 1);
+    assert!(matches!(
+        packets[0],
+        Ok(Packet::Event(Event::CommandComplete(_)))
+    ));
+    assert_eq!(decoder.pending(), 0);
+}
+
+#[test]
+fn resynchronizes_to_complete_buffered_packet() {
+    let mut decoder = H4Decoder::new();
+
+    // The bad ACL header contains a complete Data Buffer Overflow event with no parameters.
+    let (skipped, packets) = decode_all(&mut decoder, &[&[0x02, 0x04, 0x1A, 0x00, 0xFF]]);
+    assert_eq!(skipped, 2);
+    assert_eq!(packets.len(), 1);
+    match &packets[0] {
+        Err(Error::BLE(hci::event::Error::BadLength(0, 1))) => (),
+        other => panic!("Did not get bad length: {:?}", other),
+    }
+    assert_eq!(decoder.pending(), 0);
+}
+
+#[test]
+fn reports_bad_packets() {
+    let mut decoder = H4Decoder::new();
+    let (skipped, packets) = decode_all(
+        &mut decoder,
+        &[&[0x04, 0x0E, 3, 1, 0x67, 0x43], &COMMAND_COMPLETE],
+    );
+    assert_eq!(skipped, 0);
+    assert_eq!(packets.len(), 2);
+    match &packets[0] {
+        Err(Error::BLE(hci::event::Error::UnknownOpcode(opcode))) => {
+            assert_eq!(opcode.0, 0x4367)
+        }
+        other => panic!("Did not get unknown opcode: {:?}", other),
+    }
+    assert!(packets[1].is_ok());
+}
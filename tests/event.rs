@@ -7,6 +7,13 @@ use std::time::Duration;
 
 type TestEvent = Event;
 
+fn assert_round_trip(buffer: &[u8]) {
+    let event = TestEvent::new(Packet(buffer)).unwrap();
+    let mut bytes = [0; MAX_EVENT_LENGTH];
+    let len = event.copy_into_slice(&mut bytes);
+    assert_eq!(bytes[..len], *buffer);
+}
+
 #[test]
 fn connection_complete() {
     let buffer = [
@@ -25,6 +32,7 @@ fn connection_complete() {
         }
         other => panic!("Did not get connection complete event: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -71,6 +79,7 @@ fn disconnection_complete() {
         }
         other => panic!("Did not get disconnection complete event: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -102,6 +111,7 @@ fn encryption_change() {
         }
         other => panic!("Did not get encryption change event: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -135,6 +145,7 @@ fn read_remote_version_complete() {
         }
         other => panic!("Did not get read remote version info event: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -159,6 +170,7 @@ fn command_status() {
         }
         other => panic!("Did not get command status: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -175,6 +187,7 @@ fn command_status_vendor_status() {
         }
         other => panic!("Did not get command status: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -186,6 +199,7 @@ fn hardware_error() {
         }
         other => panic!("Did not get hardware error: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -207,6 +221,7 @@ fn number_of_completed_packets() {
         }
         other => panic!("Did not get number of completed packets: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -218,6 +233,7 @@ fn data_buffer_overflow() {
         }
         other => panic!("Did not get data buffer overflow: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -239,6 +255,22 @@ fn encryption_key_refresh_complete() {
         }
         other => panic!("Did not get encryption key refresh complete: {:?}", other),
     }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn authenticated_payload_timeout_expired() {
+    let buffer = [0x57, 2, 0x01, 0x02];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::AuthenticatedPayloadTimeoutExpired(conn_handle)) => {
+            assert_eq!(conn_handle, hci::ConnectionHandle(0x0201));
+        }
+        other => panic!(
+            "Did not get authenticated payload timeout expired: {:?}",
+            other
+        ),
+    }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -273,6 +305,7 @@ fn le_connection_complete() {
         }
         other => panic!("Did not get LE connection complete: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -340,6 +373,7 @@ fn le_advertising_report() {
         }
         other => panic!("Did not get advertising report: {:?}", other),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -376,6 +410,505 @@ fn le_advertising_report_failed_bad_addr_type() {
     }
 }
 
+#[test]
+fn le_extended_advertising_report() {
+    let buffer = [
+        0x3E, 53, 0x0D, 2, // header
+        0x01, 0x00, 0x01, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x01, 0x02, 0x05, 0xFC, 0xC4, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 2, 0x07, 0x08, // report 1
+        0x26, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0xFF, 0x7F, 0x7F, 0x50,
+        0x00, 0xFE, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 1, 0x09, // report 2
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeExtendedAdvertisingReport(event)) => {
+            let mut iter = event.iter();
+            let report = iter.next().unwrap();
+            assert_eq!(report.event_type, ExtendedAdvertisingEventType::CONNECTABLE);
+            assert_eq!(report.data_status, DataStatus::Complete);
+            assert_eq!(
+                report.address,
+                ReportAddress::Random(hci::BdAddr([0x01, 0x02, 0x03, 0x04, 0x05, 0x06]))
+            );
+            assert_eq!(report.primary_phy, Phy::Le1M);
+            assert_eq!(report.secondary_phy, Some(Phy::Le2M));
+            assert_eq!(report.sid, Some(0x05));
+            assert_eq!(report.tx_power, Some(-4));
+            assert_eq!(report.rssi, Some(-60));
+            assert_eq!(report.periodic_advertising_interval, None);
+            assert_eq!(report.direct_address, None);
+            assert_eq!(report.data, [0x07, 0x08]);
+
+            let report = iter.next().unwrap();
+            assert_eq!(
+                report.event_type,
+                ExtendedAdvertisingEventType::SCANNABLE | ExtendedAdvertisingEventType::DIRECTED
+            );
+            assert_eq!(report.data_status, DataStatus::Incomplete);
+            assert_eq!(report.address, ReportAddress::Anonymous);
+            assert_eq!(report.primary_phy, Phy::LeCoded);
+            assert_eq!(report.secondary_phy, None);
+            assert_eq!(report.sid, None);
+            assert_eq!(report.tx_power, None);
+            assert_eq!(report.rssi, None);
+            assert_eq!(
+                report.periodic_advertising_interval,
+                Some(Duration::from_millis(100))
+            );
+            assert_eq!(
+                report.direct_address,
+                Some(ReportAddress::UnresolvedRandom(hci::BdAddr([
+                    0x11, 0x12, 0x13, 0x14, 0x15, 0x16
+                ])))
+            );
+            assert_eq!(report.data, [0x09]);
+
+            assert!(iter.next().is_none());
+        }
+        other => panic!("Did not get extended advertising report: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_extended_advertising_report_failed_incomplete() {
+    let buffer = [
+        0x3E, 28, 0x0D, 1, 0x01, 0x00, 0x01, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x01, 0x02, 0x05,
+        0xFC, 0xC4, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 3, 0x07, 0x08,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Err(Error::LeAdvertisementReportIncomplete) => (),
+        other => panic!("Did not get incomplete advertising report: {:?}", other),
+    }
+}
+
+#[test]
+fn le_extended_advertising_report_failed_bad_data_status() {
+    let buffer = [
+        0x3E, 26, 0x0D, 1, 0x61, 0x00, 0x01, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x01, 0x02, 0x05,
+        0xFC, 0xC4, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Err(Error::BadDataStatus(code)) => assert_eq!(code, 3),
+        other => panic!("Did not get bad data status: {:?}", other),
+    }
+}
+
+#[test]
+fn le_extended_advertising_report_failed_bad_phy() {
+    let buffer = [
+        0x3E, 26, 0x0D, 1, 0x01, 0x00, 0x01, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x04, 0x02, 0x05,
+        0xFC, 0xC4, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Err(Error::BadPhy(code)) => assert_eq!(code, 4),
+        other => panic!("Did not get bad PHY: {:?}", other),
+    }
+}
+
+#[test]
+fn le_scan_timeout() {
+    let buffer = [0x3E, 1, 0x11];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeScanTimeout) => (),
+        other => panic!("Did not get scan timeout: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_periodic_advertising_sync_established() {
+    let buffer = [
+        0x3E, 16, 0x0E, 0x00, 0x01, 0x02, 0x05, 0x01, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x02,
+        0x50, 0x00, 0x05,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LePeriodicAdvertisingSyncEstablished(event)) => {
+            assert_eq!(event.status, hci::Status::Success);
+            let sync = event.sync.unwrap();
+            assert_eq!(sync.sync_handle, hci::SyncHandle(0x0201));
+            assert_eq!(sync.adv_sid, 5);
+            assert_eq!(
+                sync.advertiser_address,
+                ReportAddress::Random(hci::BdAddr([0x01, 0x02, 0x03, 0x04, 0x05, 0x06]))
+            );
+            assert_eq!(sync.advertiser_phy, Phy::Le2M);
+            assert_eq!(
+                sync.periodic_advertising_interval,
+                Duration::from_millis(100)
+            );
+            assert_eq!(sync.advertiser_clock_accuracy, CentralClockAccuracy::Ppm50);
+        }
+        other => panic!(
+            "Did not get periodic advertising sync established: {:?}",
+            other
+        ),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_periodic_advertising_sync_established_cancelled() {
+    let buffer = [
+        0x3E, 16, 0x0E, 0x44, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LePeriodicAdvertisingSyncEstablished(event)) => {
+            assert_eq!(event.status, hci::Status::OperationCancelledByHost);
+            assert!(event.sync.is_none());
+        }
+        other => panic!(
+            "Did not get periodic advertising sync established: {:?}",
+            other
+        ),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_periodic_advertising_report() {
+    let buffer = [
+        0x3E, 11, 0x0F, 0x01, 0x02, 0xF8, 0xC4, 0xFF, 0x00, 3, 0x0A, 0x0B, 0x0C,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LePeriodicAdvertisingReport(event)) => {
+            assert_eq!(event.sync_handle, hci::SyncHandle(0x0201));
+            assert_eq!(event.tx_power, Some(-8));
+            assert_eq!(event.rssi, Some(-60));
+            assert_eq!(event.cte_type, None);
+            assert_eq!(event.data_status, DataStatus::Complete);
+            assert_eq!(event.data(), [0x0A, 0x0B, 0x0C]);
+        }
+        other => panic!("Did not get periodic advertising report: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_periodic_advertising_report_incomplete_with_cte() {
+    let buffer = [0x3E, 9, 0x0F, 0x01, 0x02, 0x7F, 0x7F, 0x01, 0x01, 1, 0x0A];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LePeriodicAdvertisingReport(event)) => {
+            assert_eq!(event.tx_power, None);
+            assert_eq!(event.rssi, None);
+            assert_eq!(event.cte_type, Some(CteType::AoD1Us));
+            assert_eq!(event.data_status, DataStatus::Incomplete);
+            assert_eq!(event.data(), [0x0A]);
+        }
+        other => panic!("Did not get periodic advertising report: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_periodic_advertising_report_failed_bad_cte_type() {
+    let buffer = [0x3E, 8, 0x0F, 0x01, 0x02, 0x7F, 0x7F, 0x03, 0x00, 0];
+    match TestEvent::new(Packet(&buffer)) {
+        Err(Error::BadCteType(code)) => assert_eq!(code, 3),
+        other => panic!("Did not get bad CTE type: {:?}", other),
+    }
+}
+
+#[test]
+fn le_periodic_advertising_report_failed_data_length() {
+    let buffer = [0x3E, 9, 0x0F, 0x01, 0x02, 0x7F, 0x7F, 0xFF, 0x00, 2, 0x0A];
+    match TestEvent::new(Packet(&buffer)) {
+        Err(Error::BadLength(actual, expected)) => {
+            assert_eq!(actual, 9);
+            assert_eq!(expected, 10);
+        }
+        other => panic!("Did not get bad length: {:?}", other),
+    }
+}
+
+#[test]
+fn le_periodic_advertising_sync_lost() {
+    let buffer = [0x3E, 3, 0x10, 0x01, 0x02];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LePeriodicAdvertisingSyncLost(sync_handle)) => {
+            assert_eq!(sync_handle, hci::SyncHandle(0x0201));
+        }
+        other => panic!("Did not get periodic advertising sync lost: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_periodic_advertising_sync_transfer_received() {
+    let buffer = [
+        0x3E, 20, 0x18, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x00, 0x01, 0x02, 0x03,
+        0x04, 0x05, 0x06, 0x01, 0x50, 0x00, 0x02,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LePeriodicAdvertisingSyncTransferReceived(event)) => {
+            assert_eq!(event.status, hci::Status::Success);
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(event.service_data, 0x0403);
+            let sync = event.sync.unwrap();
+            assert_eq!(sync.sync_handle, hci::SyncHandle(0x0605));
+            assert_eq!(sync.adv_sid, 7);
+            assert_eq!(
+                sync.advertiser_address,
+                ReportAddress::Public(hci::BdAddr([1, 2, 3, 4, 5, 6]))
+            );
+            assert_eq!(sync.advertiser_phy, Phy::Le1M);
+            assert_eq!(
+                sync.periodic_advertising_interval,
+                Duration::from_millis(100)
+            );
+            assert_eq!(sync.advertiser_clock_accuracy, CentralClockAccuracy::Ppm150);
+        }
+        other => panic!(
+            "Did not get periodic advertising sync transfer received: {:?}",
+            other
+        ),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_periodic_advertising_sync_transfer_received_sync_failed() {
+    let buffer = [
+        0x3E, 20, 0x18, 0x3E, 0x01, 0x02, 0x03, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LePeriodicAdvertisingSyncTransferReceived(event)) => {
+            assert_eq!(event.status, hci::Status::ConnectionFailedToEstablish);
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(event.service_data, 0x0403);
+            assert!(event.sync.is_none());
+        }
+        other => panic!(
+            "Did not get periodic advertising sync transfer received: {:?}",
+            other
+        ),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_periodic_advertising_sync_transfer_received_failed_bad_phy() {
+    let buffer = [
+        0x3E, 20, 0x18, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x00, 0x01, 0x02, 0x03,
+        0x04, 0x05, 0x06, 0x04, 0x50, 0x00, 0x02,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Err(Error::BadPhy(code)) => assert_eq!(code, 4),
+        other => panic!("Did not get bad PHY: {:?}", other),
+    }
+}
+
+#[test]
+fn le_phy_update_complete() {
+    let buffer = [0x3E, 6, 0x0C, 0x00, 0x01, 0x02, 0x02, 0x03];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LePhyUpdateComplete(event)) => {
+            assert_eq!(event.status, hci::Status::Success);
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(event.tx_phy, Phy::Le2M);
+            assert_eq!(event.rx_phy, Phy::LeCoded);
+        }
+        other => panic!("Did not get PHY update complete: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_phy_update_complete_failed_bad_phy() {
+    let buffer = [0x3E, 6, 0x0C, 0x00, 0x01, 0x02, 0x00, 0x01];
+    match TestEvent::new(Packet(&buffer)) {
+        Err(Error::BadPhy(phy)) => assert_eq!(phy, 0),
+        other => panic!("Did not get bad PHY: {:?}", other),
+    }
+}
+
+#[test]
+fn le_channel_selection_algorithm() {
+    let buffer = [0x3E, 4, 0x14, 0x01, 0x02, 0x01];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeChannelSelectionAlgorithm(event)) => {
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(event.algorithm, ChannelSelectionAlgorithm::Algorithm2);
+        }
+        other => panic!("Did not get channel selection algorithm: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_channel_selection_algorithm_failed_bad_algorithm() {
+    let buffer = [0x3E, 4, 0x14, 0x01, 0x02, 0x02];
+    match TestEvent::new(Packet(&buffer)) {
+        Err(Error::BadChannelSelectionAlgorithm(code)) => assert_eq!(code, 2),
+        other => panic!("Did not get bad channel selection algorithm: {:?}", other),
+    }
+}
+
+#[test]
+fn le_path_loss_threshold() {
+    let buffer = [0x3E, 5, 0x20, 0x01, 0x02, 0x48, 0x02];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LePathLossThreshold(event)) => {
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(event.current_path_loss, Some(0x48));
+            assert_eq!(event.zone_entered, PathLossZone::High);
+        }
+        other => panic!("Did not get path loss threshold: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_path_loss_threshold_unavailable() {
+    let buffer = [0x3E, 5, 0x20, 0x01, 0x02, 0xFF, 0x00];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LePathLossThreshold(event)) => {
+            assert_eq!(event.current_path_loss, None);
+            assert_eq!(event.zone_entered, PathLossZone::Low);
+        }
+        other => panic!("Did not get path loss threshold: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_path_loss_threshold_failed_bad_zone() {
+    let buffer = [0x3E, 5, 0x20, 0x01, 0x02, 0x48, 0x03];
+    match TestEvent::new(Packet(&buffer)) {
+        Err(Error::BadPathLossZone(zone)) => assert_eq!(zone, 3),
+        other => panic!("Did not get bad path loss zone: {:?}", other),
+    }
+}
+
+#[test]
+fn le_transmit_power_reporting() {
+    let buffer = [
+        0x3E, 9, 0x21, 0x00, 0x01, 0x02, 0x01, 0x01, 0xFC, 0x01, 0xFD,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeTransmitPowerReporting(event)) => {
+            assert_eq!(event.status, hci::Status::Success);
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+            let report = event.report.unwrap();
+            assert_eq!(report.reason, TransmitPowerReportingReason::RemoteChanged);
+            assert_eq!(report.phy, PowerControlPhy::Le1M);
+            assert_eq!(report.tx_power_level, ReportedTxPowerLevel::Dbm(-4));
+            assert_eq!(report.tx_power_level_flags, TxPowerLevelFlags::AT_MINIMUM);
+            assert_eq!(report.delta, Some(-3));
+        }
+        other => panic!("Did not get transmit power reporting: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_transmit_power_reporting_not_managed() {
+    let buffer = [
+        0x3E, 9, 0x21, 0x00, 0x01, 0x02, 0x02, 0x04, 0x7E, 0x00, 0x7F,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeTransmitPowerReporting(event)) => {
+            let report = event.report.unwrap();
+            assert_eq!(
+                report.reason,
+                TransmitPowerReportingReason::ReadRemoteCompleted
+            );
+            assert_eq!(report.phy, PowerControlPhy::LeCodedS2);
+            assert_eq!(report.tx_power_level, ReportedTxPowerLevel::NotManaged);
+            assert_eq!(report.tx_power_level_flags, TxPowerLevelFlags::empty());
+            assert_eq!(report.delta, None);
+        }
+        other => panic!("Did not get transmit power reporting: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_transmit_power_reporting_remote_read_failed() {
+    let buffer = [
+        0x3E, 9, 0x21, 0x02, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeTransmitPowerReporting(event)) => {
+            assert_eq!(event.status, hci::Status::UnknownConnectionId);
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+            assert!(event.report.is_none());
+        }
+        other => panic!("Did not get transmit power reporting: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_transmit_power_reporting_failed_bad_reason() {
+    let buffer = [
+        0x3E, 9, 0x21, 0x00, 0x01, 0x02, 0x03, 0x01, 0xFC, 0x01, 0xFD,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Err(Error::BadTransmitPowerReportingReason(reason)) => assert_eq!(reason, 3),
+        other => panic!(
+            "Did not get bad transmit power reporting reason: {:?}",
+            other
+        ),
+    }
+}
+
+#[test]
+fn le_transmit_power_reporting_failed_bad_phy() {
+    let buffer = [
+        0x3E, 9, 0x21, 0x00, 0x01, 0x02, 0x00, 0x05, 0xFC, 0x01, 0xFD,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Err(Error::BadPhy(phy)) => assert_eq!(phy, 5),
+        other => panic!("Did not get bad PHY: {:?}", other),
+    }
+}
+
+#[test]
+fn le_read_local_p256_public_key_complete() {
+    let mut buffer = [0; 68];
+    buffer[..4].copy_from_slice(&[0x3E, 66, 0x08, 0x00]);
+    for (i, byte) in buffer[4..].iter_mut().enumerate() {
+        *byte = i as u8;
+    }
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeReadLocalP256PublicKeyComplete(event)) => {
+            assert_eq!(event.status, hci::Status::Success);
+            assert_eq!(event.public_key.0[..], buffer[4..]);
+        }
+        other => panic!("Did not get P-256 public key complete: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_generate_dhkey_complete() {
+    let mut buffer = [0x44; 36];
+    buffer[..4].copy_from_slice(&[0x3E, 34, 0x09, 0x00]);
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeGenerateDHKeyComplete(event)) => {
+            assert_eq!(event.status, hci::Status::Success);
+            assert_eq!(event.dh_key, hci::host::DhKey([0x44; 32]));
+        }
+        other => panic!("Did not get generate DHKey complete: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_generate_dhkey_complete_failed() {
+    let mut buffer = [0xFF; 36];
+    buffer[..4].copy_from_slice(&[0x3E, 34, 0x09, 0x12]);
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeGenerateDHKeyComplete(event)) => {
+            assert_eq!(event.status, hci::Status::InvalidParameters);
+        }
+        other => panic!("Did not get generate DHKey complete: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
 #[test]
 fn le_connection_update_complete() {
     let buffer = [
@@ -400,6 +933,7 @@ fn le_connection_update_complete() {
             other
         ),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -418,6 +952,7 @@ fn le_read_remote_used_features_complete() {
             other
         ),
     }
+    assert_round_trip(&buffer);
 }
 
 #[test]
@@ -447,4 +982,44 @@ fn le_long_term_key_request() {
         }
         other => panic!("Did not Get LE LTK Request: {:?}", other),
     }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_remote_connection_parameter_request() {
+    let buffer = [
+        0x3E, 11, 0x06, 0x01, 0x02, 0x28, 0x00, 0x90, 0x01, 0x0A, 0x00, 0xDC, 0x05,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeRemoteConnectionParameterRequest(event)) => {
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(
+                event.conn_interval.interval(),
+                (Duration::from_millis(50), Duration::from_millis(500))
+            );
+            assert_eq!(event.conn_interval.conn_latency(), 10);
+            assert_eq!(
+                event.conn_interval.supervision_timeout(),
+                Duration::from_secs(15)
+            );
+        }
+        other => panic!(
+            "Did not get LE Remote Connection Parameter Request: {:?}",
+            other
+        ),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_remote_connection_parameter_request_failed_bad_interval() {
+    let buffer = [
+        0x3E, 11, 0x06, 0x01, 0x02, 0x05, 0x00, 0x90, 0x01, 0x0A, 0x00, 0xDC, 0x05,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Err(Error::BadConnectionInterval(
+            hci::types::ConnectionIntervalError::IntervalTooShort(interval),
+        )) => assert_eq!(interval, Duration::from_micros(6_250)),
+        other => panic!("Did not get bad connection interval: {:?}", other),
+    }
 }
@@ -0,0 +1,101 @@
+extern crate stm32wb_hci as hci;
+
+mod vendor;
+
+use hci::event::command::ReturnParameters;
+use hci::host::executor::*;
+use hci::host::uart::{Error, Packet};
+use hci::host::HostHci;
+use hci::{Event, Opcode, Status};
+use vendor::ScriptedController;
+
+#[tokio::test]
+async fn execute_returns_command_complete() {
+    let mut controller = ScriptedController::new(&[&[0x04, 0x0E, 4, 1, 0x03, 0x0C, 0x00]]);
+    let mut queued = Vec::new();
+    let response = controller
+        .execute(Opcode(0x0C03), &[], &mut |p| queued.push(p))
+        .await
+        .unwrap();
+    match response {
+        CommandResponse::Complete(ReturnParameters::Reset(status)) => {
+            assert_eq!(status, Status::Success)
+        }
+        other => panic!("Did not get reset: {:?}", other),
+    }
+    assert_eq!(controller.written, [(Opcode(0x0C03), vec![])]);
+    assert!(queued.is_empty());
+}
+
+#[tokio::test]
+async fn execute_returns_command_status() {
+    let mut controller = ScriptedController::new(&[&[0x04, 0x0F, 4, 0x0C, 1, 0x0D, 0x20]]);
+    let mut queued = Vec::new();
+    let response = controller
+        .execute(Opcode(0x200D), &[0; 25], &mut |p| queued.push(p))
+        .await
+        .unwrap();
+    match response {
+        CommandResponse::Status(status) => assert_eq!(status, Status::CommandDisallowed),
+        other => panic!("Did not get command status: {:?}", other),
+    }
+    assert!(queued.is_empty());
+}
+
+#[tokio::test]
+async fn wait_for_response_queues_other_events() {
+    let mut controller = ScriptedController::new(&[
+        // Spontaneous Command Complete
+        &[0x04, 0x0E, 3, 1, 0x00, 0x00],
+        // Data Buffer Overflow
+        &[0x04, 0x1A, 1, 0x01],
+        // Command Status for a different command
+        &[0x04, 0x0F, 4, 0x00, 1, 0x0D, 0x20],
+        // Command Complete for the read_bd_addr command
+        &[0x04, 0x0E, 10, 1, 0x09, 0x10, 0x00, 1, 2, 3, 4, 5, 6],
+    ]);
+    controller.read_bd_addr().await.unwrap();
+
+    let mut queued = Vec::new();
+    let response = controller
+        .wait_for_response(Opcode(0x1009), &mut |p| queued.push(p))
+        .await
+        .unwrap();
+    match response {
+        CommandResponse::Complete(ReturnParameters::ReadBdAddr(params)) => {
+            assert_eq!(params.status, Status::Success);
+            assert_eq!(params.bd_addr, hci::BdAddr([1, 2, 3, 4, 5, 6]));
+        }
+        other => panic!("Did not get read BD ADDR: {:?}", other),
+    }
+
+    assert_eq!(queued.len(), 3);
+    match &queued[0] {
+        Packet::Event(Event::CommandComplete(event)) => {
+            assert_eq!(event.opcode, Opcode(0x0000));
+            assert!(matches!(event.return_params, ReturnParameters::Spontaneous));
+        }
+        other => panic!("Did not get spontaneous command complete: {:?}", other),
+    }
+    assert!(matches!(
+        queued[1],
+        Packet::Event(Event::DataBufferOverflow(_))
+    ));
+    match &queued[2] {
+        Packet::Event(Event::CommandStatus(event)) => assert_eq!(event.opcode, Opcode(0x200D)),
+        other => panic!("Did not get command status: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn wait_for_response_reports_read_errors() {
+    let mut controller = ScriptedController::new(&[&[0x04, 0x1A, 1, 0x01], &[0x07]]);
+    let mut queued = Vec::new();
+    let err = controller
+        .wait_for_response(Opcode(0x0C03), &mut |p| queued.push(p))
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadPacketType(0x07));
+    assert_eq!(queued.len(), 1);
+}
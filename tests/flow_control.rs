@@ -0,0 +1,290 @@
+extern crate stm32wb_hci as hci;
+
+mod vendor;
+
+use hci::event::command::{LeReadBufferSize, ReturnParameters};
+use hci::event::{NumberOfCompletedPackets, NumberOfCompletedPacketsPair};
+use hci::host::executor::*;
+use hci::host::flow_control::*;
+use hci::host::uart::{Packet, UartHci};
+use hci::host::HostHci;
+use hci::{ConnectionHandle, Event, Opcode};
+use std::cell::RefCell;
+use vendor::ScriptedController;
+
+fn unexpected(packet: Packet) {
+    panic!("Unexpected packet: {:?}", packet);
+}
+
+#[tokio::test]
+async fn starts_with_one_credit() {
+    let gate = CommandFlowControl::new(ScriptedController::new(&[]), unexpected);
+    assert_eq!(gate.credits(), 1);
+    assert!(gate.can_send());
+}
+
+#[tokio::test]
+async fn commands_consume_credits() {
+    let mut gate = CommandFlowControl::new(ScriptedController::new(&[]), unexpected);
+    gate.reset().await.unwrap();
+    assert_eq!(gate.credits(), 0);
+    assert_eq!(gate.inner().written.len(), 1);
+}
+
+#[tokio::test]
+async fn write_waits_for_command_complete() {
+    let queued = RefCell::new(Vec::new());
+    let mut gate = CommandFlowControl::new(
+        ScriptedController::new(&[
+            &[0x04, 0x1A, 1, 0x01],
+            &[0x04, 0x0E, 4, 1, 0x03, 0x0C, 0x00],
+        ]),
+        |p| queued.borrow_mut().push(p),
+    );
+    gate.reset().await.unwrap();
+    assert_eq!(gate.inner().written.len(), 1);
+
+    gate.reset().await.unwrap();
+    assert_eq!(gate.inner().written.len(), 2);
+    assert_eq!(gate.credits(), 0);
+    drop(gate);
+
+    let queued = queued.into_inner();
+    assert_eq!(queued.len(), 2);
+    assert!(matches!(
+        queued[0],
+        Packet::Event(Event::DataBufferOverflow(_))
+    ));
+    assert!(matches!(
+        queued[1],
+        Packet::Event(Event::CommandComplete(_))
+    ));
+}
+
+#[tokio::test]
+async fn write_waits_for_command_status() {
+    let mut gate = CommandFlowControl::new(
+        ScriptedController::new(&[&[0x04, 0x0F, 4, 0x00, 1, 0x0D, 0x20]]),
+        |_| (),
+    );
+    gate.reset().await.unwrap();
+    gate.reset().await.unwrap();
+    assert_eq!(gate.inner().written.len(), 2);
+    assert_eq!(gate.credits(), 0);
+}
+
+#[tokio::test]
+async fn write_without_credit_is_held_back() {
+    let mut gate =
+        CommandFlowControl::new(ScriptedController::new(&[&[0x04, 0x1A, 1, 0x01]]), |_| ());
+    gate.reset().await.unwrap();
+    assert!(gate.reset().await.is_err());
+    assert_eq!(gate.inner().written.len(), 1);
+}
+
+#[tokio::test]
+async fn write_discards_undecodable_packets_while_waiting() {
+    let mut gate = CommandFlowControl::new(
+        ScriptedController::new(&[&[0x04, 0xF0, 0], &[0x04, 0x0E, 3, 1, 0x00, 0x00]]),
+        |_| (),
+    );
+    gate.reset().await.unwrap();
+    gate.reset().await.unwrap();
+    assert_eq!(gate.discarded(), 1);
+}
+
+#[tokio::test]
+async fn command_complete_sets_credits() {
+    let mut gate = CommandFlowControl::new(
+        ScriptedController::new(&[&[0x04, 0x0E, 4, 5, 0x03, 0x0C, 0x00]]),
+        unexpected,
+    );
+    gate.reset().await.unwrap();
+    let response = gate
+        .wait_for_response(Opcode(0x0C03), &mut |_| panic!("Unexpected packet"))
+        .await
+        .unwrap();
+    assert!(matches!(
+        response,
+        CommandResponse::Complete(ReturnParameters::Reset(_))
+    ));
+    assert_eq!(gate.credits(), 5);
+}
+
+#[tokio::test]
+async fn command_status_sets_credits() {
+    let mut gate = CommandFlowControl::new(
+        ScriptedController::new(&[&[0x04, 0x0F, 4, 0x00, 0, 0x0D, 0x20]]),
+        unexpected,
+    );
+    gate.read().await.unwrap();
+    assert_eq!(gate.credits(), 0);
+}
+
+#[tokio::test]
+async fn other_events_do_not_change_credits() {
+    let mut gate = CommandFlowControl::new(
+        ScriptedController::new(&[&[0x04, 0x1A, 1, 0x01]]),
+        unexpected,
+    );
+    gate.read().await.unwrap();
+    assert_eq!(gate.credits(), 1);
+}
+
+#[tokio::test]
+async fn acquire_returns_immediately_with_credit() {
+    let mut gate = CommandFlowControl::new(ScriptedController::new(&[]), unexpected);
+    gate.acquire().await.unwrap();
+    assert_eq!(gate.credits(), 1);
+}
+
+#[tokio::test]
+async fn acquire_waits_for_spontaneous_command_complete() {
+    let queued = RefCell::new(Vec::new());
+    let mut gate = CommandFlowControl::new(
+        ScriptedController::new(&[
+            &[0x04, 0x0E, 4, 0, 0x03, 0x0C, 0x00],
+            &[0x04, 0x1A, 1, 0x01],
+            &[0x04, 0x0E, 3, 2, 0x00, 0x00],
+        ]),
+        |p| queued.borrow_mut().push(p),
+    );
+    gate.reset().await.unwrap();
+    gate.read().await.unwrap();
+    assert!(!gate.can_send());
+
+    gate.acquire().await.unwrap();
+    assert_eq!(gate.credits(), 2);
+    drop(gate);
+
+    let queued = queued.into_inner();
+    assert_eq!(queued.len(), 2);
+    assert!(matches!(
+        queued[0],
+        Packet::Event(Event::DataBufferOverflow(_))
+    ));
+    match &queued[1] {
+        Packet::Event(Event::CommandComplete(event)) => {
+            assert_eq!(event.num_hci_command_packets, 2);
+            assert!(matches!(event.return_params, ReturnParameters::Spontaneous));
+        }
+        other => panic!("Did not get spontaneous command complete: {:?}", other),
+    }
+}
+
+fn buffer_size(data_packet_count: u8) -> LeReadBufferSize {
+    LeReadBufferSize {
+        status: hci::Status::Success,
+        data_packet_length: 251,
+        data_packet_count,
+    }
+}
+
+fn completed(pairs: &[(u16, usize)]) -> NumberOfCompletedPackets {
+    NumberOfCompletedPackets::new(pairs.iter().map(|&(handle, count)| {
+        NumberOfCompletedPacketsPair {
+            conn_handle: ConnectionHandle(handle),
+            num_completed_packets: count,
+        }
+    }))
+}
+
+#[test]
+fn acl_credits_start_without_buffers() {
+    let mut credits = AclBufferCredits::<2>::new();
+    assert_eq!(credits.available(), 0);
+    assert_eq!(
+        credits.acquire(ConnectionHandle(0x0201)),
+        Err(AclBufferError::NoBuffers)
+    );
+}
+
+#[test]
+fn acl_credits_from_buffer_size() {
+    let mut credits = AclBufferCredits::<2>::from_buffer_size(&buffer_size(2));
+    assert_eq!(credits.data_packet_length(), 251);
+    assert_eq!(credits.available(), 2);
+
+    credits.acquire(ConnectionHandle(0x0201)).unwrap();
+    credits.acquire(ConnectionHandle(0x0201)).unwrap();
+    assert_eq!(credits.available(), 0);
+    assert_eq!(credits.outstanding(ConnectionHandle(0x0201)), 2);
+    assert_eq!(
+        credits.acquire(ConnectionHandle(0x0201)),
+        Err(AclBufferError::NoBuffers)
+    );
+}
+
+#[test]
+fn acl_credits_too_many_connections() {
+    let mut credits = AclBufferCredits::<1>::from_buffer_size(&buffer_size(4));
+    credits.acquire(ConnectionHandle(0x0201)).unwrap();
+    assert_eq!(
+        credits.acquire(ConnectionHandle(0x0202)),
+        Err(AclBufferError::TooManyConnections(ConnectionHandle(0x0202)))
+    );
+    assert_eq!(credits.available(), 3);
+}
+
+#[test]
+fn acl_credits_released_by_completed_packets() {
+    let mut credits = AclBufferCredits::<2>::from_buffer_size(&buffer_size(4));
+    credits.acquire(ConnectionHandle(0x0201)).unwrap();
+    credits.acquire(ConnectionHandle(0x0201)).unwrap();
+    credits.acquire(ConnectionHandle(0x0202)).unwrap();
+
+    credits.release(&completed(&[(0x0201, 1), (0x0202, 5), (0x0303, 1)]));
+    assert_eq!(credits.available(), 3);
+    assert_eq!(credits.outstanding(ConnectionHandle(0x0201)), 1);
+    assert_eq!(credits.outstanding(ConnectionHandle(0x0202)), 0);
+
+    // The slot of 0x0202 was freed.
+    credits.acquire(ConnectionHandle(0x0203)).unwrap();
+}
+
+#[test]
+fn acl_credits_released_by_disconnection() {
+    let mut credits = AclBufferCredits::<2>::from_buffer_size(&buffer_size(4));
+    credits.acquire(ConnectionHandle(0x0201)).unwrap();
+    credits.acquire(ConnectionHandle(0x0201)).unwrap();
+    credits.acquire(ConnectionHandle(0x0202)).unwrap();
+
+    credits.disconnect(ConnectionHandle(0x0201));
+    assert_eq!(credits.available(), 3);
+    assert_eq!(credits.outstanding(ConnectionHandle(0x0201)), 0);
+    assert_eq!(credits.outstanding(ConnectionHandle(0x0202)), 1);
+}
+
+#[test]
+fn acl_credits_process_events() {
+    let mut credits = AclBufferCredits::<2>::new();
+
+    // LE Read Buffer Size: 251 bytes, 3 packets
+    let event = Event::new(hci::event::Packet(&[
+        0x0E, 7, 1, 0x02, 0x20, 0x00, 0xFB, 0x00, 0x03,
+    ]))
+    .unwrap();
+    credits.process_event(&event);
+    assert_eq!(credits.data_packet_length(), 251);
+    assert_eq!(credits.available(), 3);
+
+    credits.acquire(ConnectionHandle(0x0201)).unwrap();
+    credits.acquire(ConnectionHandle(0x0201)).unwrap();
+    credits.acquire(ConnectionHandle(0x0202)).unwrap();
+
+    // Number of Completed Packets: 1 for 0x0201
+    let event = Event::new(hci::event::Packet(&[0x13, 5, 1, 0x01, 0x02, 0x01, 0x00])).unwrap();
+    credits.process_event(&event);
+    assert_eq!(credits.available(), 1);
+
+    // Failed Disconnection Complete for 0x0202
+    let event = Event::new(hci::event::Packet(&[0x05, 4, 0x0C, 0x02, 0x02, 0x13])).unwrap();
+    credits.process_event(&event);
+    assert_eq!(credits.available(), 1);
+
+    // Disconnection Complete for 0x0202
+    let event = Event::new(hci::event::Packet(&[0x05, 4, 0x00, 0x02, 0x02, 0x13])).unwrap();
+    credits.process_event(&event);
+    assert_eq!(credits.available(), 2);
+    assert_eq!(credits.outstanding(ConnectionHandle(0x0201)), 1);
+}
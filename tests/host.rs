@@ -2,9 +2,37 @@ extern crate stm32wb_hci as hci;
 
 mod vendor;
 
+use hci::event::command::ReturnParameters;
+use hci::host::executor::{CommandExecutor, CommandResponse};
 use hci::host::*;
+use hci::types::extended_advertisement::{
+    AdvSet, AdvertisingEvent, AdvertisingOperation, AdvertisingPhy, ExtendedAdvertisingInterval,
+};
+use hci::AdvertisingHandle;
 use std::time::Duration;
-use vendor::RecordingSink;
+use vendor::{RecordingSink, ScriptedController};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct LinkDown;
+
+/// Controller that fails every transfer.
+struct BrokenLink;
+
+impl hci::Controller for BrokenLink {
+    type Error = LinkDown;
+
+    async fn controller_write(
+        &mut self,
+        _opcode: hci::Opcode,
+        _payload: &[u8],
+    ) -> Result<(), LinkDown> {
+        Err(LinkDown)
+    }
+
+    async fn controller_read_into(&self, _buf: &mut [u8]) -> Result<(), LinkDown> {
+        Err(LinkDown)
+    }
+}
 
 #[tokio::test]
 async fn disconnect() {
@@ -15,6 +43,23 @@ async fn disconnect() {
     assert_eq!(sink.written_data, [1, 0x06, 0x04, 3, 0x01, 0x02, 0x05]);
 }
 
+#[tokio::test]
+async fn disconnect_comm_error() {
+    let mut link = BrokenLink;
+    let err = link
+        .disconnect(hci::ConnectionHandle(0x0201), hci::Status::AuthFailure)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::Comm(LinkDown));
+}
+
+#[tokio::test]
+async fn reset_comm_error() {
+    let mut link = BrokenLink;
+    assert_eq!(link.reset().await, Err(LinkDown));
+}
+
 #[tokio::test]
 async fn disconnect_bad_reason() {
     let mut sink = RecordingSink::new();
@@ -42,7 +87,8 @@ macro_rules! conn_handle_only {
                 let mut sink = RecordingSink::new();
                 sink
                     .$fn(hci::ConnectionHandle(0x0201))
-                    .await;
+                    .await
+                    .unwrap();
                 assert_eq!(sink.written_data, [1, $oc0, $oc1, 2, 0x01, 0x02]);
             }
         )*
@@ -55,6 +101,7 @@ conn_handle_only! {
     le_read_channel_map(0x15, 0x20);
     le_read_remote_used_features(0x16, 0x20);
     le_long_term_key_request_negative_reply(0x1B, 0x20);
+    le_read_phy(0x30, 0x20);
 }
 
 macro_rules! no_params {
@@ -69,7 +116,8 @@ macro_rules! no_params {
                 let mut sink = RecordingSink::new();
                 sink
                     .$fn()
-                    .await;
+                    .await
+                    .unwrap();
                 assert_eq!(sink.written_data, [1, $oc0, $oc1, 0]);
             }
         )*
@@ -91,13 +139,23 @@ no_params! {
     le_rand(0x18, 0x20);
     le_read_supported_states(0x1C, 0x20);
     le_test_end(0x1F, 0x20);
+    le_read_maximum_advertising_data_length(0x3A, 0x20);
+    le_read_number_of_supported_advertising_sets(0x3B, 0x20);
+    le_clear_advertising_sets(0x3D, 0x20);
+    le_clear_resolving_list(0x29, 0x20);
+    le_read_resolving_list_size(0x2A, 0x20);
+    le_periodic_advertising_create_sync_cancel(0x45, 0x20);
+    le_read_suggested_default_data_length(0x23, 0x20);
+    le_read_maximum_data_length(0x2F, 0x20);
+    le_read_local_p256_public_key(0x25, 0x20);
 }
 
 #[tokio::test]
 async fn set_event_mask() {
     let mut sink = RecordingSink::new();
     sink.set_event_mask(EventFlags::INQUIRY_COMPLETE | EventFlags::AUTHENTICATION_COMPLETE)
-        .await;
+        .await
+        .unwrap();
     assert_eq!(
         sink.written_data,
         [1, 0x01, 0x0C, 8, 0x21, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
@@ -108,17 +166,55 @@ async fn set_event_mask() {
 async fn read_tx_power_level() {
     let mut sink = RecordingSink::new();
     sink.read_tx_power_level(hci::ConnectionHandle(0x0201), TxPowerLevel::Current)
-        .await;
+        .await
+        .unwrap();
     assert_eq!(sink.written_data, [1, 0x2D, 0x0C, 3, 0x01, 0x02, 0x00])
 }
 
+#[tokio::test]
+async fn set_event_mask_page_2() {
+    let mut sink = RecordingSink::new();
+    sink.set_event_mask_page_2(EventFlagsPage2::AUTHENTICATED_PAYLOAD_TIMEOUT_EXPIRED)
+        .await
+        .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x63, 0x0C, 8, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00]
+    );
+}
+
+#[tokio::test]
+async fn read_authenticated_payload_timeout() {
+    let mut sink = RecordingSink::new();
+    sink.read_authenticated_payload_timeout(hci::ConnectionHandle(0x0201))
+        .await
+        .unwrap();
+    assert_eq!(sink.written_data, [1, 0x7B, 0x0C, 2, 0x01, 0x02]);
+}
+
+#[tokio::test]
+async fn write_authenticated_payload_timeout() {
+    let mut sink = RecordingSink::new();
+    sink.write_authenticated_payload_timeout(
+        hci::ConnectionHandle(0x0201),
+        AuthenticatedPayloadTimeout::new(Duration::from_secs(30)).unwrap(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x7C, 0x0C, 4, 0x01, 0x02, 0xB8, 0x0B]
+    );
+}
+
 #[tokio::test]
 async fn le_set_event_mask() {
     let mut sink = RecordingSink::new();
     sink.le_set_event_mask(
         LeEventFlags::CONNECTION_COMPLETE | LeEventFlags::REMOTE_CONNECTION_PARAMETER_REQUEST,
     )
-    .await;
+    .await
+    .unwrap();
     assert_eq!(
         sink.written_data,
         [1, 0x01, 0x20, 8, 0x21, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
@@ -345,7 +441,7 @@ async fn le_set_scan_response_data_too_long() {
 #[tokio::test]
 async fn le_set_advertising_enable() {
     let mut sink = RecordingSink::new();
-    sink.le_set_advertising_enable(true).await;
+    sink.le_set_advertising_enable(true).await.unwrap();
     assert_eq!(sink.written_data, [1, 0x0A, 0x20, 1, 1]);
 }
 
@@ -360,7 +456,8 @@ async fn le_set_scan_parameters() {
         own_address_type: OwnAddressType::Public,
         filter_policy: ScanFilterPolicy::AcceptAll,
     })
-    .await;
+    .await
+    .unwrap();
 
     // bytes 5-6: 0x21, 0x00 = 0x0021 = 33 ~= 21 ms / 0.625 ms
     // bytes 7-8: 0x10, 0x00 = 0x0010 = 16 = 10 ms / 0.625 ms
@@ -373,7 +470,7 @@ async fn le_set_scan_parameters() {
 #[tokio::test]
 async fn le_set_scan_enable() {
     let mut sink = RecordingSink::new();
-    sink.le_set_scan_enable(true, false).await;
+    sink.le_set_scan_enable(true, false).await.unwrap();
     assert_eq!(sink.written_data, [1, 0x0C, 0x20, 2, 1, 0]);
 }
 
@@ -399,7 +496,8 @@ async fn le_create_connection_no_whitelist() {
         )
         .unwrap(),
     })
-    .await;
+    .await
+    .unwrap();
     assert_eq!(
         sink.written_data,
         vec![
@@ -431,7 +529,8 @@ async fn le_create_connection_use_whitelist() {
         )
         .unwrap(),
     })
-    .await;
+    .await
+    .unwrap();
     assert_eq!(
         sink.written_data,
         vec![
@@ -445,7 +544,8 @@ async fn le_create_connection_use_whitelist() {
 async fn le_add_device_to_white_list() {
     let mut sink = RecordingSink::new();
     sink.le_add_device_to_white_list(hci::BdAddrType::Public(hci::BdAddr([1, 2, 3, 4, 5, 6])))
-        .await;
+        .await
+        .unwrap();
     assert_eq!(
         sink.written_data,
         [1, 0x11, 0x20, 7, 0x00, 1, 2, 3, 4, 5, 6]
@@ -455,7 +555,9 @@ async fn le_add_device_to_white_list() {
 #[tokio::test]
 async fn le_add_anon_advertising_devices_to_white_list() {
     let mut sink = RecordingSink::new();
-    sink.le_add_anon_advertising_devices_to_white_list().await;
+    sink.le_add_anon_advertising_devices_to_white_list()
+        .await
+        .unwrap();
     assert_eq!(
         sink.written_data,
         [1, 0x11, 0x20, 7, 0xFF, 0, 0, 0, 0, 0, 0]
@@ -466,7 +568,8 @@ async fn le_add_anon_advertising_devices_to_white_list() {
 async fn le_remove_device_from_white_list() {
     let mut sink = RecordingSink::new();
     sink.le_remove_device_from_white_list(hci::BdAddrType::Public(hci::BdAddr([1, 2, 3, 4, 5, 6])))
-        .await;
+        .await
+        .unwrap();
     assert_eq!(
         sink.written_data,
         [1, 0x12, 0x20, 7, 0x00, 1, 2, 3, 4, 5, 6]
@@ -477,7 +580,8 @@ async fn le_remove_device_from_white_list() {
 async fn le_remove_anon_advertising_devices_from_white_list() {
     let mut sink = RecordingSink::new();
     sink.le_remove_anon_advertising_devices_from_white_list()
-        .await;
+        .await
+        .unwrap();
     assert_eq!(
         sink.written_data,
         [1, 0x12, 0x20, 7, 0xFF, 0, 0, 0, 0, 0, 0]
@@ -501,7 +605,8 @@ async fn le_connection_update() {
         )
         .unwrap(),
     })
-    .await;
+    .await
+    .unwrap();
     assert_eq!(
         sink.written_data,
         vec![
@@ -558,7 +663,8 @@ async fn le_encrypt() {
             0x1e, 0x1f,
         ]),
     })
-    .await;
+    .await
+    .unwrap();
     assert_eq!(
         sink.written_data,
         vec![
@@ -580,7 +686,8 @@ async fn le_start_encryption() {
             0x0, 0x1, 0x2, 0x3, 0x4, 0x5, 0x6, 0x7, 0x8, 0x9, 0xa, 0xb, 0xc, 0xd, 0xe, 0xf,
         ]),
     })
-    .await;
+    .await
+    .unwrap();
     assert_eq!(
         sink.written_data,
         vec![
@@ -599,7 +706,8 @@ async fn le_long_term_key_request_reply() {
             0x0, 0x1, 0x2, 0x3, 0x4, 0x5, 0x6, 0x7, 0x8, 0x9, 0xa, 0xb, 0xc, 0xd, 0xe, 0xf,
         ]),
     )
-    .await;
+    .await
+    .unwrap();
     assert_eq!(
         sink.written_data,
         vec![
@@ -656,3 +764,1144 @@ async fn le_transmitter_test_length_out_of_range() {
     assert_eq!(err, Error::InvalidTestPayloadLength(0x26));
     assert_eq!(sink.written_data, []);
 }
+
+#[tokio::test]
+async fn le_remote_connection_parameter_request_reply() {
+    let mut sink = RecordingSink::new();
+    sink.le_remote_connection_parameter_request_reply(&ConnectionUpdateParameters {
+        conn_handle: hci::ConnectionHandle(0x0201),
+        conn_interval: ConnectionIntervalBuilder::new()
+            .with_range(Duration::from_millis(50), Duration::from_millis(500))
+            .with_latency(10)
+            .with_supervision_timeout(Duration::from_secs(15))
+            .build()
+            .unwrap(),
+        expected_connection_length: ExpectedConnectionLength::new(
+            Duration::from_millis(200),
+            Duration::from_millis(500),
+        )
+        .unwrap(),
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        vec![
+            1, 0x20, 0x20, 14, 0x01, 0x02, 0x28, 0x00, 0x90, 0x01, 0x0A, 0x00, 0xDC, 0x05, 0x40,
+            0x01, 0x20, 0x03,
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_remote_connection_parameter_request_negative_reply() {
+    let mut sink = RecordingSink::new();
+    sink.le_remote_connection_parameter_request_negative_reply(
+        hci::ConnectionHandle(0x0201),
+        hci::Status::UnacceptableConnectionParameters,
+    )
+    .await
+    .unwrap();
+    assert_eq!(sink.written_data, [1, 0x21, 0x20, 3, 0x01, 0x02, 0x3B]);
+}
+
+fn extended_advertising_parameters() -> ExtendedAdvertisingParameters {
+    ExtendedAdvertisingParameters {
+        adv_handle: AdvertisingHandle(0x01),
+        adv_event_properties: AdvertisingEvent::CONNECTABLE | AdvertisingEvent::SCANNABLE,
+        primary_adv_interval: ExtendedAdvertisingInterval::with_range(
+            Duration::from_millis(100),
+            Duration::from_millis(200),
+        )
+        .unwrap(),
+        primary_adv_channel_map: Channels::CH_37 | Channels::CH_39,
+        own_address_type: OwnAddressType::Random,
+        peer_address: hci::BdAddrType::Public(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+        adv_filter_policy: AdvertisingFilterPolicy::AllowConnectionAndScan,
+        adv_tx_power: None,
+        primary_adv_phy: AdvertisingPhy::Le1M,
+        secondary_adv_max_skip: 0,
+        secondary_adv_phy: AdvertisingPhy::Le2M,
+        adv_sid: 0x0A,
+        scan_request_notification_enable: true,
+    }
+}
+
+#[tokio::test]
+async fn le_set_extended_advertising_parameters() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_extended_advertising_parameters(&extended_advertising_parameters())
+        .await
+        .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x36, 0x20, 25, 0x01, 0x03, 0x00, 0xA0, 0x00, 0x00, 0x40, 0x01, 0x00, 0b101, 0x01,
+            0x00, 1, 2, 3, 4, 5, 6, 0x00, 0x7F, 0x01, 0x00, 0x02, 0x0A, 0x01
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_set_extended_advertising_parameters_bad_params() {
+    let mut sink = RecordingSink::new();
+
+    let mut params = extended_advertising_parameters();
+    params.primary_adv_channel_map = Channels::empty();
+    let err = sink
+        .le_set_extended_advertising_parameters(&params)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadChannelMap(Channels::empty()));
+
+    let mut params = extended_advertising_parameters();
+    params.primary_adv_phy = AdvertisingPhy::Le2M;
+    let err = sink
+        .le_set_extended_advertising_parameters(&params)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadPrimaryAdvertisingPhy(AdvertisingPhy::Le2M));
+
+    let mut params = extended_advertising_parameters();
+    params.adv_sid = 0x10;
+    let err = sink
+        .le_set_extended_advertising_parameters(&params)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadAdvertisingSid(0x10));
+
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_extended_advertising_data() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_extended_advertising_data(&ExtendedAdvertisingData {
+        adv_handle: AdvertisingHandle(0x02),
+        operation: AdvertisingOperation::CompleteData,
+        fragment: true,
+        data: &[1, 2, 3],
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x37, 0x20, 7, 0x02, 0x03, 0x00, 3, 1, 2, 3]
+    );
+}
+
+#[tokio::test]
+async fn le_set_extended_scan_response_data_stops_after_failed_fragment() {
+    let data: Vec<u8> = (0..600).map(|i| i as u8).collect();
+    let mut controller = ScriptedController::new(&[
+        &[0x04, 0x0E, 4, 1, 0x38, 0x20, 0x00],
+        &[0x04, 0x0E, 4, 1, 0x38, 0x20, 0x12],
+    ]);
+    let mut failure = None;
+    for fragment in ExtendedAdvertisingData::fragments(AdvertisingHandle(0x02), false, &data) {
+        controller
+            .le_set_extended_scan_response_data(&fragment)
+            .await
+            .unwrap();
+        match controller
+            .wait_for_response(hci::opcode::LE_SET_EXTENDED_SCAN_RESPONSE_DATA, &mut |p| {
+                panic!("Unexpected packet: {:?}", p)
+            })
+            .await
+            .unwrap()
+        {
+            CommandResponse::Complete(ReturnParameters::LeSetExtendedScanResponseData(status)) => {
+                if status != hci::Status::Success {
+                    failure = Some(status);
+                    break;
+                }
+            }
+            other => panic!("Did not get command complete: {:?}", other),
+        }
+    }
+
+    assert_eq!(failure, Some(hci::Status::InvalidParameters));
+    assert_eq!(controller.written.len(), 2);
+    for (i, (operation, range)) in [
+        (AdvertisingOperation::FirstFragment, 0..251),
+        (AdvertisingOperation::IntermediateFragment, 251..502),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let (opcode, params) = &controller.written[i];
+        assert_eq!(*opcode, hci::opcode::LE_SET_EXTENDED_SCAN_RESPONSE_DATA);
+        assert_eq!(
+            params[..4],
+            [0x02, operation as u8, 0x01, range.len() as u8]
+        );
+        assert_eq!(params[4..], data[range]);
+    }
+}
+
+#[tokio::test]
+async fn le_set_extended_advertising_data_too_long() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_extended_advertising_data(&ExtendedAdvertisingData {
+            adv_handle: AdvertisingHandle(0x02),
+            operation: AdvertisingOperation::CompleteData,
+            fragment: true,
+            data: &[0; 252],
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::ExtendedAdvertisingDataTooLong(252));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_periodic_advertising_parameters() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_periodic_advertising_parameters(&PeriodicAdvertisingParameters {
+        adv_handle: AdvertisingHandle(0x02),
+        min_interval: Duration::from_millis(100),
+        max_interval: Duration::from_millis(200),
+        include_tx_power: true,
+    })
+    .await
+    .unwrap();
+
+    // Intervals: 100 ms = 80 * 1.25 ms; 200 ms = 160 * 1.25 ms
+    assert_eq!(
+        sink.written_data,
+        [1, 0x3E, 0x20, 7, 0x02, 0x50, 0x00, 0xA0, 0x00, 0x40, 0x00]
+    );
+}
+
+#[tokio::test]
+async fn le_set_periodic_advertising_parameters_bad_interval() {
+    let mut sink = RecordingSink::new();
+    for (min, max) in [
+        (Duration::from_millis(200), Duration::from_millis(100)),
+        (Duration::from_micros(7_499), Duration::from_millis(100)),
+        (
+            Duration::from_millis(100),
+            Duration::from_micros(81_918_751),
+        ),
+    ] {
+        let err = sink
+            .le_set_periodic_advertising_parameters(&PeriodicAdvertisingParameters {
+                adv_handle: AdvertisingHandle(0x02),
+                min_interval: min,
+                max_interval: max,
+                include_tx_power: false,
+            })
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err, Error::BadPeriodicAdvertisingInterval(min, max));
+    }
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_periodic_advertising_data() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_periodic_advertising_data(&PeriodicAdvertisingData {
+        adv_handle: AdvertisingHandle(0x02),
+        operation: AdvertisingOperation::CompleteData,
+        data: &[1, 2, 3],
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x3F, 0x20, 6, 0x02, 0x03, 3, 1, 2, 3]
+    );
+}
+
+#[tokio::test]
+async fn le_set_periodic_advertising_data_fragments() {
+    let data: Vec<u8> = (0..600).map(|i| i as u8).collect();
+    let mut controller = ScriptedController::new(&[
+        &[0x04, 0x0E, 4, 1, 0x3F, 0x20, 0x00],
+        &[0x04, 0x0E, 4, 1, 0x3F, 0x20, 0x00],
+        &[0x04, 0x0E, 4, 1, 0x3F, 0x20, 0x00],
+    ]);
+    for fragment in PeriodicAdvertisingData::fragments(AdvertisingHandle(0x02), &data) {
+        controller
+            .le_set_periodic_advertising_data(&fragment)
+            .await
+            .unwrap();
+        match controller
+            .wait_for_response(hci::opcode::LE_SET_PERIODIC_ADVERTISING_DATA, &mut |p| {
+                panic!("Unexpected packet: {:?}", p)
+            })
+            .await
+            .unwrap()
+        {
+            CommandResponse::Complete(ReturnParameters::LeSetPeriodicAdvertisingData(status)) => {
+                assert_eq!(status, hci::Status::Success)
+            }
+            other => panic!("Did not get command complete: {:?}", other),
+        }
+    }
+
+    assert_eq!(controller.written.len(), 3);
+    for (i, (operation, range)) in [
+        (AdvertisingOperation::FirstFragment, 0..252),
+        (AdvertisingOperation::IntermediateFragment, 252..504),
+        (AdvertisingOperation::LastFragment, 504..600),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let (opcode, params) = &controller.written[i];
+        assert_eq!(*opcode, hci::opcode::LE_SET_PERIODIC_ADVERTISING_DATA);
+        assert_eq!(params[..3], [0x02, operation as u8, range.len() as u8]);
+        assert_eq!(params[3..], data[range]);
+    }
+}
+
+#[tokio::test]
+async fn le_set_periodic_advertising_data_too_long() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_periodic_advertising_data(&PeriodicAdvertisingData {
+            adv_handle: AdvertisingHandle(0x02),
+            operation: AdvertisingOperation::CompleteData,
+            data: &[0; 253],
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::PeriodicAdvertisingDataTooLong(253));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_periodic_advertising_enable() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_periodic_advertising_enable(true, AdvertisingHandle(0x02))
+        .await
+        .unwrap();
+    assert_eq!(sink.written_data, [1, 0x40, 0x20, 2, 0x01, 0x02]);
+}
+
+#[tokio::test]
+async fn le_set_extended_advertising_enable() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_extended_advertising_enable(
+        true,
+        &[
+            AdvSet {
+                handle: AdvertisingHandle(0x01),
+                duration: 0x0201,
+                max_extended_adv_events: 0x03,
+            },
+            AdvSet {
+                handle: AdvertisingHandle(0x04),
+                duration: 0,
+                max_extended_adv_events: 0,
+            },
+        ],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x39, 0x20, 10, 0x01, 2, 0x01, 0x01, 0x02, 0x03, 0x04, 0x00, 0x00, 0x00]
+    );
+}
+
+#[tokio::test]
+async fn le_set_extended_advertising_enable_too_many_sets() {
+    let mut sink = RecordingSink::new();
+    let set = AdvSet {
+        handle: AdvertisingHandle(0x01),
+        duration: 0,
+        max_extended_adv_events: 0,
+    };
+    let err = sink
+        .le_set_extended_advertising_enable(false, &[set; 64])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::TooManyAdvertisingSets(64));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_remove_advertising_set() {
+    let mut sink = RecordingSink::new();
+    sink.le_remove_advertising_set(AdvertisingHandle(0x05))
+        .await
+        .unwrap();
+    assert_eq!(sink.written_data, [1, 0x3C, 0x20, 1, 0x05]);
+}
+
+fn phy_scan_parameters(scan_type: ScanType, interval_ms: u64) -> PhyScanParameters {
+    PhyScanParameters {
+        scan_type,
+        scan_window: ScanWindow::start_every(Duration::from_millis(interval_ms))
+            .and_then(|b| b.open_for(Duration::from_millis(interval_ms / 2)))
+            .unwrap(),
+    }
+}
+
+#[tokio::test]
+async fn le_set_extended_scan_parameters() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_extended_scan_parameters(&ExtendedScanParameters {
+        own_address_type: OwnAddressType::Random,
+        filter_policy: ScanFilterPolicy::WhiteList,
+        le_1m: Some(phy_scan_parameters(ScanType::Active, 100)),
+        le_coded: Some(phy_scan_parameters(ScanType::Passive, 300)),
+    })
+    .await
+    .unwrap();
+
+    // LE 1M: 100 ms = 0x00A0 * 0.625 ms, 50 ms = 0x0050 * 0.625 ms
+    // LE Coded: 300 ms = 0x01E0 * 0.625 ms, 150 ms = 0x00F0 * 0.625 ms
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x41, 0x20, 13, 0x01, 0x01, 0b101, 0x01, 0xA0, 0x00, 0x50, 0x00, 0x00, 0xE0, 0x01,
+            0xF0, 0x00
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_set_extended_scan_parameters_coded_only() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_extended_scan_parameters(&ExtendedScanParameters {
+        own_address_type: OwnAddressType::Public,
+        filter_policy: ScanFilterPolicy::AcceptAll,
+        le_1m: None,
+        le_coded: Some(phy_scan_parameters(ScanType::Passive, 100)),
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x41, 0x20, 8, 0x00, 0x00, 0b100, 0x00, 0xA0, 0x00, 0x50, 0x00]
+    );
+}
+
+#[tokio::test]
+async fn le_set_extended_scan_parameters_no_phy() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_extended_scan_parameters(&ExtendedScanParameters {
+            own_address_type: OwnAddressType::Public,
+            filter_policy: ScanFilterPolicy::AcceptAll,
+            le_1m: None,
+            le_coded: None,
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::NoScanningPhy);
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_extended_scan_enable() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_extended_scan_enable(&ExtendedScanEnable {
+        enable: true,
+        filter_duplicates: FilterDuplicates::ResetEachPeriod,
+        duration: Some(Duration::from_millis(5_000)),
+        period: Some(Duration::from_millis(12_800)),
+    })
+    .await
+    .unwrap();
+
+    // Duration: 5 s = 500 * 10 ms; period: 12.8 s = 10 * 1.28 s
+    assert_eq!(
+        sink.written_data,
+        [1, 0x42, 0x20, 6, 0x01, 0x02, 0xF4, 0x01, 0x0A, 0x00]
+    );
+}
+
+#[tokio::test]
+async fn le_set_extended_scan_enable_continuous() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_extended_scan_enable(&ExtendedScanEnable {
+        enable: false,
+        filter_duplicates: FilterDuplicates::Disabled,
+        duration: None,
+        period: None,
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x42, 0x20, 6, 0x00, 0x00, 0, 0, 0, 0]
+    );
+}
+
+#[tokio::test]
+async fn le_set_extended_scan_enable_bad_duration() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_extended_scan_enable(&ExtendedScanEnable {
+            enable: true,
+            filter_duplicates: FilterDuplicates::Enabled,
+            duration: Some(Duration::from_millis(655_360)),
+            period: None,
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadScanDuration(Duration::from_millis(655_360)));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_extended_scan_enable_bad_period() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_extended_scan_enable(&ExtendedScanEnable {
+            enable: true,
+            filter_duplicates: FilterDuplicates::Enabled,
+            duration: Some(Duration::from_millis(10)),
+            period: Some(Duration::from_millis(1_000)),
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadScanPeriod(Duration::from_millis(1_000)));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_extended_scan_enable_period_not_longer_than_duration() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_extended_scan_enable(&ExtendedScanEnable {
+            enable: true,
+            filter_duplicates: FilterDuplicates::ResetEachPeriod,
+            duration: Some(Duration::from_millis(2_560)),
+            period: Some(Duration::from_millis(2_560)),
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadScanPeriod(Duration::from_millis(2_560)));
+    assert_eq!(sink.written_data, []);
+}
+
+fn phy_connection_parameters() -> PhyConnectionParameters {
+    PhyConnectionParameters {
+        scan_window: ScanWindow::start_every(Duration::from_millis(50))
+            .and_then(|b| b.open_for(Duration::from_millis(25)))
+            .unwrap(),
+        conn_interval: ConnectionIntervalBuilder::new()
+            .with_range(Duration::from_millis(50), Duration::from_millis(500))
+            .with_latency(10)
+            .with_supervision_timeout(Duration::from_secs(15))
+            .build()
+            .unwrap(),
+        expected_connection_length: ExpectedConnectionLength::new(
+            Duration::from_millis(200),
+            Duration::from_millis(500),
+        )
+        .unwrap(),
+    }
+}
+
+#[tokio::test]
+async fn le_extended_create_connection() {
+    let mut sink = RecordingSink::new();
+    sink.le_extended_create_connection(&ExtendedConnectionParameters {
+        initiator_filter_policy: ConnectionFilterPolicy::UseAddress,
+        own_address_type: OwnAddressType::Random,
+        peer_address: PeerAddrType::RandomDeviceAddress(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+        le_1m: None,
+        le_2m: None,
+        le_coded: Some(phy_connection_parameters()),
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        vec![
+            1, 0x43, 0x20, 26, 0x00, 0x01, 0x01, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0b100, 0x50,
+            0x00, 0x28, 0x00, 0x28, 0x00, 0x90, 0x01, 0x0A, 0x00, 0xDC, 0x05, 0x40, 0x01, 0x20,
+            0x03,
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_extended_create_connection_white_list_on_several_phys() {
+    let mut sink = RecordingSink::new();
+    sink.le_extended_create_connection(&ExtendedConnectionParameters {
+        initiator_filter_policy: ConnectionFilterPolicy::WhiteList,
+        own_address_type: OwnAddressType::Public,
+        peer_address: PeerAddrType::PublicDeviceAddress(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+        le_1m: Some(phy_connection_parameters()),
+        le_2m: Some(phy_connection_parameters()),
+        le_coded: None,
+    })
+    .await
+    .unwrap();
+
+    let phy_params = [
+        0x50, 0x00, 0x28, 0x00, 0x28, 0x00, 0x90, 0x01, 0x0A, 0x00, 0xDC, 0x05, 0x40, 0x01, 0x20,
+        0x03,
+    ];
+    let mut expected = vec![1, 0x43, 0x20, 42, 0x01, 0x00, 0, 0, 0, 0, 0, 0, 0, 0b011];
+    expected.extend_from_slice(&phy_params);
+    expected.extend_from_slice(&phy_params);
+    assert_eq!(sink.written_data, expected);
+}
+
+#[tokio::test]
+async fn le_extended_create_connection_no_phy() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_extended_create_connection(&ExtendedConnectionParameters {
+            initiator_filter_policy: ConnectionFilterPolicy::WhiteList,
+            own_address_type: OwnAddressType::Public,
+            peer_address: PeerAddrType::PublicDeviceAddress(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+            le_1m: None,
+            le_2m: Some(phy_connection_parameters()),
+            le_coded: None,
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::NoInitiatingPhy);
+    assert_eq!(sink.written_data, []);
+}
+
+fn periodic_advertising_sync_parameters() -> PeriodicAdvertisingSyncParameters {
+    PeriodicAdvertisingSyncParameters {
+        options: PeriodicSyncOptions::DUPLICATE_FILTERING,
+        adv_sid: 0x05,
+        advertiser_address: hci::BdAddrType::Random(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+        skip: 0x0003,
+        sync_timeout: Duration::from_secs(2),
+        sync_cte_type: SyncCteType::NO_AOA | SyncCteType::NO_TYPE_3,
+    }
+}
+
+#[tokio::test]
+async fn le_periodic_advertising_create_sync() {
+    let mut sink = RecordingSink::new();
+    sink.le_periodic_advertising_create_sync(&periodic_advertising_sync_parameters())
+        .await
+        .unwrap();
+
+    // Sync timeout: 2 s = 200 * 10 ms
+    assert_eq!(
+        sink.written_data,
+        [1, 0x44, 0x20, 14, 0b100, 0x05, 0x01, 1, 2, 3, 4, 5, 6, 0x03, 0x00, 0xC8, 0x00, 0b1001]
+    );
+}
+
+#[tokio::test]
+async fn le_periodic_advertising_create_sync_bad_sid() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_periodic_advertising_create_sync(&PeriodicAdvertisingSyncParameters {
+            adv_sid: 0x10,
+            ..periodic_advertising_sync_parameters()
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadAdvertisingSid(0x10));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_periodic_advertising_create_sync_bad_skip() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_periodic_advertising_create_sync(&PeriodicAdvertisingSyncParameters {
+            skip: 0x01F4,
+            ..periodic_advertising_sync_parameters()
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadPeriodicAdvertisingSkip(0x01F4));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_periodic_advertising_create_sync_bad_timeout() {
+    let mut sink = RecordingSink::new();
+    for timeout in [Duration::from_millis(99), Duration::from_millis(163_841)] {
+        let err = sink
+            .le_periodic_advertising_create_sync(&PeriodicAdvertisingSyncParameters {
+                sync_timeout: timeout,
+                ..periodic_advertising_sync_parameters()
+            })
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err, Error::BadSyncTimeout(timeout));
+    }
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_periodic_advertising_terminate_sync() {
+    let mut sink = RecordingSink::new();
+    sink.le_periodic_advertising_terminate_sync(hci::SyncHandle(0x0201))
+        .await
+        .unwrap();
+    assert_eq!(sink.written_data, [1, 0x46, 0x20, 2, 0x01, 0x02]);
+}
+
+#[tokio::test]
+async fn le_periodic_advertising_sync_transfer() {
+    let mut sink = RecordingSink::new();
+    sink.le_periodic_advertising_sync_transfer(
+        hci::ConnectionHandle(0x0201),
+        0x0403,
+        hci::SyncHandle(0x0605),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x5A, 0x20, 6, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06]
+    );
+}
+
+#[tokio::test]
+async fn le_periodic_advertising_set_info_transfer() {
+    let mut sink = RecordingSink::new();
+    sink.le_periodic_advertising_set_info_transfer(
+        hci::ConnectionHandle(0x0201),
+        0x0403,
+        AdvertisingHandle(0x05),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x5B, 0x20, 5, 0x01, 0x02, 0x03, 0x04, 0x05]
+    );
+}
+
+fn periodic_advertising_sync_transfer_parameters() -> PeriodicAdvertisingSyncTransferParameters {
+    PeriodicAdvertisingSyncTransferParameters {
+        mode: SyncTransferMode::SyncReportsEnabledFilterDuplicates,
+        skip: 0x0003,
+        sync_timeout: Duration::from_secs(2),
+        sync_cte_type: SyncCteType::CTE_REQUIRED,
+    }
+}
+
+#[tokio::test]
+async fn le_set_periodic_advertising_sync_transfer_parameters() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_periodic_advertising_sync_transfer_parameters(
+        hci::ConnectionHandle(0x0201),
+        &periodic_advertising_sync_transfer_parameters(),
+    )
+    .await
+    .unwrap();
+
+    // Sync timeout: 2 s = 200 * 10 ms
+    assert_eq!(
+        sink.written_data,
+        [1, 0x5C, 0x20, 8, 0x01, 0x02, 0x03, 0x03, 0x00, 0xC8, 0x00, 0b1_0000]
+    );
+}
+
+#[tokio::test]
+async fn le_set_periodic_advertising_sync_transfer_parameters_bad_skip() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_periodic_advertising_sync_transfer_parameters(
+            hci::ConnectionHandle(0x0201),
+            &PeriodicAdvertisingSyncTransferParameters {
+                skip: 0x01F4,
+                ..periodic_advertising_sync_transfer_parameters()
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadPeriodicAdvertisingSkip(0x01F4));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_default_periodic_advertising_sync_transfer_parameters() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_default_periodic_advertising_sync_transfer_parameters(
+        &PeriodicAdvertisingSyncTransferParameters {
+            mode: SyncTransferMode::NoSync,
+            skip: 0,
+            sync_timeout: Duration::from_millis(100),
+            sync_cte_type: SyncCteType::empty(),
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x5D, 0x20, 6, 0x00, 0x00, 0x00, 0x0A, 0x00, 0x00]
+    );
+}
+
+#[tokio::test]
+async fn le_set_default_periodic_advertising_sync_transfer_parameters_bad_timeout() {
+    let mut sink = RecordingSink::new();
+    for timeout in [Duration::from_millis(99), Duration::from_millis(163_841)] {
+        let err = sink
+            .le_set_default_periodic_advertising_sync_transfer_parameters(
+                &PeriodicAdvertisingSyncTransferParameters {
+                    sync_timeout: timeout,
+                    ..periodic_advertising_sync_transfer_parameters()
+                },
+            )
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err, Error::BadSyncTimeout(timeout));
+    }
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_enhanced_read_transmit_power_level() {
+    let mut sink = RecordingSink::new();
+    sink.le_enhanced_read_transmit_power_level(
+        hci::ConnectionHandle(0x0201),
+        hci::event::PowerControlPhy::LeCodedS2,
+    )
+    .await
+    .unwrap();
+    assert_eq!(sink.written_data, [1, 0x76, 0x20, 3, 0x01, 0x02, 0x04]);
+}
+
+#[tokio::test]
+async fn le_read_remote_transmit_power_level() {
+    let mut sink = RecordingSink::new();
+    sink.le_read_remote_transmit_power_level(
+        hci::ConnectionHandle(0x0201),
+        hci::event::PowerControlPhy::Le2M,
+    )
+    .await
+    .unwrap();
+    assert_eq!(sink.written_data, [1, 0x77, 0x20, 3, 0x01, 0x02, 0x02]);
+}
+
+#[tokio::test]
+async fn le_set_path_loss_reporting_parameters() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_path_loss_reporting_parameters(
+        hci::ConnectionHandle(0x0201),
+        &PathLossReportingParameters {
+            high_threshold: 80,
+            high_hysteresis: 5,
+            low_threshold: 50,
+            low_hysteresis: 5,
+            min_time_spent: 0x0304,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x78, 0x20, 8, 0x01, 0x02, 80, 5, 50, 5, 0x04, 0x03]
+    );
+}
+
+#[tokio::test]
+async fn le_set_path_loss_reporting_parameters_bad_thresholds() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_path_loss_reporting_parameters(
+            hci::ConnectionHandle(0x0201),
+            &PathLossReportingParameters {
+                high_threshold: 60,
+                high_hysteresis: 6,
+                low_threshold: 50,
+                low_hysteresis: 5,
+                min_time_spent: 0,
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadPathLossThresholds(50, 60));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_path_loss_reporting_enable() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_path_loss_reporting_enable(hci::ConnectionHandle(0x0201), true)
+        .await
+        .unwrap();
+    assert_eq!(sink.written_data, [1, 0x79, 0x20, 3, 0x01, 0x02, 0x01]);
+}
+
+#[tokio::test]
+async fn le_set_transmit_power_reporting_enable() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_transmit_power_reporting_enable(hci::ConnectionHandle(0x0201), false, true)
+        .await
+        .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x7A, 0x20, 4, 0x01, 0x02, 0x00, 0x01]
+    );
+}
+
+#[tokio::test]
+async fn le_add_device_to_resolving_list() {
+    let mut sink = RecordingSink::new();
+    sink.le_add_device_to_resolving_list(&ResolvingListEntry {
+        peer_identity_address: hci::BdAddrType::Random(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+        peer_irk: IdentityResolvingKey([0x11; 16]),
+        local_irk: IdentityResolvingKey([0x22; 16]),
+    })
+    .await
+    .unwrap();
+
+    let mut expected = vec![1, 0x27, 0x20, 39, 0x01, 1, 2, 3, 4, 5, 6];
+    expected.extend_from_slice(&[0x11; 16]);
+    expected.extend_from_slice(&[0x22; 16]);
+    assert_eq!(sink.written_data, expected);
+}
+
+#[test]
+fn identity_resolving_key_debug_is_redacted() {
+    assert_eq!(
+        format!("{:?}", IdentityResolvingKey([0x11; 16])),
+        "IRK (REDACTED)"
+    );
+}
+
+#[tokio::test]
+async fn le_remove_device_from_resolving_list() {
+    let mut sink = RecordingSink::new();
+    sink.le_remove_device_from_resolving_list(hci::BdAddrType::Public(hci::BdAddr([
+        1, 2, 3, 4, 5, 6,
+    ])))
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x28, 0x20, 7, 0x00, 1, 2, 3, 4, 5, 6]
+    );
+}
+
+#[tokio::test]
+async fn le_read_peer_resolvable_address() {
+    let mut sink = RecordingSink::new();
+    sink.le_read_peer_resolvable_address(hci::BdAddrType::Public(hci::BdAddr([1, 2, 3, 4, 5, 6])))
+        .await
+        .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x2B, 0x20, 7, 0x00, 1, 2, 3, 4, 5, 6]
+    );
+}
+
+#[tokio::test]
+async fn le_read_local_resolvable_address() {
+    let mut sink = RecordingSink::new();
+    sink.le_read_local_resolvable_address(hci::BdAddrType::Random(hci::BdAddr([1, 2, 3, 4, 5, 6])))
+        .await
+        .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x2C, 0x20, 7, 0x01, 1, 2, 3, 4, 5, 6]
+    );
+}
+
+#[tokio::test]
+async fn le_set_address_resolution_enable() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_address_resolution_enable(true).await.unwrap();
+    assert_eq!(sink.written_data, [1, 0x2D, 0x20, 1, 1]);
+}
+
+#[tokio::test]
+async fn le_set_resolvable_private_address_timeout() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_resolvable_private_address_timeout(Duration::from_secs(900))
+        .await
+        .unwrap();
+    assert_eq!(sink.written_data, [1, 0x2E, 0x20, 2, 0x84, 0x03]);
+}
+
+#[tokio::test]
+async fn le_set_resolvable_private_address_timeout_out_of_range() {
+    let mut sink = RecordingSink::new();
+    for timeout in [Duration::from_millis(999), Duration::from_secs(3601)] {
+        let err = sink
+            .le_set_resolvable_private_address_timeout(timeout)
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err, Error::BadResolvablePrivateAddressTimeout(timeout));
+    }
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_privacy_mode() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_privacy_mode(
+        hci::BdAddrType::Public(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+        PrivacyMode::Device,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x4E, 0x20, 8, 0x00, 1, 2, 3, 4, 5, 6, 0x01]
+    );
+}
+
+fn remote_public_key() -> P256PublicKey {
+    let mut key = [0; 64];
+    for (i, byte) in key.iter_mut().enumerate() {
+        *byte = i as u8;
+    }
+    P256PublicKey(key)
+}
+
+#[tokio::test]
+async fn le_generate_dhkey() {
+    let mut sink = RecordingSink::new();
+    sink.le_generate_dhkey(&remote_public_key()).await.unwrap();
+
+    let mut expected = vec![1, 0x26, 0x20, 64];
+    expected.extend_from_slice(&remote_public_key().0);
+    assert_eq!(sink.written_data, expected);
+}
+
+#[tokio::test]
+async fn le_generate_dhkey_v2() {
+    let mut sink = RecordingSink::new();
+    sink.le_generate_dhkey_v2(&remote_public_key(), DhKeyPrivateKey::Debug)
+        .await
+        .unwrap();
+
+    let mut expected = vec![1, 0x5E, 0x20, 65];
+    expected.extend_from_slice(&remote_public_key().0);
+    expected.push(0x01);
+    assert_eq!(sink.written_data, expected);
+}
+
+#[test]
+fn secure_connections_keys_debug_is_redacted() {
+    assert_eq!(
+        format!("{:?}", remote_public_key()),
+        "P-256 Public Key (REDACTED)"
+    );
+    assert_eq!(format!("{:?}", DhKey([0x11; 32])), "DHKey (REDACTED)");
+}
+
+#[tokio::test]
+async fn le_set_default_phy() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_default_phy(None, Some(PhyMask::LE_1M | PhyMask::LE_2M))
+        .await
+        .unwrap();
+    assert_eq!(sink.written_data, [1, 0x31, 0x20, 3, 0b01, 0, 0b011]);
+}
+
+#[tokio::test]
+async fn le_set_phy() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_phy(
+        hci::ConnectionHandle(0x0201),
+        Some(PhyMask::LE_2M),
+        Some(PhyMask::LE_2M | PhyMask::LE_CODED),
+        CodedPhyOptions::S8,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x32, 0x20, 7, 0x01, 0x02, 0b00, 0b010, 0b110, 0x02, 0x00]
+    );
+}
+
+#[tokio::test]
+async fn le_set_phy_no_preference() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_phy(
+        hci::ConnectionHandle(0x0201),
+        None,
+        None,
+        CodedPhyOptions::NoPreference,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x32, 0x20, 7, 0x01, 0x02, 0b11, 0, 0, 0x00, 0x00]
+    );
+}
+
+#[tokio::test]
+async fn le_set_phy_empty_preference() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_phy(
+            hci::ConnectionHandle(0x0201),
+            Some(PhyMask::LE_1M),
+            Some(PhyMask::empty()),
+            CodedPhyOptions::NoPreference,
+        )
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::EmptyPhyPreference);
+
+    let err = sink
+        .le_set_default_phy(Some(PhyMask::empty()), None)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::EmptyPhyPreference);
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_data_length() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_data_length(
+        hci::ConnectionHandle(0x0201),
+        MaxTxOctets::new(251).unwrap(),
+        MaxTxTime::new(Duration::from_micros(2120)).unwrap(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x22, 0x20, 6, 0x01, 0x02, 0xFB, 0x00, 0x48, 0x08]
+    );
+}
+
+#[tokio::test]
+async fn le_write_suggested_default_data_length() {
+    let mut sink = RecordingSink::new();
+    sink.le_write_suggested_default_data_length(
+        MaxTxOctets::new(27).unwrap(),
+        MaxTxTime::new(Duration::from_micros(328)).unwrap(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x24, 0x20, 4, 0x1B, 0x00, 0x48, 0x01]
+    );
+}
@@ -0,0 +1,173 @@
+extern crate stm32wb_hci as hci;
+
+mod vendor;
+
+use hci::btsnoop::Recorder;
+use hci::event::command::ReturnParameters;
+use hci::host::uart::{Packet, UartHci};
+use hci::host::{HciCommand, HostHci};
+use hci::replay::{Replay, ReplayError};
+use hci::vendor::command::gap::{GapCommand, GapCommands, Role};
+use hci::vendor::command::gatt::{
+    AddServiceParameters, GattCommand, GattCommands, ServiceType, Uuid,
+};
+use hci::vendor::event::response::VendorReturnParameters;
+use hci::vendor::event::AttributeHandle;
+use hci::Event;
+use vendor::ScriptedController;
+
+const RESET_COMPLETE: [u8; 7] = [0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00];
+const GAP_INIT_COMPLETE: [u8; 13] = [
+    0x04, 0x0E, 0x0A, 0x01, 0x8A, 0xFC, 0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00,
+];
+const GATT_INIT_COMPLETE: [u8; 7] = [0x04, 0x0E, 0x04, 0x01, 0x01, 0xFD, 0x00];
+const GATT_ADD_SERVICE_COMPLETE: [u8; 9] = [0x04, 0x0E, 0x06, 0x01, 0x02, 0xFD, 0x00, 0x0C, 0x00];
+
+fn service() -> AddServiceParameters {
+    AddServiceParameters {
+        uuid: Uuid::Uuid16(0x180D),
+        service_type: ServiceType::Primary,
+        max_attribute_records: 8,
+    }
+}
+
+fn return_params(packet: Packet) -> ReturnParameters {
+    match packet {
+        Packet::Event(Event::CommandComplete(event)) => event.return_params,
+        other => panic!("Did not get command complete: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn bring_up_sequence() {
+    let service = service();
+    let mut controller = Replay::new()
+        .expect_command(&HciCommand::Reset)
+        .respond(&RESET_COMPLETE)
+        .expect_command(&GapCommand::Init {
+            role: Role::PERIPHERAL,
+            privacy_enabled: false,
+            dev_name_characteristic_len: 8,
+        })
+        .respond(&GAP_INIT_COMPLETE)
+        .expect_command(&GattCommand::Init)
+        .respond(&GATT_INIT_COMPLETE)
+        .expect_command(&GattCommand::AddService(&service))
+        .respond(&GATT_ADD_SERVICE_COMPLETE);
+
+    controller.reset().await.unwrap();
+    let packet = controller.read().await.unwrap();
+    assert!(matches!(return_params(packet), ReturnParameters::Reset(_)));
+
+    controller
+        .init_gap(Role::PERIPHERAL, false, 8)
+        .await
+        .unwrap();
+    match return_params(controller.read().await.unwrap()) {
+        ReturnParameters::Vendor(VendorReturnParameters::GapInit(params)) => {
+            assert_eq!(params.service_handle, AttributeHandle(0x0001));
+            assert_eq!(params.dev_name_handle, AttributeHandle(0x0002));
+            assert_eq!(params.appearance_handle, AttributeHandle(0x0003));
+        }
+        other => panic!("Did not get GAP init: {:?}", other),
+    }
+
+    controller.init_gatt().await.unwrap();
+    controller.read().await.unwrap();
+
+    controller.add_service(&service).await.unwrap();
+    match return_params(controller.read().await.unwrap()) {
+        ReturnParameters::Vendor(VendorReturnParameters::GattAddService(params)) => {
+            assert_eq!(params.service_handle, AttributeHandle(0x000C));
+        }
+        other => panic!("Did not get GATT add service: {:?}", other),
+    }
+
+    assert!(controller.is_finished());
+}
+
+#[tokio::test]
+async fn unexpected_command_is_reported() {
+    let mut controller = Replay::new()
+        .expect_command(&HciCommand::Reset)
+        .respond(&RESET_COMPLETE);
+
+    let err = controller.init_gatt().await.err().unwrap();
+    assert_eq!(
+        err,
+        ReplayError::UnexpectedPacket {
+            expected: Some(vec![0x01, 0x03, 0x0C, 0x00]),
+            written: vec![0x01, 0x01, 0xFD, 0x00],
+        }
+    );
+    assert!(!controller.is_finished());
+}
+
+#[tokio::test]
+async fn response_waits_for_command() {
+    let mut controller = Replay::new()
+        .expect_command(&HciCommand::Reset)
+        .respond(&RESET_COMPLETE);
+
+    match controller.read().await {
+        Err(hci::host::uart::Error::Comm(ReplayError::ResponseNotReady)) => (),
+        other => panic!("Did not get response not ready: {:?}", other),
+    }
+
+    controller.reset().await.unwrap();
+    controller.read().await.unwrap();
+    match controller.read().await {
+        Err(hci::host::uart::Error::Comm(ReplayError::Exhausted)) => (),
+        other => panic!("Did not get exhausted: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn replays_recorded_capture() {
+    let mut recorder = Recorder::new(
+        ScriptedController::new(&[&RESET_COMPLETE, &GATT_INIT_COMPLETE]),
+        Vec::new(),
+    )
+    .unwrap();
+    recorder.reset().await.unwrap();
+    recorder.read().await.unwrap();
+    recorder.init_gatt().await.unwrap();
+    recorder.read().await.unwrap();
+    let (_, capture) = recorder.into_inner();
+
+    let mut controller = Replay::from_capture(capture.as_slice()).unwrap();
+    controller.reset().await.unwrap();
+    controller.read().await.unwrap();
+    controller.init_gatt().await.unwrap();
+    controller.read().await.unwrap();
+    assert!(controller.is_finished());
+}
+
+#[tokio::test]
+async fn respond_event_encodes_event() {
+    let event = Event::new(hci::event::Packet(&RESET_COMPLETE[1..])).unwrap();
+    let mut controller = Replay::new()
+        .expect_command(&HciCommand::Reset)
+        .respond_event(&event);
+
+    controller.reset().await.unwrap();
+    let packet = controller.read().await.unwrap();
+    assert!(matches!(return_params(packet), ReturnParameters::Reset(_)));
+}
+
+#[tokio::test]
+async fn short_reads_return_rest_of_response() {
+    let controller = Replay::new().respond(&RESET_COMPLETE);
+
+    let mut header = [0; 3];
+    let mut params = [0; 4];
+    hci::Controller::controller_read_into(&controller, &mut header)
+        .await
+        .unwrap();
+    hci::Controller::controller_read_into(&controller, &mut params)
+        .await
+        .unwrap();
+    assert_eq!(header, RESET_COMPLETE[..3]);
+    assert_eq!(params, RESET_COMPLETE[3..]);
+    assert!(controller.is_finished());
+}
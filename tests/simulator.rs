@@ -0,0 +1,309 @@
+extern crate stm32wb_hci as hci;
+
+use hci::event::command::ReturnParameters;
+use hci::host::uart::{Error, Packet, UartHci};
+use hci::host::HostHci;
+use hci::simulator::{Mode, NoPendingPacket, Simulator, DEFAULT_FIRMWARE_REVISION};
+use hci::vendor::command::gap::{
+    AdvertisingFilterPolicy, AdvertisingType, DiscoverableParameters, GapCommands, OwnAddressType,
+    Role, UndirectedConnectableParameters,
+};
+use hci::vendor::command::gatt::{
+    AddCharacteristicParameters, AddServiceParameters, CharacteristicEvent,
+    CharacteristicPermission, CharacteristicProperty, EncryptionKeySize, GattCommands, ServiceType,
+    Uuid,
+};
+use hci::vendor::command::hal::HalCommands;
+use hci::vendor::event::response::VendorReturnParameters;
+use hci::vendor::event::{AttributeHandle, FirmwareKind, VendorEvent, VendorStatus};
+use hci::{Event, Status};
+use std::time::Duration;
+
+/// Returns true if `status` carries the code of `expected`. Codes shared with the standard status
+/// codes (e.g. 0x41 for [`VendorStatus::Failed`]) are parsed as the standard status.
+fn is_vendor_status(status: Status, expected: VendorStatus) -> bool {
+    u8::from(status) == expected as u8
+}
+
+async fn vendor_return_params(controller: &mut Simulator) -> VendorReturnParameters {
+    match controller.read().await {
+        Ok(Packet::Event(Event::CommandComplete(event))) => match event.return_params {
+            ReturnParameters::Vendor(params) => params,
+            other => panic!("Did not get vendor return parameters: {:?}", other),
+        },
+        other => panic!("Did not get command complete: {:?}", other),
+    }
+}
+
+async fn initialized(role: Role) -> Simulator {
+    let mut controller = Simulator::new();
+    controller.init_gatt().await.unwrap();
+    controller.init_gap(role, false, 8).await.unwrap();
+    vendor_return_params(&mut controller).await;
+    vendor_return_params(&mut controller).await;
+    controller
+}
+
+fn service(max_attribute_records: u8) -> AddServiceParameters {
+    AddServiceParameters {
+        uuid: Uuid::Uuid16(0x180D),
+        service_type: ServiceType::Primary,
+        max_attribute_records,
+    }
+}
+
+fn characteristic(
+    service_handle: AttributeHandle,
+    properties: CharacteristicProperty,
+) -> AddCharacteristicParameters {
+    AddCharacteristicParameters {
+        service_handle,
+        characteristic_uuid: Uuid::Uuid16(0x2A37),
+        characteristic_value_len: 2,
+        characteristic_properties: properties,
+        security_permissions: CharacteristicPermission::empty(),
+        gatt_event_mask: CharacteristicEvent::empty(),
+        encryption_key_size: EncryptionKeySize::with_value(16).unwrap(),
+        is_variable: false,
+    }
+}
+
+async fn add_characteristic(
+    controller: &mut Simulator,
+    params: &AddCharacteristicParameters,
+) -> (Status, AttributeHandle) {
+    controller.add_characteristic(params).await.unwrap();
+    match vendor_return_params(controller).await {
+        VendorReturnParameters::GattAddCharacteristic(params) => {
+            (params.status, params.characteristic_handle)
+        }
+        other => panic!("Did not get add characteristic: {:?}", other),
+    }
+}
+
+fn discoverable() -> DiscoverableParameters<'static, 'static> {
+    DiscoverableParameters {
+        advertising_type: AdvertisingType::ConnectableUndirected,
+        advertising_interval: None,
+        address_type: OwnAddressType::Public,
+        filter_policy: AdvertisingFilterPolicy::AllowConnectionAndScan,
+        local_name: None,
+        advertising_data: &[],
+        conn_interval: (None, None),
+    }
+}
+
+#[tokio::test]
+async fn reset_is_followed_by_coprocessor_ready() {
+    let mut controller = initialized(Role::PERIPHERAL).await;
+    controller.reset().await.unwrap();
+
+    match controller.read().await {
+        Ok(Packet::Event(Event::CommandComplete(event))) => {
+            assert!(matches!(
+                event.return_params,
+                ReturnParameters::Reset(Status::Success)
+            ));
+        }
+        other => panic!("Did not get command complete: {:?}", other),
+    }
+    match controller.read().await {
+        Ok(Packet::Event(Event::Vendor(VendorEvent::CoprocessorReady(kind)))) => {
+            assert_eq!(kind, FirmwareKind::Wireless);
+        }
+        other => panic!("Did not get coprocessor ready: {:?}", other),
+    }
+
+    // The GAP and GATT state is gone
+    controller
+        .init_gap(Role::PERIPHERAL, false, 8)
+        .await
+        .unwrap();
+    match vendor_return_params(&mut controller).await {
+        VendorReturnParameters::GapInit(params) => {
+            assert!(is_vendor_status(params.status, VendorStatus::Failed));
+        }
+        other => panic!("Did not get GAP init: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn firmware_revision() {
+    for (mut controller, revision) in [
+        (Simulator::new(), DEFAULT_FIRMWARE_REVISION),
+        (Simulator::new().firmware_revision(0x0120), 0x0120),
+    ] {
+        controller.get_firmware_revision().await.unwrap();
+        match vendor_return_params(&mut controller).await {
+            VendorReturnParameters::HalGetFirmwareRevision(params) => {
+                assert_eq!(params.status, Status::Success);
+                assert_eq!(params.revision, revision);
+            }
+            other => panic!("Did not get firmware revision: {:?}", other),
+        }
+    }
+}
+
+#[tokio::test]
+async fn attribute_handles_are_allocated() {
+    let mut controller = Simulator::new();
+    controller.init_gatt().await.unwrap();
+    vendor_return_params(&mut controller).await;
+
+    controller
+        .init_gap(Role::PERIPHERAL, false, 8)
+        .await
+        .unwrap();
+    match vendor_return_params(&mut controller).await {
+        VendorReturnParameters::GapInit(params) => {
+            assert_eq!(params.status, Status::Success);
+            assert_eq!(params.service_handle, AttributeHandle(0x0005));
+            assert_eq!(params.dev_name_handle, AttributeHandle(0x0006));
+            assert_eq!(params.appearance_handle, AttributeHandle(0x0008));
+        }
+        other => panic!("Did not get GAP init: {:?}", other),
+    }
+
+    controller.add_service(&service(6)).await.unwrap();
+    let service_handle = match vendor_return_params(&mut controller).await {
+        VendorReturnParameters::GattAddService(params) => {
+            assert_eq!(params.status, Status::Success);
+            params.service_handle
+        }
+        other => panic!("Did not get add service: {:?}", other),
+    };
+    assert_eq!(service_handle, AttributeHandle(0x000C));
+
+    let notify = characteristic(service_handle, CharacteristicProperty::NOTIFY);
+    assert_eq!(
+        add_characteristic(&mut controller, &notify).await,
+        (Status::Success, AttributeHandle(0x000D))
+    );
+    let read = characteristic(service_handle, CharacteristicProperty::READ);
+    assert_eq!(
+        add_characteristic(&mut controller, &read).await,
+        (Status::Success, AttributeHandle(0x0010))
+    );
+    assert_eq!(
+        add_characteristic(&mut controller, &read).await.0,
+        Status::Vendor(VendorStatus::OutOfHandle)
+    );
+
+    let unknown = characteristic(AttributeHandle(0x0005), CharacteristicProperty::READ);
+    assert_eq!(
+        add_characteristic(&mut controller, &unknown).await.0,
+        Status::Vendor(VendorStatus::InvalidHandle)
+    );
+}
+
+#[tokio::test]
+async fn gap_init_requires_gatt_init() {
+    let mut controller = Simulator::new();
+    controller
+        .init_gap(Role::PERIPHERAL, false, 8)
+        .await
+        .unwrap();
+    match vendor_return_params(&mut controller).await {
+        VendorReturnParameters::GapInit(params) => {
+            assert!(is_vendor_status(params.status, VendorStatus::Failed));
+        }
+        other => panic!("Did not get GAP init: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn discoverable_transitions() {
+    let mut controller = initialized(Role::PERIPHERAL).await;
+    let connectable = UndirectedConnectableParameters {
+        advertising_interval: (Duration::from_millis(100), Duration::from_millis(200)),
+        own_address_type: OwnAddressType::Public,
+        filter_policy: AdvertisingFilterPolicy::AllowConnectionAndScan,
+    };
+
+    controller.set_discoverable(&discoverable()).await.unwrap();
+    assert!(matches!(
+        vendor_return_params(&mut controller).await,
+        VendorReturnParameters::GapSetDiscoverable(Status::Success)
+    ));
+    assert_eq!(controller.mode(), Mode::Discoverable);
+
+    controller
+        .set_undirected_connectable(&connectable)
+        .await
+        .unwrap();
+    assert!(matches!(
+        vendor_return_params(&mut controller).await,
+        VendorReturnParameters::GapSetUndirectedConnectable(Status::Vendor(
+            VendorStatus::NotAllowed
+        ))
+    ));
+    assert_eq!(controller.mode(), Mode::Discoverable);
+
+    controller.gap_set_nondiscoverable().await.unwrap();
+    assert!(matches!(
+        vendor_return_params(&mut controller).await,
+        VendorReturnParameters::GapSetNonDiscoverable(Status::Success)
+    ));
+    assert_eq!(controller.mode(), Mode::Nondiscoverable);
+
+    controller
+        .set_undirected_connectable(&connectable)
+        .await
+        .unwrap();
+    assert!(matches!(
+        vendor_return_params(&mut controller).await,
+        VendorReturnParameters::GapSetUndirectedConnectable(Status::Success)
+    ));
+    assert_eq!(controller.mode(), Mode::UndirectedConnectable);
+}
+
+#[tokio::test]
+async fn limited_discoverable_is_acknowledged_by_command_status() {
+    let mut controller = initialized(Role::PERIPHERAL).await;
+    controller
+        .set_limited_discoverable(&discoverable())
+        .await
+        .unwrap();
+    match controller.read().await {
+        Ok(Packet::Event(Event::CommandStatus(event))) => {
+            assert_eq!(event.status, Status::Success);
+        }
+        other => panic!("Did not get command status: {:?}", other),
+    }
+    assert_eq!(controller.mode(), Mode::LimitedDiscoverable);
+}
+
+#[tokio::test]
+async fn discoverable_requires_peripheral_role() {
+    let mut controller = initialized(Role::CENTRAL).await;
+    controller.set_discoverable(&discoverable()).await.unwrap();
+    match vendor_return_params(&mut controller).await {
+        VendorReturnParameters::GapSetDiscoverable(status) => {
+            assert!(is_vendor_status(status, VendorStatus::Failed));
+        }
+        other => panic!("Did not get set discoverable: {:?}", other),
+    }
+    assert_eq!(controller.mode(), Mode::Nondiscoverable);
+}
+
+#[tokio::test]
+async fn unknown_commands_are_rejected() {
+    let mut controller = Simulator::new();
+    controller.read_local_version_information().await.unwrap();
+    match controller.read().await {
+        Ok(Packet::Event(Event::CommandStatus(event))) => {
+            assert_eq!(event.status, Status::UnknownCommand);
+            assert_eq!(event.opcode, hci::opcode::READ_LOCAL_VERSION_INFO);
+        }
+        other => panic!("Did not get command status: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn read_without_pending_packet_fails() {
+    let mut controller = Simulator::new();
+    match controller.read().await {
+        Err(Error::Comm(NoPendingPacket)) => (),
+        other => panic!("Did not get no pending packet: {:?}", other),
+    }
+}
@@ -0,0 +1,126 @@
+extern crate stm32wb_hci as hci;
+
+mod vendor;
+
+use hci::host::uart::*;
+use hci::ConnectionHandle;
+use std::convert::Infallible;
+use vendor::{RecordingSink, ScriptExhausted, ScriptedController};
+
+#[test]
+fn acl_data_header_from_bytes() {
+    let header = AclDataHeader::from_bytes::<Infallible>(&[0x01, 0x22, 0x03, 0x00]).unwrap();
+    assert_eq!(
+        header,
+        AclDataHeader {
+            conn_handle: ConnectionHandle(0x0201),
+            packet_boundary: PacketBoundary::FirstFlushable,
+            broadcast: BroadcastFlag::PointToPoint,
+            data_len: 3,
+        }
+    );
+}
+
+#[test]
+fn acl_data_header_bad_broadcast_flag() {
+    let err = AclDataHeader::from_bytes::<Infallible>(&[0x01, 0x82, 0x03, 0x00])
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadBroadcastFlag(0b10));
+}
+
+#[test]
+fn acl_data_header_copy_into_slice() {
+    let header = AclDataHeader {
+        conn_handle: ConnectionHandle(0x0201),
+        packet_boundary: PacketBoundary::Continuing,
+        broadcast: BroadcastFlag::BrEdrBroadcast,
+        data_len: 0x0102,
+    };
+    let mut bytes = [0; AclDataHeader::HEADER_LENGTH];
+    header.copy_into_slice(&mut bytes);
+    assert_eq!(bytes, [0x02, 0x01, 0x52, 0x02, 0x01]);
+}
+
+#[tokio::test]
+async fn read_acl_data() {
+    let mut controller = ScriptedController::new(&[&[0x02, 0x01, 0x22, 0x05, 0x00, 1, 2, 3, 4, 5]]);
+    match controller.read().await {
+        Ok(Packet::AclData(acl)) => {
+            assert_eq!(acl.header.conn_handle, ConnectionHandle(0x0201));
+            assert_eq!(acl.header.packet_boundary, PacketBoundary::FirstFlushable);
+            assert_eq!(acl.header.broadcast, BroadcastFlag::PointToPoint);
+            assert_eq!(acl.data(), [1, 2, 3, 4, 5]);
+        }
+        other => panic!("Did not get ACL data: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn read_acl_data_too_long() {
+    let mut controller = ScriptedController::new(&[&[0x02, 0x01, 0x22, 0xFC, 0x00]]);
+    match controller.read().await {
+        Err(Error::AclDataTooLong(len)) => assert_eq!(len, 252),
+        other => panic!("Did not get ACL data too long: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn read_sync_data_is_not_supported() {
+    let mut controller = ScriptedController::new(&[&[0x03, 0x01, 0x02, 0x00]]);
+    match controller.read().await {
+        Err(Error::BadPacketType(packet_type)) => assert_eq!(packet_type, 0x03),
+        other => panic!("Did not get bad packet type: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn read_comm_error() {
+    let mut controller = ScriptedController::new(&[]);
+    match controller.read().await {
+        Err(Error::Comm(ScriptExhausted)) => (),
+        other => panic!("Did not get communication error: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn write_acl_data() {
+    let mut sink = RecordingSink::new();
+    sink.write_acl_data(
+        ConnectionHandle(0x0201),
+        PacketBoundary::FirstNonFlushable,
+        &[1, 2, 3],
+    )
+    .await
+    .unwrap();
+    assert_eq!(sink.written_data, [0x02, 0x01, 0x02, 0x03, 0x00, 1, 2, 3]);
+}
+
+#[tokio::test]
+async fn write_acl_data_too_long() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .write_acl_data(
+            ConnectionHandle(0x0201),
+            PacketBoundary::Continuing,
+            &[0; 252],
+        )
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::AclDataTooLong(252));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn packet_copy_into_slice_matches_read_packet() {
+    let acl_data: &[u8] = &[0x02, 0x01, 0x22, 0x03, 0x00, 1, 2, 3];
+    let event: &[u8] = &[0x04, 0x0F, 4, 0x00, 8, 0x01, 0x02];
+    let mut controller = ScriptedController::new(&[acl_data, event]);
+    for expected in [acl_data, event] {
+        let packet = controller.read().await.unwrap();
+        let mut bytes = [0; MAX_PACKET_LENGTH];
+        let len = packet.copy_into_slice(&mut bytes);
+        assert_eq!(bytes[..len], *expected);
+    }
+}
@@ -0,0 +1,276 @@
+extern crate stm32wb_hci as hci;
+
+use hci::event::{Event, Packet, MAX_EVENT_LENGTH};
+use hci::vendor::event::*;
+use hci::{BdAddr, ConnectionHandle};
+
+fn vendor_event(buffer: &[u8]) -> VendorEvent {
+    match Event::new(Packet(buffer)) {
+        Ok(Event::Vendor(event)) => event,
+        other => panic!("Did not get vendor event: {:?}", other),
+    }
+}
+
+fn assert_round_trip(buffer: &[u8]) {
+    let event = Event::new(Packet(buffer)).unwrap();
+    let mut bytes = [0; MAX_EVENT_LENGTH];
+    let len = event.copy_into_slice(&mut bytes);
+    assert_eq!(bytes[..len], *buffer);
+}
+
+#[test]
+fn coprocessor_ready() {
+    let buffer = [0xFF, 3, 0x00, 0x92, 0x01];
+    match vendor_event(&buffer) {
+        VendorEvent::CoprocessorReady(kind) => assert_eq!(kind, FirmwareKind::Rcc),
+        other => panic!("Did not get coprocessor ready: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn gap_device_found() {
+    let buffer = [
+        0xFF, 14, 0x06, 0x04, 0x00, 0x01, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 2, 0xAA, 0xBB, 0xF6,
+    ];
+    match vendor_event(&buffer) {
+        VendorEvent::GapDeviceFound(event) => {
+            assert_eq!(
+                event.bdaddr,
+                hci::BdAddrType::Random(BdAddr([0x01, 0x02, 0x03, 0x04, 0x05, 0x06]))
+            );
+            assert_eq!(event.data(), [0xAA, 0xBB]);
+            assert_eq!(event.rssi, Some(-10));
+        }
+        other => panic!("Did not get device found: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn events_lost() {
+    let buffer = [
+        0xFF, 10, 0x02, 0x00, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+    match vendor_event(&buffer) {
+        VendorEvent::EventsLost(flags) => assert_eq!(flags.bits(), 0x0022),
+        other => panic!("Did not get events lost: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn gap_keypress_notification() {
+    let buffer = [0xFF, 5, 0x0A, 0x04, 0x01, 0x02, 0x03];
+    match vendor_event(&buffer) {
+        VendorEvent::GapKeypressNotification(event) => {
+            assert_eq!(event.connection_handle, ConnectionHandle(0x0201));
+            assert!(matches!(
+                event.notification_type,
+                KeypressNotificationType::PasskeyCleared
+            ));
+        }
+        other => panic!("Did not get keypress notification: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn l2cap_command_reject() {
+    let buffer = [
+        0xFF, 10, 0x0A, 0x08, 0x01, 0x02, 0x07, 0x02, 0x00, 2, 0x40, 0x00,
+    ];
+    match vendor_event(&buffer) {
+        VendorEvent::L2CapCommandReject(event) => {
+            assert_eq!(event.conn_handle, ConnectionHandle(0x0201));
+            assert_eq!(event.identifier, 0x07);
+            assert_eq!(event.reason, 0x0002);
+            assert_eq!(event.data[..event.data_len as usize], [0x40, 0x00]);
+        }
+        other => panic!("Did not get command reject: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn l2cap_coc_disconnect() {
+    let buffer = [0xFF, 3, 0x14, 0x08, 0x05];
+    match vendor_event(&buffer) {
+        VendorEvent::L2CapCocDisconnect(channel_index) => assert_eq!(channel_index, 0x05),
+        other => panic!("Did not get CoC disconnect: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn gatt_eatt_bearer() {
+    let buffer = [0xFF, 5, 0x19, 0x0C, 0x03, 0x01, 0x41];
+    match vendor_event(&buffer) {
+        VendorEvent::GattEattBrearer(event) => {
+            assert_eq!(event.channel_index, 0x03);
+            assert!(matches!(event.eab_state, EabState::AttBearerTerminated));
+            assert!(matches!(event.status, GattProcedureStatus::Failed));
+        }
+        other => panic!("Did not get EATT bearer: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn gatt_multi_notification() {
+    let buffer = [
+        0xFF, 11, 0x1A, 0x0C, 0x01, 0x02, 0x05, 0x00, 3, 0x00, 0x0A, 0x0B, 0x0C,
+    ];
+    match vendor_event(&buffer) {
+        VendorEvent::GattMultiNotification(event) => {
+            assert_eq!(event.conn_handle, ConnectionHandle(0x0201));
+            assert_eq!(event.offset, 5);
+            assert_eq!(event.data_len, 3);
+            assert_eq!(event.data[..3], [0x0A, 0x0B, 0x0C]);
+        }
+        other => panic!("Did not get multi notification: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn gatt_read_ext() {
+    let buffer = [
+        0xFF, 11, 0x1D, 0x0C, 0x01, 0x02, 0x05, 0x00, 3, 0x00, 0x0A, 0x0B, 0x0C,
+    ];
+    match vendor_event(&buffer) {
+        VendorEvent::GattReadExt(event) => {
+            assert_eq!(event.conn_handle, ConnectionHandle(0x0201));
+            assert_eq!(event.offset, 5);
+            assert_eq!(event.value(), [0x0A, 0x0B, 0x0C]);
+        }
+        other => panic!("Did not get read ext: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn gatt_notification_ext() {
+    let buffer = [
+        0xFF, 13, 0x1F, 0x0C, 0x01, 0x02, 0x03, 0x04, 0x05, 0x00, 3, 0x00, 0x0A, 0x0B, 0x0C,
+    ];
+    match vendor_event(&buffer) {
+        VendorEvent::GattNotificationExt(event) => {
+            assert_eq!(event.conn_handle, ConnectionHandle(0x0201));
+            assert_eq!(event.attribute_handle, AttributeHandle(0x0403));
+            assert_eq!(event.offset, 5);
+            assert_eq!(event.value(), [0x0A, 0x0B, 0x0C]);
+        }
+        other => panic!("Did not get notification: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn hal_end_of_radio_activity() {
+    let buffer = [
+        0xFF, 10, 0x04, 0x00, 0x01, 0x05, 0x01, 0x02, 0x03, 0x04, 7, 9,
+    ];
+    match vendor_event(&buffer) {
+        VendorEvent::HalEndOfRadioActivity(event) => {
+            assert!(matches!(event.last_state, RadioEvent::Advertising));
+            assert!(matches!(event.next_state, RadioEvent::CentralConnection));
+            assert_eq!(event.next_state_sys_time, 0x0403_0201);
+            assert_eq!(event.last_state_slot, 7);
+            assert_eq!(event.next_state_slot, 9);
+        }
+        other => panic!("Did not get end of radio activity: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn hal_scan_req_report() {
+    let buffer = [
+        0xFF, 10, 0x05, 0x00, 0xC4, 0x01, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06,
+    ];
+    match vendor_event(&buffer) {
+        VendorEvent::HalScanReqReport(event) => {
+            assert_eq!(event.rssi, 0xC4);
+            match event.peer_addr {
+                hci::host::PeerAddrType::RandomDeviceAddress(addr) => {
+                    assert_eq!(addr, BdAddr([0x01, 0x02, 0x03, 0x04, 0x05, 0x06]))
+                }
+                other => panic!("Did not get random device address: {:?}", other),
+            }
+        }
+        other => panic!("Did not get scan request report: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn hal_firmware_error() {
+    let buffer = [0xFF, 6, 0x06, 0x00, 0x03, 2, 0xAA, 0xBB];
+    match vendor_event(&buffer) {
+        VendorEvent::HalFirmwareError(event) => {
+            assert!(matches!(
+                event.fw_error_type,
+                FirmwareError::NvmLevelWarning
+            ));
+            assert_eq!(event.data(), [0xAA, 0xBB]);
+        }
+        other => panic!("Did not get firmware error: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn hal_scan_req_report_public_identity_address() {
+    let buffer = [
+        0xFF, 10, 0x05, 0x00, 0xC4, 0x02, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06,
+    ];
+    match vendor_event(&buffer) {
+        VendorEvent::HalScanReqReport(event) => match event.peer_addr {
+            hci::host::PeerAddrType::PublicIdentityAddress(addr) => {
+                assert_eq!(addr, BdAddr([0x01, 0x02, 0x03, 0x04, 0x05, 0x06]))
+            }
+            other => panic!("Did not get public identity address: {:?}", other),
+        },
+        other => panic!("Did not get scan request report: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn att_find_information_response() {
+    let buffer = [
+        0xFF, 14, 0x04, 0x0C, 0x01, 0x02, 1, 8, 0x01, 0x00, 0x00, 0x28, 0x02, 0x00, 0x03, 0x28,
+    ];
+    match vendor_event(&buffer) {
+        VendorEvent::AttFindInformationResponse(event) => match event.handle_uuid_pair_iter() {
+            HandleUuidPairIterator::Format16(pairs) => {
+                let pairs: Vec<_> = pairs.map(|pair| (pair.handle, pair.uuid)).collect();
+                assert_eq!(
+                    pairs,
+                    [
+                        (AttributeHandle(0x0001), Uuid16(0x2800)),
+                        (AttributeHandle(0x0002), Uuid16(0x2803))
+                    ]
+                );
+            }
+            HandleUuidPairIterator::Format128(_) => panic!("Did not get 16-bit UUIDs"),
+        },
+        other => panic!("Did not get find information response: {:?}", other),
+    }
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn gatt_attribute_modified_offset_drops_continuation_bit() {
+    let buffer = [
+        0xFF, 11, 0x01, 0x0C, 0x01, 0x02, 0x03, 0x04, 0x05, 0x80, 1, 0x00, 0xAA,
+    ];
+    let event = Event::new(Packet(&buffer)).unwrap();
+    let mut bytes = [0; MAX_EVENT_LENGTH];
+    let len = event.copy_into_slice(&mut bytes);
+    assert_eq!(
+        bytes[..len],
+        [0xFF, 11, 0x01, 0x0C, 0x01, 0x02, 0x03, 0x04, 0x05, 0x00, 1, 0x00, 0xAA]
+    );
+}